@@ -17,7 +17,7 @@ const DB_NAME =
   (typeof window !== "undefined" && window.__TEST_DB_NAME__) || "workout-data";
 
 // Tables that must be marked as CRRs for CRDT-based replication.
-const CRR_TABLES = ["exercises", "completed_sets", "settings", "workout_plans", "workout_plan_exercises", "workout_templates", "workout_template_exercises"];
+const CRR_TABLES = ["exercises", "completed_sets", "settings", "workout_plans", "workout_plan_exercises", "workout_templates", "workout_template_exercises", "plates", "bodyweight_log", "programs", "program_days", "personal_records"];
 
 // Migration sentinel key — checked in both localStorage (legacy) and in the DB
 // itself (new: stored atomically with the migrated data).
@@ -293,18 +293,19 @@ export async function executeQuery(sql, params) {
  *
  * @param {Uint8Array} data     The raw bytes to download.
  * @param {string}     filename The suggested filename.
+ * @param {string}     mimeType Content type of the file (defaults to SQLite).
  * @returns {Promise<{ok: boolean, method?: string, error?: string, byteSize?: number}>}
  */
-export async function downloadBytes(data, filename) {
+export async function downloadBytes(data, filename, mimeType = "application/x-sqlite3") {
   const byteSize = data.length;
   console.log(`[Export] Attempting download: filename=${filename}, bytes=${byteSize}`);
 
   try {
-    const blob = new Blob([data], { type: "application/x-sqlite3" });
+    const blob = new Blob([data], { type: mimeType });
 
     // ── Strategy 1: Web Share API with files ────────────────────────────────
     if (typeof navigator !== "undefined" && navigator.share && navigator.canShare) {
-      const file = new File([blob], filename, { type: "application/x-sqlite3" });
+      const file = new File([blob], filename, { type: mimeType });
       const shareData = { files: [file] };
       try {
         if (navigator.canShare(shareData)) {
@@ -684,6 +685,43 @@ async function migrateFromSqlJs(fileData, { clearFirst = false } = {}) {
   }
 }
 
+/**
+ * Read every row of the given tables from a SQLite file without touching the
+ * app database. Used by merge-import, which plans the merge in Rust. Tables
+ * missing from the file (older backups) yield empty arrays.
+ *
+ * @param {Uint8Array} fileData Raw bytes of the incoming SQLite database.
+ * @param {string[]}   tables   Names of the tables to read.
+ * @returns {Promise<Object<string, Object[]>>} Rows keyed by table name.
+ */
+export async function readDatabaseTables(fileData, tables) {
+  if (typeof window.initSqlJs === "undefined") {
+    await loadScript("sql-wasm.js");
+  }
+
+  const SQL = await window.initSqlJs({ locateFile: (file) => file });
+  const otherDb = new SQL.Database(new Uint8Array(fileData));
+  try {
+    const result = {};
+    for (const table of tables) {
+      const rows = [];
+      try {
+        const stmt = otherDb.prepare(`SELECT * FROM "${table}"`);
+        while (stmt.step()) {
+          rows.push(stmt.getAsObject());
+        }
+        stmt.free();
+      } catch {
+        // Table doesn't exist in this file.
+      }
+      result[table] = rows;
+    }
+    return result;
+  } finally {
+    otherDb.close();
+  }
+}
+
 /**
  * Dynamically load a script by inserting a <script> tag.
  * Returns a promise that resolves when the script has loaded.
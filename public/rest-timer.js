@@ -0,0 +1,47 @@
+// Rest timer alerts: vibration plus, when the app is in the background, a
+// system notification. Every call is best-effort — browsers without the
+// Vibration or Notification APIs (iOS Safari) simply get no alert.
+
+/**
+ * Asks for notification permission the first time a rest timer starts.
+ * Does nothing once the user has granted or denied it.
+ */
+export function requestRestNotificationPermission() {
+  if (typeof Notification === "undefined" || Notification.permission !== "default") {
+    return;
+  }
+  try {
+    Notification.requestPermission().catch(() => {});
+  } catch (error) {
+    console.log("[RestTimer] Notification permission request failed:", error);
+  }
+}
+
+/**
+ * Signals that the rest for `exerciseName` is over.
+ */
+export function notifyRestOver(exerciseName) {
+  if (typeof navigator !== "undefined" && typeof navigator.vibrate === "function") {
+    navigator.vibrate([200, 100, 200]);
+  }
+  if (
+    typeof Notification !== "undefined" &&
+    Notification.permission === "granted" &&
+    typeof document !== "undefined" &&
+    document.hidden
+  ) {
+    try {
+      new Notification("Rest over", { body: `Next set of ${exerciseName}`, tag: "rest-timer" });
+    } catch (error) {
+      // Android Chrome only allows notifications from a service worker.
+      navigator.serviceWorker?.ready
+        .then((registration) =>
+          registration.showNotification("Rest over", {
+            body: `Next set of ${exerciseName}`,
+            tag: "rest-timer",
+          }),
+        )
+        .catch(() => console.log("[RestTimer] Notification failed:", error));
+    }
+  }
+}
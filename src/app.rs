@@ -1,17 +1,22 @@
+use crate::components::analysis_view::AnalysisView;
 use crate::components::bottom_sheet::{BottomSheet, BottomSheetItem, BottomSheetVariant};
 use crate::components::confirmation_dialog::{ConfirmVariant, ConfirmationDialog};
 #[cfg(debug_assertions)]
 use crate::components::debug_panel::DebugPanel;
+use crate::components::e1rm_chart::E1rmChart;
 use crate::components::exercise_form::ExerciseForm;
 use crate::components::history_view::HistoryView;
 use crate::components::library_view::LibraryView;
+use crate::components::personal_records::{NewRecordsBanner, PersonalRecordsList};
+use crate::components::plate_breakdown::PlateBreakdown;
+use crate::components::rest_timer::{RestTimerBanner, use_rest_timer_alert};
 use crate::components::rpe_slider::RPESlider;
 use crate::components::settings_view::SettingsView;
 use crate::components::sync_status_indicator::SyncStatusIndicator;
 use crate::components::tab_bar::{Tab, TabBar};
 use crate::components::tape_measure::TapeMeasure;
 use crate::components::workout_view::WorkoutView;
-use crate::models::{CompletedSet, SetType, SetTypeConfig};
+use crate::models::{CompletedSet, SetKind, SetTypeConfig};
 use crate::state::{
     InitializationState, WorkoutError, WorkoutState, WorkoutStateManager, is_archive_blocked,
 };
@@ -275,7 +280,7 @@ fn LibraryTab() -> Element {
 #[component]
 fn AnalysisTab() -> Element {
     let state = consume_context::<WorkoutState>();
-    rsx! { HistoryView { state, exercise_id: None } }
+    rsx! { AnalysisView { state } }
 }
 
 #[component]
@@ -333,6 +338,8 @@ fn LibraryExercise(exercise_id: String) -> Element {
     let mut show_permanent_delete_dialog = use_signal(|| false);
     let mut edit_muscle_groups: Signal<Option<Vec<crate::models::ExerciseMuscleGroup>>> =
         use_signal(|| None);
+    // Day tapped on the e1RM chart, scrolled to in the history below.
+    let mut focus_day: Signal<Option<String>> = use_signal(|| None);
 
     // Look in active exercises first; fall back to archived exercises.
     let active_exercises = workout_state.exercises();
@@ -420,6 +427,7 @@ fn LibraryExercise(exercise_id: String) -> Element {
                 ExerciseForm {
                     initial_exercise: Some(exercise.clone()),
                     initial_muscle_groups: loaded_groups,
+                    unit: workout_state.settings().weight_unit,
                     on_cancel: move |_| {
                         show_edit_form.set(false);
                         edit_muscle_groups.set(None);
@@ -761,9 +769,21 @@ fn LibraryExercise(exercise_id: String) -> Element {
             }
 
             // Body
+            E1rmChart {
+                state: workout_state,
+                exercise_id: exercise_id.clone(),
+                on_select: move |date: chrono::NaiveDate| {
+                    focus_day.set(Some(date.format("%Y-%m-%d").to_string()));
+                }
+            }
+            PersonalRecordsList {
+                state: workout_state,
+                exercise_id: exercise_id.clone()
+            }
             HistoryView {
                 state: workout_state,
-                exercise_id: Some(exercise_id)
+                exercise_id: Some(exercise_id),
+                focus_day: focus_day
             }
         }
     }
@@ -823,6 +843,9 @@ pub fn App() -> Element {
         }
     });
 
+    // Rest timer expiry alert, independent of which tab is showing.
+    use_rest_timer_alert(workout_state);
+
     // Trigger background sync exactly once when the database transitions to Ready.
     // Sync is non-blocking: the app is fully usable while sync runs.
     // Sync short-circuits if no credentials are configured (see SyncCredentials::load),
@@ -1319,7 +1342,17 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
     let session_for_display = session_clone.clone();
     let mut reps_input = use_signal(|| session.predicted.reps as f64);
     let mut rpe_input = use_signal(|| session.predicted.rpe as f64);
+    // Weight is held in canonical kg; conversion to the display unit happens
+    // only at the readout, step buttons and tape measure below.
     let mut weight_input = use_signal(|| session.predicted.weight.map(|w| w as f64).unwrap_or(0.0));
+    // Seconds or metres for timed, distance and carry exercises.
+    let mut measure_input = use_signal(|| session.predicted.measure.unwrap_or(0.0));
+    let unit = state.settings().weight_unit;
+    // Kind applied to the next logged set; warm-ups are logged from the
+    // warm-up checklist instead.
+    let mut kind_input = use_signal(|| SetKind::Working);
+    // Free-text comment attached to the next logged set only.
+    let mut comment_input = use_signal(String::new);
 
     // Sync inputs when session or predicted changes (e.g., after logging a set or starting a new session)
     let mut last_session_id = use_signal(|| session.session_id.clone());
@@ -1327,35 +1360,43 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
 
     if *last_session_id.peek() != session.session_id || *last_predicted.peek() != session.predicted
     {
+        if *last_session_id.peek() != session.session_id {
+            kind_input.set(SetKind::Working);
+        }
         last_session_id.set(session.session_id.clone());
         last_predicted.set(session.predicted);
         reps_input.set(session.predicted.reps as f64);
         rpe_input.set(session.predicted.rpe as f64);
         weight_input.set(session.predicted.weight.map(|w| w as f64).unwrap_or(0.0));
+        measure_input.set(session.predicted.measure.unwrap_or(0.0));
     }
 
     let state_for_log = state;
     let session_for_log = session_clone.clone();
     let log_set = move |_| {
         let session = &session_for_log;
-        let reps = reps_input() as u32;
-        let rpe = rpe_input() as f32;
-        let weight = if session.predicted.weight.is_some() {
-            Some(weight_input() as f32)
+        let config = &session.exercise.set_type_config;
+        // Timed, distance and carry sets are a single effort.
+        let reps = if config.measure_increment().is_some() {
+            1
         } else {
-            None
+            reps_input() as u32
         };
+        let rpe = rpe_input() as f32;
 
         let set = CompletedSet {
             set_number: (session.completed_sets.len() + 1) as u32,
             reps,
             rpe,
-            set_type: if let Some(w) = weight {
-                SetType::Weighted { weight: w }
-            } else {
-                SetType::Bodyweight
-            },
+            set_type: config.set_type(
+                weight_input() as f32,
+                measure_input(),
+                session.bodyweight.unwrap_or(0.0),
+            ),
+            kind: kind_input(),
+            comment: comment_input().trim().to_string(),
         };
+        comment_input.set(String::new());
 
         let state_clone = state_for_log;
         spawn(async move {
@@ -1371,10 +1412,108 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
     let mut show_complete_confirm = use_signal(|| false);
     let mut show_discard_confirm = use_signal(|| false);
 
+    let weight_config = match session_for_display.exercise.set_type_config {
+        SetTypeConfig::Weighted {
+            min_weight,
+            increment,
+            bar_weight,
+        } => Some((min_weight, increment, bar_weight)),
+        SetTypeConfig::Carry {
+            min_weight,
+            increment,
+            ..
+        } => Some((min_weight, increment, None)),
+        SetTypeConfig::LoadedBodyweight { increment, .. } => Some((0.0, increment, None)),
+        _ => None,
+    };
+    // `Some(assisted)` for loaded bodyweight exercises, whose dial holds the
+    // added or assisting weight rather than the whole load.
+    let loaded_bodyweight = match session_for_display.exercise.set_type_config {
+        SetTypeConfig::LoadedBodyweight { assisted, .. } => Some(assisted),
+        _ => None,
+    };
+    let weight_label = match loaded_bodyweight {
+        Some(true) => "Assistance",
+        Some(false) => "Added",
+        None => "Weight",
+    };
+    let measure_step = session_for_display
+        .exercise
+        .set_type_config
+        .measure_increment();
+    // Step buttons move five increments at a time, like the reps buttons.
+    let measure_jump = measure_step.unwrap_or(0.0) * 5.0;
+    let (measure_label, max_measure) = match session_for_display.exercise.set_type_config {
+        SetTypeConfig::Timed { .. } => ("Time", crate::models::MAX_DURATION_SECS as f64),
+        _ => ("Distance", crate::models::MAX_DISTANCE_M as f64),
+    };
+    let measure_text = crate::format::fmt_measure(
+        &session_for_display.exercise.set_type_config,
+        measure_input(),
+    );
+
     rsx! {
         div {
             class: "max-w-md mx-auto space-y-2",
 
+            // Exercise notes (form cues)
+            if !session_for_display.exercise.notes.trim().is_empty() {
+                div {
+                    class: "alert bg-base-200 text-sm whitespace-pre-line py-2",
+                    "data-testid": "exercise-notes",
+                    "{session_for_display.exercise.notes}"
+                }
+            }
+
+            // Rep Range / RPE overrides from the Plan Slot
+            if !session_for_display.targets.is_empty() {
+                div {
+                    class: "text-center text-sm font-semibold text-base-content/60",
+                    "data-testid": "session-slot-targets",
+                    "Target: {session_for_display.targets.summary()}"
+                }
+            }
+
+            NewRecordsBanner { state }
+
+            RestTimerBanner { state }
+
+            // Warm-up pre-sets (until the first working set is logged)
+            if session_for_display.completed_sets.is_empty() && !session_for_display.warmups.is_empty() {
+                div {
+                    class: "card bg-base-100 shadow-xl",
+                    "data-testid": "warmup-list",
+                    div {
+                        class: "card-body p-2 sm:p-4 gap-1",
+                        span { class: "text-sm font-semibold text-base-content/60 uppercase px-1", "Warm-up" }
+                        for (index, warmup) in session_for_display.warmups.iter().copied().enumerate() {
+                            label {
+                                key: "{index}",
+                                class: "label cursor-pointer justify-start gap-3 py-1 px-1",
+                                "data-testid": "warmup-set",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "checkbox checkbox-sm",
+                                    checked: warmup.done,
+                                    disabled: warmup.done,
+                                    onchange: move |_| {
+                                        spawn(async move {
+                                            if let Err(e) = WorkoutStateManager::log_warmup(&state, index).await {
+                                                WorkoutStateManager::handle_error(&state, e);
+                                            }
+                                        });
+                                    },
+                                }
+                                span {
+                                    class: if warmup.done { "font-mono line-through opacity-50" } else { "font-mono font-bold" },
+                                    "{crate::format::fmt_weight_in(warmup.weight, unit)} × {warmup.reps}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // Input Section
             div {
                 class: "card bg-base-100 shadow-xl",
@@ -1384,10 +1523,10 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
                         class: "flex flex-col gap-2 items-stretch w-full",
 
                         // Weight Input (compact: inline header + tape measure)
-                        if let SetTypeConfig::Weighted { min_weight, increment } = session_for_display.exercise.set_type_config {
+                        if let Some((min_weight, increment, bar_weight)) = weight_config {
                             div {
                                 class: "form-control w-full",
-                                // Row 1: [−10] Weight 80kg [+10]
+                                // Row 1: [−10] Weight 80kg [+10] (steps are in the display unit)
                                 div {
                                     class: "flex items-center justify-between w-full px-1",
                                     button {
@@ -1396,7 +1535,9 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
                                         onclick: {
                                             let min = min_weight as f64;
                                             move |_| {
-                                                let new_val = (weight_input() - 10.0).clamp(min, 500.0);
+                                                let new_val = unit
+                                                    .to_kg(unit.from_kg(weight_input()) - 10.0)
+                                                    .clamp(min, 500.0);
                                                 if (new_val - weight_input()).abs() > 0.001 {
                                                     weight_input.set(new_val);
                                                 }
@@ -1409,12 +1550,12 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
                                         span {
                                             class: "text-sm font-semibold text-base-content/60 uppercase",
                                             "data-testid": "weight-label",
-                                            "Weight"
+                                            "{weight_label}"
                                         }
                                         span {
                                             class: "text-2xl font-black text-primary",
                                             "data-testid": "weight-readout",
-                                            "{crate::format::fmt_weight(weight_input())} kg"
+                                            {crate::format::fmt_weight_in(weight_input(), unit)}
                                         }
                                     }
                                     button {
@@ -1423,7 +1564,9 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
                                         onclick: {
                                             let min = min_weight as f64;
                                             move |_| {
-                                                let new_val = (weight_input() + 10.0).clamp(min, 500.0);
+                                                let new_val = unit
+                                                    .to_kg(unit.from_kg(weight_input()) + 10.0)
+                                                    .clamp(min, 500.0);
                                                 if (new_val - weight_input()).abs() > 0.001 {
                                                     weight_input.set(new_val);
                                                 }
@@ -1434,72 +1577,157 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
                                 }
                                 // Row 2: TapeMeasure
                                 TapeMeasure {
-                                    value: weight_input(),
-                                    min: min_weight as f64,
-                                    max: 500.0,
-                                    step: increment as f64,
-                                    on_change: move |val| weight_input.set(val)
+                                    value: unit.from_kg(weight_input()),
+                                    min: unit.from_kg(min_weight as f64),
+                                    max: unit.from_kg(500.0),
+                                    step: unit.from_kg(increment as f64),
+                                    on_change: move |val| {
+                                        weight_input.set(unit.to_kg(val).clamp(min_weight as f64, 500.0))
+                                    }
+                                }
+                                if let Some(assisted) = loaded_bodyweight {
+                                    match session_for_display.bodyweight {
+                                        Some(bodyweight) => {
+                                            let added = if assisted { -weight_input() } else { weight_input() };
+                                            rsx! {
+                                                div {
+                                                    class: "text-center text-sm text-base-content/60",
+                                                    "data-testid": "system-load",
+                                                    "Bodyweight {crate::format::fmt_weight_in(bodyweight, unit)} · System load {crate::format::fmt_weight_in(bodyweight as f64 + added, unit)}"
+                                                }
+                                            }
+                                        }
+                                        None => rsx! {
+                                            div {
+                                                class: "alert alert-warning text-sm py-2",
+                                                "data-testid": "bodyweight-missing",
+                                                "Log your bodyweight in Settings to record this exercise."
+                                            }
+                                        },
+                                    }
+                                }
+                                if let Some(bar) = bar_weight {
+                                    if !state.plates().is_empty() {
+                                        PlateBreakdown {
+                                            target_kg: weight_input(),
+                                            bar_kg: bar as f64,
+                                            plates: state.plates(),
+                                            unit,
+                                        }
+                                    }
                                 }
                             }
                         }
 
-                        // Reps Input (compact: inline header + tape measure)
-                        div {
-                            class: "form-control w-full",
-                            // Row 1: [−1] Reps 5 [+5]
+                        // Time / distance input for timed, distance and carry exercises
+                        if let Some(step) = measure_step {
                             div {
-                                class: "flex items-center justify-between w-full px-1",
-                                button {
-                                    "data-testid": "reps-step-down",
-                                    class: "btn btn-circle btn-sm glass border border-error/30 hover:border-error text-error transition-all",
-                                    onclick: move |_| {
-                                        let new_val = (reps_input() - 5.0).clamp(1.0, 100.0);
-                                        if (new_val - reps_input()).abs() > 0.001 {
-                                            reps_input.set(new_val);
+                                class: "form-control w-full",
+                                div {
+                                    class: "flex items-center justify-between w-full px-1",
+                                    button {
+                                        "data-testid": "measure-step-down",
+                                        class: "btn btn-circle btn-sm glass border border-error/30 hover:border-error text-error transition-all",
+                                        onclick: move |_| {
+                                            let new_val = (measure_input() - measure_jump).clamp(step, max_measure);
+                                            if (new_val - measure_input()).abs() > 0.001 {
+                                                measure_input.set(new_val);
+                                            }
+                                        },
+                                        "−{measure_jump}"
+                                    }
+                                    div {
+                                        class: "flex items-baseline gap-2",
+                                        span {
+                                            class: "text-sm font-semibold text-base-content/60 uppercase",
+                                            "data-testid": "measure-label",
+                                            "{measure_label}"
                                         }
-                                    },
-                                    "−5"
+                                        span {
+                                            class: "text-2xl font-black text-primary",
+                                            "data-testid": "measure-readout",
+                                            "{measure_text}"
+                                        }
+                                    }
+                                    button {
+                                        "data-testid": "measure-step-up",
+                                        class: "btn btn-circle btn-sm glass border border-success/30 hover:border-success text-success transition-all",
+                                        onclick: move |_| {
+                                            let new_val = (measure_input() + measure_jump).clamp(step, max_measure);
+                                            if (new_val - measure_input()).abs() > 0.001 {
+                                                measure_input.set(new_val);
+                                            }
+                                        },
+                                        "+{measure_jump}"
+                                    }
                                 }
+                                TapeMeasure {
+                                    value: measure_input(),
+                                    min: step,
+                                    max: max_measure,
+                                    step,
+                                    on_change: move |val| measure_input.set(val)
+                                }
+                            }
+                        } else {
+                            // Reps Input (compact: inline header + tape measure)
+                            div {
+                                class: "form-control w-full",
+                                // Row 1: [−1] Reps 5 [+5]
                                 div {
-                                    class: "flex items-baseline gap-2",
-                                    span {
-                                        class: "text-sm font-semibold text-base-content/60 uppercase",
-                                        "data-testid": "reps-label",
-                                        "Reps"
-                                    }
-                                    span {
-                                        class: "text-2xl font-black text-primary",
-                                        "data-testid": "reps-readout",
-                                        "{reps_input}"
+                                    class: "flex items-center justify-between w-full px-1",
+                                    button {
+                                        "data-testid": "reps-step-down",
+                                        class: "btn btn-circle btn-sm glass border border-error/30 hover:border-error text-error transition-all",
+                                        onclick: move |_| {
+                                            let new_val = (reps_input() - 5.0).clamp(1.0, 100.0);
+                                            if (new_val - reps_input()).abs() > 0.001 {
+                                                reps_input.set(new_val);
+                                            }
+                                        },
+                                        "−5"
                                     }
-                                    if session_for_display.predicted.reps_clamped {
+                                    div {
+                                        class: "flex items-baseline gap-2",
+                                        span {
+                                            class: "text-sm font-semibold text-base-content/60 uppercase",
+                                            "data-testid": "reps-label",
+                                            "Reps"
+                                        }
                                         span {
-                                            class: "badge badge-warning badge-sm",
-                                            "data-testid": "reps-clamped-badge",
-                                            "clamped"
+                                            class: "text-2xl font-black text-primary",
+                                            "data-testid": "reps-readout",
+                                            "{reps_input}"
+                                        }
+                                        if session_for_display.predicted.reps_clamped {
+                                            span {
+                                                class: "badge badge-warning badge-sm",
+                                                "data-testid": "reps-clamped-badge",
+                                                "clamped"
+                                            }
                                         }
                                     }
+                                    button {
+                                        "data-testid": "reps-step-up",
+                                        class: "btn btn-circle btn-sm glass border border-success/30 hover:border-success text-success transition-all",
+                                        onclick: move |_| {
+                                            let new_val = (reps_input() + 5.0).clamp(1.0, 100.0);
+                                            if (new_val - reps_input()).abs() > 0.001 {
+                                                reps_input.set(new_val);
+                                            }
+                                        },
+                                        "+5"
+                                    }
                                 }
-                                button {
-                                    "data-testid": "reps-step-up",
-                                    class: "btn btn-circle btn-sm glass border border-success/30 hover:border-success text-success transition-all",
-                                    onclick: move |_| {
-                                        let new_val = (reps_input() + 5.0).clamp(1.0, 100.0);
-                                        if (new_val - reps_input()).abs() > 0.001 {
-                                            reps_input.set(new_val);
-                                        }
-                                    },
-                                    "+5"
+                                // Row 2: TapeMeasure
+                                TapeMeasure {
+                                    value: reps_input(),
+                                    min: 1.0,
+                                    max: 100.0,
+                                    step: 1.0,
+                                    on_change: move |val| reps_input.set(val)
                                 }
                             }
-                            // Row 2: TapeMeasure
-                            TapeMeasure {
-                                value: reps_input(),
-                                min: 1.0,
-                                max: 100.0,
-                                step: 1.0,
-                                on_change: move |val| reps_input.set(val)
-                            }
                         }
 
                         // RPE Input (compact: header with description + slider)
@@ -1533,6 +1761,35 @@ pub fn ActiveSession(state: WorkoutState, session: crate::state::WorkoutSession)
                         }
                     }
 
+                    // Comment for the next logged set
+                    input {
+                        r#type: "text",
+                        class: "input input-bordered input-sm w-full mt-2",
+                        "data-testid": "set-comment-input",
+                        placeholder: "Set comment (optional)",
+                        value: "{comment_input}",
+                        oninput: move |evt| comment_input.set(evt.value())
+                    }
+
+                    // Set kind for the next logged set
+                    div {
+                        class: "join w-full mt-2",
+                        "data-testid": "set-kind-toggle",
+                        for kind in SetKind::ALL.into_iter().filter(|k| *k != SetKind::Warmup) {
+                            button {
+                                key: "{kind}",
+                                class: if kind_input() == kind {
+                                    "btn btn-sm btn-primary join-item flex-1"
+                                } else {
+                                    "btn btn-sm btn-outline join-item flex-1"
+                                },
+                                "data-testid": "set-kind-{kind}",
+                                onclick: move |_| kind_input.set(kind),
+                                "{kind.label()}"
+                            }
+                        }
+                    }
+
                     // Log Set Button + Action Menu Trigger
                     div {
                         class: "mt-2 flex gap-2",
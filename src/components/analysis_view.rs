@@ -0,0 +1,310 @@
+use crate::app::Route;
+use crate::domain::analysis::{
+    AnalysisSnapshot, ExerciseLink, ExerciseProgress, TOP_MOVERS, summarize, top_movers,
+};
+use crate::models::{ProgressState, VolumeTargets, WeightUnit};
+use crate::state::{WorkoutState, WorkoutStateManager};
+use dioxus::prelude::*;
+
+/// Slope in kg per day as `"+1.2 kg/wk"` in `unit`.
+fn weekly_slope(slope: f64, unit: WeightUnit) -> String {
+    format!("{:+.1} {}/wk", unit.from_kg(slope * 7.0), unit)
+}
+
+/// The Analysis tab: weekly Volume per Muscle Group, a Progress State
+/// summary, training frequency and the Top Movers. Every exercise listed
+/// opens its Library page.
+#[component]
+pub fn AnalysisView(state: WorkoutState) -> Element {
+    let mut snapshot = use_signal(|| None::<AnalysisSnapshot>);
+
+    use_effect(move || {
+        spawn(async move {
+            match WorkoutStateManager::load_analysis(&state).await {
+                Ok(s) => snapshot.set(Some(s)),
+                Err(e) => log::warn!("Failed to load analysis: {}", e),
+            }
+        });
+    });
+
+    let Some(current) = snapshot() else {
+        return rsx! {
+            div {
+                class: "flex justify-center py-12",
+                "data-testid": "analysis-view",
+                span { class: "loading loading-spinner loading-md" }
+            }
+        };
+    };
+    let settings = state.settings();
+    let unit = settings.weight_unit;
+
+    rsx! {
+        div {
+            class: "pb-4",
+            "data-testid": "analysis-view",
+            h2 { class: "text-2xl font-bold mb-4", "Analysis" }
+            if current.progress.is_empty() && current.volumes.is_empty() {
+                p {
+                    class: "text-center text-base-content/50 py-12",
+                    "data-testid": "analysis-empty",
+                    "Log some sets to see your analysis."
+                }
+            } else {
+                VolumeCard { snapshot: current.clone(), targets: settings.volume_targets }
+                ProgressCard { snapshot: current.clone(), unit }
+                FrequencyCard { frequency: current.frequency.clone() }
+                TopMoversCard { progress: current.progress.clone(), unit }
+            }
+        }
+    }
+}
+
+/// A tappable exercise name that opens the Library detail page.
+#[component]
+fn ExerciseRow(exercise: ExerciseLink, children: Element) -> Element {
+    let navigator = use_navigator();
+    let id = exercise.exercise_id.clone();
+    rsx! {
+        button {
+            class: "flex justify-between items-center gap-2 w-full text-left text-sm py-1 hover:bg-base-200 rounded",
+            "data-testid": "analysis-exercise-link",
+            "data-exercise-id": "{exercise.exercise_id}",
+            onclick: move |_| {
+                navigator.push(Route::LibraryExercise { exercise_id: id.clone() });
+            },
+            span { class: "truncate", "{exercise.name}" }
+            {children}
+        }
+    }
+}
+
+#[component]
+fn VolumeCard(snapshot: AnalysisSnapshot, targets: VolumeTargets) -> Element {
+    let max = snapshot
+        .volumes
+        .iter()
+        .map(|v| v.this_week.max(v.weekly_average))
+        .fold(0.0, f64::max)
+        .max(1.0);
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-md mb-4",
+            "data-testid": "analysis-volume",
+            div {
+                class: "card-body p-4 gap-2",
+                h3 { class: "text-sm font-semibold uppercase text-base-content/60", "Weekly volume" }
+                if snapshot.volumes.is_empty() {
+                    p { class: "text-sm text-base-content/50", "No volume in the training window." }
+                }
+                for row in snapshot.volumes {
+                    {
+                        let label = format!("{:.1} / {:.1} sets", row.this_week, row.weekly_average);
+                        let zone = targets.zone(&row.group, row.this_week);
+                        let bar = format!(
+                            "width: {:.0}%; background-color: {}",
+                            row.this_week / max * 100.0,
+                            zone.color()
+                        );
+                        let average = format!("left: {:.0}%", row.weekly_average / max * 100.0);
+                        rsx! {
+                            details {
+                                key: "{row.group}",
+                                "data-testid": "analysis-volume-row",
+                                "data-zone": "{zone.label()}",
+                                summary {
+                                    class: "cursor-pointer list-none",
+                                    div {
+                                        class: "flex justify-between text-sm",
+                                        span { class: "font-medium", "{row.group}" }
+                                        span { class: "text-base-content/60", "{label}" }
+                                    }
+                                    div {
+                                        class: "relative h-2 rounded bg-base-200 mt-1",
+                                        div { class: "h-2 rounded", title: "{zone.label()}", style: "{bar}" }
+                                        div {
+                                            class: "absolute top-0 h-2 w-0.5 bg-base-content/60",
+                                            title: "Weekly average",
+                                            style: "{average}",
+                                        }
+                                    }
+                                }
+                                div {
+                                    class: "pl-2 pt-1",
+                                    for (sub, volume) in row.sub_muscles {
+                                        {
+                                            let sub_label = format!("{:.1} sets", volume);
+                                            rsx! {
+                                                div {
+                                                    key: "{sub}",
+                                                    class: "flex justify-between text-xs py-0.5",
+                                                    "data-testid": "analysis-sub-muscle",
+                                                    span { "{sub}" }
+                                                    span { class: "text-base-content/60", "{sub_label}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if row.exercises.is_empty() {
+                                        p { class: "text-xs text-base-content/50", "No exercise trains this as Primary." }
+                                    }
+                                    for exercise in row.exercises {
+                                        ExerciseRow { key: "{exercise.exercise_id}", exercise: exercise.clone() }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                p { class: "text-xs text-base-content/50", "Last 7 days / average week of the training window" }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProgressCard(snapshot: AnalysisSnapshot, unit: WeightUnit) -> Element {
+    let summary = summarize(&snapshot.progress);
+    let mut lifts = snapshot.progress.clone();
+    // Stalled lifts first, they are the ones worth a look.
+    lifts.sort_by_key(|p| match p.state {
+        ProgressState::Stalled { .. } => 0,
+        ProgressState::Progressing { .. } => 1,
+        ProgressState::InsufficientData => 2,
+    });
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-md mb-4",
+            "data-testid": "analysis-progress",
+            div {
+                class: "card-body p-4 gap-2",
+                h3 { class: "text-sm font-semibold uppercase text-base-content/60", "Progress" }
+                div {
+                    class: "grid grid-cols-3 gap-2 text-center",
+                    div {
+                        "data-testid": "analysis-progressing",
+                        div { class: "text-2xl font-bold text-success", "{summary.progressing}" }
+                        div { class: "text-xs", "Progressing" }
+                    }
+                    div {
+                        "data-testid": "analysis-stalled",
+                        div { class: "text-2xl font-bold text-warning", "{summary.stalled}" }
+                        div { class: "text-xs", "Stalled" }
+                    }
+                    div {
+                        "data-testid": "analysis-insufficient",
+                        div { class: "text-2xl font-bold text-base-content/50", "{summary.insufficient}" }
+                        div { class: "text-xs", "Insufficient data" }
+                    }
+                }
+                for lift in lifts {
+                    ExerciseRow {
+                        key: "{lift.exercise.exercise_id}",
+                        exercise: lift.exercise.clone(),
+                        ProgressBadge { progress: lift.clone(), unit }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProgressBadge(progress: ExerciseProgress, unit: WeightUnit) -> Element {
+    let slope = progress.slope().map(|s| weekly_slope(s, unit));
+    let class = match progress.state {
+        ProgressState::Progressing { .. } => "badge badge-success badge-sm shrink-0",
+        ProgressState::Stalled { .. } => "badge badge-warning badge-sm shrink-0",
+        ProgressState::InsufficientData => "badge badge-ghost badge-sm shrink-0",
+    };
+    rsx! {
+        span {
+            class,
+            if let Some(slope) = slope { "{slope}" } else { "Not enough data" }
+        }
+    }
+}
+
+#[component]
+fn FrequencyCard(frequency: Vec<u32>) -> Element {
+    let weeks = frequency.len().max(1) as f64;
+    let average = format!(
+        "{:.1} days/wk",
+        frequency.iter().sum::<u32>() as f64 / weeks
+    );
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-md mb-4",
+            "data-testid": "analysis-frequency",
+            div {
+                class: "card-body p-4 gap-2",
+                div {
+                    class: "flex justify-between items-center",
+                    h3 { class: "text-sm font-semibold uppercase text-base-content/60", "Training frequency" }
+                    span { class: "badge badge-sm", "data-testid": "analysis-frequency-average", "{average}" }
+                }
+                div {
+                    class: "flex items-end gap-1 h-20",
+                    for (i, days) in frequency.iter().copied().enumerate() {
+                        {
+                            let height = format!("height: {:.0}%", days.min(7) as f64 / 7.0 * 100.0);
+                            rsx! {
+                                div {
+                                    key: "{i}",
+                                    class: "flex-1 bg-primary/70 rounded-t min-h-px",
+                                    "data-testid": "analysis-frequency-week",
+                                    "data-days": "{days}",
+                                    title: "{days} days",
+                                    style: "{height}",
+                                }
+                            }
+                        }
+                    }
+                }
+                p { class: "text-xs text-base-content/50", "Training days per week, oldest first" }
+            }
+        }
+    }
+}
+
+#[component]
+fn TopMoversCard(progress: Vec<ExerciseProgress>, unit: WeightUnit) -> Element {
+    let (rising, falling) = top_movers(&progress, TOP_MOVERS);
+    if rising.is_empty() && falling.is_empty() {
+        return rsx! {};
+    }
+    let rows = |list: Vec<&ExerciseProgress>, class: &'static str| {
+        list.into_iter()
+            .map(|p| {
+                (
+                    p.exercise.clone(),
+                    weekly_slope(p.slope().unwrap_or_default(), unit),
+                    class,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let rising = rows(rising, "text-success shrink-0");
+    let falling = rows(falling, "text-warning shrink-0");
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-md mb-4",
+            "data-testid": "analysis-top-movers",
+            div {
+                class: "card-body p-4 gap-2",
+                h3 { class: "text-sm font-semibold uppercase text-base-content/60", "Top movers" }
+                for (exercise, slope, class) in rising.into_iter().chain(falling) {
+                    ExerciseRow {
+                        key: "{exercise.exercise_id}",
+                        exercise: exercise.clone(),
+                        span { class, "data-testid": "analysis-mover-slope", "{slope}" }
+                    }
+                }
+            }
+        }
+    }
+}
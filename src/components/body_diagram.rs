@@ -1,8 +1,23 @@
-use crate::models::{ContributionTier, ExerciseMuscleGroup, MuscleGroup};
+use crate::models::{ContributionTier, ExerciseMuscleGroup, MuscleGroup, VolumeZone};
 use dioxus::prelude::*;
+use std::collections::HashMap;
 
 fn fill_for(muscle: &MuscleGroup, selections: &[ExerciseMuscleGroup]) -> &'static str {
-    match selections.iter().find(|s| &s.muscle_group == muscle) {
+    // A parent region shows a selected Sub-Muscle, and a Sub-Muscle region
+    // its selected parent, so a tag stays visible at either level.
+    let selected = selections
+        .iter()
+        .find(|s| &s.muscle_group == muscle)
+        .or_else(|| {
+            selections
+                .iter()
+                .find(|s| s.muscle_group.parent_id().as_ref() == Some(muscle))
+        })
+        .or_else(|| {
+            let parent = muscle.parent_id()?;
+            selections.iter().find(|s| s.muscle_group == parent)
+        });
+    match selected {
         Some(s) => match s.tier {
             ContributionTier::Primary => "#3b82f6",
             ContributionTier::Secondary => "#93c5fd",
@@ -12,6 +27,18 @@ fn fill_for(muscle: &MuscleGroup, selections: &[ExerciseMuscleGroup]) -> &'stati
     }
 }
 
+/// Outline colour and width of a region: the Volume Zone of its top-level
+/// group when known.
+fn outline_for(
+    muscle: &MuscleGroup,
+    zones: &HashMap<MuscleGroup, VolumeZone>,
+) -> (&'static str, &'static str) {
+    match zones.get(&muscle.top_level()) {
+        Some(zone) => (zone.color(), "3"),
+        None => ("#6b7280", "1.5"),
+    }
+}
+
 fn toggle(
     muscle: MuscleGroup,
     mut selections: Vec<ExerciseMuscleGroup>,
@@ -31,14 +58,21 @@ fn toggle(
 /// Interactive SVG body diagram for selecting muscle groups.
 ///
 /// The parent owns the selection state; this component only emits change events.
+/// With `zones`, each region is outlined in the colour of its Volume Zone.
+/// The Sub-muscles toggle splits Chest, Shoulders, Core, Quads, Back and
+/// Glutes into finer regions for their Sub-Muscles.
 #[component]
 pub fn BodyDiagram(
     /// Current muscle group selections.
     selections: Vec<ExerciseMuscleGroup>,
     /// Called with the updated selection list when the user taps a region.
     on_selection_change: EventHandler<Vec<ExerciseMuscleGroup>>,
+    /// Volume Zone of each Muscle Group's 7-day Volume.
+    #[props(default)]
+    zones: HashMap<MuscleGroup, VolumeZone>,
 ) -> Element {
     let mut show_back = use_signal(|| false);
+    let mut detailed = use_signal(|| false);
 
     let sel = selections.clone();
 
@@ -61,11 +95,19 @@ pub fn BodyDiagram(
                     onclick: move |_| show_back.set(true),
                     "Back"
                 }
+                button {
+                    "data-testid": "body-diagram-detail-btn",
+                    class: if detailed() { "join-item btn btn-sm btn-primary" } else { "join-item btn btn-sm btn-ghost" },
+                    onclick: move |_| detailed.set(!detailed()),
+                    "Sub-muscles"
+                }
             }
 
             if !show_back() {
                 FrontDiagram {
                     selections: sel.clone(),
+                    zones: zones.clone(),
+                    detailed: detailed(),
                     on_tap: move |muscle| {
                         on_selection_change.call(toggle(muscle, selections.clone()));
                     },
@@ -73,11 +115,59 @@ pub fn BodyDiagram(
             } else {
                 BackDiagram {
                     selections: sel.clone(),
+                    zones: zones.clone(),
+                    detailed: detailed(),
                     on_tap: move |muscle| {
                         on_selection_change.call(toggle(muscle, selections.clone()));
                     },
                 }
             }
+
+            if !zones.is_empty() {
+                div {
+                    "data-testid": "volume-zone-legend",
+                    class: "flex flex-wrap justify-center gap-x-3 gap-y-1 text-xs text-base-content/70",
+                    for zone in [VolumeZone::Under, VolumeZone::Building, VolumeZone::OnTarget, VolumeZone::Over] {
+                        span {
+                            key: "{zone.label()}",
+                            class: "flex items-center gap-1",
+                            span {
+                                class: "inline-block w-3 h-3 rounded-sm border-2",
+                                style: "border-color: {zone.color()}",
+                            }
+                            "{zone.label()}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One tappable polygon of the detailed diagram. `mirror` marks the second
+/// side of a pair, which carries no test id.
+#[component]
+fn SubRegion(
+    muscle: MuscleGroup,
+    points: &'static str,
+    selections: Vec<ExerciseMuscleGroup>,
+    zones: HashMap<MuscleGroup, VolumeZone>,
+    #[props(default)] mirror: bool,
+    on_tap: EventHandler<MuscleGroup>,
+) -> Element {
+    let fill = fill_for(&muscle, &selections);
+    let (stroke, width) = outline_for(&muscle, &zones);
+    let testid = (!mirror).then(|| format!("region-{:?}", muscle));
+
+    rsx! {
+        polygon {
+            "data-testid": testid,
+            points: "{points}",
+            fill: "{fill}",
+            stroke: "{stroke}",
+            stroke_width: "{width}",
+            style: "cursor: pointer",
+            onclick: move |_| on_tap.call(muscle.clone()),
         }
     }
 }
@@ -85,14 +175,22 @@ pub fn BodyDiagram(
 #[component]
 fn FrontDiagram(
     selections: Vec<ExerciseMuscleGroup>,
+    zones: HashMap<MuscleGroup, VolumeZone>,
+    detailed: bool,
     on_tap: EventHandler<MuscleGroup>,
 ) -> Element {
     let chest_fill = fill_for(&MuscleGroup::Chest, &selections);
+    let (chest_stroke, chest_width) = outline_for(&MuscleGroup::Chest, &zones);
     let shoulders_fill = fill_for(&MuscleGroup::Shoulders, &selections);
+    let (shoulders_stroke, shoulders_width) = outline_for(&MuscleGroup::Shoulders, &zones);
     let biceps_fill = fill_for(&MuscleGroup::Biceps, &selections);
+    let (biceps_stroke, biceps_width) = outline_for(&MuscleGroup::Biceps, &zones);
     let forearms_fill = fill_for(&MuscleGroup::Forearms, &selections);
+    let (forearms_stroke, forearms_width) = outline_for(&MuscleGroup::Forearms, &zones);
     let core_fill = fill_for(&MuscleGroup::Core, &selections);
+    let (core_stroke, core_width) = outline_for(&MuscleGroup::Core, &zones);
     let quads_fill = fill_for(&MuscleGroup::Quads, &selections);
+    let (quads_stroke, quads_width) = outline_for(&MuscleGroup::Quads, &zones);
 
     rsx! {
         svg {
@@ -123,62 +221,137 @@ fn FrontDiagram(
                 stroke_width: "1",
             }
 
-            // ── Left Shoulder ──────────────────────────────────────────────
-            polygon {
-                "data-testid": "region-Shoulders",
-                points: "28,68 68,68 68,100 24,100",
-                fill: "{shoulders_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Shoulders)
-                },
-            }
+            if !detailed {
+                // ── Left Shoulder ──────────────────────────────────────────
+                polygon {
+                    "data-testid": "region-Shoulders",
+                    points: "28,68 68,68 68,100 24,100",
+                    fill: "{shoulders_fill}",
+                    stroke: "{shoulders_stroke}",
+                    stroke_width: "{shoulders_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Shoulders)
+                    },
+                }
 
-            // ── Right Shoulder ─────────────────────────────────────────────
-            polygon {
-                points: "132,68 172,68 176,100 132,100",
-                fill: "{shoulders_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: move |_| on_tap.call(MuscleGroup::Shoulders),
+                // ── Right Shoulder ─────────────────────────────────────────
+                polygon {
+                    points: "132,68 172,68 176,100 132,100",
+                    fill: "{shoulders_fill}",
+                    stroke: "{shoulders_stroke}",
+                    stroke_width: "{shoulders_width}",
+                    style: "cursor: pointer",
+                    onclick: move |_| on_tap.call(MuscleGroup::Shoulders),
+                }
+            } else {
+                SubRegion {
+                    muscle: MuscleGroup::SideDelts,
+                    points: "28,68 48,68 46,100 24,100",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::FrontDelts,
+                    points: "48,68 68,68 68,100 46,100",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::FrontDelts,
+                    points: "132,68 152,68 154,100 132,100",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::SideDelts,
+                    points: "152,68 172,68 176,100 154,100",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
             }
 
-            // ── Chest ──────────────────────────────────────────────────────
-            rect {
-                "data-testid": "region-Chest",
-                x: "68",
-                y: "68",
-                width: "64",
-                height: "50",
-                fill: "{chest_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Chest)
-                },
+            if !detailed {
+                // ── Chest ──────────────────────────────────────────────────
+                rect {
+                    "data-testid": "region-Chest",
+                    x: "68",
+                    y: "68",
+                    width: "64",
+                    height: "50",
+                    fill: "{chest_fill}",
+                    stroke: "{chest_stroke}",
+                    stroke_width: "{chest_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Chest)
+                    },
+                }
+            } else {
+                SubRegion {
+                    muscle: MuscleGroup::UpperChest,
+                    points: "68,68 132,68 132,90 68,90",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::LowerChest,
+                    points: "68,90 132,90 132,118 68,118",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
             }
 
-            // ── Core ───────────────────────────────────────────────────────
-            rect {
-                "data-testid": "region-Core",
-                x: "68",
-                y: "118",
-                width: "64",
-                height: "65",
-                fill: "{core_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Core)
-                },
+            if !detailed {
+                // ── Core ───────────────────────────────────────────────────
+                rect {
+                    "data-testid": "region-Core",
+                    x: "68",
+                    y: "118",
+                    width: "64",
+                    height: "65",
+                    fill: "{core_fill}",
+                    stroke: "{core_stroke}",
+                    stroke_width: "{core_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Core)
+                    },
+                }
+            } else {
+                SubRegion {
+                    muscle: MuscleGroup::Obliques,
+                    points: "68,118 82,118 82,183 68,183",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Abs,
+                    points: "82,118 118,118 118,183 82,183",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Obliques,
+                    points: "118,118 132,118 132,183 118,183",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
             }
 
             // ── Left Bicep ─────────────────────────────────────────────────
@@ -186,8 +359,8 @@ fn FrontDiagram(
                 "data-testid": "region-Biceps",
                 points: "20,100 68,100 65,155 15,155",
                 fill: "{biceps_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{biceps_stroke}",
+                stroke_width: "{biceps_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -199,8 +372,8 @@ fn FrontDiagram(
             polygon {
                 points: "132,100 180,100 185,155 135,155",
                 fill: "{biceps_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{biceps_stroke}",
+                stroke_width: "{biceps_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -213,8 +386,8 @@ fn FrontDiagram(
                 "data-testid": "region-Forearms",
                 points: "15,155 65,155 60,205 10,205",
                 fill: "{forearms_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{forearms_stroke}",
+                stroke_width: "{forearms_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -226,8 +399,8 @@ fn FrontDiagram(
             polygon {
                 points: "135,155 185,155 190,205 140,205",
                 fill: "{forearms_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{forearms_stroke}",
+                stroke_width: "{forearms_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -235,28 +408,61 @@ fn FrontDiagram(
                 },
             }
 
-            // ── Left Quad ──────────────────────────────────────────────────
-            polygon {
-                "data-testid": "region-Quads",
-                points: "68,183 97,183 93,305 62,305",
-                fill: "{quads_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Quads)
-                },
-            }
+            if !detailed {
+                // ── Left Quad ──────────────────────────────────────────────
+                polygon {
+                    "data-testid": "region-Quads",
+                    points: "68,183 97,183 93,305 62,305",
+                    fill: "{quads_fill}",
+                    stroke: "{quads_stroke}",
+                    stroke_width: "{quads_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Quads)
+                    },
+                }
 
-            // ── Right Quad ─────────────────────────────────────────────────
-            polygon {
-                points: "103,183 132,183 138,305 107,305",
-                fill: "{quads_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: move |_| on_tap.call(MuscleGroup::Quads),
+                // ── Right Quad ─────────────────────────────────────────────
+                polygon {
+                    points: "103,183 132,183 138,305 107,305",
+                    fill: "{quads_fill}",
+                    stroke: "{quads_stroke}",
+                    stroke_width: "{quads_width}",
+                    style: "cursor: pointer",
+                    onclick: move |_| on_tap.call(MuscleGroup::Quads),
+                }
+            } else {
+                SubRegion {
+                    muscle: MuscleGroup::Quads,
+                    points: "68,183 88,183 84,305 62,305",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Adductors,
+                    points: "88,183 97,183 93,305 84,305",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Adductors,
+                    points: "103,183 112,183 116,305 107,305",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Quads,
+                    points: "112,183 132,183 138,305 116,305",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
             }
 
             // ── Region Labels ──────────────────────────────────────────────
@@ -275,14 +481,26 @@ fn FrontDiagram(
 }
 
 #[component]
-fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<MuscleGroup>) -> Element {
+fn BackDiagram(
+    selections: Vec<ExerciseMuscleGroup>,
+    zones: HashMap<MuscleGroup, VolumeZone>,
+    detailed: bool,
+    on_tap: EventHandler<MuscleGroup>,
+) -> Element {
     let back_fill = fill_for(&MuscleGroup::Back, &selections);
+    let (back_stroke, back_width) = outline_for(&MuscleGroup::Back, &zones);
     let traps_fill = fill_for(&MuscleGroup::Traps, &selections);
+    let (traps_stroke, traps_width) = outline_for(&MuscleGroup::Traps, &zones);
     let shoulders_fill = fill_for(&MuscleGroup::Shoulders, &selections);
+    let (shoulders_stroke, shoulders_width) = outline_for(&MuscleGroup::Shoulders, &zones);
     let triceps_fill = fill_for(&MuscleGroup::Triceps, &selections);
+    let (triceps_stroke, triceps_width) = outline_for(&MuscleGroup::Triceps, &zones);
     let glutes_fill = fill_for(&MuscleGroup::Glutes, &selections);
+    let (glutes_stroke, glutes_width) = outline_for(&MuscleGroup::Glutes, &zones);
     let hamstrings_fill = fill_for(&MuscleGroup::Hamstrings, &selections);
+    let (hamstrings_stroke, hamstrings_width) = outline_for(&MuscleGroup::Hamstrings, &zones);
     let calves_fill = fill_for(&MuscleGroup::Calves, &selections);
+    let (calves_stroke, calves_width) = outline_for(&MuscleGroup::Calves, &zones);
 
     rsx! {
         svg {
@@ -318,8 +536,8 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
                 "data-testid": "region-Traps",
                 points: "68,52 132,52 136,95 64,95",
                 fill: "{traps_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{traps_stroke}",
+                stroke_width: "{traps_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -327,45 +545,95 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
                 },
             }
 
-            // ── Left Shoulder (back) ───────────────────────────────────────
-            polygon {
-                "data-testid": "region-Shoulders",
-                points: "28,68 68,68 64,100 24,100",
-                fill: "{shoulders_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Shoulders)
-                },
-            }
+            if !detailed {
+                // ── Left Shoulder (back) ───────────────────────────────────
+                polygon {
+                    "data-testid": "region-Shoulders",
+                    points: "28,68 68,68 64,100 24,100",
+                    fill: "{shoulders_fill}",
+                    stroke: "{shoulders_stroke}",
+                    stroke_width: "{shoulders_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Shoulders)
+                    },
+                }
 
-            // ── Right Shoulder (back) ──────────────────────────────────────
-            polygon {
-                points: "132,68 172,68 176,100 136,100",
-                fill: "{shoulders_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Shoulders)
-                },
+                // ── Right Shoulder (back) ──────────────────────────────────
+                polygon {
+                    points: "132,68 172,68 176,100 136,100",
+                    fill: "{shoulders_fill}",
+                    stroke: "{shoulders_stroke}",
+                    stroke_width: "{shoulders_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Shoulders)
+                    },
+                }
+            } else {
+                SubRegion {
+                    muscle: MuscleGroup::RearDelts,
+                    points: "28,68 68,68 64,100 24,100",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::RearDelts,
+                    points: "132,68 172,68 176,100 136,100",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
             }
 
-            // ── Back (lats) ────────────────────────────────────────────────
-            polygon {
-                "data-testid": "region-Back",
-                points: "64,95 136,95 132,183 68,183",
-                fill: "{back_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Back)
-                },
+            if !detailed {
+                // ── Back (lats) ────────────────────────────────────────────
+                polygon {
+                    "data-testid": "region-Back",
+                    points: "64,95 136,95 132,183 68,183",
+                    fill: "{back_fill}",
+                    stroke: "{back_stroke}",
+                    stroke_width: "{back_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Back)
+                    },
+                }
+            } else {
+                SubRegion {
+                    muscle: MuscleGroup::Lats,
+                    points: "64,95 80,95 80,183 68,183",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::MidBack,
+                    points: "80,95 120,95 120,145 80,145",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::LowerBack,
+                    points: "80,145 120,145 120,183 80,183",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Lats,
+                    points: "120,95 136,95 132,183 120,183",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
             }
 
             // ── Left Tricep ────────────────────────────────────────────────
@@ -373,8 +641,8 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
                 "data-testid": "region-Triceps",
                 points: "20,100 64,100 62,155 16,155",
                 fill: "{triceps_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{triceps_stroke}",
+                stroke_width: "{triceps_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -386,8 +654,8 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
             polygon {
                 points: "136,100 180,100 184,155 138,155",
                 fill: "{triceps_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{triceps_stroke}",
+                stroke_width: "{triceps_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -395,18 +663,43 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
                 },
             }
 
-            // ── Glutes ─────────────────────────────────────────────────────
-            polygon {
-                "data-testid": "region-Glutes",
-                points: "68,183 132,183 136,225 64,225",
-                fill: "{glutes_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
-                style: "cursor: pointer",
-                onclick: {
-                    let on_tap = on_tap;
-                    move |_| on_tap.call(MuscleGroup::Glutes)
-                },
+            if !detailed {
+                // ── Glutes ─────────────────────────────────────────────────
+                polygon {
+                    "data-testid": "region-Glutes",
+                    points: "68,183 132,183 136,225 64,225",
+                    fill: "{glutes_fill}",
+                    stroke: "{glutes_stroke}",
+                    stroke_width: "{glutes_width}",
+                    style: "cursor: pointer",
+                    onclick: {
+                        let on_tap = on_tap;
+                        move |_| on_tap.call(MuscleGroup::Glutes)
+                    },
+                }
+            } else {
+                SubRegion {
+                    muscle: MuscleGroup::Abductors,
+                    points: "68,183 78,183 76,225 64,225",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Glutes,
+                    points: "78,183 122,183 124,225 76,225",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    on_tap,
+                }
+                SubRegion {
+                    muscle: MuscleGroup::Abductors,
+                    points: "122,183 132,183 136,225 124,225",
+                    selections: selections.clone(),
+                    zones: zones.clone(),
+                    mirror: true,
+                    on_tap,
+                }
             }
 
             // ── Left Hamstring ─────────────────────────────────────────────
@@ -414,8 +707,8 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
                 "data-testid": "region-Hamstrings",
                 points: "64,225 97,225 93,305 60,305",
                 fill: "{hamstrings_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{hamstrings_stroke}",
+                stroke_width: "{hamstrings_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -427,8 +720,8 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
             polygon {
                 points: "103,225 136,225 140,305 107,305",
                 fill: "{hamstrings_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{hamstrings_stroke}",
+                stroke_width: "{hamstrings_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -441,8 +734,8 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
                 "data-testid": "region-Calves",
                 points: "60,305 93,305 89,375 56,375",
                 fill: "{calves_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{calves_stroke}",
+                stroke_width: "{calves_width}",
                 style: "cursor: pointer",
                 onclick: {
                     let on_tap = on_tap;
@@ -454,8 +747,8 @@ fn BackDiagram(selections: Vec<ExerciseMuscleGroup>, on_tap: EventHandler<Muscle
             polygon {
                 points: "107,305 140,305 144,375 111,375",
                 fill: "{calves_fill}",
-                stroke: "#6b7280",
-                stroke_width: "1.5",
+                stroke: "{calves_stroke}",
+                stroke_width: "{calves_width}",
                 style: "cursor: pointer",
                 onclick: move |_| on_tap.call(MuscleGroup::Calves),
             }
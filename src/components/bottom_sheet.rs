@@ -122,7 +122,7 @@ mod tests {
 
     #[test]
     fn bottom_sheet_items_have_correct_labels() {
-        let items = vec![
+        let items = [
             BottomSheetItem {
                 label: "View History".to_string(),
                 icon: None,
@@ -1,5 +1,10 @@
-use crate::state::WorkoutState;
+use crate::export::{ExportFilter, ExportFormat};
+use crate::import::ImportPlan;
+use crate::merge::MergePlan;
+use crate::models::WeightUnit;
+use crate::state::{WorkoutState, WorkoutStateManager};
 use dioxus::prelude::*;
+use wasm_bindgen::JsValue;
 
 /// The SQLite magic number used to validate imported files.
 const SQLITE_MAGIC: &[u8] = b"SQLite format 3\0";
@@ -12,11 +17,34 @@ pub(crate) fn is_valid_sqlite(data: &[u8]) -> bool {
     data.len() >= SQLITE_MAGIC.len() && data.starts_with(SQLITE_MAGIC)
 }
 
-/// Panel with Export and Import buttons for the workout database.
+/// Local midnight at the start of a `YYYY-MM-DD` date input, in Unix ms;
+/// `None` for an empty or unparseable value.
+fn local_day_start_ms(date: &str) -> Option<f64> {
+    if date.is_empty() {
+        return None;
+    }
+    let ms = js_sys::Date::new(&JsValue::from_str(&format!("{}T00:00:00", date))).get_time();
+    (!ms.is_nan()).then_some(ms)
+}
+
+/// Local midnight at the end of a `YYYY-MM-DD` date input, so the whole day
+/// is included.
+fn local_day_end_ms(date: &str) -> Option<f64> {
+    let start = local_day_start_ms(date)?;
+    let next = js_sys::Date::new(&JsValue::from_f64(start));
+    next.set_date(next.get_date() + 1);
+    Some(next.get_time())
+}
+
+/// Panel with Export and Import buttons for the workout database, followed
+/// by the backup merge, the history export and the history import.
 ///
 /// - Export: serialises the SQLite database and triggers a browser download.
 /// - Import: presents a file picker, validates the file, calls `importDatabase()`,
 ///   and persists via crsqlite-wasm's IndexedDB backend.
+/// - Merge backup: unions another database file with this one after a summary.
+/// - Export history: downloads the filtered sets as CSV or JSON Lines.
+/// - Import history: previews and logs sets from a Strong, Hevy or other CSV.
 #[component]
 pub fn DataManagementPanel(state: WorkoutState) -> Element {
     let mut import_error = use_signal(|| Option::<String>::None);
@@ -223,6 +251,547 @@ pub fn DataManagementPanel(state: WorkoutState) -> Element {
                 }
             }
         }
+        DatabaseMerge { state }
+        HistoryExport { state }
+        HistoryImport { state }
+    }
+}
+
+/// Merge-import of a backup from another device (`crate::merge`).  Unlike
+/// Import, which replaces the database, this keeps everything logged here and
+/// takes the newer copy of each row; a per-table summary is shown first.
+#[component]
+fn DatabaseMerge(state: WorkoutState) -> Element {
+    let mut plan = use_signal(|| Option::<MergePlan>::None);
+    let mut is_busy = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut merged = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "mt-6 flex flex-col gap-2",
+            "data-testid": "database-merge",
+            span { class: "text-sm font-semibold", "Merge a backup" }
+            p {
+                class: "text-xs text-base-content/60",
+                "Adds what another device logged without losing anything recorded here. Where both have the same item, the most recently changed copy is kept."
+            }
+            input {
+                r#type: "file",
+                accept: ".sqlite,.db",
+                class: "file-input file-input-bordered file-input-sm w-full",
+                "data-testid": "merge-db-file-input",
+                disabled: is_busy(),
+                onchange: move |event| {
+                    spawn(async move {
+                        error.set(None);
+                        merged.set(false);
+                        plan.set(None);
+                        let files = event.files();
+                        let Some(file) = files.first() else {
+                            return;
+                        };
+                        let Some(db) = state.database() else {
+                            error.set(Some("Merge failed: database not initialized".to_string()));
+                            return;
+                        };
+                        is_busy.set(true);
+                        let data = match file.read_bytes().await {
+                            Ok(bytes) => bytes.to_vec(),
+                            Err(e) => {
+                                log::error!("[DataManagement] Failed to read {}: {:?}", file.name(), e);
+                                error.set(Some("Failed to read the selected file.".to_string()));
+                                is_busy.set(false);
+                                return;
+                            }
+                        };
+                        if !is_valid_sqlite(&data) {
+                            error.set(Some("The selected file is not a valid SQLite database.".to_string()));
+                            is_busy.set(false);
+                            return;
+                        }
+                        match db.preview_merge(data).await {
+                            Ok(p) => plan.set(Some(p)),
+                            Err(e) => {
+                                log::error!("[DataManagement] Merge preview failed: {}", e);
+                                error.set(Some(format!("Failed to read the backup: {}", e)));
+                            }
+                        }
+                        is_busy.set(false);
+                    });
+                }
+            }
+            if let Some(p) = plan() {
+                div {
+                    class: "bg-base-200 rounded-lg p-3 flex flex-col gap-2 text-sm",
+                    "data-testid": "merge-preview",
+                    table {
+                        class: "table table-xs",
+                        thead {
+                            tr {
+                                th {}
+                                th { class: "text-right", "Added" }
+                                th { class: "text-right", "Updated" }
+                                th { class: "text-right", "Skipped" }
+                            }
+                        }
+                        tbody {
+                            for table in p.summary.iter() {
+                                tr {
+                                    key: "{table.label}",
+                                    "data-testid": "merge-summary-row",
+                                    td { "{table.label}" }
+                                    td { class: "text-right", "{table.added}" }
+                                    td { class: "text-right", "{table.updated}" }
+                                    td { class: "text-right text-base-content/60", "{table.skipped}" }
+                                }
+                            }
+                        }
+                    }
+                    p {
+                        class: "text-xs text-base-content/60",
+                        "Skipped items are already up to date here. Settings on this device are kept."
+                    }
+                    div {
+                        class: "flex gap-2",
+                        button {
+                            class: if is_busy() {
+                                "btn btn-primary btn-sm flex-1 loading"
+                            } else {
+                                "btn btn-primary btn-sm flex-1"
+                            },
+                            "data-testid": "confirm-merge-btn",
+                            disabled: is_busy() || p.is_empty(),
+                            onclick: move |_| {
+                                spawn(async move {
+                                    let Some(p) = plan() else {
+                                        return;
+                                    };
+                                    is_busy.set(true);
+                                    error.set(None);
+                                    match WorkoutStateManager::apply_merge(&state, &p).await {
+                                        Ok(()) => {
+                                            log::debug!("[DataManagement] Merged {} changes", p.changes.len());
+                                            plan.set(None);
+                                            merged.set(true);
+                                        }
+                                        Err(e) => {
+                                            log::error!("[DataManagement] Merge failed: {}", e);
+                                            error.set(Some(format!("Merge failed: {}", e)));
+                                        }
+                                    }
+                                    is_busy.set(false);
+                                });
+                            },
+                            if p.is_empty() { "Nothing to merge" } else { "Merge" }
+                        }
+                        button {
+                            class: "btn btn-ghost btn-sm",
+                            disabled: is_busy(),
+                            onclick: move |_| plan.set(None),
+                            "Cancel"
+                        }
+                    }
+                }
+            }
+            if merged() {
+                div {
+                    class: "alert alert-success text-sm py-2",
+                    "data-testid": "merge-success",
+                    span { "Backup merged." }
+                }
+            }
+            if let Some(err) = error() {
+                div {
+                    class: "alert alert-error text-sm py-2",
+                    "data-testid": "merge-error",
+                    span { {err} }
+                }
+            }
+        }
+    }
+}
+
+/// Date-range and exercise filters plus format choice for the plain-text
+/// history export (`crate::export`).  Weights use the display unit.
+#[component]
+fn HistoryExport(state: WorkoutState) -> Element {
+    let mut format = use_signal(ExportFormat::default);
+    let mut from_date = use_signal(String::new);
+    let mut to_date = use_signal(String::new);
+    let mut exercise_id = use_signal(String::new);
+    let mut is_exporting = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+
+    let mut exercises = state.exercises();
+    exercises.sort_by_key(|e| e.name.to_lowercase());
+
+    let export = move |_| {
+        spawn(async move {
+            let Some(db) = state.database() else {
+                error.set(Some("Export failed: database not initialized".to_string()));
+                return;
+            };
+            is_exporting.set(true);
+            error.set(None);
+
+            let filter = ExportFilter {
+                from_ms: local_day_start_ms(&from_date()),
+                to_ms: local_day_end_ms(&to_date()),
+                exercise_id: Some(exercise_id()).filter(|id| !id.is_empty()),
+            };
+            let format = format();
+            let result = match db.get_sets_for_export(&filter).await {
+                Ok(rows) => {
+                    let text =
+                        crate::export::serialize(&rows, format, state.settings().weight_unit);
+                    crate::state::Database::download_file(
+                        text.as_bytes(),
+                        format.filename(),
+                        format.mime_type(),
+                    )
+                    .await
+                    .map(|_| rows.len())
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(count) => log::debug!("[DataManagement] Exported {} sets", count),
+                Err(e) => {
+                    log::error!("[DataManagement] History export failed: {}", e);
+                    error.set(Some(format!("Export failed: {}", e)));
+                }
+            }
+            is_exporting.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "mt-6 flex flex-col gap-2",
+            "data-testid": "history-export",
+            span { class: "text-sm font-semibold", "Export history" }
+            p {
+                class: "text-xs text-base-content/60",
+                "Every set with its exercise, weight, reps, RPE, e1RM, plan and local time, for spreadsheets or your coach."
+            }
+            div {
+                class: "flex gap-2",
+                input {
+                    r#type: "date",
+                    class: "input input-bordered input-sm flex-1",
+                    "data-testid": "export-from-date",
+                    "aria-label": "From date",
+                    value: "{from_date}",
+                    oninput: move |evt| from_date.set(evt.value()),
+                }
+                input {
+                    r#type: "date",
+                    class: "input input-bordered input-sm flex-1",
+                    "data-testid": "export-to-date",
+                    "aria-label": "To date",
+                    value: "{to_date}",
+                    oninput: move |evt| to_date.set(evt.value()),
+                }
+            }
+            select {
+                class: "select select-bordered select-sm w-full",
+                "data-testid": "export-exercise-select",
+                onchange: move |evt| exercise_id.set(evt.value()),
+                option { value: "", selected: exercise_id().is_empty(), "All exercises" }
+                for exercise in exercises {
+                    {
+                        let id = exercise.id.clone().unwrap_or_default();
+                        rsx! {
+                            option {
+                                key: "{id}",
+                                value: "{id}",
+                                selected: exercise_id() == id,
+                                "{exercise.name}"
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "flex gap-2",
+                select {
+                    class: "select select-bordered select-sm flex-1",
+                    "data-testid": "export-format-select",
+                    onchange: move |evt| {
+                        format.set(if evt.value() == "jsonl" {
+                            ExportFormat::JsonLines
+                        } else {
+                            ExportFormat::Csv
+                        })
+                    },
+                    option { value: "csv", selected: format() == ExportFormat::Csv, "CSV" }
+                    option { value: "jsonl", selected: format() == ExportFormat::JsonLines, "JSON Lines" }
+                }
+                button {
+                    class: if is_exporting() {
+                        "btn btn-outline btn-sm loading"
+                    } else {
+                        "btn btn-outline btn-sm"
+                    },
+                    "data-testid": "export-history-btn",
+                    disabled: is_exporting(),
+                    onclick: export,
+                    "Export history"
+                }
+            }
+            if let Some(err) = error() {
+                div {
+                    class: "alert alert-error text-sm py-2",
+                    "data-testid": "export-history-error",
+                    span { {err} }
+                }
+            }
+        }
+    }
+}
+
+/// Dry run of a CSV history import against the current library, and
+/// whether the file said which unit its weights are in.
+fn plan_history_import(
+    state: WorkoutState,
+    text: &str,
+    source_unit: WeightUnit,
+) -> Result<(ImportPlan, bool), crate::import::ImportError> {
+    let settings = state.settings();
+    crate::import::parse(text, source_unit).map(|parsed| {
+        let unit_specified = parsed.unit_specified;
+        let plan = crate::import::plan_import(
+            parsed,
+            &state.exercises(),
+            settings.target_rpe as f32,
+            settings.weight_unit,
+        );
+        (plan, unit_specified)
+    })
+}
+
+/// CSV history import (`crate::import`).  Picking a file only shows a dry
+/// run — which exercises the rows map to, which would be created, and which
+/// rows or already logged sets would be skipped — and nothing is written
+/// until it is confirmed.
+#[component]
+fn HistoryImport(state: WorkoutState) -> Element {
+    let mut text = use_signal(|| Option::<String>::None);
+    // Unit for files whose weights carry none; starts at the display unit.
+    let mut source_unit = use_signal(|| state.settings().weight_unit);
+    let mut is_importing = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut imported = use_signal(|| Option::<usize>::None);
+    // Previewed sets that are already logged, e.g. from an earlier import.
+    let mut already_logged = use_signal(|| 0usize);
+
+    let target_rpe = state.settings().target_rpe as f32;
+    let preview = text().map(|text| plan_history_import(state, &text, source_unit()));
+
+    use_effect(move || {
+        already_logged.set(0);
+        let Some(Ok((plan, _))) =
+            text().map(|text| plan_history_import(state, &text, source_unit()))
+        else {
+            return;
+        };
+        spawn(async move {
+            match WorkoutStateManager::count_already_imported(&state, &plan).await {
+                Ok(count) => already_logged.set(count),
+                Err(e) => log::warn!("Failed to check the import for logged sets: {}", e),
+            }
+        });
+    });
+
+    let run_import = move |plan: ImportPlan| {
+        spawn(async move {
+            is_importing.set(true);
+            error.set(None);
+            match WorkoutStateManager::import_history(&state, &plan).await {
+                Ok(count) => {
+                    log::debug!("[DataManagement] Imported {} sets", count);
+                    imported.set(Some(count));
+                    text.set(None);
+                }
+                Err(e) => {
+                    log::error!("[DataManagement] History import failed: {}", e);
+                    error.set(Some(format!("Import failed: {}", e)));
+                }
+            }
+            is_importing.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "mt-6 flex flex-col gap-2",
+            "data-testid": "history-import",
+            span { class: "text-sm font-semibold", "Import history" }
+            p {
+                class: "text-xs text-base-content/60",
+                "Bring in sets from a Strong or Hevy CSV export, or any CSV with exercise and date columns. You'll see a preview before anything is saved."
+            }
+            input {
+                r#type: "file",
+                accept: ".csv,text/csv",
+                class: "file-input file-input-bordered file-input-sm w-full",
+                "data-testid": "import-history-file-input",
+                disabled: is_importing(),
+                onchange: move |event| {
+                    spawn(async move {
+                        error.set(None);
+                        imported.set(None);
+                        let files = event.files();
+                        let Some(file) = files.first() else {
+                            return;
+                        };
+                        match file.read_bytes().await {
+                            Ok(bytes) => text.set(Some(String::from_utf8_lossy(&bytes).into_owned())),
+                            Err(e) => {
+                                log::error!("[DataManagement] Failed to read {}: {:?}", file.name(), e);
+                                error.set(Some("Failed to read the selected file.".to_string()));
+                            }
+                        }
+                    });
+                }
+            }
+            match preview {
+                None => rsx! {},
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "alert alert-error text-sm py-2",
+                        "data-testid": "import-history-error",
+                        span { "{e}" }
+                    }
+                },
+                Some(Ok((plan, unit_specified))) => {
+                    let new_count = plan.new_exercise_count();
+                    let set_count = plan.set_count();
+                    let duplicate_count = already_logged().min(set_count);
+                    let import_count = set_count - duplicate_count;
+                    rsx! {
+                        div {
+                            class: "bg-base-200 rounded-lg p-3 flex flex-col gap-2 text-sm",
+                            "data-testid": "import-preview",
+                            p {
+                                class: "font-semibold",
+                                "{plan.source.label()}: {set_count} sets across {plan.mappings.len()} exercises ({new_count} new)"
+                            }
+                            if !unit_specified {
+                                label {
+                                    class: "flex items-center gap-2",
+                                    span { "Weights in this file are in" }
+                                    select {
+                                        class: "select select-bordered select-xs",
+                                        "data-testid": "import-unit-select",
+                                        onchange: move |evt| {
+                                            source_unit.set(if evt.value() == "lb" { WeightUnit::Lb } else { WeightUnit::Kg })
+                                        },
+                                        option { value: "kg", selected: source_unit() == WeightUnit::Kg, "kg" }
+                                        option { value: "lb", selected: source_unit() == WeightUnit::Lb, "lb" }
+                                    }
+                                }
+                            }
+                            ul {
+                                class: "flex flex-col gap-1 max-h-60 overflow-y-auto",
+                                for mapping in plan.mappings.iter() {
+                                    li {
+                                        key: "{mapping.source_name}",
+                                        class: "flex justify-between gap-2",
+                                        "data-testid": "import-mapping",
+                                        span {
+                                            class: "truncate",
+                                            "{mapping.source_name}"
+                                            if mapping.target.exercise().name != mapping.source_name {
+                                                span { class: "text-base-content/60", " → {mapping.target.exercise().name}" }
+                                            }
+                                        }
+                                        span {
+                                            class: "flex gap-1 shrink-0 items-center",
+                                            if mapping.target.is_new() {
+                                                span { class: "badge badge-accent badge-sm", "new {mapping.target.exercise().set_type_config.type_name()}" }
+                                            }
+                                            span { class: "text-base-content/60", "{mapping.sets.len()} sets" }
+                                        }
+                                    }
+                                }
+                            }
+                            if new_count > 0 {
+                                p {
+                                    class: "text-xs text-base-content/60",
+                                    "New exercises are created without muscle groups; add them in the Library so their sets count towards volume."
+                                }
+                            }
+                            if plan.defaulted_rpe > 0 {
+                                p {
+                                    class: "text-xs text-base-content/60",
+                                    "data-testid": "import-defaulted-rpe",
+                                    "{plan.defaulted_rpe} sets have no RPE or RIR and will be logged at RPE {target_rpe}."
+                                }
+                            }
+                            if duplicate_count > 0 {
+                                p {
+                                    class: "text-xs text-base-content/60",
+                                    "data-testid": "import-already-logged",
+                                    "{duplicate_count} sets are already logged and will be skipped."
+                                }
+                            }
+                            if !plan.skipped.is_empty() {
+                                details {
+                                    class: "text-xs",
+                                    "data-testid": "import-skipped",
+                                    summary { class: "text-warning cursor-pointer", "{plan.skipped.len()} rows will be skipped" }
+                                    ul {
+                                        class: "mt-1 max-h-40 overflow-y-auto",
+                                        for skipped in plan.skipped.iter() {
+                                            li { key: "{skipped.line}", "Line {skipped.line}: {skipped.reason}" }
+                                        }
+                                    }
+                                }
+                            }
+                            div {
+                                class: "flex gap-2",
+                                button {
+                                    class: if is_importing() {
+                                        "btn btn-primary btn-sm flex-1 loading"
+                                    } else {
+                                        "btn btn-primary btn-sm flex-1"
+                                    },
+                                    "data-testid": "confirm-import-history-btn",
+                                    disabled: is_importing() || import_count == 0,
+                                    onclick: {
+                                        let plan = plan.clone();
+                                        move |_| run_import(plan.clone())
+                                    },
+                                    "Import {import_count} sets"
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    disabled: is_importing(),
+                                    onclick: move |_| text.set(None),
+                                    "Cancel"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(count) = imported() {
+                div {
+                    class: "alert alert-success text-sm py-2",
+                    "data-testid": "import-history-success",
+                    span { "Imported {count} sets." }
+                }
+            }
+            if let Some(err) = error() {
+                div {
+                    class: "alert alert-error text-sm py-2",
+                    "data-testid": "import-history-error",
+                    span { {err} }
+                }
+            }
+        }
     }
 }
 
@@ -0,0 +1,105 @@
+use crate::domain::deload::DeloadAdvice;
+use crate::models::WorkoutTemplate;
+use crate::state::{WorkoutState, WorkoutStateManager};
+use dioxus::prelude::*;
+
+/// Fatigue summary on the Plan Builder. Hidden until at least one Fatigue
+/// Signal fires; offers to reshape the Future Plan (or a template loaded into
+/// it) as a deload.
+#[component]
+pub fn DeloadAdvisorCard(state: WorkoutState, has_exercises: bool) -> Element {
+    let mut advice = use_signal(DeloadAdvice::default);
+    let mut templates = use_signal(Vec::<WorkoutTemplate>::new);
+    let mut template_id = use_signal(String::new);
+    let mut building = use_signal(|| false);
+    let mut built = use_signal(|| false);
+
+    use_effect(move || {
+        spawn(async move {
+            match WorkoutStateManager::assess_deload(&state).await {
+                Ok(a) => advice.set(a),
+                Err(e) => log::warn!("Failed to assess deload: {}", e),
+            }
+            if let Some(db) = state.database() {
+                match db.list_templates().await {
+                    Ok(t) => templates.set(t),
+                    Err(e) => log::warn!("Failed to list templates: {}", e),
+                }
+            }
+        });
+    });
+
+    let current = advice();
+    if current.signals.is_empty() {
+        return rsx! {};
+    }
+    let unit = state.settings().weight_unit;
+    let reasons: Vec<String> = current.signals.iter().map(|s| s.describe(unit)).collect();
+    let can_build = !building() && (has_exercises || !template_id().is_empty());
+
+    rsx! {
+        div {
+            class: if current.recommend {
+                "alert alert-warning flex flex-col items-stretch gap-2 mb-4"
+            } else {
+                "alert flex flex-col items-stretch gap-2 mb-4"
+            },
+            "data-testid": "deload-advisor",
+            "data-recommend": "{current.recommend}",
+            p {
+                class: "font-bold",
+                if current.recommend { "Deload week recommended" } else { "Fatigue building" }
+            }
+            ul {
+                class: "text-sm list-disc pl-5",
+                for reason in reasons {
+                    li { "data-testid": "fatigue-signal", "{reason}" }
+                }
+            }
+            if current.recommend {
+                if built() {
+                    p {
+                        class: "text-sm",
+                        "data-testid": "deload-plan-built",
+                        "Plan reshaped: half the sets at a lower target RPE."
+                    }
+                } else {
+                    div {
+                        class: "flex gap-2",
+                        select {
+                            class: "select select-bordered select-sm flex-1 min-w-0",
+                            "data-testid": "deload-template-select",
+                            value: "{template_id}",
+                            onchange: move |evt| template_id.set(evt.value()),
+                            option {
+                                value: "",
+                                if has_exercises { "Current plan" } else { "Choose a template" }
+                            }
+                            for t in templates() {
+                                option { key: "{t.id}", value: "{t.id}", "{t.name}" }
+                            }
+                        }
+                        button {
+                            class: "btn btn-sm btn-primary",
+                            "data-testid": "build-deload-plan",
+                            disabled: !can_build,
+                            onclick: move |_| {
+                                building.set(true);
+                                spawn(async move {
+                                    let id = template_id();
+                                    let template = (!id.is_empty()).then_some(id.as_str());
+                                    match WorkoutStateManager::build_deload_plan(&state, template).await {
+                                        Ok(()) => built.set(true),
+                                        Err(e) => log::warn!("Failed to build deload plan: {}", e),
+                                    }
+                                    building.set(false);
+                                });
+                            },
+                            "Build deload plan"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
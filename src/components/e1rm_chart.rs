@@ -0,0 +1,307 @@
+use crate::domain::e1rm::{e1rm_fit, e1rm_trend};
+use crate::state::WorkoutState;
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+const WIDTH: f64 = 320.0;
+const HEIGHT: f64 = 160.0;
+const PAD: f64 = 12.0;
+/// History loaded for the "All" range, in weeks.
+const ALL_WEEKS: u32 = 520;
+
+/// Time range shown by the chart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChartRange {
+    Weeks(u32),
+    All,
+}
+
+impl ChartRange {
+    /// Ranges offered above the chart.
+    pub const OPTIONS: [ChartRange; 4] = [
+        ChartRange::Weeks(4),
+        ChartRange::Weeks(12),
+        ChartRange::Weeks(26),
+        ChartRange::All,
+    ];
+
+    fn label(self) -> String {
+        match self {
+            ChartRange::Weeks(w) => format!("{}W", w),
+            ChartRange::All => "All".to_string(),
+        }
+    }
+
+    /// First date inside the range, `None` for all history.
+    fn since(self, today: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            ChartRange::Weeks(w) => Some(today - chrono::Duration::weeks(w as i64)),
+            ChartRange::All => None,
+        }
+    }
+}
+
+/// A Peak e1RM session placed in SVG coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartPoint {
+    pub date: NaiveDate,
+    pub e1rm: f64,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Everything the SVG draws, in view-box coordinates.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChartGeometry {
+    pub points: Vec<ChartPoint>,
+    /// Ends of the regression line, clipped to the visible dates.
+    pub trend: Option<[(f64, f64); 2]>,
+    /// e1RM at the bottom and top edges of the plot.
+    pub y_min: f64,
+    pub y_max: f64,
+}
+
+/// Lays out the `visible` sessions and the regression line fitted to the
+/// `window` sessions (pass an empty slice to hide the line). Both slices are
+/// oldest first.
+pub fn chart_geometry(visible: &[(NaiveDate, f64)], window: &[(NaiveDate, f64)]) -> ChartGeometry {
+    let (Some(&(first, _)), Some(&(last, _))) = (visible.first(), visible.last()) else {
+        return ChartGeometry::default();
+    };
+    let span = (last - first).num_days().max(1) as f64;
+    let x_of = |date: NaiveDate| {
+        if first == last {
+            WIDTH / 2.0
+        } else {
+            PAD + (date - first).num_days() as f64 / span * (WIDTH - 2.0 * PAD)
+        }
+    };
+
+    // Regression line over the part of the window that is on screen.
+    let trend = e1rm_fit(window).and_then(|(slope, intercept)| {
+        let origin = window[0].0;
+        let start = origin.max(first);
+        let end = window[window.len() - 1].0.min(last);
+        let value = |d: NaiveDate| intercept + slope * (d - origin).num_days() as f64;
+        (start < end).then(|| [(start, value(start)), (end, value(end))])
+    });
+
+    let values = visible
+        .iter()
+        .map(|(_, v)| *v)
+        .chain(trend.iter().flat_map(|ends| ends.iter().map(|(_, v)| *v)));
+    let (lo, hi) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+        (lo.min(v), hi.max(v))
+    });
+    let margin = ((hi - lo) * 0.1).max(1.0);
+    let (y_min, y_max) = (lo - margin, hi + margin);
+    let y_of = |v: f64| HEIGHT - PAD - (v - y_min) / (y_max - y_min) * (HEIGHT - 2.0 * PAD);
+
+    ChartGeometry {
+        points: visible
+            .iter()
+            .map(|&(date, e1rm)| ChartPoint {
+                date,
+                e1rm,
+                x: x_of(date),
+                y: y_of(e1rm),
+            })
+            .collect(),
+        trend: trend.map(|[(a, av), (b, bv)]| [(x_of(a), y_of(av)), (x_of(b), y_of(bv))]),
+        y_min,
+        y_max,
+    }
+}
+
+/// Peak e1RM per session for one exercise, with the Training Window's
+/// regression line. Tapping a point reports its date through `on_select`.
+/// Renders nothing until the exercise has a weighted session.
+#[component]
+pub fn E1rmChart(
+    state: WorkoutState,
+    exercise_id: String,
+    on_select: EventHandler<NaiveDate>,
+) -> Element {
+    let mut history = use_signal(Vec::<(NaiveDate, f64)>::new);
+    let mut range = use_signal(|| ChartRange::Weeks(12));
+
+    use_effect(move || {
+        let eid = exercise_id.clone();
+        spawn(async move {
+            let Some(db) = state.database() else {
+                return;
+            };
+            let global = state.settings().e1rm_formula;
+            let formula = state
+                .exercises()
+                .iter()
+                .find(|e| e.id.as_deref() == Some(eid.as_str()))
+                .map_or(global, |e| e.e1rm_formula_or(global));
+            match db.get_e1rm_session_history(&eid, formula, ALL_WEEKS).await {
+                Ok(sessions) => history.set(sessions),
+                Err(e) => log::warn!("Failed to load e1RM history: {}", e),
+            }
+        });
+    });
+
+    let sessions = history();
+    if sessions.is_empty() {
+        return rsx! {};
+    }
+    let settings = state.settings();
+    let unit = settings.weight_unit;
+    let today = chrono::DateTime::from_timestamp_millis(js_sys::Date::now() as i64)
+        .map(|t| t.date_naive())
+        .unwrap_or(sessions[sessions.len() - 1].0);
+
+    let since = range().since(today);
+    let visible: Vec<(NaiveDate, f64)> = sessions
+        .iter()
+        .copied()
+        .filter(|(d, _)| since.is_none_or(|s| *d >= s))
+        .collect();
+    let window_start = today - chrono::Duration::weeks(settings.training_window_weeks);
+    let mut window: Vec<(NaiveDate, f64)> = sessions
+        .iter()
+        .copied()
+        .filter(|(d, _)| *d >= window_start)
+        .collect();
+    if (window.len() as i64) < settings.min_sessions_for_regression {
+        window.clear();
+    }
+    let weekly = (!window.is_empty()).then(|| unit.from_kg(e1rm_trend(&window) * 7.0));
+    let slope_label = weekly.map(|w| format!("{:+.1} {}/wk", w, unit));
+    let geometry = chart_geometry(&visible, &window);
+    let polyline = geometry
+        .points
+        .iter()
+        .map(|p| format!("{:.1},{:.1}", p.x, p.y))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let top = format!("{:.0}", unit.from_kg(geometry.y_max));
+    let bottom = format!("{:.0}", unit.from_kg(geometry.y_min));
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-md mb-4",
+            "data-testid": "e1rm-chart",
+            div {
+                class: "card-body p-4 gap-2",
+                div {
+                    class: "flex justify-between items-center",
+                    h3 { class: "text-sm font-semibold uppercase text-base-content/60", "e1RM trend" }
+                    if let (Some(weekly), Some(label)) = (weekly, slope_label) {
+                        span {
+                            class: if weekly > 0.0 { "badge badge-success badge-sm" } else { "badge badge-warning badge-sm" },
+                            "data-testid": "e1rm-chart-slope",
+                            "{label}"
+                        }
+                    }
+                }
+                div {
+                    class: "join self-center",
+                    for option in ChartRange::OPTIONS {
+                        button {
+                            key: "{option.label()}",
+                            class: if range() == option { "join-item btn btn-xs btn-primary" } else { "join-item btn btn-xs btn-ghost" },
+                            "data-testid": "e1rm-chart-range",
+                            onclick: move |_| range.set(option),
+                            "{option.label()}"
+                        }
+                    }
+                }
+                if geometry.points.is_empty() {
+                    p {
+                        class: "text-center text-sm text-base-content/50 py-6",
+                        "No sessions in this range."
+                    }
+                } else {
+                    svg {
+                        xmlns: "http://www.w3.org/2000/svg",
+                        view_box: "0 0 {WIDTH} {HEIGHT}",
+                        class: "w-full h-auto",
+                        "aria-label": "e1RM trend chart",
+                        text { x: "2", y: "10", font_size: "9", fill: "currentColor", opacity: "0.5", "{top}" }
+                        text { x: "2", y: "{HEIGHT - 2.0}", font_size: "9", fill: "currentColor", opacity: "0.5", "{bottom}" }
+                        if let Some([(x1, y1), (x2, y2)]) = geometry.trend {
+                            line {
+                                "data-testid": "e1rm-chart-trend",
+                                x1: "{x1}",
+                                y1: "{y1}",
+                                x2: "{x2}",
+                                y2: "{y2}",
+                                stroke: "#f59e0b",
+                                stroke_width: "1.5",
+                                stroke_dasharray: "4 3",
+                            }
+                        }
+                        polyline {
+                            points: "{polyline}",
+                            fill: "none",
+                            stroke: "#3b82f6",
+                            stroke_width: "2",
+                        }
+                        for point in geometry.points {
+                            circle {
+                                key: "{point.date}",
+                                "data-testid": "e1rm-chart-point",
+                                "data-date": "{point.date}",
+                                cx: "{point.x}",
+                                cy: "{point.y}",
+                                r: "4",
+                                fill: "#3b82f6",
+                                style: "cursor: pointer",
+                                "aria-label": "{point.date}",
+                                onclick: move |_| on_select.call(point.date),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_empty_history_has_no_geometry() {
+        assert_eq!(chart_geometry(&[], &[]), ChartGeometry::default());
+    }
+
+    #[test]
+    fn test_points_span_the_width_and_higher_is_up() {
+        let sessions = [(d(1), 100.0), (d(11), 110.0), (d(21), 105.0)];
+        let g = chart_geometry(&sessions, &[]);
+        assert_eq!(g.points[0].x, PAD);
+        assert_eq!(g.points[2].x, WIDTH - PAD);
+        assert_eq!(g.points[1].x, WIDTH / 2.0);
+        assert!(g.points[1].y < g.points[0].y, "110 plots above 100");
+        assert!(g.y_min < 100.0 && g.y_max > 110.0);
+        assert_eq!(g.trend, None);
+    }
+
+    #[test]
+    fn test_single_point_is_centred() {
+        let g = chart_geometry(&[(d(5), 100.0)], &[]);
+        assert_eq!(g.points[0].x, WIDTH / 2.0);
+    }
+
+    #[test]
+    fn test_trend_is_clipped_to_visible_dates() {
+        let window = [(d(1), 100.0), (d(11), 105.0), (d(21), 110.0)];
+        // Only the last two sessions are in range.
+        let visible = &window[1..];
+        let g = chart_geometry(visible, &window);
+        let [(x1, y1), (x2, y2)] = g.trend.expect("trend line");
+        assert_eq!((x1, x2), (PAD, WIDTH - PAD));
+        assert!((y1 - g.points[0].y).abs() < 1e-9);
+        assert!((y2 - g.points[1].y).abs() < 1e-9);
+    }
+}
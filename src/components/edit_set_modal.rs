@@ -1,7 +1,7 @@
 use crate::components::rpe_slider::RPESlider;
 use crate::components::step_controls::StepControls;
 use crate::components::tape_measure::TapeMeasure;
-use crate::models::{ExerciseMetadata, HistorySet, SetType, SetTypeConfig};
+use crate::models::{ExerciseMetadata, HistorySet, SetType, SetTypeConfig, WeightUnit};
 use dioxus::prelude::*;
 use wasm_bindgen::JsValue;
 
@@ -25,29 +25,64 @@ fn date_string_to_ms(date_str: &str) -> f64 {
 pub fn EditSetModal(
     set: HistorySet,
     exercise: ExerciseMetadata,
-    on_save: EventHandler<(u32, f32, Option<f32>, f64)>,
+    /// Unit the weight is shown and edited in; `on_save` always reports kg.
+    unit: WeightUnit,
+    on_save: EventHandler<(u32, f32, SetType, String, f64)>,
     on_delete: EventHandler<i64>,
     on_cancel: EventHandler<()>,
 ) -> Element {
     let mut reps = use_signal(|| set.reps);
     let mut rpe = use_signal(|| set.rpe as f64);
-    let mut weight = use_signal(|| {
-        if let SetType::Weighted { weight } = set.set_type {
-            Some(weight as f64)
-        } else {
-            None
-        }
+    // Loaded bodyweight sets edit the magnitude of the added or assisting
+    // weight against the bodyweight snapshot taken when the set was logged.
+    let bodyweight = match set.set_type {
+        SetType::LoadedBodyweight { bodyweight, .. } => bodyweight,
+        _ => 0.0,
+    };
+    let mut weight = use_signal(|| match set.set_type {
+        SetType::LoadedBodyweight { added, .. } => Some(added.abs() as f64),
+        _ => set.set_type.weight().map(|w| w as f64),
     });
+    let mut measure = use_signal(|| set.set_type.measure().unwrap_or(0.0));
     let mut recorded_at = use_signal(|| set.recorded_at);
+    let mut comment = use_signal(|| set.comment.clone());
 
-    let is_weighted = matches!(exercise.set_type_config, SetTypeConfig::Weighted { .. });
-    let (min_weight, increment) = match exercise.set_type_config {
+    let config = exercise.set_type_config.clone();
+    let is_weighted = matches!(
+        config,
+        SetTypeConfig::Weighted { .. }
+            | SetTypeConfig::Carry { .. }
+            | SetTypeConfig::LoadedBodyweight { .. }
+    );
+    let weight_label = match config {
+        SetTypeConfig::LoadedBodyweight { assisted: true, .. } => "Assistance",
+        SetTypeConfig::LoadedBodyweight {
+            assisted: false, ..
+        } => "Added",
+        _ => "Weight",
+    };
+    let (min_weight, increment) = match config {
         SetTypeConfig::Weighted {
             min_weight,
             increment,
+            ..
+        }
+        | SetTypeConfig::Carry {
+            min_weight,
+            increment,
+            ..
         } => (min_weight as f64, increment as f64),
-        SetTypeConfig::Bodyweight => (0.0, 1.0),
+        SetTypeConfig::LoadedBodyweight { increment, .. } => (0.0, increment as f64),
+        SetTypeConfig::Bodyweight
+        | SetTypeConfig::Timed { .. }
+        | SetTypeConfig::Distance { .. } => (0.0, 1.0),
     };
+    let measure_increment = config.measure_increment();
+    let (measure_label, max_measure) = match config {
+        SetTypeConfig::Timed { .. } => ("Time", crate::models::MAX_DURATION_SECS as f64),
+        _ => ("Distance", crate::models::MAX_DISTANCE_M as f64),
+    };
+    let measure_text = crate::format::fmt_measure(&config, measure());
 
     rsx! {
         div {
@@ -83,35 +118,56 @@ pub fn EditSetModal(
                     if is_weighted {
                         div {
                             class: "mb-8",
-                            label { class: "label font-bold", "Weight (kg)" }
+                            label { class: "label font-bold", "{weight_label} ({unit})" }
                             TapeMeasure {
-                                value: weight().unwrap_or(min_weight),
-                                min: min_weight,
-                                max: 500.0,
-                                step: increment,
-                                on_change: move |val| weight.set(Some(val))
+                                value: unit.from_kg(weight().unwrap_or(min_weight)),
+                                min: unit.from_kg(min_weight),
+                                max: unit.from_kg(500.0),
+                                step: unit.from_kg(increment),
+                                on_change: move |val| {
+                                    weight.set(Some(unit.to_kg(val).clamp(min_weight, 500.0)))
+                                }
                             }
                             div {
                                 class: "text-center text-4xl font-black text-primary mt-2",
-                                "{crate::format::fmt_weight(weight().unwrap_or(min_weight) as f32)} kg"
+                                {crate::format::fmt_weight_in(weight().unwrap_or(min_weight), unit)}
                             }
                         }
                     }
 
-                    div {
-                        class: "mb-8",
-                        "data-testid": "reps-section",
-                        label { class: "label font-bold", "Reps" }
+                    if let Some(step) = measure_increment {
                         div {
-                            class: "text-center text-5xl font-black text-primary mb-2",
-                            "{reps}"
+                            class: "mb-8",
+                            "data-testid": "measure-section",
+                            label { class: "label font-bold", "{measure_label}" }
+                            div {
+                                class: "text-center text-5xl font-black text-primary mb-2",
+                                "{measure_text}"
+                            }
+                            StepControls {
+                                value: measure(),
+                                steps: vec![-5.0 * step, -step, step, 5.0 * step],
+                                min: step,
+                                max: max_measure,
+                                on_change: move |val| measure.set(val)
+                            }
                         }
-                        StepControls {
-                            value: reps() as f64,
-                            steps: vec![-5.0, -1.0, 1.0, 5.0],
-                            min: 1.0,
-                            max: 100.0,
-                            on_change: move |val| reps.set(val as u32)
+                    } else {
+                        div {
+                            class: "mb-8",
+                            "data-testid": "reps-section",
+                            label { class: "label font-bold", "Reps" }
+                            div {
+                                class: "text-center text-5xl font-black text-primary mb-2",
+                                "{reps}"
+                            }
+                            StepControls {
+                                value: reps() as f64,
+                                steps: vec![-5.0, -1.0, 1.0, 5.0],
+                                min: 1.0,
+                                max: 100.0,
+                                on_change: move |val| reps.set(val as u32)
+                            }
                         }
                     }
 
@@ -124,6 +180,19 @@ pub fn EditSetModal(
                         }
                     }
 
+                    div {
+                        class: "mb-8",
+                        label { class: "label font-bold", "Comment" }
+                        textarea {
+                            class: "textarea textarea-bordered w-full",
+                            "data-testid": "set-comment-input",
+                            rows: "2",
+                            placeholder: "e.g. left knee twinge",
+                            value: "{comment}",
+                            oninput: move |evt| comment.set(evt.value())
+                        }
+                    }
+
                     div {
                         class: "mb-8",
                         label { class: "label font-bold", "Date" }
@@ -147,7 +216,19 @@ pub fn EditSetModal(
                         class: "btn btn-primary btn-block shadow-lg",
                         "data-testid": "save-set-button",
                         onclick: move |_| {
-                            on_save.call((reps(), rpe() as f32, weight().map(|w| w as f32), recorded_at()));
+                            let set_type = config
+                                .set_type(
+                                    weight().unwrap_or(min_weight) as f32,
+                                    measure(),
+                                    bodyweight,
+                                );
+                            on_save.call((
+                                reps(),
+                                rpe() as f32,
+                                set_type,
+                                comment().trim().to_string(),
+                                recorded_at(),
+                            ));
                         },
                         "Save Changes"
                     }
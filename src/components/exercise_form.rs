@@ -2,11 +2,85 @@ use crate::components::body_diagram::BodyDiagram;
 use crate::components::muscle_group_tier_cards::MuscleGroupTierCards;
 use crate::components::step_controls::StepControls;
 use crate::components::tape_measure::TapeMeasure;
-use crate::models::{ExerciseMetadata, ExerciseMuscleGroup, SetTypeConfig};
+use crate::domain::rest::MAX_REST_SECS;
+use crate::models::{
+    DEFAULT_REST_SECS, E1rmFormula, ExerciseMetadata, ExerciseMuscleGroup, MuscleGroup,
+    ProgressionStrategy, SetTypeConfig, VolumeZone, WeightUnit,
+};
+use crate::state::{WorkoutState, WorkoutStateManager};
 use dioxus::prelude::*;
+use std::collections::HashMap;
 
 const MAX_EXERCISE_NAME_LENGTH: usize = 100;
 
+/// Increment presets offered per display unit. Kilogram presets match common
+/// metric plates (2 × 1.25 kg = 2.5 kg); pound presets match 2.5 lb and 5 lb
+/// change plates plus the common 10 lb / 25 lb machine pin steps.
+fn increment_presets(unit: WeightUnit) -> &'static [f32] {
+    match unit {
+        WeightUnit::Kg => &[1.25, 2.5, 5.0, 10.0],
+        WeightUnit::Lb => &[2.5, 5.0, 10.0, 25.0],
+    }
+}
+
+/// Bar weight presets offered per display unit: technique, women's and men's
+/// Olympic bars in kilograms; the common 15 / 35 / 45 lb bars in pounds.
+fn bar_presets(unit: WeightUnit) -> &'static [f32] {
+    match unit {
+        WeightUnit::Kg => &[10.0, 15.0, 20.0],
+        WeightUnit::Lb => &[15.0, 35.0, 45.0],
+    }
+}
+
+/// What a set of the exercise records besides RPE.
+#[derive(Clone, Copy, PartialEq)]
+enum Measure {
+    Reps,
+    Time,
+    Distance,
+}
+
+impl Measure {
+    fn of(config: &SetTypeConfig) -> Self {
+        match config {
+            SetTypeConfig::Weighted { .. }
+            | SetTypeConfig::Bodyweight
+            | SetTypeConfig::LoadedBodyweight { .. } => Measure::Reps,
+            SetTypeConfig::Timed { .. } => Measure::Time,
+            SetTypeConfig::Distance { .. } | SetTypeConfig::Carry { .. } => Measure::Distance,
+        }
+    }
+
+    /// Step presets in seconds for timed exercises and metres for distance ones.
+    fn increment_presets(self) -> &'static [f32] {
+        match self {
+            Measure::Reps => &[],
+            Measure::Time => &[5.0, 10.0, 15.0, 30.0],
+            Measure::Distance => &[1.0, 5.0, 10.0, 50.0],
+        }
+    }
+}
+
+/// How an unweighted rep exercise is loaded on top of bodyweight.
+#[derive(Clone, Copy, PartialEq)]
+enum BodyweightLoad {
+    None,
+    Added,
+    Assisted,
+}
+
+impl BodyweightLoad {
+    fn of(config: &SetTypeConfig) -> Self {
+        match config {
+            SetTypeConfig::LoadedBodyweight {
+                assisted: false, ..
+            } => BodyweightLoad::Added,
+            SetTypeConfig::LoadedBodyweight { assisted: true, .. } => BodyweightLoad::Assisted,
+            _ => BodyweightLoad::None,
+        }
+    }
+}
+
 fn validate_exercise_name(name: &str) -> Result<(), String> {
     if name.trim().is_empty() {
         return Err("Exercise name cannot be empty".to_string());
@@ -32,10 +106,21 @@ pub fn ExerciseForm(
     initial_exercise: Option<ExerciseMetadata>,
     /// Pre-loaded muscle group assignments (empty for create, existing for edit).
     initial_muscle_groups: Vec<ExerciseMuscleGroup>,
+    /// Unit the minimum weight and increment are entered in. Values are
+    /// converted back to canonical kg before `on_save` is called.
+    unit: WeightUnit,
     on_cancel: EventHandler<()>,
     /// Called with the exercise metadata and selected muscle groups on save.
     on_save: EventHandler<(ExerciseMetadata, Vec<ExerciseMuscleGroup>)>,
 ) -> Element {
+    // Weights are shown on the loading grid of `unit`, rounded to its plate
+    // steps when the exercise was entered in the other unit.  The stored
+    // exercise decides on save whether those weights were actually edited.
+    let stored_exercise = initial_exercise.clone();
+    let initial_exercise = initial_exercise.map(|e| ExerciseMetadata {
+        set_type_config: e.loading_config(unit),
+        ..e
+    });
     let mut exercise_name = use_signal(|| {
         initial_exercise
             .as_ref()
@@ -45,35 +130,123 @@ pub fn ExerciseForm(
     let mut is_weighted = use_signal(|| {
         initial_exercise
             .as_ref()
-            .map(|e| matches!(e.set_type_config, SetTypeConfig::Weighted { .. }))
+            .map(|e| {
+                matches!(
+                    e.set_type_config,
+                    SetTypeConfig::Weighted { .. } | SetTypeConfig::Carry { .. }
+                )
+            })
             .unwrap_or(true)
     });
+    let mut measure = use_signal(|| {
+        initial_exercise
+            .as_ref()
+            .map(|e| Measure::of(&e.set_type_config))
+            .unwrap_or(Measure::Reps)
+    });
+    let mut bodyweight_load = use_signal(|| {
+        initial_exercise
+            .as_ref()
+            .map(|e| BodyweightLoad::of(&e.set_type_config))
+            .unwrap_or(BodyweightLoad::None)
+    });
+    let mut measure_increment = use_signal(|| {
+        initial_exercise
+            .as_ref()
+            .and_then(|e| e.set_type_config.measure_increment())
+            .map(|inc| inc as f32)
+            // 5 s or 5 m: fine enough for planks and carries alike.
+            .unwrap_or(5.0)
+    });
     let mut min_weight = use_signal(|| {
         if let Some(ExerciseMetadata {
-            set_type_config: SetTypeConfig::Weighted { min_weight, .. },
+            set_type_config:
+                SetTypeConfig::Weighted { min_weight, .. } | SetTypeConfig::Carry { min_weight, .. },
             ..
         }) = &initial_exercise
         {
-            *min_weight
+            unit.from_kg(*min_weight as f64) as f32
         } else {
             0.0
         }
     });
     let mut increment = use_signal(|| {
         if let Some(ExerciseMetadata {
-            set_type_config: SetTypeConfig::Weighted { increment, .. },
+            set_type_config:
+                SetTypeConfig::Weighted { increment, .. }
+                | SetTypeConfig::Carry { increment, .. }
+                | SetTypeConfig::LoadedBodyweight { increment, .. },
+            ..
+        }) = &initial_exercise
+        {
+            unit.from_kg(*increment as f64) as f32
+        } else {
+            match unit {
+                // 2.5 kg is a common barbell increment (1.25kg plates on each side)
+                WeightUnit::Kg => 2.5,
+                // 5 lb is the pound equivalent (2.5lb plates on each side)
+                WeightUnit::Lb => 5.0,
+            }
+        }
+    });
+    let mut bar_weight = use_signal(|| {
+        if let Some(ExerciseMetadata {
+            set_type_config:
+                SetTypeConfig::Weighted {
+                    bar_weight: Some(bar),
+                    ..
+                },
             ..
         }) = &initial_exercise
         {
-            *increment
+            Some(unit.from_kg(*bar as f64) as f32)
         } else {
-            // 2.5 kg is a common barbell increment (1.25kg plates on each side)
-            2.5
+            None
         }
     });
+    let mut rest_secs = use_signal(|| {
+        initial_exercise
+            .as_ref()
+            .map(|e| e.rest_duration_secs())
+            .unwrap_or(DEFAULT_REST_SECS)
+    });
+    let mut e1rm_formula = use_signal(|| initial_exercise.as_ref().and_then(|e| e.e1rm_formula));
+    let mut progression = use_signal(|| {
+        initial_exercise
+            .as_ref()
+            .map(|e| e.progression)
+            .unwrap_or_default()
+    });
+    let mut notes = use_signal(|| {
+        initial_exercise
+            .as_ref()
+            .map(|e| e.notes.clone())
+            .unwrap_or_default()
+    });
     let mut validation_error = use_signal(|| None::<String>);
     let mut muscle_groups = use_signal(|| initial_muscle_groups);
 
+    // Colour the muscle picker by this week's Volume Zones when the form is
+    // rendered inside the app.
+    let workout_state = try_consume_context::<WorkoutState>();
+    let mut volume_zones = use_signal(HashMap::<MuscleGroup, VolumeZone>::new);
+    use_effect(move || {
+        let Some(state) = workout_state else {
+            return;
+        };
+        spawn(async move {
+            match WorkoutStateManager::weekly_volume(&state).await {
+                Ok(weekly) => volume_zones.set(
+                    weekly
+                        .into_iter()
+                        .map(|(group, _, zone)| (group, zone))
+                        .collect(),
+                ),
+                Err(e) => log::warn!("Failed to load weekly volume: {}", e),
+            }
+        });
+    });
+
     let initial_id = initial_exercise.as_ref().and_then(|e| e.id.clone());
     let is_edit = initial_exercise.is_some();
 
@@ -92,19 +265,47 @@ pub fn ExerciseForm(
 
         validation_error.set(None);
 
+        let edited_config = match (is_weighted(), measure()) {
+            (true, Measure::Distance) => SetTypeConfig::Carry {
+                min_weight: unit.to_kg(min_weight() as f64) as f32,
+                increment: unit.to_kg(increment() as f64) as f32,
+                increment_m: measure_increment(),
+            },
+            (true, _) => SetTypeConfig::Weighted {
+                min_weight: unit.to_kg(min_weight() as f64) as f32,
+                increment: unit.to_kg(increment() as f64) as f32,
+                bar_weight: bar_weight().map(|b| unit.to_kg(b as f64) as f32),
+            },
+            (false, Measure::Reps) => match bodyweight_load() {
+                BodyweightLoad::None => SetTypeConfig::Bodyweight,
+                load => SetTypeConfig::LoadedBodyweight {
+                    increment: unit.to_kg(increment() as f64) as f32,
+                    assisted: load == BodyweightLoad::Assisted,
+                },
+            },
+            (false, Measure::Time) => SetTypeConfig::Timed {
+                increment_secs: measure_increment().round() as u32,
+            },
+            (false, Measure::Distance) => SetTypeConfig::Distance {
+                increment_m: measure_increment(),
+            },
+        };
+        let (set_type_config, weight_unit) = match &stored_exercise {
+            Some(stored) => stored.edited_loading(edited_config, unit),
+            None => (edited_config, unit),
+        };
+
         let exercise = ExerciseMetadata {
             id: initial_id.clone(),
             name,
-            set_type_config: if is_weighted() {
-                SetTypeConfig::Weighted {
-                    min_weight: min_weight(),
-                    increment: increment(),
-                }
-            } else {
-                SetTypeConfig::Bodyweight
-            },
+            set_type_config,
             min_reps: initial_exercise.as_ref().map(|e| e.min_reps).unwrap_or(1),
             max_reps: initial_exercise.as_ref().and_then(|e| e.max_reps),
+            notes: notes().trim().to_string(),
+            rest_secs: Some(rest_secs()),
+            e1rm_formula: e1rm_formula(),
+            progression: progression(),
+            weight_unit,
         };
 
         on_save.call((exercise, muscle_groups()));
@@ -166,7 +367,140 @@ pub fn ExerciseForm(
                             class: "checkbox",
                             r#type: "checkbox",
                             checked: is_weighted(),
-                            oninput: move |e| is_weighted.set(e.checked())
+                            oninput: move |e| {
+                                is_weighted.set(e.checked());
+                                // A weighted set is a rep set or a carry; there is no weighted hold.
+                                if e.checked() && measure() == Measure::Time {
+                                    measure.set(Measure::Reps);
+                                }
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "form-control mt-4",
+                    label {
+                        class: "label",
+                        r#for: "measure-select",
+                        span {
+                            class: "label-text",
+                            "Measured By"
+                        }
+                    }
+                    select {
+                        id: "measure-select",
+                        "data-testid": "measure-select",
+                        class: "select select-bordered w-full",
+                        onchange: move |e| {
+                            let selected = match e.value().as_str() {
+                                "time" => Measure::Time,
+                                "distance" => Measure::Distance,
+                                _ => Measure::Reps,
+                            };
+                            measure.set(selected);
+                            measure_increment.set(5.0);
+                        },
+                        option { value: "reps", selected: measure() == Measure::Reps, "Reps" }
+                        option {
+                            value: "time",
+                            selected: measure() == Measure::Time,
+                            disabled: is_weighted(),
+                            "Time"
+                        }
+                        option {
+                            value: "distance",
+                            selected: measure() == Measure::Distance,
+                            if is_weighted() { "Distance (carry)" } else { "Distance" }
+                        }
+                    }
+                }
+                if !is_weighted() && measure() == Measure::Reps {
+                    div {
+                        class: "form-control mt-4",
+                        label {
+                            class: "label",
+                            r#for: "bodyweight-load-select",
+                            span {
+                                class: "label-text",
+                                "Bodyweight Load"
+                            }
+                        }
+                        select {
+                            id: "bodyweight-load-select",
+                            "data-testid": "bodyweight-load-select",
+                            class: "select select-bordered w-full",
+                            onchange: move |e| {
+                                bodyweight_load.set(match e.value().as_str() {
+                                    "added" => BodyweightLoad::Added,
+                                    "assisted" => BodyweightLoad::Assisted,
+                                    _ => BodyweightLoad::None,
+                                });
+                            },
+                            option { value: "none", selected: bodyweight_load() == BodyweightLoad::None, "Bodyweight only" }
+                            option { value: "added", selected: bodyweight_load() == BodyweightLoad::Added, "Added weight (belt, vest)" }
+                            option { value: "assisted", selected: bodyweight_load() == BodyweightLoad::Assisted, "Assisted (band, machine)" }
+                        }
+                    }
+                    if bodyweight_load() != BodyweightLoad::None {
+                        div {
+                            class: "form-control w-full mt-6",
+                            label {
+                                class: "label flex-col items-start gap-1",
+                                span {
+                                    class: "label-text font-bold text-lg",
+                                    if bodyweight_load() == BodyweightLoad::Assisted { "Assistance Increment ({unit})" } else { "Weight Increment ({unit})" }
+                                }
+                                span {
+                                    class: "label-text-alt text-base-content/70 whitespace-normal",
+                                    "Suggestions work on your logged bodyweight plus added weight, or minus assistance, and move in this step."
+                                }
+                            }
+                            div {
+                                class: "flex flex-wrap gap-3 justify-center mt-2",
+                                for &inc in increment_presets(unit) {
+                                    button {
+                                        key: "{inc}",
+                                        class: if (increment() - inc).abs() < 0.001 {
+                                            "btn btn-primary btn-md flex-1 min-w-[70px] shadow-lg"
+                                        } else {
+                                            "btn btn-outline btn-md flex-1 min-w-[70px]"
+                                        },
+                                        onclick: move |_| increment.set(inc),
+                                        "{inc}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if measure() != Measure::Reps {
+                    div {
+                        class: "form-control w-full mt-6",
+                        label {
+                            class: "label flex-col items-start gap-1",
+                            span {
+                                class: "label-text font-bold text-lg",
+                                if measure() == Measure::Time { "Time Increment (s)" } else { "Distance Increment (m)" }
+                            }
+                            span {
+                                class: "label-text-alt text-base-content/70 whitespace-normal",
+                                "The step suggestions move in. Each RPE point below 10 counts as one step left in reserve."
+                            }
+                        }
+                        div {
+                            class: "flex flex-wrap gap-3 justify-center mt-2",
+                            for &inc in measure().increment_presets() {
+                                button {
+                                    key: "{inc}",
+                                    class: if (measure_increment() - inc).abs() < 0.001 {
+                                        "btn btn-primary btn-md flex-1 min-w-[70px] shadow-lg"
+                                    } else {
+                                        "btn btn-outline btn-md flex-1 min-w-[70px]"
+                                    },
+                                    onclick: move |_| measure_increment.set(inc),
+                                    "{inc}"
+                                }
+                            }
                         }
                     }
                 }
@@ -179,7 +513,7 @@ pub fn ExerciseForm(
                                 class: "label flex-col items-start gap-1",
                                 span {
                                     class: "label-text font-bold text-lg",
-                                    "Minimum Weight (kg)"
+                                    "Minimum Weight ({unit})"
                                 }
                                 span {
                                     class: "label-text-alt text-base-content/70 whitespace-normal",
@@ -189,19 +523,19 @@ pub fn ExerciseForm(
                             TapeMeasure {
                                 value: min_weight() as f64,
                                 min: (min_weight() % increment()) as f64,
-                                max: 500.0,
+                                max: unit.from_kg(500.0),
                                 step: increment() as f64,
                                 on_change: move |val| min_weight.set(val as f32)
                             }
                             div {
                                 class: "text-center text-3xl font-black text-primary mt-2",
-                                "{crate::format::fmt_weight(min_weight())} kg"
+                                "{crate::format::fmt_weight(min_weight())} {unit}"
                             }
                             StepControls {
                                 value: min_weight() as f64,
                                 steps: vec![-10.0, 10.0],
                                 min: 0.0,
-                                max: 500.0,
+                                max: unit.from_kg(500.0),
                                 on_change: move |val| min_weight.set(val as f32)
                             }
                         }
@@ -211,29 +545,82 @@ pub fn ExerciseForm(
                                 class: "label flex-col items-start gap-1",
                                 span {
                                     class: "label-text font-bold text-lg",
-                                    "Weight Increment (kg)"
+                                    "Weight Increment ({unit})"
                                 }
                                 span {
                                     class: "label-text-alt text-base-content/70 whitespace-normal",
-                                    "The smallest amount of weight you can add on top of the minimum (e.g., adding two 1.25kg plates equals a 2.5kg increment)."
+                                    match unit {
+                                        WeightUnit::Kg => "The smallest amount of weight you can add on top of the minimum (e.g., adding two 1.25kg plates equals a 2.5kg increment).",
+                                        WeightUnit::Lb => "The smallest amount of weight you can add on top of the minimum (e.g., adding two 2.5lb plates equals a 5lb increment).",
+                                    }
                                 }
                             }
                             div {
                                 class: "flex flex-wrap gap-3 justify-center mt-2",
-                                for &inc in &[1.25, 2.5, 5.0, 10.0] {
+                                for &inc in increment_presets(unit) {
                                     button {
                                         key: "{inc}",
-                                        class: if (increment() - inc as f32).abs() < 0.001 {
+                                        class: if (increment() - inc).abs() < 0.001 {
                                             "btn btn-primary btn-md flex-1 min-w-[70px] shadow-lg"
                                         } else {
                                             "btn btn-outline btn-md flex-1 min-w-[70px]"
                                         },
-                                        onclick: move |_| increment.set(inc as f32),
+                                        onclick: move |_| increment.set(inc),
                                         "{inc}"
                                     }
                                 }
                             }
                         }
+                        if measure() == Measure::Reps {
+                            div {
+                                class: "form-control w-full",
+                                label {
+                                    class: "label cursor-pointer",
+                                    span {
+                                        class: "label-text font-bold text-lg",
+                                        "Plate-loaded"
+                                    }
+                                    input {
+                                        "data-testid": "plate-loaded-toggle",
+                                        class: "checkbox",
+                                        r#type: "checkbox",
+                                        checked: bar_weight().is_some(),
+                                        oninput: move |e| {
+                                            bar_weight.set(if e.checked() {
+                                                Some(min_weight())
+                                            } else {
+                                                None
+                                            })
+                                        }
+                                    }
+                                }
+                                if let Some(bar) = bar_weight() {
+                                    span {
+                                        class: "label-text-alt text-base-content/70 whitespace-normal px-1",
+                                        "Bar weight ({unit}) — suggestions are broken into plates per side from your plate inventory."
+                                    }
+                                    div {
+                                        class: "flex flex-wrap gap-3 justify-center mt-2",
+                                        for &preset in bar_presets(unit) {
+                                            button {
+                                                key: "{preset}",
+                                                class: if (bar - preset).abs() < 0.001 {
+                                                    "btn btn-primary btn-md flex-1 min-w-[70px] shadow-lg"
+                                                } else {
+                                                    "btn btn-outline btn-md flex-1 min-w-[70px]"
+                                                },
+                                                onclick: move |_| bar_weight.set(Some(preset)),
+                                                "{preset}"
+                                            }
+                                        }
+                                    }
+                                    div {
+                                        class: "text-center text-xl font-black text-primary mt-2",
+                                        "Bar: {crate::format::fmt_weight(bar)} {unit}"
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
 
@@ -250,12 +637,151 @@ pub fn ExerciseForm(
                     BodyDiagram {
                         selections: muscle_groups(),
                         on_selection_change: move |updated| muscle_groups.set(updated),
+                        zones: volume_zones(),
                     }
                     div { class: "mt-3",
                         MuscleGroupTierCards {
                             selections: muscle_groups(),
                             on_selection_change: move |updated| muscle_groups.set(updated),
+                            zones: volume_zones(),
+                        }
+                    }
+                }
+
+                // ── Rest ───────────────────────────────────────────────────────
+                div {
+                    class: "form-control mt-6",
+                    label {
+                        class: "label flex-col items-start gap-1",
+                        span {
+                            class: "label-text font-bold text-lg",
+                            "Rest Between Sets"
                         }
+                        span {
+                            class: "label-text-alt text-base-content/70 whitespace-normal",
+                            "The rest timer starts with this countdown whenever you log a set. Set it to 0 to turn the timer off."
+                        }
+                    }
+                    div {
+                        class: "text-center text-3xl font-black text-primary",
+                        "data-testid": "rest-secs-readout",
+                        if rest_secs() == 0 {
+                            "Off"
+                        } else {
+                            {crate::format::fmt_duration(rest_secs())}
+                        }
+                    }
+                    StepControls {
+                        value: rest_secs() as f64,
+                        steps: vec![-60.0, -15.0, 15.0, 60.0],
+                        min: 0.0,
+                        max: MAX_REST_SECS as f64,
+                        on_change: move |val: f64| rest_secs.set(val as u32)
+                    }
+                }
+
+                // ── e1RM Formula ───────────────────────────────────────────────
+                if measure() == Measure::Reps && (is_weighted() || bodyweight_load() != BodyweightLoad::None) {
+                    div {
+                        class: "form-control mt-6",
+                        label {
+                            class: "label",
+                            r#for: "e1rm-formula-select",
+                            span {
+                                class: "label-text font-bold text-lg",
+                                "e1RM Formula"
+                            }
+                        }
+                        select {
+                            id: "e1rm-formula-select",
+                            "data-testid": "exercise-e1rm-formula-select",
+                            class: "select select-bordered w-full",
+                            onchange: move |e| e1rm_formula.set(e.value().parse::<E1rmFormula>().ok()),
+                            option { value: "", selected: e1rm_formula().is_none(), "Use global setting" }
+                            for formula in E1rmFormula::ALL {
+                                option {
+                                    key: "{formula}",
+                                    value: "{formula}",
+                                    selected: e1rm_formula() == Some(formula),
+                                    "{formula.label()}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // ── Progression ────────────────────────────────────────────────
+                if is_weighted() && measure() == Measure::Reps {
+                    div {
+                        class: "form-control mt-6",
+                        label {
+                            class: "label flex-col items-start gap-1",
+                            r#for: "progression-select",
+                            span {
+                                class: "label-text font-bold text-lg",
+                                "Progression"
+                            }
+                            span {
+                                class: "label-text-alt text-base-content/70 whitespace-normal",
+                                "How the next Suggestion is chosen. Linear and double progression build on your last session's working sets."
+                            }
+                        }
+                        select {
+                            id: "progression-select",
+                            "data-testid": "exercise-progression-select",
+                            class: "select select-bordered w-full",
+                            onchange: move |e| {
+                                let chosen = ProgressionStrategy::ALL
+                                    .into_iter()
+                                    .find(|s| s.key() == e.value())
+                                    .unwrap_or_default();
+                                progression.set(chosen);
+                            },
+                            for strategy in ProgressionStrategy::ALL {
+                                option {
+                                    key: "{strategy.key()}",
+                                    value: "{strategy.key()}",
+                                    selected: progression().key() == strategy.key(),
+                                    "{strategy.label()}"
+                                }
+                            }
+                        }
+                        if let ProgressionStrategy::PercentOfE1rm(pct) = progression() {
+                            div {
+                                class: "text-center text-3xl font-black text-primary mt-3",
+                                "data-testid": "progression-percent-readout",
+                                "{pct}% of e1RM"
+                            }
+                            StepControls {
+                                value: pct as f64,
+                                steps: vec![-5.0, -1.0, 1.0, 5.0],
+                                min: 1.0,
+                                max: 100.0,
+                                on_change: move |val: f64| {
+                                    progression.set(ProgressionStrategy::PercentOfE1rm(val as u32))
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // ── Notes ──────────────────────────────────────────────────────
+                div {
+                    class: "form-control mt-6",
+                    label {
+                        class: "label",
+                        span {
+                            class: "label-text font-bold text-lg",
+                            "Notes"
+                        }
+                    }
+                    textarea {
+                        class: "textarea textarea-bordered w-full",
+                        "data-testid": "exercise-notes-input",
+                        rows: "3",
+                        placeholder: "Form cues, e.g. elbows tucked",
+                        value: "{notes}",
+                        oninput: move |evt| notes.set(evt.value())
                     }
                 }
 
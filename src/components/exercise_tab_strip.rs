@@ -1,3 +1,4 @@
+use crate::domain::slot_group;
 use crate::models::PlanExercise;
 use dioxus::prelude::*;
 
@@ -52,6 +53,7 @@ pub fn ExerciseTabStrip(
                             let completed = completed_counts.get(idx).copied().unwrap_or(0);
                             let all_done = completed >= planned;
                             let over_plan = completed > planned;
+                            let group_label = slot_group::group_label(&exercises, idx);
 
                             rsx! {
                                 button {
@@ -65,6 +67,13 @@ pub fn ExerciseTabStrip(
                                     onclick: move |_| on_select.call(idx),
                                     div {
                                         class: "flex items-center gap-1",
+                                        if let Some(label) = group_label {
+                                            span {
+                                                class: "text-[10px] font-black opacity-70",
+                                                "data-testid": "tab-group-label",
+                                                "{label}"
+                                            }
+                                        }
                                         span {
                                             class: "text-xs font-bold truncate max-w-[80px]",
                                             "{exercise_name.to_uppercase()}"
@@ -1,7 +1,8 @@
 use crate::components::edit_set_modal::EditSetModal;
-use crate::models::{ExerciseMetadata, HistorySet};
+use crate::models::{ExerciseMetadata, HistorySet, SetType};
 use crate::state::{Database, WorkoutState, WorkoutStateManager};
 use dioxus::prelude::*;
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
 
@@ -122,7 +123,12 @@ pub fn HistoryView(
     /// When `Some`, a back button is rendered and this handler is called on click.
     /// Omit to suppress the back button (e.g. when the parent already provides one).
     on_back: Option<EventHandler<()>>,
+    /// Day ("YYYY-MM-DD") to scroll to, loading older pages until it appears.
+    /// Reset to `None` once reached or once history runs out.
+    focus_day: Option<Signal<Option<String>>>,
 ) -> Element {
+    let unit = state.settings().weight_unit;
+
     // Track exercise_id prop in a signal for reactivity in effects
     let mut eid_signal = use_signal(|| exercise_id.clone());
     if *eid_signal.peek() != exercise_id {
@@ -164,12 +170,13 @@ pub fn HistoryView(
     let mut has_more = use_signal(|| true);
     let mut loading = use_signal(|| false);
     let mut exercise_name = use_signal(String::new);
+    let mut exercise_notes = use_signal(String::new);
 
     // Edit modal state
     let mut editing_set = use_signal(|| None::<HistorySet>);
     let mut editing_exercise = use_signal(|| None::<ExerciseMetadata>);
 
-    // Fetch the exercise name for the toggle label
+    // Fetch the exercise name for the toggle label and its notes for the header
     {
         let state_ref = state;
         use_effect(move || {
@@ -187,6 +194,7 @@ pub fn HistoryView(
                             .find(|e| e.id.as_deref() == Some(id.as_str()))
                     {
                         exercise_name.set(ex.name.clone());
+                        exercise_notes.set(ex.notes.clone());
                     }
                 });
             }
@@ -278,6 +286,25 @@ pub fn HistoryView(
         }
     });
 
+    // Day-group elements by date label, for scrolling to a focused day
+    let mut day_elements = use_signal(HashMap::<String, web_sys::Element>::new);
+    use_effect(move || {
+        let Some(mut focus) = focus_day else {
+            return;
+        };
+        let Some(day) = focus() else {
+            return;
+        };
+        if let Some(el) = day_elements.read().get(&day) {
+            el.scroll_into_view();
+            focus.set(None);
+        } else if !has_more() {
+            focus.set(None);
+        } else if !loading() {
+            load_trigger.with_mut(|v| *v += 1);
+        }
+    });
+
     // Get local UTC offset from the browser
     let utc_offset = get_utc_offset_minutes();
     let grouped = group_sets_by_day(&sets.read(), utc_offset);
@@ -370,6 +397,14 @@ pub fn HistoryView(
                 }
             }
 
+            if scope() == HistoryScope::Exercise && !exercise_notes().trim().is_empty() {
+                div {
+                    class: "alert bg-base-200 text-sm whitespace-pre-line mb-4",
+                    "data-testid": "history-exercise-notes",
+                    "{exercise_notes}"
+                }
+            }
+
             // Feed
             if grouped.is_empty() && !loading() {
                 div {
@@ -385,6 +420,14 @@ pub fn HistoryView(
                             key: "{day.date_label}",
                             class: "mb-6",
                             "data-testid": "history-day-group",
+                            onmounted: {
+                                let label = day.date_label.clone();
+                                move |el: MountedEvent| {
+                                    if let Some(raw) = el.data().downcast::<web_sys::Element>() {
+                                        day_elements.write().insert(label.clone(), raw.clone());
+                                    }
+                                }
+                            },
                             h3 {
                                 class: "text-sm font-bold text-base-content/50 uppercase tracking-widest mb-2",
                                 "data-testid": "history-day-label",
@@ -392,7 +435,15 @@ pub fn HistoryView(
                             }
                             for eg in day.exercises.iter() {
                                 {
-                                    let has_weighted = eg.sets.iter().any(|s| matches!(s.set_type, crate::models::SetType::Weighted { .. }));
+                                    let has_weighted = eg.sets.iter().any(|s| s.set_type.system_load().is_some());
+                                    let has_reps = eg.sets.iter().any(|s| s.set_type.measure().is_none());
+                                    let measure_heading = if eg.sets.iter().any(|s| matches!(s.set_type, crate::models::SetType::Timed { .. })) {
+                                        Some("Time")
+                                    } else if eg.sets.iter().any(|s| s.set_type.measure().is_some()) {
+                                        Some("Distance")
+                                    } else {
+                                        None
+                                    };
                                     rsx! {
                                         div {
                                             key: "{eg.exercise_id}",
@@ -413,7 +464,12 @@ pub fn HistoryView(
                                                             if has_weighted {
                                                                 th { "Weight" }
                                                             }
-                                                            th { "Reps" }
+                                                            if let Some(heading) = measure_heading {
+                                                                th { "{heading}" }
+                                                            }
+                                                            if has_reps {
+                                                                th { "Reps" }
+                                                            }
                                                             th { "RPE" }
                                                         }
                                                     }
@@ -440,17 +496,41 @@ pub fn HistoryView(
                                                                         });
                                                                     }
                                                                 },
-                                                                td { "{set.set_number}" }
+                                                                td {
+                                                                    "{set.kind.badge()}{set.set_number}"
+                                                                }
                                                                 if has_weighted {
-                                                                    if let crate::models::SetType::Weighted { weight } = set.set_type {
-                                                                        td { "{crate::format::fmt_weight(weight)} kg" }
+                                                                    if let SetType::LoadedBodyweight { added, .. } = set.set_type {
+                                                                        td { {crate::format::fmt_bodyweight_load(added, unit)} }
+                                                                    } else if let Some(weight) = set.set_type.weight() {
+                                                                        td { {crate::format::fmt_weight_in(weight, unit)} }
+                                                                    } else {
+                                                                        td { "—" }
+                                                                    }
+                                                                }
+                                                                if measure_heading.is_some() {
+                                                                    td { {crate::format::fmt_set_measure(&set.set_type).unwrap_or_else(|| "—".to_string())} }
+                                                                }
+                                                                if has_reps {
+                                                                    if set.set_type.measure().is_none() {
+                                                                        td { "{set.reps}" }
                                                                     } else {
                                                                         td { "—" }
                                                                     }
                                                                 }
-                                                                td { "{set.reps}" }
                                                                 td { "{set.rpe:.1}" }
                                                             }
+                                                            if !set.comment.is_empty() {
+                                                                tr {
+                                                                    key: "{set.id}-comment",
+                                                                    "data-testid": "history-set-comment",
+                                                                    td {
+                                                                        colspan: "5",
+                                                                        class: "text-xs italic text-base-content/60 pt-0",
+                                                                        "{set.comment}"
+                                                                    }
+                                                                }
+                                                            }
                                                         }
                                                     }
                                                 }
@@ -529,18 +609,31 @@ pub fn HistoryView(
             EditSetModal {
                 set: set.clone(),
                 exercise: ex.clone(),
+                unit,
                 on_cancel: move |_| {
                     editing_set.set(None);
                     editing_exercise.set(None);
                 },
                 on_save: {
                     let state_ref = state;
-                    move |(reps, rpe, weight, recorded_at)| {
+                    let set = set.clone();
+                    let formula = ex.e1rm_formula_or(state.settings().e1rm_formula);
+                    move |(reps, rpe, set_type, comment, recorded_at): (u32, f32, crate::models::SetType, String, f64)| {
                         let state_ref = state_ref;
+                        let original = set.clone();
                         let set_id = set.id;
                         spawn(async move {
-                            if let Some(db) = state_ref.database()
-                                && db.update_set(set_id, reps, rpe, weight, recorded_at).await.is_ok()
+                            let edited = crate::models::CompletedSet {
+                                set_number: original.set_number,
+                                reps,
+                                rpe,
+                                set_type: set_type.clone(),
+                                kind: original.kind,
+                                comment: comment.clone(),
+                            };
+                            if WorkoutStateManager::update_set(&state_ref, &original, &edited, recorded_at, formula)
+                                .await
+                                .is_ok()
                             {
                                 // Update the set in the local signal to refresh the UI in place
                                 sets.with_mut(|s| {
@@ -548,11 +641,8 @@ pub fn HistoryView(
                                         item.reps = reps;
                                         item.rpe = rpe;
                                         item.recorded_at = recorded_at;
-                                        if let Some(w) = weight {
-                                            item.set_type = crate::models::SetType::Weighted { weight: w };
-                                        } else {
-                                            item.set_type = crate::models::SetType::Bodyweight;
-                                        }
+                                        item.set_type = set_type;
+                                        item.comment = comment;
                                     }
                                 });
 
@@ -566,11 +656,14 @@ pub fn HistoryView(
                 },
                 on_delete: {
                     let state_ref = state;
+                    let formula = ex.e1rm_formula_or(state.settings().e1rm_formula);
                     move |set_id| {
                         let state_ref = state_ref;
+                        let deleted = set.clone();
                         spawn(async move {
-                            if let Some(db) = state_ref.database()
-                                && db.delete_set(set_id).await.is_ok()
+                            if WorkoutStateManager::delete_set(&state_ref, &deleted, formula)
+                                .await
+                                .is_ok()
                             {
                                 // Remove the set from the local signal
                                 sets.with_mut(|s| {
@@ -619,7 +712,7 @@ fn get_utc_offset_minutes() -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::SetType;
+    use crate::models::{SetKind, SetType};
 
     fn make_set(
         id: i64,
@@ -636,6 +729,9 @@ mod tests {
             reps: 8,
             rpe: 7.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
+            rest_secs: None,
             recorded_at,
         }
     }
@@ -6,20 +6,7 @@ use dioxus::prelude::*;
 use std::collections::HashMap;
 
 /// Canonical enum order for muscle group section headers.
-const MUSCLE_GROUP_ORDER: &[MuscleGroup] = &[
-    MuscleGroup::Chest,
-    MuscleGroup::Back,
-    MuscleGroup::Shoulders,
-    MuscleGroup::Biceps,
-    MuscleGroup::Triceps,
-    MuscleGroup::Quads,
-    MuscleGroup::Hamstrings,
-    MuscleGroup::Glutes,
-    MuscleGroup::Calves,
-    MuscleGroup::Core,
-    MuscleGroup::Forearms,
-    MuscleGroup::Traps,
-];
+const MUSCLE_GROUP_ORDER: &[MuscleGroup] = &MuscleGroup::ALL;
 
 #[derive(Clone, PartialEq)]
 pub struct TestSearchQuery(pub String);
@@ -27,6 +14,7 @@ pub struct TestSearchQuery(pub String);
 #[component]
 pub fn LibraryView() -> Element {
     let workout_state = consume_context::<WorkoutState>();
+    let unit = workout_state.settings().weight_unit;
     let navigator = use_navigator();
     // Allow injecting a search query context for easier unit testing
     let test_query = try_consume_context::<TestSearchQuery>();
@@ -73,7 +61,9 @@ pub fn LibraryView() -> Element {
         };
         source
             .into_iter()
-            .filter(|e| e.name.to_lowercase().contains(&query))
+            .filter(|e| {
+                e.name.to_lowercase().contains(&query) || e.notes.to_lowercase().contains(&query)
+            })
             .collect::<Vec<_>>()
     });
 
@@ -84,6 +74,7 @@ pub fn LibraryView() -> Element {
                 ExerciseForm {
                     initial_exercise: None,
                     initial_muscle_groups: vec![],
+                    unit,
                     on_cancel: move |_| show_new_form.set(false),
                     on_save: move |(exercise, muscle_groups)| {
                         spawn(async move {
@@ -253,13 +244,29 @@ pub fn LibraryView() -> Element {
                                                 }
                                             } else {
                                                 match exercise.set_type_config {
-                                                    SetTypeConfig::Weighted { min_weight, increment } => rsx! {
+                                                    SetTypeConfig::Weighted { min_weight, increment, .. } => rsx! {
                                                         span { class: "badge badge-primary badge-sm font-bold", "WEIGHTED" }
-                                                        span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(min_weight)}kg (+{crate::format::fmt_weight(increment)}kg)" }
+                                                        span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(unit.from_kg(min_weight as f64))}{unit} (+{crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit})" }
                                                     },
                                                     SetTypeConfig::Bodyweight => rsx! {
                                                         span { class: "badge badge-secondary badge-sm font-bold", "BODYWEIGHT" }
                                                     },
+                                                    SetTypeConfig::LoadedBodyweight { increment, assisted } => rsx! {
+                                                        span { class: "badge badge-secondary badge-sm font-bold", if assisted { "ASSISTED" } else { "BW+" } }
+                                                        span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit}" }
+                                                    },
+                                                    SetTypeConfig::Timed { increment_secs } => rsx! {
+                                                        span { class: "badge badge-accent badge-sm font-bold", "TIMED" }
+                                                        span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_duration(increment_secs)}" }
+                                                    },
+                                                    SetTypeConfig::Distance { increment_m } => rsx! {
+                                                        span { class: "badge badge-accent badge-sm font-bold", "DISTANCE" }
+                                                        span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_distance(increment_m)}" }
+                                                    },
+                                                    SetTypeConfig::Carry { min_weight, increment, .. } => rsx! {
+                                                        span { class: "badge badge-accent badge-sm font-bold", "CARRY" }
+                                                        span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(unit.from_kg(min_weight as f64))}{unit} (+{crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit})" }
+                                                    },
                                                 }
                                             }
                                         }
@@ -353,7 +360,8 @@ pub fn LibraryView() -> Element {
                     let groups_map = primary_muscle_groups();
 
                     // Build groups: for each muscle group in enum order, collect exercises that
-                    // list it as a Primary association.  An exercise may appear in multiple groups.
+                    // list it, or one of its Sub-Muscles, as a Primary association.  An exercise
+                    // may appear in multiple groups.
                     let sections: Vec<(MuscleGroup, Vec<ExerciseMetadata>)> = MUSCLE_GROUP_ORDER
                         .iter()
                         .filter_map(|mg| {
@@ -362,7 +370,7 @@ pub fn LibraryView() -> Element {
                                 .filter(|ex| {
                                     ex.id.as_deref()
                                         .and_then(|id| groups_map.get(id))
-                                        .map(|primaries| primaries.contains(mg))
+                                        .map(|primaries| primaries.iter().any(|p| p.top_level() == *mg))
                                         .unwrap_or(false)
                                 })
                                 .cloned()
@@ -444,13 +452,29 @@ pub fn LibraryView() -> Element {
                                                                     div {
                                                                         class: "flex gap-2 mt-1 items-center",
                                                                         match exercise.set_type_config {
-                                                                            SetTypeConfig::Weighted { min_weight, increment } => rsx! {
+                                                                            SetTypeConfig::Weighted { min_weight, increment, .. } => rsx! {
                                                                                 span { class: "badge badge-primary badge-sm font-bold", "WEIGHTED" }
-                                                                                span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(min_weight)}kg (+{crate::format::fmt_weight(increment)}kg)" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(unit.from_kg(min_weight as f64))}{unit} (+{crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit})" }
                                                                             },
                                                                             SetTypeConfig::Bodyweight => rsx! {
                                                                                 span { class: "badge badge-secondary badge-sm font-bold", "BODYWEIGHT" }
                                                                             },
+                                                                            SetTypeConfig::LoadedBodyweight { increment, assisted } => rsx! {
+                                                                                span { class: "badge badge-secondary badge-sm font-bold", if assisted { "ASSISTED" } else { "BW+" } }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit}" }
+                                                                            },
+                                                                            SetTypeConfig::Timed { increment_secs } => rsx! {
+                                                                                span { class: "badge badge-accent badge-sm font-bold", "TIMED" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_duration(increment_secs)}" }
+                                                                            },
+                                                                            SetTypeConfig::Distance { increment_m } => rsx! {
+                                                                                span { class: "badge badge-accent badge-sm font-bold", "DISTANCE" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_distance(increment_m)}" }
+                                                                            },
+                                                                            SetTypeConfig::Carry { min_weight, increment, .. } => rsx! {
+                                                                                span { class: "badge badge-accent badge-sm font-bold", "CARRY" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(unit.from_kg(min_weight as f64))}{unit} (+{crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit})" }
+                                                                            },
                                                                         }
                                                                     }
                                                                 }
@@ -573,13 +597,29 @@ pub fn LibraryView() -> Element {
                                                                     div {
                                                                         class: "flex gap-2 mt-1 items-center",
                                                                         match exercise.set_type_config {
-                                                                            SetTypeConfig::Weighted { min_weight, increment } => rsx! {
+                                                                            SetTypeConfig::Weighted { min_weight, increment, .. } => rsx! {
                                                                                 span { class: "badge badge-primary badge-sm font-bold", "WEIGHTED" }
-                                                                                span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(min_weight)}kg (+{crate::format::fmt_weight(increment)}kg)" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(unit.from_kg(min_weight as f64))}{unit} (+{crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit})" }
                                                                             },
                                                                             SetTypeConfig::Bodyweight => rsx! {
                                                                                 span { class: "badge badge-secondary badge-sm font-bold", "BODYWEIGHT" }
                                                                             },
+                                                                            SetTypeConfig::LoadedBodyweight { increment, assisted } => rsx! {
+                                                                                span { class: "badge badge-secondary badge-sm font-bold", if assisted { "ASSISTED" } else { "BW+" } }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit}" }
+                                                                            },
+                                                                            SetTypeConfig::Timed { increment_secs } => rsx! {
+                                                                                span { class: "badge badge-accent badge-sm font-bold", "TIMED" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_duration(increment_secs)}" }
+                                                                            },
+                                                                            SetTypeConfig::Distance { increment_m } => rsx! {
+                                                                                span { class: "badge badge-accent badge-sm font-bold", "DISTANCE" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "STEP: {crate::format::fmt_distance(increment_m)}" }
+                                                                            },
+                                                                            SetTypeConfig::Carry { min_weight, increment, .. } => rsx! {
+                                                                                span { class: "badge badge-accent badge-sm font-bold", "CARRY" }
+                                                                                span { class: "text-xs font-bold text-base-content/50", "START: {crate::format::fmt_weight(unit.from_kg(min_weight as f64))}{unit} (+{crate::format::fmt_weight(unit.from_kg(increment as f64))}{unit})" }
+                                                                            },
                                                                         }
                                                                     }
                                                                 }
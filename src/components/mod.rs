@@ -1,9 +1,12 @@
+pub mod analysis_view;
 pub mod body_diagram;
 pub mod bottom_sheet;
 pub mod confirmation_dialog;
 pub mod data_management;
 #[cfg(debug_assertions)]
 pub mod debug_panel;
+pub mod deload_advisor;
+pub mod e1rm_chart;
 pub mod edit_set_modal;
 pub mod exercise_form;
 pub mod exercise_tab_strip;
@@ -11,11 +14,16 @@ pub mod history_view;
 pub mod library_view;
 pub mod muscle_group_tier_cards;
 pub mod pairing;
+pub mod personal_records;
 pub mod plan_builder;
+pub mod plate_breakdown;
+pub mod program_builder;
+pub mod rest_timer;
 pub mod rpe_slider;
 pub mod settings_view;
 pub mod step_controls;
 pub mod sync_status_indicator;
 pub mod tab_bar;
 pub mod tape_measure;
+pub mod volume_flags;
 pub mod workout_view;
@@ -1,5 +1,6 @@
-use crate::models::{ContributionTier, ExerciseMuscleGroup};
+use crate::models::{ContributionTier, ExerciseMuscleGroup, MuscleGroup, VolumeZone};
 use dioxus::prelude::*;
+use std::collections::HashMap;
 
 fn cycle_tier(tier: &ContributionTier) -> Option<ContributionTier> {
     match tier {
@@ -29,12 +30,17 @@ fn tier_badge_class(tier: &ContributionTier) -> &'static str {
 ///
 /// Tapping a card cycles through Primary → Secondary → Tertiary → removed.
 /// The parent owns selection state; this component emits changes.
+/// With `zones`, each card carries a dot in the colour of its Volume Zone; a
+/// Sub-Muscle shows its parent's.
 #[component]
 pub fn MuscleGroupTierCards(
     /// Current muscle group selections.
     selections: Vec<ExerciseMuscleGroup>,
     /// Called with the updated selection list when the user modifies a card.
     on_selection_change: EventHandler<Vec<ExerciseMuscleGroup>>,
+    /// Volume Zone of each Muscle Group's 7-day Volume.
+    #[props(default)]
+    zones: HashMap<MuscleGroup, VolumeZone>,
 ) -> Element {
     if selections.is_empty() {
         return rsx! {
@@ -58,6 +64,7 @@ pub fn MuscleGroupTierCards(
                     let label = tier_label(&emg.tier);
                     let muscle = emg.muscle_group.clone();
                     let tier = emg.tier.clone();
+                    let zone = zones.get(&emg.muscle_group.top_level()).copied();
 
                     rsx! {
                         button {
@@ -83,6 +90,15 @@ pub fn MuscleGroupTierCards(
                                     on_selection_change.call(updated);
                                 }
                             },
+                            if let Some(zone) = zone {
+                                span {
+                                    "data-testid": "tier-card-zone",
+                                    "data-zone": "{zone.label()}",
+                                    class: "inline-block w-2.5 h-2.5 rounded-full",
+                                    style: "background-color: {zone.color()}",
+                                    title: "{zone.label()}",
+                                }
+                            }
                             span {
                                 class: "text-sm font-medium",
                                 "{name}"
@@ -0,0 +1,87 @@
+use crate::models::PersonalRecord;
+use crate::state::{WorkoutState, WorkoutStateManager};
+use dioxus::prelude::*;
+
+/// Celebration on the Record Screen for the Personal Records the last set
+/// earned. Stays until dismissed or the next set is logged.
+#[component]
+pub fn NewRecordsBanner(state: WorkoutState) -> Element {
+    let records = state.new_records();
+    if records.is_empty() {
+        return rsx! {};
+    }
+    let unit = state.settings().weight_unit;
+
+    rsx! {
+        div {
+            class: "alert alert-success shadow-xl flex items-start",
+            "data-testid": "new-records",
+            div {
+                class: "flex-1 grid gap-1",
+                span { class: "font-black uppercase", "New personal record!" }
+                for record in records {
+                    span {
+                        key: "{record.id}",
+                        class: "text-sm",
+                        "data-testid": "new-record",
+                        "{record.describe(unit)}"
+                    }
+                }
+            }
+            button {
+                class: "btn btn-ghost btn-xs",
+                "data-testid": "new-records-dismiss",
+                onclick: move |_| state.set_new_records(Vec::new()),
+                "✕"
+            }
+        }
+    }
+}
+
+/// Personal Records of one exercise, newest first, for the Library detail
+/// page. Renders nothing until the exercise has a record.
+#[component]
+pub fn PersonalRecordsList(state: WorkoutState, exercise_id: String) -> Element {
+    let mut records = use_signal(Vec::<PersonalRecord>::new);
+
+    use_effect(move || {
+        let eid = exercise_id.clone();
+        spawn(async move {
+            match WorkoutStateManager::personal_records(&state, &eid).await {
+                Ok(list) => records.set(list),
+                Err(e) => log::warn!("Failed to load personal records: {}", e),
+            }
+        });
+    });
+
+    if records().is_empty() {
+        return rsx! {};
+    }
+    let unit = state.settings().weight_unit;
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-md mb-4",
+            "data-testid": "personal-records",
+            div {
+                class: "card-body p-4 gap-2",
+                h3 { class: "text-sm font-semibold uppercase text-base-content/60", "Personal records" }
+                for record in records() {
+                    {
+                        let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(record.recorded_at))
+                            .to_locale_date_string("default", &wasm_bindgen::JsValue::UNDEFINED);
+                        rsx! {
+                            div {
+                                key: "{record.id}",
+                                class: "flex justify-between gap-2 text-sm",
+                                "data-testid": "personal-record",
+                                span { "{record.describe(unit)}" }
+                                span { class: "text-base-content/50 shrink-0", "{date}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
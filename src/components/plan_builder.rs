@@ -1,5 +1,9 @@
 use crate::components::confirmation_dialog::{ConfirmVariant, ConfirmationDialog};
-use crate::models::{ExerciseMetadata, SetTypeConfig, WorkoutTemplate};
+use crate::components::deload_advisor::DeloadAdvisorCard;
+use crate::components::program_builder::ProgramsModal;
+use crate::components::volume_flags::VolumeFlagsCard;
+use crate::domain::slot_group;
+use crate::models::{ExerciseMetadata, SetTypeConfig, SlotTargets, WorkoutTemplate};
 use crate::state::{WorkoutState, WorkoutStateManager};
 use dioxus::prelude::*;
 
@@ -9,6 +13,9 @@ pub fn PlanBuilder(state: WorkoutState) -> Element {
     let mut search_query = use_signal(String::new);
     let mut show_save_template = use_signal(|| false);
     let mut show_load_template = use_signal(|| false);
+    let mut show_programs = use_signal(|| false);
+    let mut suggesting = use_signal(|| false);
+    let mut nothing_to_suggest = use_signal(|| false);
     let settings = state.settings();
 
     let plan = state.current_plan();
@@ -28,45 +35,97 @@ pub fn PlanBuilder(state: WorkoutState) -> Element {
                 "Plan Your Workout"
             }
 
+            DeloadAdvisorCard { state, has_exercises }
+            VolumeFlagsCard { state }
+
             // Exercise list
             if has_exercises {
                 div {
                     class: "grid gap-3 mb-4",
-                    for pe in exercises.iter() {
+                    for (idx, pe) in exercises.iter().enumerate() {
                         {
                             let pe_id = pe.id.clone();
                             let exercise_name = pe.exercise.name.clone();
                             let planned_sets = pe.planned_sets;
                             let pe_id_remove = pe_id.clone();
+                            let pe_id_targets = pe_id.clone();
+                            let group_label = slot_group::group_label(&exercises, idx);
+                            let grouped = group_label.is_some();
+                            let has_next = idx + 1 < exercises.len();
+                            let linked_to_next = slot_group::group_members(&exercises, idx).contains(&(idx + 1));
                             rsx! {
                                 div {
                                     key: "{pe_id}",
-                                    class: "card bg-base-100 shadow-md border border-base-200",
-                                    "data-testid": "plan-exercise-row",
+                                    class: "flex flex-col gap-1",
                                     div {
-                                        class: "card-body p-4",
+                                        class: if grouped {
+                                            "card bg-base-100 shadow-md border border-base-200 border-l-4 border-l-primary"
+                                        } else {
+                                            "card bg-base-100 shadow-md border border-base-200"
+                                        },
+                                        "data-testid": "plan-exercise-row",
                                         div {
-                                            class: "flex justify-between items-center",
+                                            class: "card-body p-4",
                                             div {
-                                                class: "flex-1 min-w-0",
-                                                h3 {
-                                                    class: "font-bold text-base truncate",
-                                                    "{exercise_name.to_uppercase()}"
+                                                class: "flex justify-between items-center",
+                                                div {
+                                                    class: "flex-1 min-w-0 flex items-center gap-2",
+                                                    if let Some(label) = group_label {
+                                                        span {
+                                                            class: "badge badge-primary badge-sm font-bold",
+                                                            "data-testid": "slot-group-label",
+                                                            "{label}"
+                                                        }
+                                                    }
+                                                    h3 {
+                                                        class: "font-bold text-base truncate",
+                                                        "{exercise_name.to_uppercase()}"
+                                                    }
                                                 }
-                                            }
-                                            div {
-                                                class: "flex items-center gap-2 ml-3",
-                                                // Set-count stepper
-                                                button {
-                                                    class: "btn btn-circle btn-sm btn-ghost",
-                                                    "data-testid": "decrement-sets",
-                                                    disabled: planned_sets <= 1,
-                                                    onclick: {
-                                                        let pe_id = pe_id.clone();
-                                                        move |_| {
-                                                            if planned_sets > 1 {
+                                                div {
+                                                    class: "flex items-center gap-2 ml-3",
+                                                    // Set-count stepper
+                                                    button {
+                                                        class: "btn btn-circle btn-sm btn-ghost",
+                                                        "data-testid": "decrement-sets",
+                                                        disabled: planned_sets <= 1,
+                                                        onclick: {
+                                                            let pe_id = pe_id.clone();
+                                                            move |_| {
+                                                                if planned_sets > 1 {
+                                                                    let pe_id = pe_id.clone();
+                                                                    let new_sets = planned_sets - 1;
+                                                                    spawn(async move {
+                                                                        let db = state.database().unwrap();
+                                                                        let now = js_sys::Date::now();
+                                                                        let _ = db.execute(
+                                                                            "UPDATE workout_plan_exercises SET planned_sets = ?, updated_at = ? WHERE id = ?",
+                                                                            &[
+                                                                                wasm_bindgen::JsValue::from_f64(new_sets as f64),
+                                                                                wasm_bindgen::JsValue::from_f64(now),
+                                                                                wasm_bindgen::JsValue::from_str(&pe_id),
+                                                                            ],
+                                                                        ).await;
+                                                                        let _ = WorkoutStateManager::resume_active_plan(&state).await;
+                                                                    });
+                                                                }
+                                                            }
+                                                        },
+                                                        "−"
+                                                    }
+                                                    span {
+                                                        class: "text-lg font-bold w-8 text-center",
+                                                        "data-testid": "planned-sets-value",
+                                                        "{planned_sets}"
+                                                    }
+                                                    button {
+                                                        class: "btn btn-circle btn-sm btn-ghost",
+                                                        "data-testid": "increment-sets",
+                                                        onclick: {
+                                                            let pe_id = pe_id.clone();
+                                                            move |_| {
                                                                 let pe_id = pe_id.clone();
-                                                                let new_sets = planned_sets - 1;
+                                                                let new_sets = planned_sets + 1;
                                                                 spawn(async move {
                                                                     let db = state.database().unwrap();
                                                                     let now = js_sys::Date::now();
@@ -81,57 +140,63 @@ pub fn PlanBuilder(state: WorkoutState) -> Element {
                                                                     let _ = WorkoutStateManager::resume_active_plan(&state).await;
                                                                 });
                                                             }
-                                                        }
-                                                    },
-                                                    "−"
-                                                }
-                                                span {
-                                                    class: "text-lg font-bold w-8 text-center",
-                                                    "data-testid": "planned-sets-value",
-                                                    "{planned_sets}"
-                                                }
-                                                button {
-                                                    class: "btn btn-circle btn-sm btn-ghost",
-                                                    "data-testid": "increment-sets",
-                                                    onclick: {
-                                                        let pe_id = pe_id.clone();
-                                                        move |_| {
-                                                            let pe_id = pe_id.clone();
-                                                            let new_sets = planned_sets + 1;
+                                                        },
+                                                        "+"
+                                                    }
+                                                    // Remove button
+                                                    button {
+                                                        class: "btn btn-circle btn-sm btn-ghost text-error ml-1",
+                                                        "data-testid": "remove-exercise",
+                                                        onclick: move |_| {
+                                                            let pe_id = pe_id_remove.clone();
                                                             spawn(async move {
-                                                                let db = state.database().unwrap();
-                                                                let now = js_sys::Date::now();
-                                                                let _ = db.execute(
-                                                                    "UPDATE workout_plan_exercises SET planned_sets = ?, updated_at = ? WHERE id = ?",
-                                                                    &[
-                                                                        wasm_bindgen::JsValue::from_f64(new_sets as f64),
-                                                                        wasm_bindgen::JsValue::from_f64(now),
-                                                                        wasm_bindgen::JsValue::from_str(&pe_id),
-                                                                    ],
-                                                                ).await;
-                                                                let _ = WorkoutStateManager::resume_active_plan(&state).await;
+                                                                if let Err(e) = WorkoutStateManager::remove_exercise_from_plan(&state, &pe_id).await {
+                                                                    log::warn!("Failed to remove exercise: {}", e);
+                                                                }
                                                             });
-                                                        }
-                                                    },
-                                                    "+"
+                                                        },
+                                                        "✕"
+                                                    }
                                                 }
-                                                // Remove button
-                                                button {
-                                                    class: "btn btn-circle btn-sm btn-ghost text-error ml-1",
-                                                    "data-testid": "remove-exercise",
-                                                    onclick: move |_| {
-                                                        let pe_id = pe_id_remove.clone();
+                                            }
+                                            SlotTargetsEditor {
+                                                exercise: pe.exercise.clone(),
+                                                targets: pe.targets,
+                                                default_rpe: settings.target_rpe,
+                                                on_change: {
+                                                    let pe_id = pe_id_targets.clone();
+                                                    move |targets| {
+                                                        let pe_id = pe_id.clone();
                                                         spawn(async move {
-                                                            if let Err(e) = WorkoutStateManager::remove_exercise_from_plan(&state, &pe_id).await {
-                                                                log::warn!("Failed to remove exercise: {}", e);
+                                                            if let Err(e) = WorkoutStateManager::set_plan_slot_targets(&state, &pe_id, targets).await {
+                                                                log::warn!("Failed to update slot targets: {}", e);
                                                             }
                                                         });
-                                                    },
-                                                    "✕"
-                                                }
+                                                    }
+                                                },
                                             }
                                         }
                                     }
+                                    // Superset / circuit link to the next slot
+                                    if has_next {
+                                        button {
+                                            class: if linked_to_next {
+                                                "btn btn-xs btn-primary self-center"
+                                            } else {
+                                                "btn btn-xs btn-ghost self-center text-base-content/50"
+                                            },
+                                            "data-testid": "link-next-slot",
+                                            "data-linked": "{linked_to_next}",
+                                            onclick: move |_| {
+                                                spawn(async move {
+                                                    if let Err(e) = WorkoutStateManager::link_plan_slots(&state, idx, !linked_to_next).await {
+                                                        log::warn!("Failed to update slot group: {}", e);
+                                                    }
+                                                });
+                                            },
+                                            if linked_to_next { "Linked · Unlink" } else { "Link as superset" }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -142,6 +207,29 @@ pub fn PlanBuilder(state: WorkoutState) -> Element {
                     class: "text-center py-8 text-base-content/50",
                     "data-testid": "plan-empty-hint",
                     p { "Add exercises to build your workout plan." }
+                    button {
+                        class: "btn btn-secondary btn-sm mt-4",
+                        "data-testid": "suggest-workout-button",
+                        disabled: suggesting(),
+                        onclick: move |_| {
+                            suggesting.set(true);
+                            spawn(async move {
+                                match WorkoutStateManager::suggest_workout(&state).await {
+                                    Ok(added) => nothing_to_suggest.set(added == 0),
+                                    Err(e) => log::warn!("Failed to suggest a workout: {}", e),
+                                }
+                                suggesting.set(false);
+                            });
+                        },
+                        "Suggest a workout"
+                    }
+                    if nothing_to_suggest() {
+                        p {
+                            class: "text-sm mt-2",
+                            "data-testid": "suggest-workout-empty",
+                            "Every muscle group is at its weekly target or still recovering."
+                        }
+                    }
                 }
             }
 
@@ -165,6 +253,12 @@ pub fn PlanBuilder(state: WorkoutState) -> Element {
                     onclick: move |_| show_load_template.set(true),
                     "Load Template"
                 }
+                button {
+                    class: "btn btn-ghost btn-sm flex-1",
+                    "data-testid": "programs-button",
+                    onclick: move |_| show_programs.set(true),
+                    "Programs"
+                }
                 if has_exercises {
                     button {
                         class: "btn btn-ghost btn-sm flex-1",
@@ -199,6 +293,14 @@ pub fn PlanBuilder(state: WorkoutState) -> Element {
                 }
             }
 
+            // Programs modal
+            if show_programs() {
+                ProgramsModal {
+                    state,
+                    on_close: move |_| show_programs.set(false),
+                }
+            }
+
             // Load template modal
             if show_load_template() {
                 LoadTemplateModal {
@@ -220,6 +322,153 @@ pub fn PlanBuilder(state: WorkoutState) -> Element {
     }
 }
 
+/// Lowest and highest target RPE a slot can override to.
+const SLOT_RPE_RANGE: (f64, f64) = (5.0, 10.0);
+
+/// Collapsible Rep Range / target RPE overrides for one plan slot. Shows the
+/// targets in effect — the exercise's and settings' until overridden.
+#[component]
+fn SlotTargetsEditor(
+    exercise: ExerciseMetadata,
+    targets: SlotTargets,
+    default_rpe: f64,
+    on_change: EventHandler<SlotTargets>,
+) -> Element {
+    let mut expanded = use_signal(|| false);
+    let (min_reps, max_reps) = targets.rep_range_for(&exercise);
+    let rpe = targets.target_rpe_or(default_rpe);
+    let max_label = max_reps.map_or("∞".to_string(), |m| m.to_string());
+    let summary = if targets.is_empty() {
+        "Default targets".to_string()
+    } else {
+        targets.summary()
+    };
+    let with_range = move |min: i32, max: Option<i32>| SlotTargets {
+        rep_range: Some((min, max)),
+        ..targets
+    };
+    let with_rpe = move |rpe: f64| SlotTargets {
+        target_rpe: Some(rpe),
+        ..targets
+    };
+
+    rsx! {
+        div {
+            class: "mt-1",
+            button {
+                class: if targets.is_empty() {
+                    "btn btn-xs btn-ghost px-1 text-base-content/50"
+                } else {
+                    "btn btn-xs btn-ghost px-1 text-primary"
+                },
+                "data-testid": "slot-targets-toggle",
+                onclick: move |_| expanded.set(!expanded()),
+                "{summary}"
+            }
+            if expanded() {
+                div {
+                    class: "grid gap-1 mt-1 text-sm",
+                    "data-testid": "slot-targets-editor",
+                    div {
+                        class: "flex items-center justify-between",
+                        span { class: "opacity-60", "Min reps" }
+                        div {
+                            class: "flex items-center gap-2",
+                            button {
+                                class: "btn btn-circle btn-xs btn-ghost",
+                                "data-testid": "slot-min-reps-dec",
+                                disabled: min_reps <= 1,
+                                onclick: move |_| on_change.call(with_range(min_reps - 1, max_reps)),
+                                "−"
+                            }
+                            span {
+                                class: "font-bold w-8 text-center",
+                                "data-testid": "slot-min-reps",
+                                "{min_reps}"
+                            }
+                            button {
+                                class: "btn btn-circle btn-xs btn-ghost",
+                                "data-testid": "slot-min-reps-inc",
+                                onclick: move |_| {
+                                    let min = min_reps + 1;
+                                    on_change.call(with_range(min, max_reps.map(|m| m.max(min))));
+                                },
+                                "+"
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex items-center justify-between",
+                        span { class: "opacity-60", "Max reps" }
+                        div {
+                            class: "flex items-center gap-2",
+                            button {
+                                class: "btn btn-circle btn-xs btn-ghost",
+                                "data-testid": "slot-max-reps-dec",
+                                disabled: max_reps.is_some_and(|m| m <= min_reps),
+                                onclick: move |_| {
+                                    // An open-ended range first closes at the minimum.
+                                    let max = max_reps.map_or(min_reps, |m| m - 1);
+                                    on_change.call(with_range(min_reps, Some(max)));
+                                },
+                                "−"
+                            }
+                            span {
+                                class: "font-bold w-8 text-center",
+                                "data-testid": "slot-max-reps",
+                                "{max_label}"
+                            }
+                            button {
+                                class: "btn btn-circle btn-xs btn-ghost",
+                                "data-testid": "slot-max-reps-inc",
+                                disabled: max_reps.is_none(),
+                                onclick: move |_| {
+                                    on_change.call(with_range(min_reps, max_reps.map(|m| m + 1)))
+                                },
+                                "+"
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex items-center justify-between",
+                        span { class: "opacity-60", "Target RPE" }
+                        div {
+                            class: "flex items-center gap-2",
+                            button {
+                                class: "btn btn-circle btn-xs btn-ghost",
+                                "data-testid": "slot-rpe-dec",
+                                disabled: rpe <= SLOT_RPE_RANGE.0,
+                                onclick: move |_| on_change.call(with_rpe(rpe - 0.5)),
+                                "−"
+                            }
+                            span {
+                                class: "font-bold w-8 text-center",
+                                "data-testid": "slot-rpe",
+                                "{rpe}"
+                            }
+                            button {
+                                class: "btn btn-circle btn-xs btn-ghost",
+                                "data-testid": "slot-rpe-inc",
+                                disabled: rpe >= SLOT_RPE_RANGE.1,
+                                onclick: move |_| on_change.call(with_rpe(rpe + 0.5)),
+                                "+"
+                            }
+                        }
+                    }
+                    if !targets.is_empty() {
+                        button {
+                            class: "btn btn-xs btn-ghost justify-self-end",
+                            "data-testid": "slot-targets-reset",
+                            onclick: move |_| on_change.call(SlotTargets::default()),
+                            "Reset to defaults"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 pub fn ExercisePickerModal(
     state: WorkoutState,
@@ -286,6 +535,11 @@ pub fn ExercisePickerModal(
                                     let badge = match &exercise.set_type_config {
                                         SetTypeConfig::Weighted { .. } => ("WEIGHTED", "badge-primary"),
                                         SetTypeConfig::Bodyweight => ("BODYWEIGHT", "badge-secondary"),
+                                        SetTypeConfig::LoadedBodyweight { assisted: false, .. } => ("BW+", "badge-secondary"),
+                                        SetTypeConfig::LoadedBodyweight { assisted: true, .. } => ("ASSISTED", "badge-secondary"),
+                                        SetTypeConfig::Timed { .. } => ("TIMED", "badge-accent"),
+                                        SetTypeConfig::Distance { .. } => ("DISTANCE", "badge-accent"),
+                                        SetTypeConfig::Carry { .. } => ("CARRY", "badge-accent"),
                                     };
                                     rsx! {
                                         button {
@@ -0,0 +1,52 @@
+use crate::domain::plates::nearest_load;
+use crate::format::{fmt_weight, fmt_weight_in};
+use crate::models::{Plate, WeightUnit};
+use dioxus::prelude::*;
+
+/// Per-side plate list for a barbell load, shown under the weight suggestion
+/// on the Record Screen. When the inventory cannot build `target_kg` exactly,
+/// the closest buildable load is shown instead.
+#[component]
+pub fn PlateBreakdown(
+    target_kg: f64,
+    bar_kg: f64,
+    plates: Vec<Plate>,
+    unit: WeightUnit,
+) -> Element {
+    let Some(load) = nearest_load(target_kg, bar_kg, &plates, |_| true) else {
+        return rsx! {};
+    };
+    let exact = load.is_exact(target_kg);
+    let per_side = if load.per_side.is_empty() {
+        "empty bar".to_string()
+    } else {
+        load.per_side
+            .iter()
+            .map(|stack| {
+                let w = fmt_weight(unit.from_kg(stack.weight));
+                if stack.count > 1 {
+                    format!("{}×{}", stack.count, w)
+                } else {
+                    w
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" + ")
+    };
+
+    rsx! {
+        div {
+            "data-testid": "plate-breakdown",
+            class: "flex flex-wrap items-baseline justify-center gap-x-2 text-xs text-base-content/60 px-1",
+            span { class: "font-semibold uppercase", "Per side" }
+            span { class: "font-bold text-base-content/80", "{per_side}" }
+            if !exact {
+                span {
+                    "data-testid": "plate-breakdown-closest",
+                    class: "text-warning font-semibold",
+                    "closest: {fmt_weight_in(load.total, unit)}"
+                }
+            }
+        }
+    }
+}
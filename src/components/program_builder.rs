@@ -0,0 +1,478 @@
+use crate::components::confirmation_dialog::{ConfirmVariant, ConfirmationDialog};
+use crate::domain::program::{ProgramWorkout, day_letter};
+use crate::models::{Program, ProgramDay, ProgressionRule, WorkoutTemplate};
+use crate::state::{WorkoutState, WorkoutStateManager};
+use dioxus::prelude::*;
+
+/// Longest block the builder offers, in weeks.
+const MAX_PROGRAM_WEEKS: u32 = 12;
+
+/// Programs list with "next workout" actions, opened from the Plan Builder.
+#[component]
+pub fn ProgramsModal(state: WorkoutState, on_close: EventHandler<()>) -> Element {
+    let mut programs = use_signal(Vec::<(Program, Option<ProgramWorkout>)>::new);
+    let mut creating = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut delete_target = use_signal(|| Option::<(String, String)>::None);
+    let mut refresh_tick = use_signal(|| 0u32);
+
+    use_effect(move || {
+        // Re-read on every refresh tick.
+        let _ = refresh_tick();
+        spawn(async move {
+            let Some(db) = state.database() else {
+                return;
+            };
+            match db.list_programs().await {
+                Ok(list) => {
+                    let mut with_next = Vec::with_capacity(list.len());
+                    for program in list {
+                        let next = WorkoutStateManager::next_program_workout(&state, &program)
+                            .await
+                            .unwrap_or_else(|e| {
+                                log::warn!("Failed to find next program workout: {}", e);
+                                None
+                            });
+                        with_next.push((program, next));
+                    }
+                    programs.set(with_next);
+                }
+                Err(e) => log::warn!("Failed to list programs: {}", e),
+            }
+        });
+    });
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-[200] flex items-end sm:items-center justify-center bg-black/60 backdrop-blur-sm",
+            "data-testid": "programs-modal",
+            onclick: move |_| on_close.call(()),
+            div {
+                class: "bg-base-100 rounded-2xl shadow-2xl w-full max-w-md flex flex-col max-h-[90dvh]",
+                onclick: move |evt| evt.stop_propagation(),
+
+                div {
+                    class: "p-4 border-b border-base-200 flex justify-between items-center",
+                    h3 { class: "text-lg font-bold", "Programs" }
+                    button {
+                        class: "btn btn-circle btn-ghost btn-sm",
+                        onclick: move |_| on_close.call(()),
+                        "✕"
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto p-4 grid gap-3 content-start",
+                    if let Some(msg) = error() {
+                        div {
+                            class: "alert alert-error text-sm py-2",
+                            "data-testid": "program-error",
+                            "{msg}"
+                        }
+                    }
+
+                    if creating() {
+                        ProgramForm {
+                            state,
+                            on_cancel: move |_| creating.set(false),
+                            on_saved: move |_| {
+                                creating.set(false);
+                                refresh_tick.with_mut(|t| *t = t.wrapping_add(1));
+                            },
+                        }
+                    } else {
+                        if programs().is_empty() {
+                            p {
+                                class: "text-center text-base-content/50 py-4",
+                                "No programs yet. Build one from your saved templates."
+                            }
+                        }
+                        for (program, next) in programs() {
+                            {
+                                let pid = program.id.clone();
+                                let pname = program.name.clone();
+                                let rotation = program
+                                    .days
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, d)| {
+                                        let name = if d.template_name.is_empty() {
+                                            "deleted template"
+                                        } else {
+                                            d.template_name.as_str()
+                                        };
+                                        format!("{}: {}", day_letter(i), name)
+                                    })
+                                    .collect::<Vec<_>>()
+                                    .join(" · ");
+                                let next_name = next
+                                    .and_then(|w| program.days.get(w.day))
+                                    .map(|d| d.template_name.clone())
+                                    .unwrap_or_default();
+                                rsx! {
+                                    div {
+                                        key: "{pid}",
+                                        class: "p-3 rounded-lg border border-base-200 grid gap-2",
+                                        "data-testid": "program-item",
+                                        div {
+                                            class: "flex justify-between items-center gap-2",
+                                            div {
+                                                class: "min-w-0",
+                                                span { class: "font-bold", "{pname}" }
+                                                span {
+                                                    class: "text-sm text-base-content/50 ml-2",
+                                                    "{program.weeks} weeks"
+                                                }
+                                            }
+                                            button {
+                                                class: "btn btn-ghost btn-sm btn-circle text-error",
+                                                "data-testid": "program-delete-button",
+                                                onclick: {
+                                                    let pid = pid.clone();
+                                                    let pname = pname.clone();
+                                                    move |_| delete_target.set(Some((pid.clone(), pname.clone())))
+                                                },
+                                                "🗑"
+                                            }
+                                        }
+                                        p { class: "text-xs text-base-content/60", "{rotation}" }
+                                        if let Some(workout) = next {
+                                            button {
+                                                class: "btn btn-primary btn-sm",
+                                                "data-testid": "program-next-workout",
+                                                onclick: move |_| {
+                                                    let program = program.clone();
+                                                    spawn(async move {
+                                                        match WorkoutStateManager::load_next_program_workout(&state, &program).await {
+                                                            Ok(()) => on_close.call(()),
+                                                            Err(e) => error.set(Some(e.to_string())),
+                                                        }
+                                                    });
+                                                },
+                                                "Next: {workout.label()} — {next_name}"
+                                            }
+                                        } else {
+                                            span {
+                                                class: "badge badge-success",
+                                                "data-testid": "program-finished",
+                                                "Program complete"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        button {
+                            class: "btn btn-outline btn-sm",
+                            "data-testid": "new-program-button",
+                            onclick: move |_| {
+                                error.set(None);
+                                creating.set(true);
+                            },
+                            "+ New Program"
+                        }
+                    }
+                }
+            }
+
+            if let Some((pid, pname)) = delete_target() {
+                ConfirmationDialog {
+                    title: format!("Delete program \"{}\"?", pname),
+                    body: "The program will be removed. Its templates and any plans already loaded from it are kept.".to_string(),
+                    confirm_label: "Delete".to_string(),
+                    cancel_label: "Cancel".to_string(),
+                    variant: ConfirmVariant::Danger,
+                    on_cancel: move |_| delete_target.set(None),
+                    on_confirm: move |_| {
+                        let pid = pid.clone();
+                        delete_target.set(None);
+                        spawn(async move {
+                            if let Err(e) = WorkoutStateManager::delete_program(&state, &pid).await {
+                                log::warn!("Failed to delete program: {}", e);
+                            }
+                            refresh_tick.with_mut(|t| *t = t.wrapping_add(1));
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Form for a new program: template rotation, length, progression and deload.
+#[component]
+fn ProgramForm(
+    state: WorkoutState,
+    on_cancel: EventHandler<()>,
+    on_saved: EventHandler<()>,
+) -> Element {
+    let mut templates = use_signal(Vec::<WorkoutTemplate>::new);
+    let mut name = use_signal(String::new);
+    let mut weeks = use_signal(|| 4u32);
+    let mut days = use_signal(Vec::<ProgramDay>::new);
+    let mut progression = use_signal(ProgressionRule::default);
+    let mut deload = use_signal(|| true);
+    let mut error = use_signal(|| Option::<String>::None);
+
+    use_effect(move || {
+        spawn(async move {
+            if let Some(db) = state.database() {
+                match db.list_templates().await {
+                    Ok(t) => templates.set(t),
+                    Err(e) => log::warn!("Failed to list templates: {}", e),
+                }
+            }
+        });
+    });
+
+    let can_save = !name().trim().is_empty() && !days().is_empty();
+    let rpe_rule = ProgressionRule::Rpe {
+        start: 7.0,
+        step: 0.5,
+    };
+    let volume_rule = ProgressionRule::Volume { step: 1 };
+
+    rsx! {
+        div {
+            class: "grid gap-3",
+            "data-testid": "program-form",
+            input {
+                r#type: "text",
+                placeholder: "Program name...",
+                class: "input input-bordered w-full",
+                "data-testid": "program-name-input",
+                value: "{name}",
+                oninput: move |evt| name.set(evt.value()),
+            }
+
+            // Length
+            div {
+                class: "flex items-center justify-between",
+                span { class: "font-semibold", "Weeks" }
+                div {
+                    class: "flex items-center gap-2",
+                    button {
+                        class: "btn btn-circle btn-sm btn-ghost",
+                        "data-testid": "program-weeks-dec",
+                        disabled: weeks() <= 1,
+                        onclick: move |_| weeks.set(weeks() - 1),
+                        "−"
+                    }
+                    span {
+                        class: "text-lg font-bold w-8 text-center",
+                        "data-testid": "program-weeks",
+                        "{weeks}"
+                    }
+                    button {
+                        class: "btn btn-circle btn-sm btn-ghost",
+                        "data-testid": "program-weeks-inc",
+                        disabled: weeks() >= MAX_PROGRAM_WEEKS,
+                        onclick: move |_| weeks.set(weeks() + 1),
+                        "+"
+                    }
+                }
+            }
+
+            // Day rotation
+            div {
+                class: "grid gap-1",
+                span { class: "font-semibold", "Days" }
+                for (i, day) in days().into_iter().enumerate() {
+                    div {
+                        key: "{i}",
+                        class: "flex items-center gap-2",
+                        "data-testid": "program-day",
+                        span { class: "badge badge-primary badge-sm font-bold", "{day_letter(i)}" }
+                        span { class: "flex-1 truncate", "{day.template_name}" }
+                        button {
+                            class: "btn btn-ghost btn-xs btn-circle",
+                            "data-testid": "program-day-remove",
+                            onclick: move |_| {
+                                days.with_mut(|d| {
+                                    d.remove(i);
+                                });
+                            },
+                            "✕"
+                        }
+                    }
+                }
+                if templates().is_empty() {
+                    p {
+                        class: "text-sm text-base-content/50",
+                        "Save a workout as a template to use it as a program day."
+                    }
+                } else {
+                    select {
+                        class: "select select-bordered select-sm w-full",
+                        "data-testid": "program-add-day",
+                        value: "",
+                        onchange: move |evt| {
+                            let id = evt.value();
+                            if let Some(t) = templates().into_iter().find(|t| t.id == id) {
+                                days.with_mut(|d| {
+                                    d.push(ProgramDay {
+                                        id: String::new(),
+                                        template_id: t.id,
+                                        template_name: t.name,
+                                    })
+                                });
+                            }
+                        },
+                        option { value: "", disabled: true, selected: true, "+ Add day from template" }
+                        for t in templates() {
+                            option { key: "{t.id}", value: "{t.id}", "{t.name}" }
+                        }
+                    }
+                }
+            }
+
+            // Progression
+            div {
+                class: "grid gap-1",
+                span { class: "font-semibold", "Weekly progression" }
+                div {
+                    class: "join w-full",
+                    for (label, rule, testid) in [
+                        ("None", ProgressionRule::None, "progression-none"),
+                        ("RPE", rpe_rule, "progression-rpe"),
+                        ("Volume", volume_rule, "progression-volume"),
+                    ] {
+                        button {
+                            key: "{testid}",
+                            class: if progression().tag() == rule.tag() {
+                                "btn btn-sm join-item flex-1 btn-primary"
+                            } else {
+                                "btn btn-sm join-item flex-1"
+                            },
+                            "data-testid": "{testid}",
+                            onclick: move |_| {
+                                if progression().tag() != rule.tag() {
+                                    progression.set(rule);
+                                }
+                            },
+                            "{label}"
+                        }
+                    }
+                }
+                {match progression() {
+                    ProgressionRule::None => rsx! {
+                        p { class: "text-xs text-base-content/60", "Every week repeats the templates as saved." }
+                    },
+                    ProgressionRule::Rpe { start, step } => rsx! {
+                        div {
+                            class: "flex items-center justify-between text-sm",
+                            span { "Week 1 RPE" }
+                            div {
+                                class: "flex items-center gap-2",
+                                button {
+                                    class: "btn btn-circle btn-xs btn-ghost",
+                                    disabled: start <= 5.0,
+                                    onclick: move |_| progression.set(ProgressionRule::Rpe { start: start - 0.5, step }),
+                                    "−"
+                                }
+                                span { class: "font-bold w-8 text-center", "data-testid": "progression-start", "{start}" }
+                                button {
+                                    class: "btn btn-circle btn-xs btn-ghost",
+                                    disabled: start >= 10.0,
+                                    onclick: move |_| progression.set(ProgressionRule::Rpe { start: start + 0.5, step }),
+                                    "+"
+                                }
+                            }
+                        }
+                        div {
+                            class: "flex items-center justify-between text-sm",
+                            span { "RPE added per week" }
+                            div {
+                                class: "flex items-center gap-2",
+                                button {
+                                    class: "btn btn-circle btn-xs btn-ghost",
+                                    disabled: step <= 0.5,
+                                    onclick: move |_| progression.set(ProgressionRule::Rpe { start, step: step - 0.5 }),
+                                    "−"
+                                }
+                                span { class: "font-bold w-8 text-center", "data-testid": "progression-step", "{step}" }
+                                button {
+                                    class: "btn btn-circle btn-xs btn-ghost",
+                                    disabled: step >= 2.0,
+                                    onclick: move |_| progression.set(ProgressionRule::Rpe { start, step: step + 0.5 }),
+                                    "+"
+                                }
+                            }
+                        }
+                    },
+                    ProgressionRule::Volume { step } => rsx! {
+                        div {
+                            class: "flex items-center justify-between text-sm",
+                            span { "Sets added per week" }
+                            div {
+                                class: "flex items-center gap-2",
+                                button {
+                                    class: "btn btn-circle btn-xs btn-ghost",
+                                    disabled: step <= 1,
+                                    onclick: move |_| progression.set(ProgressionRule::Volume { step: step - 1 }),
+                                    "−"
+                                }
+                                span { class: "font-bold w-8 text-center", "data-testid": "progression-step", "{step}" }
+                                button {
+                                    class: "btn btn-circle btn-xs btn-ghost",
+                                    disabled: step >= 3,
+                                    onclick: move |_| progression.set(ProgressionRule::Volume { step: step + 1 }),
+                                    "+"
+                                }
+                            }
+                        }
+                    },
+                }}
+            }
+
+            label {
+                class: "label cursor-pointer justify-start gap-3",
+                input {
+                    r#type: "checkbox",
+                    class: "checkbox checkbox-sm",
+                    "data-testid": "program-deload-toggle",
+                    checked: deload(),
+                    disabled: weeks() < 2,
+                    onchange: move |evt| deload.set(evt.checked()),
+                }
+                span { "Last week is a deload" }
+            }
+
+            if let Some(msg) = error() {
+                p { class: "text-error text-sm", "data-testid": "program-form-error", "{msg}" }
+            }
+
+            div {
+                class: "flex gap-2 justify-end",
+                button {
+                    class: "btn btn-ghost btn-sm",
+                    onclick: move |_| on_cancel.call(()),
+                    "Cancel"
+                }
+                button {
+                    class: "btn btn-primary btn-sm",
+                    "data-testid": "confirm-save-program",
+                    disabled: !can_save,
+                    onclick: move |_| {
+                        let weeks = weeks();
+                        let program = Program {
+                            id: String::new(),
+                            name: name().trim().to_string(),
+                            weeks,
+                            deload_week: (deload() && weeks > 1).then_some(weeks),
+                            progression: progression(),
+                            days: days(),
+                        };
+                        spawn(async move {
+                            match WorkoutStateManager::create_program(&state, &program).await {
+                                Ok(_) => on_saved.call(()),
+                                Err(e) => error.set(Some(e.to_string())),
+                            }
+                        });
+                    },
+                    "Save Program"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::domain::rest::RestTimer;
+use crate::state::WorkoutState;
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "/public/rest-timer.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = requestRestNotificationPermission)]
+    fn request_rest_notification_permission();
+
+    #[wasm_bindgen(js_name = notifyRestOver)]
+    fn notify_rest_over(exercise_name: &str);
+}
+
+/// Seconds added by the "+30s" button.
+const EXTEND_SECS: u32 = 30;
+
+fn fmt_countdown(secs: u32) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Fires the vibration/notification hook when the running rest timer
+/// expires, whichever tab is showing. Call once from the app root.
+pub fn use_rest_timer_alert(state: WorkoutState) {
+    use_effect(move || {
+        let Some(timer) = state.rest_timer() else {
+            return;
+        };
+        request_rest_notification_permission();
+        spawn(async move {
+            let wait_ms = (timer.ends_at() - js_sys::Date::now()).max(0.0);
+            gloo_timers::future::sleep(std::time::Duration::from_millis(wait_ms as u64)).await;
+            // Skipped, extended or replaced by a newer set in the meantime.
+            if state.rest_timer().as_ref() == Some(&timer) {
+                notify_rest_over(&timer.exercise_name);
+            }
+        });
+    });
+}
+
+/// Rest countdown on the Record Screen. The timer itself lives in
+/// [`WorkoutState`]; this only renders it, so leaving the tab loses nothing.
+#[component]
+pub fn RestTimerBanner(state: WorkoutState) -> Element {
+    let mut now = use_signal(js_sys::Date::now);
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::sleep(std::time::Duration::from_millis(250)).await;
+            now.set(js_sys::Date::now());
+        }
+    });
+
+    let Some(timer) = state.rest_timer() else {
+        return rsx! {};
+    };
+    let now = now();
+    let expired = timer.is_expired(now);
+    let progress = if timer.duration_secs == 0 {
+        100.0
+    } else {
+        (timer.elapsed_secs(now) as f64 / timer.duration_secs as f64 * 100.0).min(100.0)
+    };
+    let readout = if expired {
+        format!(
+            "+{}",
+            fmt_countdown(timer.elapsed_secs(now).saturating_sub(timer.duration_secs))
+        )
+    } else {
+        fmt_countdown(timer.remaining_secs(now))
+    };
+
+    rsx! {
+        div {
+            class: if expired {
+                "card bg-success text-success-content shadow-xl"
+            } else {
+                "card bg-base-100 shadow-xl"
+            },
+            "data-testid": "rest-timer",
+            "data-expired": "{expired}",
+            div {
+                class: "card-body p-2 sm:p-4 gap-1",
+                div {
+                    class: "flex items-center justify-between px-1",
+                    span {
+                        class: "text-sm font-semibold uppercase opacity-60",
+                        if expired { "Rest over" } else { "Rest" }
+                    }
+                    span {
+                        class: "text-2xl font-black font-mono",
+                        "data-testid": "rest-timer-readout",
+                        "{readout}"
+                    }
+                    div {
+                        class: "flex gap-1",
+                        button {
+                            class: "btn btn-ghost btn-xs",
+                            "data-testid": "rest-timer-extend",
+                            onclick: move |_| {
+                                if let Some(timer) = state.rest_timer() {
+                                    state.set_rest_timer(Some(RestTimer {
+                                        duration_secs: timer.duration_secs + EXTEND_SECS,
+                                        ..timer
+                                    }));
+                                }
+                            },
+                            "+{EXTEND_SECS}s"
+                        }
+                        button {
+                            class: "btn btn-ghost btn-xs",
+                            "data-testid": "rest-timer-dismiss",
+                            onclick: move |_| state.set_rest_timer(None),
+                            if expired { "Dismiss" } else { "Skip" }
+                        }
+                    }
+                }
+                progress {
+                    class: "progress progress-primary w-full",
+                    value: "{progress}",
+                    max: "100",
+                }
+            }
+        }
+    }
+}
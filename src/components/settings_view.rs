@@ -1,6 +1,9 @@
 use crate::components::pairing::PairingStep;
 use crate::log_buffer::LogEntry;
-use crate::models::Settings;
+use crate::models::{
+    BodyweightEntry, E1rmFormula, MuscleGroup, Plate, RpeCalibration, Settings, VolumeTarget,
+    WarmupRamp, WeightUnit,
+};
 use crate::state::{SyncStatus, WorkoutState, WorkoutStateManager};
 use crate::sync::SyncCredentials;
 use dioxus::prelude::*;
@@ -34,6 +37,16 @@ fn copy_to_clipboard(text: &str) {
     }
 }
 
+/// `target` with its `mev`, `mav` or `mrv` landmark replaced by `value`.
+fn with_landmark(mut target: VolumeTarget, field: &str, value: u32) -> VolumeTarget {
+    match field {
+        "mev" => target.mev = value,
+        "mav" => target.mav = value,
+        _ => target.mrv = value,
+    }
+    target
+}
+
 /// Truncate a sync_id to `n` chars + ellipsis for display.
 fn truncate_id(id: &str, n: usize) -> String {
     if id.len() <= n {
@@ -43,6 +56,439 @@ fn truncate_id(id: &str, n: usize) -> String {
     }
 }
 
+/// Common home-gym plate sets as `(weight, count)` in their own unit.
+const STANDARD_KG_PLATES: &[(f64, u32)] = &[
+    (25.0, 4),
+    (20.0, 4),
+    (15.0, 2),
+    (10.0, 2),
+    (5.0, 2),
+    (2.5, 2),
+    (1.25, 2),
+];
+const STANDARD_LB_PLATES: &[(f64, u32)] = &[
+    (45.0, 8),
+    (35.0, 2),
+    (25.0, 2),
+    (10.0, 2),
+    (5.0, 2),
+    (2.5, 2),
+];
+
+/// Lists the user's plate inventory with add/remove controls. Weights are
+/// shown and entered in the display unit and stored in kg.
+#[component]
+fn PlateInventoryCard(state: WorkoutState, unit: WeightUnit) -> Element {
+    let plates = state.plates();
+    let mut new_weight = use_signal(String::new);
+
+    let save = move |plate: Plate| {
+        spawn(async move {
+            if let Err(e) = WorkoutStateManager::save_plate(&state, plate).await {
+                log::warn!("Failed to save plate: {}", e);
+            }
+        });
+    };
+    let remove = move |plate_id: String| {
+        spawn(async move {
+            if let Err(e) = WorkoutStateManager::delete_plate(&state, &plate_id).await {
+                log::warn!("Failed to remove plate: {}", e);
+            }
+        });
+    };
+    let add_standard = move |set: &'static [(f64, u32)], set_unit: WeightUnit| {
+        spawn(async move {
+            for &(weight, count) in set {
+                let plate = Plate {
+                    id: None,
+                    weight: set_unit.to_kg(weight) as f32,
+                    count,
+                };
+                if let Err(e) = WorkoutStateManager::save_plate(&state, plate).await {
+                    log::warn!("Failed to save plate: {}", e);
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-xl mb-6",
+            "data-testid": "plates-section",
+            div {
+                class: "card-body",
+                h3 { class: "card-title text-base font-bold mb-4", "Plates" }
+
+                if plates.is_empty() {
+                    p {
+                        class: "text-sm opacity-60 mb-3",
+                        "Add the plates you own to see a per-side plate list for plate-loaded exercises"
+                    }
+                    div {
+                        class: "flex gap-2 mb-4",
+                        button {
+                            class: "btn btn-outline btn-sm flex-1",
+                            "data-testid": "add-standard-kg-plates",
+                            onclick: move |_| add_standard(STANDARD_KG_PLATES, WeightUnit::Kg),
+                            "Add standard kg set"
+                        }
+                        button {
+                            class: "btn btn-outline btn-sm flex-1",
+                            "data-testid": "add-standard-lb-plates",
+                            onclick: move |_| add_standard(STANDARD_LB_PLATES, WeightUnit::Lb),
+                            "Add standard lb set"
+                        }
+                    }
+                }
+
+                for plate in plates {
+                    div {
+                        key: "{plate.id.clone().unwrap_or_default()}",
+                        class: "flex items-center justify-between py-1",
+                        "data-testid": "plate-row",
+                        span {
+                            class: "font-mono font-bold",
+                            {crate::format::fmt_weight_in(plate.weight, unit)}
+                        }
+                        div {
+                            class: "flex items-center gap-2",
+                            button {
+                                class: "btn btn-xs btn-outline",
+                                disabled: plate.count < 2,
+                                onclick: {
+                                    let plate = plate.clone();
+                                    move |_| {
+                                        let mut p = plate.clone();
+                                        p.count = p.count.saturating_sub(2);
+                                        save(p);
+                                    }
+                                },
+                                "−2"
+                            }
+                            span { class: "font-mono w-8 text-center", "×{plate.count}" }
+                            button {
+                                class: "btn btn-xs btn-outline",
+                                onclick: {
+                                    let plate = plate.clone();
+                                    move |_| {
+                                        let mut p = plate.clone();
+                                        p.count += 2;
+                                        save(p);
+                                    }
+                                },
+                                "+2"
+                            }
+                            button {
+                                class: "btn btn-xs btn-ghost text-error",
+                                "data-testid": "remove-plate",
+                                onclick: {
+                                    let id = plate.id.clone();
+                                    move |_| {
+                                        if let Some(id) = id.clone() {
+                                            remove(id);
+                                        }
+                                    }
+                                },
+                                "✕"
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "join w-full mt-3",
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        step: "any",
+                        placeholder: "Plate weight ({unit})",
+                        value: "{new_weight}",
+                        class: "input input-bordered join-item flex-1",
+                        "data-testid": "new-plate-weight",
+                        oninput: move |evt| new_weight.set(evt.value()),
+                    }
+                    button {
+                        class: "btn btn-primary join-item",
+                        "data-testid": "add-plate",
+                        onclick: move |_| {
+                            if let Ok(w) = new_weight().parse::<f64>()
+                                && w > 0.0
+                            {
+                                save(Plate {
+                                    id: None,
+                                    weight: unit.to_kg(w) as f32,
+                                    count: 2,
+                                });
+                                new_weight.set(String::new());
+                            }
+                        },
+                        "Add pair"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Entries shown in the bodyweight log card; older ones stay in the database.
+const BODYWEIGHT_LOG_ROWS: usize = 10;
+
+/// Logs dated bodyweight entries, used as the body of loaded and assisted
+/// bodyweight sets.  Masses are shown and entered in the display unit and
+/// stored in kg.
+#[component]
+fn BodyweightLogCard(state: WorkoutState, unit: WeightUnit) -> Element {
+    let entries = state.bodyweight_log();
+    let mut new_mass = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let remove = move |entry_id: String| {
+        spawn(async move {
+            if let Err(e) = WorkoutStateManager::delete_bodyweight_entry(&state, &entry_id).await {
+                log::warn!("Failed to remove bodyweight entry: {}", e);
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-xl mb-6",
+            "data-testid": "bodyweight-section",
+            div {
+                class: "card-body",
+                h3 { class: "card-title text-base font-bold mb-4", "Bodyweight" }
+
+                if entries.is_empty() {
+                    p {
+                        class: "text-sm opacity-60 mb-3",
+                        "Log your bodyweight to record weighted and assisted bodyweight exercises"
+                    }
+                }
+
+                for entry in entries.into_iter().take(BODYWEIGHT_LOG_ROWS) {
+                    div {
+                        key: "{entry.id.clone().unwrap_or_default()}",
+                        class: "flex items-center justify-between py-1",
+                        "data-testid": "bodyweight-row",
+                        span {
+                            class: "text-sm opacity-70",
+                            {
+                                String::from(
+                                    js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(entry.recorded_at))
+                                        .to_locale_date_string("default", &wasm_bindgen::JsValue::UNDEFINED),
+                                )
+                            }
+                        }
+                        div {
+                            class: "flex items-center gap-2",
+                            span {
+                                class: "font-mono font-bold",
+                                {crate::format::fmt_weight_in(entry.mass, unit)}
+                            }
+                            button {
+                                class: "btn btn-xs btn-ghost text-error",
+                                "data-testid": "remove-bodyweight",
+                                onclick: {
+                                    let id = entry.id.clone();
+                                    move |_| {
+                                        if let Some(id) = id.clone() {
+                                            remove(id);
+                                        }
+                                    }
+                                },
+                                "✕"
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "join w-full mt-3",
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        step: "any",
+                        placeholder: "Bodyweight ({unit})",
+                        value: "{new_mass}",
+                        class: "input input-bordered join-item flex-1",
+                        "data-testid": "new-bodyweight",
+                        oninput: move |evt| {
+                            new_mass.set(evt.value());
+                            error.set(None);
+                        },
+                    }
+                    button {
+                        class: "btn btn-primary join-item",
+                        "data-testid": "log-bodyweight",
+                        onclick: move |_| {
+                            let Ok(mass) = new_mass().parse::<f64>() else {
+                                return;
+                            };
+                            let entry = BodyweightEntry {
+                                id: None,
+                                recorded_at: js_sys::Date::now(),
+                                mass: unit.to_kg(mass) as f32,
+                            };
+                            spawn(async move {
+                                match WorkoutStateManager::save_bodyweight_entry(&state, entry).await {
+                                    Ok(()) => new_mass.set(String::new()),
+                                    Err(e) => error.set(Some(e.to_string())),
+                                }
+                            });
+                        },
+                        "Log"
+                    }
+                }
+                if let Some(message) = error() {
+                    p { class: "text-sm text-error mt-2", "{message}" }
+                }
+            }
+        }
+    }
+}
+
+/// "−3.3% / RPE · −2.9% / rep" for a calibration row.
+fn calibration_steps(calibration: &RpeCalibration) -> String {
+    format!(
+        "−{:.1}% / RPE · −{:.1}% / rep",
+        calibration.rpe_step_pct(),
+        calibration.rep_step_pct()
+    )
+}
+
+/// The trainee's fitted RPE Calibration next to the population default,
+/// each exercise's own fit, the opt-in for Suggestions and a refit button.
+#[component]
+fn RpeCalibrationCard(state: WorkoutState) -> Element {
+    let settings = state.settings();
+    let mut calibrating = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut refreshed = use_signal(|| 0u32);
+    let mut per_exercise = use_signal(Vec::<(String, RpeCalibration)>::new);
+
+    use_effect(move || {
+        refreshed();
+        spawn(async move {
+            let Some(db) = state.database() else {
+                return;
+            };
+            match db.get_exercise_rpe_calibrations().await {
+                Ok(calibrations) => per_exercise.set(calibrations),
+                Err(e) => log::warn!("Failed to load RPE calibrations: {}", e),
+            }
+        });
+    });
+
+    let exercises = state.exercises();
+    let mut rows: Vec<(String, String)> = per_exercise()
+        .iter()
+        .filter_map(|(id, calibration)| {
+            let name = exercises
+                .iter()
+                .find(|e| e.id.as_deref() == Some(id.as_str()))?
+                .name
+                .clone();
+            let steps = format!(
+                "{} ({} sets)",
+                calibration_steps(calibration),
+                calibration.sets
+            );
+            Some((name, steps))
+        })
+        .collect();
+    rows.sort();
+
+    let population = calibration_steps(&RpeCalibration::POPULATION);
+    let yours = settings.rpe_calibration.map(|c| calibration_steps(&c));
+    let fitted_from = settings
+        .rpe_calibration
+        .map(|c| format!("Fitted from {} sets over the Training Window", c.sets));
+
+    let recalibrate = move |_| {
+        calibrating.set(true);
+        error.set(None);
+        spawn(async move {
+            if let Err(e) = WorkoutStateManager::calibrate_rpe(&state).await {
+                error.set(Some(e.to_string()));
+            }
+            calibrating.set(false);
+            refreshed += 1;
+        });
+    };
+
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-xl mb-6",
+            "data-testid": "rpe-calibration-section",
+            div {
+                class: "card-body",
+                h3 { class: "card-title text-base font-bold mb-2", "RPE Calibration" }
+                p {
+                    class: "text-sm text-base-content/60 mb-4",
+                    "How much of your max you lose per RPE point and per extra rep, fitted to your own sets. Sparse data stays close to the default."
+                }
+                div {
+                    class: "grid grid-cols-[5rem_1fr] gap-1 text-sm mb-2",
+                    span { class: "font-semibold", "Default" }
+                    span { class: "font-mono", "data-testid": "rpe-calibration-default", "{population}" }
+                    span { class: "font-semibold", "Yours" }
+                    if let Some(yours) = yours {
+                        span { class: "font-mono", "data-testid": "rpe-calibration-yours", "{yours}" }
+                    } else {
+                        span { class: "opacity-60", "data-testid": "rpe-calibration-yours", "Not fitted yet" }
+                    }
+                }
+                if let Some(fitted_from) = fitted_from {
+                    p { class: "text-xs opacity-60 mb-2", "{fitted_from}" }
+                }
+                if !rows.is_empty() {
+                    div {
+                        class: "text-sm mb-2",
+                        "data-testid": "rpe-calibration-exercises",
+                        for (name, steps) in rows {
+                            div {
+                                key: "{name}",
+                                class: "flex justify-between gap-2 py-0.5",
+                                span { "{name}" }
+                                span { class: "font-mono text-xs", "{steps}" }
+                            }
+                        }
+                    }
+                }
+                label {
+                    class: "label cursor-pointer",
+                    span { class: "label-text font-semibold", "Use for Suggestions" }
+                    input {
+                        r#type: "checkbox",
+                        class: "toggle toggle-primary",
+                        "data-testid": "use-rpe-calibration-toggle",
+                        checked: settings.use_rpe_calibration,
+                        onchange: move |evt| {
+                            let mut s = state.settings();
+                            s.use_rpe_calibration = evt.checked();
+                            spawn(async move {
+                                if let Err(e) = WorkoutStateManager::update_settings(&state, s).await {
+                                    log::warn!("Failed to persist settings: {}", e);
+                                }
+                            });
+                        }
+                    }
+                }
+                button {
+                    class: "btn btn-outline btn-sm mt-2",
+                    "data-testid": "recalibrate-rpe-button",
+                    disabled: calibrating(),
+                    onclick: recalibrate,
+                    if calibrating() { "Calibrating…" } else { "Recalibrate" }
+                }
+                if let Some(message) = error() {
+                    p { class: "text-sm text-error mt-2", "{message}" }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 pub fn SettingsView(state: WorkoutState) -> Element {
     let settings = state.settings();
@@ -357,6 +803,57 @@ pub fn SettingsView(state: WorkoutState) -> Element {
                 }
             }
 
+            // ── Units card ────────────────────────────────────────────────────
+            div {
+                class: "card bg-base-100 shadow-xl mb-6",
+                div {
+                    class: "card-body",
+                    h3 { class: "card-title text-base font-bold mb-4", "Units" }
+
+                    div {
+                        class: "form-control",
+                        label {
+                            class: "label",
+                            span { class: "label-text font-semibold", "Weight Unit" }
+                        }
+                        div {
+                            class: "join w-full",
+                            "data-testid": "weight-unit-toggle",
+                            for unit in [WeightUnit::Kg, WeightUnit::Lb] {
+                                button {
+                                    key: "{unit}",
+                                    class: if settings.weight_unit == unit {
+                                        "btn btn-primary join-item flex-1"
+                                    } else {
+                                        "btn btn-outline join-item flex-1"
+                                    },
+                                    "data-testid": "weight-unit-{unit}",
+                                    onclick: move |_| {
+                                        let mut s = settings;
+                                        s.weight_unit = unit;
+                                        persist(s);
+                                    },
+                                    "{unit}"
+                                }
+                            }
+                        }
+                        label {
+                            class: "label",
+                            span {
+                                class: "label-text-alt opacity-60",
+                                "Logged sets are stored in kilograms, so switching units never changes your history"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ── Plate inventory card ──────────────────────────────────────────
+            PlateInventoryCard { state, unit: settings.weight_unit }
+
+            // ── Bodyweight log card ───────────────────────────────────────────
+            BodyweightLogCard { state, unit: settings.weight_unit }
+
             // ── Algorithm settings card ──────────────────────────────────────
             div {
                 class: "card bg-base-100 shadow-xl mb-6",
@@ -364,6 +861,42 @@ pub fn SettingsView(state: WorkoutState) -> Element {
                     class: "card-body",
                     h3 { class: "card-title text-base font-bold mb-4", "Algorithm Tuning" }
 
+                    // e1RM Formula used wherever an exercise has no override
+                    div {
+                        class: "form-control mb-6",
+                        label {
+                            class: "label",
+                            span { class: "label-text font-semibold", "e1RM Formula" }
+                        }
+                        select {
+                            class: "select select-bordered w-full",
+                            "data-testid": "e1rm-formula-select",
+                            value: "{settings.e1rm_formula}",
+                            onchange: move |evt| {
+                                if let Ok(formula) = evt.value().parse::<E1rmFormula>() {
+                                    let mut s = settings;
+                                    s.e1rm_formula = formula;
+                                    persist(s);
+                                }
+                            },
+                            for formula in E1rmFormula::ALL {
+                                option {
+                                    key: "{formula}",
+                                    value: "{formula}",
+                                    selected: settings.e1rm_formula == formula,
+                                    "{formula.label()}"
+                                }
+                            }
+                        }
+                        label {
+                            class: "label",
+                            span {
+                                class: "label-text-alt opacity-60",
+                                "How suggestions, records and charts estimate a one-rep max. Exercises can override it"
+                            }
+                        }
+                    }
+
                     // Target RPE slider (6.0 – 10.0, step 0.5)
                     div {
                         class: "form-control mb-6",
@@ -475,6 +1008,9 @@ pub fn SettingsView(state: WorkoutState) -> Element {
                 }
             }
 
+            // ── RPE calibration card ──────────────────────────────────────────
+            RpeCalibrationCard { state }
+
             // ── Workout planning card ─────────────────────────────────────────
             div {
                 class: "card bg-base-100 shadow-xl mb-6",
@@ -514,7 +1050,7 @@ pub fn SettingsView(state: WorkoutState) -> Element {
                     }
 
                     div {
-                        class: "form-control mb-2",
+                        class: "form-control mb-6",
                         label {
                             class: "label",
                             span { class: "label-text font-semibold", "Default Bodyweight Reps" }
@@ -543,6 +1079,34 @@ pub fn SettingsView(state: WorkoutState) -> Element {
                             }
                         }
                     }
+
+                    div {
+                        class: "form-control mb-2",
+                        label {
+                            class: "label",
+                            span { class: "label-text font-semibold", "Warm-up Ramp" }
+                        }
+                        input {
+                            r#type: "text",
+                            value: "{settings.warmup_ramp}",
+                            class: "input input-bordered w-full font-mono",
+                            "data-testid": "warmup-ramp-input",
+                            onchange: move |evt| {
+                                if let Ok(ramp) = evt.value().parse::<WarmupRamp>() {
+                                    let mut s = settings;
+                                    s.warmup_ramp = ramp;
+                                    persist(s);
+                                }
+                            }
+                        }
+                        label {
+                            class: "label",
+                            span {
+                                class: "label-text-alt opacity-60",
+                                "Warm-up sets before the first working set, as % of the working weight × reps (e.g. bar×10, 50%×5, 70%×3, 85%×1). Leave empty to skip warm-ups"
+                            }
+                        }
+                    }
                 }
             }
 
@@ -617,6 +1181,63 @@ pub fn SettingsView(state: WorkoutState) -> Element {
                 }
             }
 
+            // ── Volume Targets card ───────────────────────────────────────────
+            div {
+                class: "card bg-base-100 shadow-xl mb-6",
+                "data-testid": "volume-targets",
+                div {
+                    class: "card-body",
+                    h3 { class: "card-title text-base font-bold mb-2", "Weekly Volume Targets" }
+                    p {
+                        class: "text-sm text-base-content/60 mb-2",
+                        "Intensity-adjusted sets per week: minimum effective (MEV), target (MAV) and maximum recoverable (MRV)."
+                    }
+                    div {
+                        class: "grid grid-cols-[1fr_repeat(3,4rem)] gap-1 items-center text-sm",
+                        span {}
+                        span { class: "text-center font-semibold", "MEV" }
+                        span { class: "text-center font-semibold", "MAV" }
+                        span { class: "text-center font-semibold", "MRV" }
+                        for group in MuscleGroup::ALL {
+                            {
+                                let target = settings.volume_targets.get(&group);
+                                let name = group.to_string();
+                                let fields = [("mev", target.mev), ("mav", target.mav), ("mrv", target.mrv)];
+                                rsx! {
+                                    span { key: "{name}", "{name}" }
+                                    for (field, value) in fields {
+                                        {
+                                            let group = group.clone();
+                                            rsx! {
+                                                input {
+                                                    key: "{name}-{field}",
+                                                    r#type: "number",
+                                                    min: "0",
+                                                    value: "{value}",
+                                                    class: "input input-bordered input-sm w-full text-center",
+                                                    "data-testid": "volume-target-{name}-{field}",
+                                                    onchange: move |evt| {
+                                                        let Ok(val) = evt.value().parse::<u32>() else {
+                                                            return;
+                                                        };
+                                                        let updated = with_landmark(target, field, val);
+                                                        if updated.mev <= updated.mav && updated.mav <= updated.mrv {
+                                                            let mut s = settings;
+                                                            s.volume_targets.set(&group, updated);
+                                                            persist(s);
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // ── Data management card (existing) ─────────────────────────────
             div {
                 class: "card bg-base-100 shadow-xl mb-6",
@@ -0,0 +1,65 @@
+use crate::models::{MuscleGroup, VolumeZone};
+use crate::state::{WorkoutState, WorkoutStateManager};
+use dioxus::prelude::*;
+
+/// Muscle Groups whose 7-day Volume is under MEV or over MRV, shown on the
+/// Plan Builder before the next plan is built. Hidden when every group is
+/// within its targets.
+#[component]
+pub fn VolumeFlagsCard(state: WorkoutState) -> Element {
+    let mut weekly = use_signal(Vec::<(MuscleGroup, f64, VolumeZone)>::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match WorkoutStateManager::weekly_volume(&state).await {
+                Ok(w) => weekly.set(w),
+                Err(e) => log::warn!("Failed to load weekly volume: {}", e),
+            }
+        });
+    });
+
+    let targets = state.settings().volume_targets;
+    let flags: Vec<(String, String, VolumeZone)> = weekly()
+        .into_iter()
+        .filter(|(_, _, zone)| zone.is_flagged())
+        .map(|(group, volume, zone)| {
+            let target = targets.get(&group);
+            let detail = match zone {
+                VolumeZone::Over => format!("{:.1} sets, MRV {}", volume, target.mrv),
+                _ => format!("{:.1} sets, MEV {}", volume, target.mev),
+            };
+            (group.to_string(), detail, zone)
+        })
+        .collect();
+    if flags.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "alert flex flex-col items-stretch gap-2 mb-4",
+            "data-testid": "volume-flags",
+            p { class: "font-bold", "Weekly volume off target" }
+            ul {
+                class: "text-sm grid gap-1",
+                for (group, detail, zone) in flags {
+                    li {
+                        key: "{group}",
+                        class: "flex justify-between gap-2",
+                        "data-testid": "volume-flag",
+                        "data-zone": "{zone.label()}",
+                        span {
+                            class: "flex items-center gap-2",
+                            span {
+                                class: "inline-block w-2.5 h-2.5 rounded-full",
+                                style: "background-color: {zone.color()}",
+                            }
+                            "{group}: {zone.label()}"
+                        }
+                        span { class: "text-base-content/60", "{detail}" }
+                    }
+                }
+            }
+        }
+    }
+}
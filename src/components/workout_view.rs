@@ -1,6 +1,7 @@
 use crate::app::ActiveSession;
 use crate::components::exercise_tab_strip::ExerciseTabStrip;
 use crate::components::plan_builder::{ExercisePickerModal, PlanBuilder};
+use crate::domain::slot_group;
 use crate::state::{WorkoutState, WorkoutStateManager};
 use dioxus::prelude::*;
 
@@ -10,6 +11,9 @@ pub fn WorkoutView(state: WorkoutState) -> Element {
     let current_plan = state.current_plan();
     let mut active_tab_index = use_signal(|| 0usize);
     let mut set_counts = use_signal(Vec::<u32>::new);
+    // (exercise id, completed set count) of the session when counts were last
+    // fetched, to tell a freshly logged set from a tab switch.
+    let mut last_logged = use_signal(|| None::<(Option<String>, usize)>);
     let mut show_add_exercise = use_signal(|| false);
     let mut add_search_query = use_signal(String::new);
 
@@ -17,47 +21,83 @@ pub fn WorkoutView(state: WorkoutState) -> Element {
         // Plan started — show tab strip + recorder
         (Some(plan), _) if plan.started_at.is_some() && plan.ended_at.is_none() => {
             let exercises = plan.exercises.clone();
-            let plan_started_at = plan.started_at.unwrap_or(0.0);
             let active_idx = active_tab_index();
 
-            // Fetch completed set counts per exercise
-            {
-                let exercise_ids: Vec<String> = exercises
-                    .iter()
-                    .filter_map(|pe| pe.exercise.id.clone())
-                    .collect();
-                let _current_counts = set_counts();
-
-                use_effect(move || {
-                    let eids = exercise_ids.clone();
-                    let since = plan_started_at;
-                    spawn(async move {
-                        if let Some(db) = state.database() {
-                            match db.count_sets_since(&eids, since).await {
-                                Ok(counts) => {
-                                    let mut result: Vec<u32> = vec![0; eids.len()];
-                                    for (eid, cnt) in &counts {
-                                        if let Some(pos) = eids.iter().position(|id| id == eid) {
-                                            result[pos] = *cnt;
-                                        }
-                                    }
-                                    if let Some(session) = state.current_session()
-                                        && let Some(sid) = &session.exercise.id
-                                        && let Some(pos) = eids.iter().position(|id| id == sid)
-                                    {
-                                        result[pos] =
-                                            result[pos].max(result[pos].saturating_add(
-                                                session.completed_sets.len() as u32,
-                                            ));
-                                    }
-                                    set_counts.set(result);
-                                }
-                                Err(e) => log::warn!("Failed to count sets: {}", e),
-                            }
+            // Fetch completed set counts per exercise. Re-runs whenever the plan
+            // changes or a set is logged, and then advances to the next member
+            // of a superset / circuit.
+            use_effect(move || {
+                let Some(plan) = state.current_plan() else {
+                    return;
+                };
+                let session_key = state
+                    .current_session()
+                    .map(|s| (s.exercise.id.clone(), s.completed_sets.len()));
+                let just_logged = matches!(
+                    (&*last_logged.peek(), &session_key),
+                    (Some((prev_id, prev_len)), Some((id, len))) if prev_id == id && len > prev_len
+                );
+                last_logged.set(session_key.clone());
+
+                let slots = plan.exercises;
+                let since = plan.started_at.unwrap_or(0.0);
+                spawn(async move {
+                    let Some(db) = state.database() else {
+                        return;
+                    };
+                    let eids: Vec<String> = slots
+                        .iter()
+                        .filter_map(|pe| pe.exercise.id.clone())
+                        .collect();
+                    let counts = match db.count_sets_since(&eids, since).await {
+                        Ok(counts) => counts,
+                        Err(e) => {
+                            log::warn!("Failed to count sets: {}", e);
+                            return;
+                        }
+                    };
+                    let result: Vec<u32> = slots
+                        .iter()
+                        .map(|pe| {
+                            counts
+                                .iter()
+                                .find(|(eid, _)| Some(eid) == pe.exercise.id.as_ref())
+                                .map(|(_, cnt)| *cnt)
+                                .unwrap_or(0)
+                        })
+                        .collect();
+                    set_counts.set(result.clone());
+
+                    let Some((Some(logged_id), _)) = session_key.filter(|_| just_logged) else {
+                        return;
+                    };
+                    let active = *active_tab_index.peek();
+                    let logged_idx = if slots.get(active).and_then(|pe| pe.exercise.id.as_ref())
+                        == Some(&logged_id)
+                    {
+                        Some(active)
+                    } else {
+                        slots
+                            .iter()
+                            .position(|pe| pe.exercise.id.as_ref() == Some(&logged_id))
+                    };
+                    if let Some(logged_idx) = logged_idx
+                        && let Some(next) = slot_group::next_group_slot(&slots, &result, logged_idx)
+                    {
+                        // No rest between members of a round; the rest timer
+                        // runs once the round wraps back to its first member.
+                        if next > logged_idx {
+                            state.set_rest_timer(None);
+                        }
+                        active_tab_index.set(next);
+                        if let Err(e) =
+                            WorkoutStateManager::start_slot_session(&state, &slots[next]).await
+                        {
+                            log::warn!("Failed to start session: {}", e);
                         }
-                    });
+                    }
                 });
-            }
+            });
 
             let counts = {
                 let c = set_counts();
@@ -80,6 +120,15 @@ pub fn WorkoutView(state: WorkoutState) -> Element {
                 }
             }
 
+            let group_rounds = slot_group::group_rounds(&exercises, &display_counts, active_idx);
+            let group_name = match slot_group::group_label(&exercises, active_idx) {
+                Some(label) if slot_group::group_members(&exercises, active_idx).len() == 2 => {
+                    format!("Superset {}", &label[..1])
+                }
+                Some(label) => format!("Circuit {}", &label[..1]),
+                None => String::new(),
+            };
+
             rsx! {
                 div {
                     class: "max-w-2xl mx-auto",
@@ -95,9 +144,9 @@ pub fn WorkoutView(state: WorkoutState) -> Element {
                         on_select: move |idx: usize| {
                             active_tab_index.set(idx);
                             if let Some(pe) = exercises.get(idx) {
-                                let exercise = pe.exercise.clone();
+                                let slot = pe.clone();
                                 spawn(async move {
-                                    if let Err(e) = WorkoutStateManager::start_session(&state, exercise).await {
+                                    if let Err(e) = WorkoutStateManager::start_slot_session(&state, &slot).await {
                                         log::warn!("Failed to start session: {}", e);
                                     }
                                 });
@@ -105,6 +154,14 @@ pub fn WorkoutView(state: WorkoutState) -> Element {
                         },
                     }
 
+                    if let Some(rounds) = group_rounds {
+                        div {
+                            class: "text-center text-sm font-semibold text-base-content/60 mb-2",
+                            "data-testid": "group-round",
+                            "{group_name} · Round {(rounds.done + 1).min(rounds.planned)} of {rounds.planned}"
+                        }
+                    }
+
                     if let Some(session) = current_session {
                         ActiveSession { state, session }
                     }
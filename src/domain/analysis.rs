@@ -0,0 +1,227 @@
+//! Pure aggregation behind the Analysis dashboard.
+//!
+//! The dashboard answers four questions at a glance: how much Volume each
+//! Muscle Group got this week against its usual week, how many lifts are
+//! Progressing or Stalled, how many Training Days a week the trainee manages,
+//! and which lifts are moving fastest in either direction.
+
+use crate::models::{MuscleGroup, MuscleGroupVolume, ProgressState};
+use chrono::NaiveDate;
+
+/// Lifts listed in each direction under Top Movers.
+pub const TOP_MOVERS: usize = 3;
+
+/// An exercise the dashboard can link to, by id and name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExerciseLink {
+    pub exercise_id: String,
+    pub name: String,
+}
+
+/// Progress State of one exercise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExerciseProgress {
+    pub exercise: ExerciseLink,
+    pub state: ProgressState,
+}
+
+impl ExerciseProgress {
+    /// e1RM Trend slope in kg per day; `None` without enough data.
+    pub fn slope(&self) -> Option<f64> {
+        match self.state {
+            ProgressState::Progressing { slope } | ProgressState::Stalled { slope } => Some(slope),
+            ProgressState::InsufficientData => None,
+        }
+    }
+}
+
+/// Volume of one Muscle Group, with the exercises that train it as Primary.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupVolume {
+    pub group: MuscleGroup,
+    /// Intensity-Adjusted Sets over the last 7 days.
+    pub this_week: f64,
+    /// Average week of the Training Window.
+    pub weekly_average: f64,
+    pub exercises: Vec<ExerciseLink>,
+    /// 7-day Volume of each Sub-Muscle that has any, already included in
+    /// `this_week`.
+    pub sub_muscles: Vec<(MuscleGroup, f64)>,
+}
+
+impl GroupVolume {
+    pub fn new(
+        group: MuscleGroup,
+        volume: &MuscleGroupVolume,
+        training_window_weeks: u32,
+        exercises: Vec<ExerciseLink>,
+    ) -> Self {
+        GroupVolume {
+            group,
+            this_week: volume.rolling_7d,
+            weekly_average: volume.rolling_training_period / training_window_weeks.max(1) as f64,
+            exercises,
+            sub_muscles: Vec::new(),
+        }
+    }
+}
+
+/// Everything the Analysis dashboard shows.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnalysisSnapshot {
+    /// Muscle Groups with any Volume in the Training Window.
+    pub volumes: Vec<GroupVolume>,
+    pub progress: Vec<ExerciseProgress>,
+    /// Training Days per week, oldest week first; see [`weekly_frequency`].
+    pub frequency: Vec<u32>,
+}
+
+/// Number of exercises in each Progress State.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ProgressSummary {
+    pub progressing: usize,
+    pub stalled: usize,
+    pub insufficient: usize,
+}
+
+pub fn summarize(progress: &[ExerciseProgress]) -> ProgressSummary {
+    progress
+        .iter()
+        .fold(ProgressSummary::default(), |mut summary, p| {
+            match p.state {
+                ProgressState::Progressing { .. } => summary.progressing += 1,
+                ProgressState::Stalled { .. } => summary.stalled += 1,
+                ProgressState::InsufficientData => summary.insufficient += 1,
+            }
+            summary
+        })
+}
+
+/// Training Days in each of the `weeks` rolling 7-day weeks ending on
+/// `today`, oldest first. Days outside the span are ignored.
+pub fn weekly_frequency(days: &[NaiveDate], today: NaiveDate, weeks: u32) -> Vec<u32> {
+    let mut counts = vec![0; weeks as usize];
+    for day in days {
+        let age = (today - *day).num_days();
+        if age < 0 {
+            continue;
+        }
+        let back = (age / 7) as usize;
+        if back < counts.len() {
+            let index = counts.len() - 1 - back;
+            counts[index] += 1;
+        }
+    }
+    counts
+}
+
+/// The fastest rising lifts, steepest first, and the fastest falling lifts,
+/// steepest first, at most `n` each. Flat and Insufficient Data lifts are in
+/// neither list.
+pub fn top_movers(
+    progress: &[ExerciseProgress],
+    n: usize,
+) -> (Vec<&ExerciseProgress>, Vec<&ExerciseProgress>) {
+    let mut sloped: Vec<(&ExerciseProgress, f64)> = progress
+        .iter()
+        .filter_map(|p| p.slope().map(|s| (p, s)))
+        .collect();
+    sloped.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let rising = sloped
+        .iter()
+        .filter(|(_, s)| *s > 0.0)
+        .take(n)
+        .map(|(p, _)| *p)
+        .collect();
+    let falling = sloped
+        .iter()
+        .rev()
+        .filter(|(_, s)| *s < 0.0)
+        .take(n)
+        .map(|(p, _)| *p)
+        .collect();
+    (rising, falling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lift(name: &str, state: ProgressState) -> ExerciseProgress {
+        ExerciseProgress {
+            exercise: ExerciseLink {
+                exercise_id: name.to_lowercase(),
+                name: name.to_string(),
+            },
+            state,
+        }
+    }
+
+    fn names(list: &[&ExerciseProgress]) -> Vec<String> {
+        list.iter().map(|p| p.exercise.name.clone()).collect()
+    }
+
+    #[test]
+    fn test_summarize_counts_each_state() {
+        let progress = [
+            lift("Squat", ProgressState::Progressing { slope: 0.2 }),
+            lift("Bench", ProgressState::Stalled { slope: 0.0 }),
+            lift("Row", ProgressState::Stalled { slope: -0.1 }),
+            lift("Curl", ProgressState::InsufficientData),
+        ];
+        assert_eq!(
+            summarize(&progress),
+            ProgressSummary {
+                progressing: 1,
+                stalled: 2,
+                insufficient: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_weekly_frequency_buckets_rolling_weeks() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 28).unwrap();
+        let ago = |days| today - chrono::Duration::days(days);
+        let days = [
+            ago(0),
+            ago(3),
+            ago(6),
+            ago(7),
+            ago(13),
+            ago(20),
+            ago(21),
+            ago(-1),
+        ];
+        // Three weeks: [15..21], [8..14] and [1..7] days back, this week last.
+        assert_eq!(weekly_frequency(&days, today, 3), vec![1, 2, 3]);
+        assert_eq!(weekly_frequency(&[], today, 2), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_top_movers_rank_by_slope_in_each_direction() {
+        let progress = [
+            lift("Squat", ProgressState::Progressing { slope: 0.2 }),
+            lift("Deadlift", ProgressState::Progressing { slope: 0.5 }),
+            lift("Press", ProgressState::Progressing { slope: 0.1 }),
+            lift("Bench", ProgressState::Stalled { slope: 0.0 }),
+            lift("Row", ProgressState::Stalled { slope: -0.1 }),
+            lift("Dip", ProgressState::Stalled { slope: -0.4 }),
+            lift("Curl", ProgressState::InsufficientData),
+        ];
+        let (rising, falling) = top_movers(&progress, 2);
+        assert_eq!(names(&rising), ["Deadlift", "Squat"]);
+        assert_eq!(names(&falling), ["Dip", "Row"]);
+    }
+
+    #[test]
+    fn test_group_volume_averages_the_window() {
+        let volume = MuscleGroupVolume {
+            daily: 1.0,
+            rolling_7d: 6.0,
+            rolling_training_period: 48.0,
+        };
+        let row = GroupVolume::new(MuscleGroup::Chest, &volume, 12, Vec::new());
+        assert_eq!((row.this_week, row.weekly_average), (6.0, 4.0));
+    }
+}
@@ -0,0 +1,316 @@
+//! Pure Suggested Workout generation from Volume deficits.
+//!
+//! Each Muscle Group's deficit is how far its 7-day Volume sits below its MAV.
+//! Groups trained as Primary in the last [`RECOVERY_HOURS`] are left to
+//! recover. Exercises from the Candidate Pool are then picked greedily: the
+//! one whose Contribution Tiers cover the most remaining deficit goes next,
+//! with enough sets to close the deficit of the group it fills best.
+
+use crate::models::{ContributionTier, ExerciseMuscleGroup, MuscleGroup, VolumeTargets};
+
+/// Hours after a Primary set during which a Muscle Group is left to recover.
+pub const RECOVERY_HOURS: f64 = 48.0;
+
+/// Most Plan Slots in a Suggested Workout.
+pub const MAX_SLOTS: usize = 5;
+
+/// Planned sets per slot are kept within this range.
+pub const MIN_SLOT_SETS: u32 = 2;
+pub const MAX_SLOT_SETS: u32 = 5;
+
+/// Most planned sets across the whole Suggested Workout.
+pub const MAX_SESSION_SETS: u32 = 20;
+
+/// Remaining deficits smaller than this count as filled.
+const EPSILON: f64 = 1e-6;
+
+/// An Active Exercise that may be suggested.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate {
+    pub exercise_id: String,
+    pub muscle_groups: Vec<ExerciseMuscleGroup>,
+}
+
+impl Candidate {
+    /// Volume one set at `rpe` adds to each of the exercise's top-level
+    /// Muscle Groups, split by Contribution Tier the same way logged sets
+    /// are, with Sub-Muscles rolled up into their parents.
+    fn per_set(&self, rpe: f64) -> Vec<(MuscleGroup, f64)> {
+        let total: f64 = self.muscle_groups.iter().map(|m| m.tier.as_weight()).sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+        let mut per_set: Vec<(MuscleGroup, f64)> = Vec::new();
+        for m in &self.muscle_groups {
+            let group = m.muscle_group.top_level();
+            let volume = rpe / 10.0 * m.tier.as_weight() / total;
+            match per_set.iter_mut().find(|(g, _)| *g == group) {
+                Some((_, v)) => *v += volume,
+                None => per_set.push((group, volume)),
+            }
+        }
+        per_set
+    }
+
+    /// Top-level groups trained as Primary.
+    fn primary(&self) -> impl Iterator<Item = MuscleGroup> + '_ {
+        self.muscle_groups
+            .iter()
+            .filter(|m| m.tier == ContributionTier::Primary)
+            .map(|m| m.muscle_group.top_level())
+    }
+}
+
+/// One slot of a Suggested Workout.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuggestedSlot {
+    pub exercise_id: String,
+    pub planned_sets: u32,
+}
+
+/// Muscle Groups trained as Primary by any of `recent` exercises.
+pub fn recovering(recent: &[&Candidate]) -> Vec<MuscleGroup> {
+    let mut groups: Vec<MuscleGroup> = Vec::new();
+    for group in recent.iter().flat_map(|c| c.primary()) {
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+    groups
+}
+
+/// How far each Muscle Group's 7-day Volume is below its MAV; groups at or
+/// above it, and `recovering` groups, have no deficit.
+pub fn deficits(
+    weekly: &[(MuscleGroup, f64)],
+    targets: &VolumeTargets,
+    recovering: &[MuscleGroup],
+) -> Vec<(MuscleGroup, f64)> {
+    weekly
+        .iter()
+        .filter(|(group, _)| !recovering.contains(group))
+        .map(|(group, volume)| (group.clone(), targets.get(group).mav as f64 - volume))
+        .filter(|(_, deficit)| *deficit > EPSILON)
+        .collect()
+}
+
+/// Picks exercises and planned sets that best fill `deficits`, planning sets
+/// at `rpe`. Candidates that train a `recovering` group as Primary are
+/// skipped. Returns an empty list when nothing needs filling.
+pub fn suggest(
+    deficits: &[(MuscleGroup, f64)],
+    candidates: &[Candidate],
+    recovering: &[MuscleGroup],
+    rpe: f64,
+) -> Vec<SuggestedSlot> {
+    let mut remaining: Vec<(MuscleGroup, f64)> = deficits.to_vec();
+    let left = |remaining: &[(MuscleGroup, f64)], group: &MuscleGroup| {
+        remaining
+            .iter()
+            .find(|(g, _)| g == group)
+            .map_or(0.0, |(_, d)| *d)
+    };
+
+    let mut pool: Vec<&Candidate> = candidates
+        .iter()
+        .filter(|c| !c.primary().any(|g| recovering.contains(&g)))
+        .collect();
+    let mut slots = Vec::new();
+    let mut budget = MAX_SESSION_SETS;
+
+    while slots.len() < MAX_SLOTS && budget >= MIN_SLOT_SETS {
+        // Deficit one set of each candidate covers; the first best wins ties.
+        let mut best: Option<(usize, f64)> = None;
+        for (i, candidate) in pool.iter().enumerate() {
+            let covered: f64 = candidate
+                .per_set(rpe)
+                .iter()
+                .map(|(g, v)| v.min(left(&remaining, g)))
+                .sum();
+            if covered > EPSILON && best.is_none_or(|(_, b)| covered > b + EPSILON) {
+                best = Some((i, covered));
+            }
+        }
+        let Some((index, _)) = best else {
+            break;
+        };
+        let candidate = pool.remove(index);
+        let per_set = candidate.per_set(rpe);
+
+        // Enough sets to close the group this exercise fills best.
+        let (per, deficit) = per_set
+            .iter()
+            .map(|(g, v)| (*v, left(&remaining, g)))
+            .max_by(|a, b| a.0.min(a.1).total_cmp(&b.0.min(b.1)))
+            .expect("a covering candidate trains at least one group");
+        let sets = ((deficit / per).ceil() as u32)
+            .clamp(MIN_SLOT_SETS, MAX_SLOT_SETS)
+            .min(budget);
+        budget -= sets;
+
+        for (group, per) in &per_set {
+            if let Some((_, d)) = remaining.iter_mut().find(|(g, _)| g == group) {
+                *d = (*d - per * sets as f64).max(0.0);
+            }
+        }
+        slots.push(SuggestedSlot {
+            exercise_id: candidate.exercise_id.clone(),
+            planned_sets: sets,
+        });
+    }
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::VolumeTarget;
+
+    fn candidate(id: &str, groups: &[(MuscleGroup, ContributionTier)]) -> Candidate {
+        Candidate {
+            exercise_id: id.to_string(),
+            muscle_groups: groups
+                .iter()
+                .map(|(g, t)| ExerciseMuscleGroup {
+                    exercise_id: id.to_string(),
+                    muscle_group: g.clone(),
+                    tier: t.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    fn ids(slots: &[SuggestedSlot]) -> Vec<&str> {
+        slots.iter().map(|s| s.exercise_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_deficits_measure_against_mav_and_skip_recovering() {
+        let mut targets = VolumeTargets::default();
+        targets.set(&MuscleGroup::Chest, VolumeTarget::new(8, 14, 22));
+        targets.set(&MuscleGroup::Back, VolumeTarget::new(10, 18, 25));
+        targets.set(&MuscleGroup::Quads, VolumeTarget::new(8, 15, 20));
+        let weekly = [
+            (MuscleGroup::Chest, 10.0),
+            (MuscleGroup::Back, 20.0),
+            (MuscleGroup::Quads, 0.0),
+        ];
+        assert_eq!(
+            deficits(&weekly, &targets, &[MuscleGroup::Quads]),
+            vec![(MuscleGroup::Chest, 4.0)]
+        );
+    }
+
+    #[test]
+    fn test_candidates_fill_remaining_deficits_in_turn() {
+        use ContributionTier::*;
+        let candidates = [
+            candidate("fly", &[(MuscleGroup::Chest, Primary)]),
+            candidate(
+                "bench",
+                &[
+                    (MuscleGroup::Chest, Primary),
+                    (MuscleGroup::Triceps, Secondary),
+                ],
+            ),
+            candidate("curl", &[(MuscleGroup::Biceps, Primary)]),
+        ];
+        // Per set the fly covers 0.8 of chest and the bench 0.53 chest plus
+        // 0.27 triceps: a tie the first listed wins. Once chest is filled the
+        // bench still covers triceps; the curl covers nothing.
+        let deficits = [(MuscleGroup::Chest, 4.0), (MuscleGroup::Triceps, 4.0)];
+        let slots = suggest(&deficits, &candidates, &[], 8.0);
+        assert_eq!(ids(&slots), ["fly", "bench"]);
+
+        let only_bench = [candidate(
+            "bench",
+            &[
+                (MuscleGroup::Chest, Primary),
+                (MuscleGroup::Triceps, Secondary),
+            ],
+        )];
+        let slots = suggest(&deficits, &only_bench, &[], 8.0);
+        // 4.0 / 0.533 = 7.5 sets, capped at the slot maximum.
+        assert_eq!(slots[0].planned_sets, MAX_SLOT_SETS);
+    }
+
+    #[test]
+    fn test_sets_close_the_deficit_within_bounds() {
+        use ContributionTier::*;
+        let candidates = [candidate("squat", &[(MuscleGroup::Quads, Primary)])];
+        // 2.4 / 0.8 = 3 sets.
+        let slots = suggest(&[(MuscleGroup::Quads, 2.4)], &candidates, &[], 8.0);
+        assert_eq!(
+            slots,
+            vec![SuggestedSlot {
+                exercise_id: "squat".into(),
+                planned_sets: 3
+            }]
+        );
+        // A tiny deficit still gets the minimum sets.
+        let slots = suggest(&[(MuscleGroup::Quads, 0.1)], &candidates, &[], 8.0);
+        assert_eq!(slots[0].planned_sets, MIN_SLOT_SETS);
+    }
+
+    #[test]
+    fn test_recovering_primary_groups_are_skipped() {
+        use ContributionTier::*;
+        let squat = candidate("squat", &[(MuscleGroup::Quads, Primary)]);
+        let rdl = candidate(
+            "rdl",
+            &[
+                (MuscleGroup::Hamstrings, Primary),
+                (MuscleGroup::Quads, Tertiary),
+            ],
+        );
+        let recovering_groups = recovering(&[&squat]);
+        assert_eq!(recovering_groups, vec![MuscleGroup::Quads]);
+        let deficits = [(MuscleGroup::Quads, 5.0), (MuscleGroup::Hamstrings, 5.0)];
+        let slots = suggest(&deficits, &[squat, rdl], &recovering_groups, 8.0);
+        assert_eq!(ids(&slots), ["rdl"]);
+    }
+
+    #[test]
+    fn test_sub_muscles_count_towards_their_parent() {
+        use ContributionTier::*;
+        let raise = candidate("raise", &[(MuscleGroup::SideDelts, Primary)]);
+        let face_pull = candidate(
+            "face-pull",
+            &[
+                (MuscleGroup::RearDelts, Primary),
+                (MuscleGroup::MidBack, Primary),
+            ],
+        );
+        assert_eq!(recovering(&[&raise]), vec![MuscleGroup::Shoulders]);
+        // 2.4 / 0.8 = 3 sets of the raise fill the shoulders.
+        let slots = suggest(
+            &[(MuscleGroup::Shoulders, 2.4)],
+            &[face_pull, raise],
+            &[],
+            8.0,
+        );
+        assert_eq!(
+            slots,
+            vec![SuggestedSlot {
+                exercise_id: "raise".into(),
+                planned_sets: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_session_budget_and_slot_limit() {
+        use ContributionTier::*;
+        let groups = MuscleGroup::ALL;
+        let candidates: Vec<Candidate> = groups
+            .iter()
+            .map(|g| candidate(&g.to_string(), &[(g.clone(), Primary)]))
+            .collect();
+        let deficits: Vec<(MuscleGroup, f64)> = groups.iter().map(|g| (g.clone(), 10.0)).collect();
+        let slots = suggest(&deficits, &candidates, &[], 8.0);
+        assert_eq!(slots.len(), 4, "four slots of five sets use the budget");
+        let total: u32 = slots.iter().map(|s| s.planned_sets).sum();
+        assert_eq!(total, MAX_SESSION_SETS);
+        assert!(suggest(&[], &candidates, &[], 8.0).is_empty());
+    }
+}
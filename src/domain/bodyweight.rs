@@ -0,0 +1,98 @@
+//! Pure helpers for loaded and assisted bodyweight exercises.
+//!
+//! Their e1RM is computed from the System Load — bodyweight plus added weight,
+//! or minus assistance — so a heavier trainee doing the same pull-up is
+//! credited with the heavier lift.
+
+use crate::models::BodyweightEntry;
+
+/// Body mass in force at `at_ms`: the latest entry on or before it, or the
+/// earliest entry when every entry is later.  `None` for an empty log.
+pub fn bodyweight_at(entries: &[BodyweightEntry], at_ms: f64) -> Option<f32> {
+    let before = entries
+        .iter()
+        .filter(|e| e.recorded_at <= at_ms)
+        .max_by(|a, b| a.recorded_at.total_cmp(&b.recorded_at));
+    let earliest = || {
+        entries
+            .iter()
+            .min_by(|a, b| a.recorded_at.total_cmp(&b.recorded_at))
+    };
+    before.or_else(earliest).map(|e| e.mass)
+}
+
+/// Added (or, when `assisted`, assisting) weight that brings `bodyweight` to
+/// `system_load`, snapped to whole `increment`s.
+///
+/// Never negative; assistance is capped one increment below bodyweight so the
+/// System Load stays positive.
+pub fn weight_for_load(system_load: f64, bodyweight: f64, assisted: bool, increment: f64) -> f64 {
+    let raw = if assisted {
+        bodyweight - system_load
+    } else {
+        system_load - bodyweight
+    };
+    let snapped = if increment > 0.0 {
+        (raw / increment).round() * increment
+    } else {
+        raw
+    };
+    let snapped = snapped.max(0.0);
+    if assisted && increment > 0.0 && snapped >= bodyweight {
+        ((bodyweight / increment).ceil() - 1.0).max(0.0) * increment
+    } else {
+        snapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn entry(recorded_at: f64, mass: f32) -> BodyweightEntry {
+        BodyweightEntry {
+            id: None,
+            recorded_at,
+            mass,
+        }
+    }
+
+    #[test]
+    fn bodyweight_at_picks_latest_entry_on_or_before() {
+        let log = vec![entry(100.0, 80.0), entry(300.0, 82.0), entry(200.0, 81.0)];
+        assert_eq!(bodyweight_at(&log, 250.0), Some(81.0));
+        assert_eq!(bodyweight_at(&log, 300.0), Some(82.0));
+        assert_eq!(bodyweight_at(&log, 1_000.0), Some(82.0));
+    }
+
+    #[test]
+    fn bodyweight_at_falls_back_to_earliest_entry() {
+        let log = vec![entry(300.0, 82.0), entry(200.0, 81.0)];
+        assert_eq!(bodyweight_at(&log, 50.0), Some(81.0));
+        assert_eq!(bodyweight_at(&[], 50.0), None);
+    }
+
+    #[test]
+    fn weight_for_load_adds_for_weighted() {
+        // 80 kg trainee, 101 kg target → 21 kg → 20 kg on a 2.5 kg grid.
+        assert!((weight_for_load(101.0, 80.0, false, 2.5) - 20.0).abs() < EPSILON);
+        // Target below bodyweight → nothing added.
+        assert!(weight_for_load(70.0, 80.0, false, 2.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn weight_for_load_assists_for_assisted() {
+        // 80 kg trainee, 52 kg target → 28 kg assistance → 30 kg on a 5 kg grid.
+        assert!((weight_for_load(52.0, 80.0, true, 5.0) - 30.0).abs() < EPSILON);
+        // Target above bodyweight → no assistance.
+        assert!(weight_for_load(90.0, 80.0, true, 5.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn weight_for_load_caps_assistance_below_bodyweight() {
+        assert!((weight_for_load(0.0, 80.0, true, 5.0) - 75.0).abs() < EPSILON);
+        assert!((weight_for_load(-5.0, 78.0, true, 5.0) - 75.0).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,268 @@
+//! RPE Calibration: refits the Linear RPE curve to the trainee's own Sets.
+//!
+//! Sets of one exercise on one Training Day are taken to share an e1RM, so
+//! `ln load = ln e1RM + ln(1 − rpe_step × (10 − rpe)) + (reps − 1) × ln rep_decay`.
+//! Centring each session on its mean removes the unknown e1RM and leaves a
+//! two-parameter least-squares problem, solved by Gauss–Newton. Gaussian
+//! priors on the population curve keep sparse histories close to it.
+
+use crate::models::RpeCalibration;
+
+/// One e1RM-eligible Set as the fit sees it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CalibrationSet {
+    /// System Load of the Set.
+    pub load: f64,
+    pub reps: u32,
+    pub rpe: f64,
+}
+
+/// Sets an exercise needs before it gets its own calibration instead of
+/// sharing the trainee's.
+pub const MIN_EXERCISE_SETS: u32 = 12;
+
+/// Expected scatter of a Set's log-load around the curve (about 3%).
+const NOISE_SD: f64 = 0.03;
+/// Prior spread of the RPE step around the population value.
+const RPE_STEP_PRIOR_SD: f64 = 0.01;
+/// Prior spread of `ln rep_decay` around the population value.
+const LOG_DECAY_PRIOR_SD: f64 = 0.01;
+/// Bounds that keep the curve falling and positive across the RPE scale.
+const RPE_STEP_RANGE: (f64, f64) = (0.005, 0.08);
+const REP_DECAY_RANGE: (f64, f64) = (0.9, 0.995);
+const MAX_ITERATIONS: usize = 25;
+
+/// Fits the curve to `sessions`, each the Sets of one exercise on one
+/// Training Day.  Sets without a positive load have no log-load and are
+/// dropped; sessions left with a single Set carry no information about the
+/// curve and are skipped.  With nothing left the population curve is
+/// returned unchanged.
+pub fn fit(sessions: &[Vec<CalibrationSet>]) -> RpeCalibration {
+    let sessions: Vec<Vec<CalibrationSet>> = sessions
+        .iter()
+        .map(|s| s.iter().filter(|set| set.load > 0.0).copied().collect())
+        .filter(|s: &Vec<CalibrationSet>| s.len() >= 2)
+        .collect();
+    let sets = sessions.iter().map(|s| s.len() as u32).sum();
+
+    let prior = [
+        RpeCalibration::POPULATION.rpe_step,
+        RpeCalibration::POPULATION.rep_decay.ln(),
+    ];
+    let precision = [
+        1.0 / (RPE_STEP_PRIOR_SD * RPE_STEP_PRIOR_SD),
+        1.0 / (LOG_DECAY_PRIOR_SD * LOG_DECAY_PRIOR_SD),
+    ];
+    let noise = 1.0 / (NOISE_SD * NOISE_SD);
+
+    let mut theta = prior;
+    for _ in 0..MAX_ITERATIONS {
+        // Normal equations of the posterior: (JᵀJ/σ² + P) Δ = Jᵀr/σ² − P(θ − θ₀).
+        let mut h = [[precision[0], 0.0], [0.0, precision[1]]];
+        let mut g = [
+            -precision[0] * (theta[0] - prior[0]),
+            -precision[1] * (theta[1] - prior[1]),
+        ];
+        for session in &sessions {
+            // Per Set: observed log-load, modelled log-fraction and its
+            // gradient in (rpe_step, ln rep_decay).
+            let rows: Vec<[f64; 4]> = session
+                .iter()
+                .map(|set| {
+                    let reserve = 10.0 - set.rpe;
+                    let rpe_factor = 1.0 - theta[0] * reserve;
+                    let extra_reps = set.reps as f64 - 1.0;
+                    [
+                        set.load.ln(),
+                        rpe_factor.ln() + extra_reps * theta[1],
+                        -reserve / rpe_factor,
+                        extra_reps,
+                    ]
+                })
+                .collect();
+            let n = rows.len() as f64;
+            let mut mean = [0.0; 4];
+            for row in &rows {
+                for (m, v) in mean.iter_mut().zip(row) {
+                    *m += v / n;
+                }
+            }
+            for row in &rows {
+                let residual = (row[0] - mean[0]) - (row[1] - mean[1]);
+                let j = [row[2] - mean[2], row[3] - mean[3]];
+                for a in 0..2 {
+                    g[a] += noise * j[a] * residual;
+                    for b in 0..2 {
+                        h[a][b] += noise * j[a] * j[b];
+                    }
+                }
+            }
+        }
+
+        // The prior keeps `h` positive definite, so the 2×2 solve is safe.
+        let det = h[0][0] * h[1][1] - h[0][1] * h[1][0];
+        let delta = [
+            (h[1][1] * g[0] - h[0][1] * g[1]) / det,
+            (h[0][0] * g[1] - h[1][0] * g[0]) / det,
+        ];
+        theta = [
+            (theta[0] + delta[0]).clamp(RPE_STEP_RANGE.0, RPE_STEP_RANGE.1),
+            (theta[1] + delta[1]).clamp(REP_DECAY_RANGE.0.ln(), REP_DECAY_RANGE.1.ln()),
+        ];
+        if delta[0].abs() < 1e-12 && delta[1].abs() < 1e-12 {
+            break;
+        }
+    }
+
+    RpeCalibration {
+        rpe_step: theta[0],
+        rep_decay: theta[1].exp(),
+        sets,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::e1rm::E1rmModel;
+
+    /// Sessions of four Sets each at `e1rm`s around 150, loaded exactly as
+    /// `truth` predicts.
+    fn sessions_from(truth: RpeCalibration, count: usize) -> Vec<Vec<CalibrationSet>> {
+        let prescriptions = [(3, 9.0), (5, 8.0), (8, 7.5), (10, 9.5)];
+        (0..count)
+            .map(|i| {
+                let e1rm = 150.0 + i as f64;
+                prescriptions
+                    .iter()
+                    .map(|&(reps, rpe)| CalibrationSet {
+                        load: truth.predicted_weight(e1rm, reps, rpe),
+                        reps,
+                        rpe,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_no_sessions_gives_the_population_curve() {
+        assert_eq!(fit(&[]), RpeCalibration::POPULATION);
+    }
+
+    #[test]
+    fn test_single_set_sessions_are_skipped() {
+        let lone = vec![CalibrationSet {
+            load: 100.0,
+            reps: 5,
+            rpe: 8.0,
+        }];
+        assert_eq!(fit(&[lone.clone(), lone]), RpeCalibration::POPULATION);
+    }
+
+    #[test]
+    fn test_zero_load_sets_are_dropped() {
+        let mut sessions = sessions_from(RpeCalibration::POPULATION, 3);
+        let clean = fit(&sessions);
+        for session in &mut sessions {
+            session.push(CalibrationSet {
+                load: 0.0,
+                reps: 5,
+                rpe: 8.0,
+            });
+        }
+        let fitted = fit(&sessions);
+        assert!(fitted.rpe_step.is_finite() && fitted.rep_decay.is_finite());
+        assert_eq!(fitted, clean);
+
+        let empty_bar = vec![
+            CalibrationSet {
+                load: 0.0,
+                reps: 5,
+                rpe: 8.0,
+            },
+            CalibrationSet {
+                load: 100.0,
+                reps: 5,
+                rpe: 9.0,
+            },
+        ];
+        assert_eq!(fit(&[empty_bar]), RpeCalibration::POPULATION);
+    }
+
+    #[test]
+    fn test_recovers_the_curve_from_plenty_of_data() {
+        let truth = RpeCalibration {
+            rpe_step: 0.045,
+            rep_decay: 0.955,
+            sets: 0,
+        };
+        let fitted = fit(&sessions_from(truth, 30));
+        assert_eq!(fitted.sets, 120);
+        // The prior still pulls a little towards the population curve.
+        assert!(
+            (fitted.rpe_step - truth.rpe_step).abs() < 0.002,
+            "{fitted:?}"
+        );
+        assert!(
+            (fitted.rep_decay - truth.rep_decay).abs() < 0.001,
+            "{fitted:?}"
+        );
+    }
+
+    #[test]
+    fn test_a_lifter_who_grinds_more_reps_gets_a_flatter_rep_curve() {
+        let grinder = RpeCalibration {
+            rep_decay: 0.985,
+            ..RpeCalibration::POPULATION
+        };
+        let fitted = fit(&sessions_from(grinder, 10));
+        assert!(fitted.rep_decay > RpeCalibration::POPULATION.rep_decay);
+        assert!(fitted.predicted_weight(150.0, 8, 8.0) > 150.0 * 0.7);
+    }
+
+    #[test]
+    fn test_sparse_data_stays_near_the_population_curve() {
+        let truth = RpeCalibration {
+            rpe_step: 0.06,
+            rep_decay: 0.93,
+            sets: 0,
+        };
+        let sparse = fit(&sessions_from(truth, 1));
+        let plenty = fit(&sessions_from(truth, 30));
+        let population = RpeCalibration::POPULATION;
+        // One session moves the fit towards the truth, but less than thirty.
+        assert!(sparse.rep_decay < population.rep_decay);
+        assert!(
+            (sparse.rep_decay - population.rep_decay).abs()
+                < (plenty.rep_decay - population.rep_decay).abs()
+        );
+    }
+
+    #[test]
+    fn test_fit_stays_within_bounds() {
+        let extreme = RpeCalibration {
+            rpe_step: 0.2,
+            rep_decay: 0.5,
+            sets: 0,
+        };
+        let sessions: Vec<Vec<CalibrationSet>> = (0..30)
+            .map(|_| {
+                [(1, 10.0), (10, 6.0)]
+                    .iter()
+                    .map(|&(reps, rpe)| CalibrationSet {
+                        load: 100.0
+                            * (1.0 - extreme.rpe_step * (10.0 - rpe)).max(0.05)
+                            * extreme.rep_decay.powi(reps as i32 - 1),
+                        reps,
+                        rpe,
+                    })
+                    .collect()
+            })
+            .collect();
+        let fitted = fit(&sessions);
+        assert!(fitted.rpe_step >= RPE_STEP_RANGE.0 && fitted.rpe_step <= RPE_STEP_RANGE.1);
+        assert!(fitted.rep_decay >= REP_DECAY_RANGE.0 && fitted.rep_decay <= REP_DECAY_RANGE.1);
+        assert!(fitted.rep_decay.is_finite() && fitted.rpe_step.is_finite());
+    }
+}
@@ -0,0 +1,367 @@
+//! Pure fatigue rules behind the deload advisor.
+//!
+//! Three independent signals are combined: lifts whose e1RM trend has
+//! stalled, RPE creeping up at an unchanged load, and weekly muscle-group
+//! volume running above the trainee's own baseline. A deload week is
+//! recommended when two different kinds of signal agree, or when enough lifts
+//! have stalled on their own.
+
+use crate::models::{MuscleGroup, MuscleGroupVolume, PlanExercise, SlotTargets, WeightUnit};
+
+/// Stalled lifts that recommend a deload without any other signal.
+pub const STALLED_LIFTS_ALONE: usize = 3;
+
+/// RPE rise at the same load, first to latest session, that counts as creep.
+pub const RPE_CREEP_THRESHOLD: f64 = 1.0;
+
+/// Sessions at one load needed before RPE creep is judged.
+pub const MIN_CREEP_SESSIONS: usize = 3;
+
+/// Weeks of sets examined for RPE creep.
+pub const RPE_CREEP_WINDOW_WEEKS: u32 = 4;
+
+/// Last-7-day volume over the weekly baseline that counts as a spike.
+pub const VOLUME_SPIKE_RATIO: f64 = 1.3;
+
+/// Weeks of history before the last 7 days needed to judge a volume spike.
+pub const MIN_BASELINE_WEEKS: f64 = 2.0;
+
+/// How far a deload drops the target RPE.
+pub const DELOAD_RPE_DROP: f64 = 2.0;
+
+/// A working set reduced to what RPE creep looks at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoadRpe {
+    /// Unix ms the set was logged.
+    pub recorded_at: f64,
+    /// Load in kg.
+    pub load: f64,
+    pub rpe: f64,
+}
+
+/// One reason to consider a deload.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FatigueSignal {
+    /// Lifts whose e1RM regression slope is flat or falling.
+    StalledLifts { names: Vec<String> },
+    /// The same load feels harder: average RPE rose by `rise`.
+    RpeCreep { name: String, load: f64, rise: f64 },
+    /// Last-7-day volume is `ratio` times the weekly baseline.
+    VolumeAboveBaseline { group: MuscleGroup, ratio: f64 },
+}
+
+impl FatigueSignal {
+    /// One-line reason shown by the advisor, loads in `unit`.
+    pub fn describe(&self, unit: WeightUnit) -> String {
+        match self {
+            FatigueSignal::StalledLifts { names } => {
+                format!("Stalled e1RM: {}", names.join(", "))
+            }
+            FatigueSignal::RpeCreep { name, load, rise } => format!(
+                "{} at {:.1} {} feels harder (+{:.1} RPE)",
+                name,
+                unit.from_kg(*load),
+                unit,
+                rise
+            ),
+            FatigueSignal::VolumeAboveBaseline { group, ratio } => format!(
+                "{} volume {:.0}% above your weekly baseline",
+                group,
+                (ratio - 1.0) * 100.0
+            ),
+        }
+    }
+
+    fn kind(&self) -> u8 {
+        match self {
+            FatigueSignal::StalledLifts { .. } => 0,
+            FatigueSignal::RpeCreep { .. } => 1,
+            FatigueSignal::VolumeAboveBaseline { .. } => 2,
+        }
+    }
+}
+
+/// The advisor's verdict and the signals behind it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeloadAdvice {
+    pub signals: Vec<FatigueSignal>,
+    pub recommend: bool,
+}
+
+/// Rise in average RPE at the most-repeated load, from the first to the
+/// latest session that used it; `None` without [`MIN_CREEP_SESSIONS`]
+/// sessions at any single load. Sessions are UTC days.
+pub fn rpe_creep(sets: &[LoadRpe]) -> Option<(f64, f64)> {
+    // load (in grams, to key on exact loads) → day → (rpe sum, count)
+    let mut by_load: std::collections::BTreeMap<i64, std::collections::BTreeMap<i64, (f64, u32)>> =
+        std::collections::BTreeMap::new();
+    for set in sets {
+        let day = (set.recorded_at / 86_400_000.0).floor() as i64;
+        let entry = by_load
+            .entry((set.load * 1000.0).round() as i64)
+            .or_default()
+            .entry(day)
+            .or_insert((0.0, 0));
+        entry.0 += set.rpe;
+        entry.1 += 1;
+    }
+
+    by_load
+        .into_iter()
+        .filter(|(_, days)| days.len() >= MIN_CREEP_SESSIONS)
+        .max_by_key(|(load, days)| (days.len(), *load))
+        .map(|(load, days)| {
+            let mean = |(sum, n): &(f64, u32)| sum / *n as f64;
+            let first = days.values().next().map(mean).unwrap_or(0.0);
+            let last = days.values().next_back().map(mean).unwrap_or(0.0);
+            (load as f64 / 1000.0, last - first)
+        })
+}
+
+/// Last-7-day volume relative to the average earlier week of the training
+/// window.  The baseline leaves out the last 7 days and only spans the weeks
+/// since `first_set_at` (Unix ms, the earliest logged set); `None` with fewer
+/// than [`MIN_BASELINE_WEEKS`] of them or no baseline volume.
+pub fn volume_ratio(
+    volume: &MuscleGroupVolume,
+    training_window_weeks: u32,
+    first_set_at: Option<f64>,
+    now: f64,
+) -> Option<f64> {
+    const WEEK_MS: f64 = 7.0 * 86_400_000.0;
+    let window_start = now - training_window_weeks as f64 * WEEK_MS;
+    let baseline_weeks = (now - WEEK_MS - first_set_at?.max(window_start)) / WEEK_MS;
+    if baseline_weeks < MIN_BASELINE_WEEKS {
+        return None;
+    }
+    let baseline = (volume.rolling_training_period - volume.rolling_7d) / baseline_weeks;
+    (baseline > 0.0).then(|| volume.rolling_7d / baseline)
+}
+
+/// Combines the per-exercise and per-muscle inputs into advice.
+///
+/// `stalled` names the lifts whose progress state is Stalled; `creep` pairs
+/// an exercise name with its [`rpe_creep`] result; `volumes` pairs a muscle
+/// group with its [`volume_ratio`].
+pub fn assess(
+    stalled: &[String],
+    creep: &[(String, Option<(f64, f64)>)],
+    volumes: &[(MuscleGroup, Option<f64>)],
+) -> DeloadAdvice {
+    let mut signals = Vec::new();
+    if !stalled.is_empty() {
+        signals.push(FatigueSignal::StalledLifts {
+            names: stalled.to_vec(),
+        });
+    }
+    for (name, result) in creep {
+        if let Some((load, rise)) = *result
+            && rise >= RPE_CREEP_THRESHOLD
+        {
+            signals.push(FatigueSignal::RpeCreep {
+                name: name.clone(),
+                load,
+                rise,
+            });
+        }
+    }
+    for (group, ratio) in volumes {
+        if let Some(ratio) = *ratio
+            && ratio >= VOLUME_SPIKE_RATIO
+        {
+            signals.push(FatigueSignal::VolumeAboveBaseline {
+                group: group.clone(),
+                ratio,
+            });
+        }
+    }
+
+    let mut kinds: Vec<u8> = signals.iter().map(FatigueSignal::kind).collect();
+    kinds.dedup();
+    let recommend = kinds.len() >= 2 || stalled.len() >= STALLED_LIFTS_ALONE;
+    DeloadAdvice { signals, recommend }
+}
+
+/// Planned sets and Slot Targets for `slot` in a deload: half the sets,
+/// rounded up, at [`DELOAD_RPE_DROP`] below `base_rpe`.
+pub fn deload_slot(slot: &PlanExercise, base_rpe: f64) -> (u32, SlotTargets) {
+    let sets = slot.planned_sets.div_ceil(2).max(1);
+    let targets = SlotTargets {
+        target_rpe: Some((base_rpe - DELOAD_RPE_DROP).max(1.0)),
+        ..slot.targets
+    };
+    (sets, targets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseMetadata, SetTypeConfig};
+
+    const DAY: f64 = 86_400_000.0;
+
+    fn set(day: u32, load: f64, rpe: f64) -> LoadRpe {
+        LoadRpe {
+            recorded_at: day as f64 * DAY + 3_600_000.0,
+            load,
+            rpe,
+        }
+    }
+
+    #[test]
+    fn test_rpe_creep_at_the_most_repeated_load() {
+        let sets = [
+            set(0, 100.0, 7.0),
+            set(0, 100.0, 7.0),
+            set(3, 100.0, 7.5),
+            set(3, 105.0, 9.0),
+            set(7, 100.0, 8.0),
+            set(7, 100.0, 8.5),
+        ];
+        let (load, rise) = rpe_creep(&sets).unwrap();
+        assert_eq!(load, 100.0);
+        assert!((rise - 1.25).abs() < 1e-9, "rise {rise}");
+    }
+
+    #[test]
+    fn test_rpe_creep_needs_enough_sessions() {
+        let sets = [set(0, 100.0, 7.0), set(3, 100.0, 9.0), set(5, 102.5, 9.0)];
+        assert_eq!(rpe_creep(&sets), None);
+        assert_eq!(rpe_creep(&[]), None);
+    }
+
+    #[test]
+    fn test_volume_ratio_against_weekly_baseline() {
+        let now = 1_000.0 * DAY;
+        let long_ago = Some(0.0);
+        // 44 sets over the 11 weeks before the last 7 days.
+        let volume = MuscleGroupVolume {
+            daily: 0.0,
+            rolling_7d: 6.0,
+            rolling_training_period: 50.0,
+        };
+        assert_eq!(volume_ratio(&volume, 12, long_ago, now), Some(1.5));
+        let only_this_week = MuscleGroupVolume {
+            daily: 0.0,
+            rolling_7d: 6.0,
+            rolling_training_period: 6.0,
+        };
+        assert_eq!(volume_ratio(&only_this_week, 12, long_ago, now), None);
+        assert_eq!(volume_ratio(&volume, 0, long_ago, now), None);
+        assert_eq!(volume_ratio(&volume, 12, None, now), None);
+    }
+
+    #[test]
+    fn test_volume_ratio_baseline_spans_only_the_history_there_is() {
+        let now = 1_000.0 * DAY;
+        // Three weeks of history: two baseline weeks of 4 sets, then 6.
+        let volume = MuscleGroupVolume {
+            daily: 0.0,
+            rolling_7d: 6.0,
+            rolling_training_period: 14.0,
+        };
+        assert_eq!(
+            volume_ratio(&volume, 12, Some(now - 21.0 * DAY), now),
+            Some(1.5)
+        );
+        // Ten days of history is too little to have a baseline.
+        let short = MuscleGroupVolume {
+            daily: 0.0,
+            rolling_7d: 6.0,
+            rolling_training_period: 8.0,
+        };
+        assert_eq!(volume_ratio(&short, 12, Some(now - 10.0 * DAY), now), None);
+    }
+
+    #[test]
+    fn test_single_signal_kind_does_not_recommend() {
+        let advice = assess(
+            &["Squat".to_string()],
+            &[("Bench".to_string(), Some((80.0, 0.5)))],
+            &[(MuscleGroup::Quads, Some(1.1))],
+        );
+        assert_eq!(advice.signals.len(), 1);
+        assert!(!advice.recommend);
+    }
+
+    #[test]
+    fn test_two_signal_kinds_recommend() {
+        let advice = assess(
+            &["Squat".to_string()],
+            &[("Bench".to_string(), Some((80.0, 1.5)))],
+            &[],
+        );
+        assert!(advice.recommend);
+        assert_eq!(
+            advice.signals[1],
+            FatigueSignal::RpeCreep {
+                name: "Bench".to_string(),
+                load: 80.0,
+                rise: 1.5
+            }
+        );
+
+        let advice = assess(&[], &[], &[(MuscleGroup::Back, Some(1.4))]);
+        assert!(!advice.recommend, "a volume spike alone is not enough");
+    }
+
+    #[test]
+    fn test_describe_signals() {
+        let creep = FatigueSignal::RpeCreep {
+            name: "Bench".to_string(),
+            load: 80.0,
+            rise: 1.5,
+        };
+        assert_eq!(
+            creep.describe(WeightUnit::Kg),
+            "Bench at 80.0 kg feels harder (+1.5 RPE)"
+        );
+        let spike = FatigueSignal::VolumeAboveBaseline {
+            group: MuscleGroup::Back,
+            ratio: 1.4,
+        };
+        assert_eq!(
+            spike.describe(WeightUnit::Kg),
+            "Back volume 40% above your weekly baseline"
+        );
+    }
+
+    #[test]
+    fn test_many_stalled_lifts_recommend_alone() {
+        let stalled: Vec<String> = ["Squat", "Bench", "Deadlift"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert!(assess(&stalled, &[], &[]).recommend);
+    }
+
+    #[test]
+    fn test_deload_slot_halves_sets_and_drops_rpe() {
+        let slot = PlanExercise {
+            id: "s".to_string(),
+            exercise: ExerciseMetadata {
+                id: None,
+                name: "Squat".to_string(),
+                set_type_config: SetTypeConfig::Bodyweight,
+                min_reps: 1,
+                max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            },
+            planned_sets: 5,
+            position: 0,
+            group_id: None,
+            targets: SlotTargets {
+                rep_range: Some((3, Some(5))),
+                target_rpe: None,
+            },
+        };
+        let (sets, targets) = deload_slot(&slot, 8.0);
+        assert_eq!(sets, 3);
+        assert_eq!(targets.target_rpe, Some(6.0));
+        assert_eq!(targets.rep_range, Some((3, Some(5))));
+    }
+}
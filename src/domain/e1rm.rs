@@ -1,25 +1,145 @@
 //! Pure e1RM (estimated one-rep max) calculation functions.
 //!
-//! Ported from the Flutter `strength_assistant` reference implementation.
+//! Each [`E1rmModel`] maps a rep count and RPE to the fraction of 1RM they
+//! allow; e1RM estimates and predicted weights both go through it, so one
+//! model serves the Suggestion, the Historical Max at R comparison and the
+//! e1RM Trend. [`E1rmFormula`] picks one of the built-in models; an
+//! [`RpeCalibration`] is the Linear RPE curve refitted to the trainee.
 //! No database dependencies or side effects — pure math only.
 
+use crate::models::{E1rmFormula, RpeCalibration};
 use chrono::NaiveDate;
 
-/// Calculates the assumption factor for a given rep count and RPE.
-///
-/// This represents the fraction of 1RM that can be lifted for the given reps at the given RPE.
-pub fn assumption(rep: u32, rpe: f64) -> f64 {
-    (rpe * 0.03269803 + 0.6730197) * 0.970546521_f64.powi(rep as i32 - 1)
+/// Fraction-of-1RM model behind e1RM estimates and weight predictions.
+pub trait E1rmModel {
+    /// Fraction of 1RM that can be lifted for `reps` at `rpe`.
+    fn assumption(&self, reps: u32, rpe: f64) -> f64;
+
+    /// Estimates the one-rep max from a completed set.
+    fn e1rm(&self, weight: f64, reps: u32, rpe: f64) -> f64 {
+        weight / self.assumption(reps, rpe)
+    }
+
+    /// Predicts the weight achievable for target reps and RPE given an e1RM.
+    fn predicted_weight(&self, e1rm: f64, target_reps: u32, target_rpe: f64) -> f64 {
+        e1rm * self.assumption(target_reps, target_rpe)
+    }
+}
+
+/// Reps to failure: the reps done plus the reps in reserve implied by `rpe`.
+fn reps_to_failure(reps: u32, rpe: f64) -> f64 {
+    reps as f64 + (10.0 - rpe)
+}
+
+/// RPE-linear, rep-exponential model ported from the Flutter
+/// `strength_assistant` reference implementation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LinearRpe;
+
+impl E1rmModel for LinearRpe {
+    fn assumption(&self, reps: u32, rpe: f64) -> f64 {
+        (rpe * 0.03269803 + 0.6730197) * 0.970546521_f64.powi(reps as i32 - 1)
+    }
+}
+
+/// Epley on reps to failure; a single to failure is 100%.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Epley;
+
+impl E1rmModel for Epley {
+    fn assumption(&self, reps: u32, rpe: f64) -> f64 {
+        let r = reps_to_failure(reps, rpe);
+        if r <= 1.0 {
+            1.0
+        } else {
+            1.0 / (1.0 + r / 30.0)
+        }
+    }
+}
+
+/// Brzycki on reps to failure, capped where the formula breaks down.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Brzycki;
+
+impl E1rmModel for Brzycki {
+    fn assumption(&self, reps: u32, rpe: f64) -> f64 {
+        let r = reps_to_failure(reps, rpe).clamp(1.0, 36.0);
+        (37.0 - r) / 36.0
+    }
+}
+
+/// The RTS RPE chart, indexed by reps to failure in half-rep steps.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RpeChart;
+
+/// Percent of 1RM for 1, 1.5, 2, … 16 reps to failure (1 @ 10 up to 12 @ 6).
+const RPE_CHART: [f64; 31] = [
+    100.0, 97.8, 95.5, 93.9, 92.2, 90.7, 89.2, 87.8, 86.3, 85.0, 83.7, 82.4, 81.1, 79.9, 78.6,
+    77.4, 76.2, 75.1, 73.9, 72.3, 70.7, 69.4, 68.0, 66.7, 65.3, 64.0, 62.6, 61.3, 59.9, 58.6, 57.4,
+];
+
+impl E1rmModel for RpeChart {
+    /// Interpolates between chart entries; past the chart the last step
+    /// keeps going, floored so predictions stay positive.
+    fn assumption(&self, reps: u32, rpe: f64) -> f64 {
+        let index = ((reps_to_failure(reps, rpe) - 1.0) * 2.0).max(0.0);
+        let last = RPE_CHART.len() - 1;
+        let percent = if index >= last as f64 {
+            let step = RPE_CHART[last - 1] - RPE_CHART[last];
+            (RPE_CHART[last] - step * (index - last as f64)).max(20.0)
+        } else {
+            let i = index.floor() as usize;
+            let t = index - i as f64;
+            RPE_CHART[i] + (RPE_CHART[i + 1] - RPE_CHART[i]) * t
+        };
+        percent / 100.0
+    }
+}
+
+impl E1rmModel for E1rmFormula {
+    fn assumption(&self, reps: u32, rpe: f64) -> f64 {
+        match self {
+            E1rmFormula::LinearRpe => LinearRpe.assumption(reps, rpe),
+            E1rmFormula::Epley => Epley.assumption(reps, rpe),
+            E1rmFormula::Brzycki => Brzycki.assumption(reps, rpe),
+            E1rmFormula::RpeChart => RpeChart.assumption(reps, rpe),
+        }
+    }
 }
 
-/// Estimates the one-rep max from a completed set.
-pub fn e1rm(weight: f64, reps: u32, rpe: f64) -> f64 {
-    weight / assumption(reps, rpe)
+/// The Linear RPE curve with the trainee's own RPE and rep steps.
+impl E1rmModel for RpeCalibration {
+    fn assumption(&self, reps: u32, rpe: f64) -> f64 {
+        (1.0 - self.rpe_step * (10.0 - rpe)) * self.rep_decay.powi(reps as i32 - 1)
+    }
+}
+
+/// The model a Suggestion projects with: the e1RM Formula, or a fitted
+/// [`RpeCalibration`] once the trainee opts in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SuggestionModel {
+    Formula(E1rmFormula),
+    Calibrated(RpeCalibration),
+}
+
+impl SuggestionModel {
+    /// Uses `calibration` when there is one and the trainee opted in, and
+    /// falls back to `formula` otherwise.
+    pub fn new(formula: E1rmFormula, calibration: Option<RpeCalibration>, opted_in: bool) -> Self {
+        match calibration {
+            Some(calibration) if opted_in => SuggestionModel::Calibrated(calibration),
+            _ => SuggestionModel::Formula(formula),
+        }
+    }
 }
 
-/// Predicts the weight achievable for target reps and RPE given an e1RM.
-pub fn predicted_weight(e1rm: f64, target_reps: u32, target_rpe: f64) -> f64 {
-    e1rm * assumption(target_reps, target_rpe)
+impl E1rmModel for SuggestionModel {
+    fn assumption(&self, reps: u32, rpe: f64) -> f64 {
+        match self {
+            SuggestionModel::Formula(formula) => formula.assumption(reps, rpe),
+            SuggestionModel::Calibrated(calibration) => calibration.assumption(reps, rpe),
+        }
+    }
 }
 
 /// Computes the least-squares linear regression slope over a series of (date, e1RM) pairs.
@@ -29,8 +149,14 @@ pub fn predicted_weight(e1rm: f64, target_reps: u32, target_rpe: f64) -> f64 {
 ///   numerical stability; the slope is returned in e1RM units per day.
 /// - Returns `0.0` for empty or single-element input (slope undefined).
 pub fn e1rm_trend(sessions: &[(NaiveDate, f64)]) -> f64 {
+    e1rm_fit(sessions).map_or(0.0, |(slope, _)| slope)
+}
+
+/// The regression line behind [`e1rm_trend`]: `(slope per day, e1RM at the
+/// first session's date)`. `None` when the slope is undefined.
+pub fn e1rm_fit(sessions: &[(NaiveDate, f64)]) -> Option<(f64, f64)> {
     if sessions.len() < 2 {
-        return 0.0;
+        return None;
     }
 
     let origin = sessions[0].0;
@@ -46,10 +172,11 @@ pub fn e1rm_trend(sessions: &[(NaiveDate, f64)]) -> f64 {
 
     let denom = n * sum_xx - sum_x * sum_x;
     if denom == 0.0 {
-        return 0.0;
+        return None;
     }
 
-    (n * sum_xy - sum_x * sum_y) / denom
+    let slope = (n * sum_xy - sum_x * sum_y) / denom;
+    Some((slope, (sum_y - slope * sum_x) / n))
 }
 
 /// Blends today's e1RM estimate with a historical average.
@@ -68,12 +195,12 @@ mod tests {
 
     const EPSILON: f64 = 1e-6;
 
-    // --- assumption() tests ---
+    // --- LinearRpe::assumption() tests ---
 
     #[test]
     fn assumption_known_inputs_rep5_rpe8() {
         // rep=5, rpe=8: (8 * 0.03269803 + 0.6730197) * 0.970546521^4
-        let result = assumption(5, 8.0);
+        let result = LinearRpe.assumption(5, 8.0);
         let expected = (8.0 * 0.03269803 + 0.6730197) * 0.970546521_f64.powi(4);
         assert!(
             (result - expected).abs() < EPSILON,
@@ -84,25 +211,25 @@ mod tests {
     #[test]
     fn assumption_rep1_rpe10() {
         // rep=1: exponent is 0, so result = rpe * 0.03269803 + 0.6730197
-        let result = assumption(1, 10.0);
+        let result = LinearRpe.assumption(1, 10.0);
         let expected = 10.0 * 0.03269803 + 0.6730197;
         assert!((result - expected).abs() < EPSILON);
     }
 
     #[test]
     fn assumption_rep1_returns_no_nan() {
-        let result = assumption(1, 10.0);
+        let result = LinearRpe.assumption(1, 10.0);
         assert!(!result.is_nan());
         assert!(result > 0.0);
     }
 
-    // --- e1rm() tests ---
+    // --- LinearRpe::e1rm() tests ---
 
     #[test]
     fn e1rm_known_values() {
         // 100kg for 5 reps at RPE 8
-        let a = assumption(5, 8.0);
-        let result = e1rm(100.0, 5, 8.0);
+        let a = LinearRpe.assumption(5, 8.0);
+        let result = LinearRpe.e1rm(100.0, 5, 8.0);
         let expected = 100.0 / a;
         assert!((result - expected).abs() < EPSILON);
     }
@@ -110,8 +237,8 @@ mod tests {
     #[test]
     fn e1rm_rep1_rpe10() {
         // At 1 rep RPE 10, e1RM should be close to the actual weight
-        let result = e1rm(200.0, 1, 10.0);
-        let a = assumption(1, 10.0);
+        let result = LinearRpe.e1rm(200.0, 1, 10.0);
+        let a = LinearRpe.assumption(1, 10.0);
         let expected = 200.0 / a;
         assert!((result - expected).abs() < EPSILON);
         // Should be very close to 200 (assumption at rep=1 rpe=10 is close to 1.0)
@@ -121,7 +248,7 @@ mod tests {
         );
     }
 
-    // --- predicted_weight() tests ---
+    // --- LinearRpe::predicted_weight() tests ---
 
     #[test]
     fn predicted_weight_roundtrip() {
@@ -129,8 +256,8 @@ mod tests {
         let weight = 140.0;
         let reps = 3;
         let rpe = 9.0;
-        let estimated = e1rm(weight, reps, rpe);
-        let predicted = predicted_weight(estimated, reps, rpe);
+        let estimated = LinearRpe.e1rm(weight, reps, rpe);
+        let predicted = LinearRpe.predicted_weight(estimated, reps, rpe);
         assert!(
             (predicted - weight).abs() < EPSILON,
             "roundtrip failed: predicted={predicted}, original={weight}"
@@ -140,11 +267,100 @@ mod tests {
     #[test]
     fn predicted_weight_known_values() {
         let one_rm = 150.0;
-        let result = predicted_weight(one_rm, 5, 8.0);
-        let expected = one_rm * assumption(5, 8.0);
+        let result = LinearRpe.predicted_weight(one_rm, 5, 8.0);
+        let expected = one_rm * LinearRpe.assumption(5, 8.0);
         assert!((result - expected).abs() < EPSILON);
     }
 
+    // --- alternative models ---
+
+    #[test]
+    fn every_model_is_100_percent_for_a_single_at_rpe_10() {
+        for formula in E1rmFormula::ALL {
+            if formula == E1rmFormula::LinearRpe {
+                continue; // the reference fit is only ≈ 1.0 there
+            }
+            assert!(
+                (formula.assumption(1, 10.0) - 1.0).abs() < EPSILON,
+                "{formula:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn epley_and_brzycki_known_values() {
+        // 5 reps at RPE 10: Epley 1 + 5/30, Brzycki 36/32.
+        assert!((Epley.e1rm(100.0, 5, 10.0) - 100.0 * (1.0 + 5.0 / 30.0)).abs() < EPSILON);
+        assert!((Brzycki.e1rm(100.0, 5, 10.0) - 112.5).abs() < EPSILON);
+        // RPE 8 leaves two reps in reserve: same as 7 reps to failure.
+        assert!((Epley.assumption(5, 8.0) - Epley.assumption(7, 10.0)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn rpe_chart_reads_and_interpolates_the_table() {
+        assert!((RpeChart.assumption(5, 8.0) - 0.811).abs() < EPSILON);
+        assert!((RpeChart.assumption(3, 9.5) - 0.907).abs() < EPSILON);
+        // Halfway between 3 @ 8.5 (0.878) and 3 @ 8 (0.863).
+        assert!((RpeChart.assumption(3, 8.25) - 0.8705).abs() < EPSILON);
+        // Beyond the chart keeps decreasing but stays positive.
+        let far = RpeChart.assumption(30, 6.0);
+        assert!(far < RpeChart.assumption(12, 6.0) && far > 0.0);
+    }
+
+    #[test]
+    fn every_model_round_trips_e1rm_and_predicted_weight() {
+        for formula in E1rmFormula::ALL {
+            let estimated = formula.e1rm(140.0, 3, 9.0);
+            let predicted = formula.predicted_weight(estimated, 3, 9.0);
+            assert!((predicted - 140.0).abs() < EPSILON, "{formula:?}");
+            // More reps at the same RPE always predict less weight.
+            assert!(formula.predicted_weight(estimated, 8, 9.0) < predicted);
+        }
+    }
+
+    #[test]
+    fn population_calibration_matches_linear_rpe() {
+        for (reps, rpe) in [(1, 10.0), (5, 8.0), (3, 9.5), (12, 6.0)] {
+            assert!(
+                (RpeCalibration::POPULATION.assumption(reps, rpe)
+                    - LinearRpe.assumption(reps, rpe))
+                .abs()
+                    < EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn suggestion_model_uses_calibration_only_when_opted_in() {
+        let fitted = RpeCalibration {
+            rpe_step: 0.04,
+            rep_decay: 0.96,
+            sets: 20,
+        };
+        assert_eq!(
+            SuggestionModel::new(E1rmFormula::Epley, Some(fitted), true),
+            SuggestionModel::Calibrated(fitted)
+        );
+        assert_eq!(
+            SuggestionModel::new(E1rmFormula::Epley, Some(fitted), false),
+            SuggestionModel::Formula(E1rmFormula::Epley)
+        );
+        assert_eq!(
+            SuggestionModel::new(E1rmFormula::Epley, None, true),
+            SuggestionModel::Formula(E1rmFormula::Epley)
+        );
+        // A flatter rep curve predicts more weight for the same reps.
+        let e1rm = 150.0;
+        assert!(
+            SuggestionModel::Calibrated(RpeCalibration {
+                rep_decay: 0.98,
+                ..fitted
+            })
+            .predicted_weight(e1rm, 8, 8.0)
+                > SuggestionModel::Calibrated(fitted).predicted_weight(e1rm, 8, 8.0)
+        );
+    }
+
     // --- blended_e1rm() tests ---
 
     #[test]
@@ -263,4 +479,18 @@ mod tests {
         let slope = e1rm_trend(&sessions);
         assert!(slope.is_finite(), "slope should be finite, got {slope}");
     }
+
+    #[test]
+    fn e1rm_fit_intercept_is_value_at_first_date() {
+        // e1RM = 100 + 0.5 × day
+        let sessions = [
+            (d(2024, 1, 1), 100.0),
+            (d(2024, 1, 5), 102.0),
+            (d(2024, 1, 11), 105.0),
+        ];
+        let (slope, intercept) = e1rm_fit(&sessions).unwrap();
+        assert!((slope - 0.5).abs() < EPSILON, "slope {slope}");
+        assert!((intercept - 100.0).abs() < EPSILON, "intercept {intercept}");
+        assert_eq!(e1rm_fit(&sessions[..1]), None);
+    }
 }
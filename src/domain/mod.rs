@@ -1,2 +1,15 @@
+pub mod analysis;
+pub mod autoplan;
+pub mod bodyweight;
+pub mod calibration;
+pub mod deload;
 pub mod e1rm;
+pub mod plates;
+pub mod program;
+pub mod progression;
+pub mod records;
+pub mod reserve;
+pub mod rest;
 pub mod rpe;
+pub mod slot_group;
+pub mod warmup;
@@ -0,0 +1,207 @@
+//! Pure plate-math for plate-loaded exercises.
+//!
+//! Breaks a target barbell load into a per-side plate list using the user's
+//! plate inventory, snapping to the nearest buildable load when the exact
+//! weight cannot be made. All weights are canonical kilograms.
+
+use crate::models::Plate;
+
+/// Resolution of the subset-sum search: 1 unit = 10 g.
+const UNITS_PER_KG: f64 = 100.0;
+
+/// A run of identical plates on one side of the bar.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlateStack {
+    /// Weight of a single plate in kg
+    pub weight: f64,
+    /// Number of these plates on each side
+    pub count: u32,
+}
+
+/// A buildable barbell load: the bar plus identical plates on both sides.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlateLoad {
+    /// Total load in kg (bar + both sides)
+    pub total: f64,
+    /// Plates on one side, heaviest first
+    pub per_side: Vec<PlateStack>,
+}
+
+impl PlateLoad {
+    /// Returns true when this load matches `target_kg` to within 10 g.
+    pub fn is_exact(&self, target_kg: f64) -> bool {
+        (self.total - target_kg).abs() < 0.01
+    }
+}
+
+fn to_units(kg: f64) -> usize {
+    (kg * UNITS_PER_KG).round().max(0.0) as usize
+}
+
+/// Finds the load nearest to `target_kg` that can be built from `bar_kg` and
+/// the plates in `inventory`, considering only totals for which `accept`
+/// returns true.
+///
+/// Plates are loaded in pairs, so each inventory row contributes
+/// [`Plate::pairs`] plates per side. Ties between a lighter and a heavier
+/// load resolve to the lighter one. Returns `None` when no buildable load is
+/// accepted (including the bare bar).
+pub fn nearest_load(
+    target_kg: f64,
+    bar_kg: f64,
+    inventory: &[Plate],
+    accept: impl Fn(f64) -> bool,
+) -> Option<PlateLoad> {
+    // One entry per usable plate on a side, heaviest first so reconstruction
+    // prefers big plates.
+    let mut items: Vec<f64> = inventory
+        .iter()
+        .filter(|p| p.weight > 0.0)
+        .flat_map(|p| std::iter::repeat_n(p.weight as f64, p.pairs() as usize))
+        .collect();
+    items.sort_by(|a, b| b.total_cmp(a));
+
+    let max_side: usize = items.iter().map(|w| to_units(*w)).sum();
+
+    // parent[s] = (item index, previous side sum) for the first way `s` was
+    // reached; the root (empty side) has no parent.
+    let mut reachable = vec![false; max_side + 1];
+    let mut parent: Vec<Option<(usize, usize)>> = vec![None; max_side + 1];
+    reachable[0] = true;
+    for (i, w) in items.iter().enumerate() {
+        let w = to_units(*w);
+        for s in (w..=max_side).rev() {
+            if !reachable[s] && reachable[s - w] {
+                reachable[s] = true;
+                parent[s] = Some((i, s - w));
+            }
+        }
+    }
+
+    let mut best: Option<PlateLoad> = None;
+    for side in (0..=max_side).filter(|s| reachable[*s]) {
+        let mut plates = Vec::new();
+        let mut s = side;
+        while let Some((i, prev)) = parent[s] {
+            plates.push(items[i]);
+            s = prev;
+        }
+        let total = bar_kg + 2.0 * plates.iter().sum::<f64>();
+        if !accept(total) {
+            continue;
+        }
+        let better = match &best {
+            None => true,
+            Some(b) => {
+                let (d, bd) = ((total - target_kg).abs(), (b.total - target_kg).abs());
+                d < bd - 1e-9 || ((d - bd).abs() <= 1e-9 && total < b.total)
+            }
+        };
+        if better {
+            plates.sort_by(|a, b| b.total_cmp(a));
+            let mut per_side: Vec<PlateStack> = Vec::new();
+            for w in plates {
+                match per_side.last_mut() {
+                    Some(stack) if stack.weight == w => stack.count += 1,
+                    _ => per_side.push(PlateStack {
+                        weight: w,
+                        count: 1,
+                    }),
+                }
+            }
+            best = Some(PlateLoad { total, per_side });
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plate(weight: f32, count: u32) -> Plate {
+        Plate {
+            id: None,
+            weight,
+            count,
+        }
+    }
+
+    fn standard_kg() -> Vec<Plate> {
+        vec![
+            plate(25.0, 4),
+            plate(20.0, 2),
+            plate(10.0, 2),
+            plate(5.0, 2),
+            plate(2.5, 2),
+            plate(1.25, 2),
+        ]
+    }
+
+    #[test]
+    fn test_exact_load_uses_heaviest_plates() {
+        let load = nearest_load(100.0, 20.0, &standard_kg(), |_| true).unwrap();
+        assert!(load.is_exact(100.0));
+        assert_eq!(
+            load.per_side,
+            vec![
+                PlateStack {
+                    weight: 25.0,
+                    count: 1
+                },
+                PlateStack {
+                    weight: 10.0,
+                    count: 1
+                },
+                PlateStack {
+                    weight: 5.0,
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unbuildable_load_snaps_to_nearest() {
+        // 101 kg needs 40.5 per side; nearest buildable is 40.0 (100 kg).
+        let load = nearest_load(101.0, 20.0, &standard_kg(), |_| true).unwrap();
+        assert!(!load.is_exact(101.0));
+        assert!((load.total - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tie_resolves_to_lighter_load() {
+        let inventory = vec![plate(5.0, 2)];
+        // 25 kg is equidistant from 20 (bar) and 30 (bar + 5×2).
+        let load = nearest_load(25.0, 20.0, &inventory, |_| true).unwrap();
+        assert!((load.total - 20.0).abs() < 1e-9);
+        assert!(load.per_side.is_empty());
+    }
+
+    #[test]
+    fn test_inventory_limits_are_respected() {
+        // Only one pair of 20s: 140 kg is out of reach, max is 60 kg.
+        let inventory = vec![plate(20.0, 3)];
+        let load = nearest_load(140.0, 20.0, &inventory, |_| true).unwrap();
+        assert!((load.total - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_accept_filters_candidates() {
+        // Reject anything below 50 kg: the only option left is 60 kg.
+        let inventory = vec![plate(20.0, 2)];
+        let load = nearest_load(30.0, 20.0, &inventory, |w| w >= 50.0).unwrap();
+        assert!((load.total - 60.0).abs() < 1e-9);
+        assert!(nearest_load(30.0, 20.0, &inventory, |_| false).is_none());
+    }
+
+    #[test]
+    fn test_lb_plates_total_is_exact() {
+        // 45 lb plates on a 45 lb bar: 225 lb = bar + 2 pairs.
+        let lb = 0.453_592_37_f64;
+        let inventory = vec![plate((45.0 * lb) as f32, 4)];
+        let load = nearest_load(225.0 * lb, 45.0 * lb, &inventory, |_| true).unwrap();
+        assert!((load.total / lb - 225.0).abs() < 0.01);
+        assert_eq!(load.per_side[0].count, 2);
+    }
+}
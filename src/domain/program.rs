@@ -0,0 +1,212 @@
+//! Pure Program scheduling: which template day comes next and how the
+//! week's progression and deload reshape its Plan Slots.
+//!
+//! Workouts are numbered across the whole block — index `i` is day
+//! `i % days` of week `i / days + 1` — so the next workout follows from the
+//! highest index already started.
+
+use crate::domain::deload::deload_slot;
+use crate::models::{PlanExercise, Program, ProgressionRule, SlotTargets};
+
+/// Highest target RPE a progression can reach.
+const MAX_TARGET_RPE: f64 = 10.0;
+
+/// One scheduled workout of a Program.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgramWorkout {
+    /// 0-based index across the whole block.
+    pub index: u32,
+    /// 1-based week number.
+    pub week: u32,
+    /// 0-based day within the week's rotation.
+    pub day: usize,
+    pub deload: bool,
+}
+
+impl ProgramWorkout {
+    /// Display label such as `"Week 2 · Day B"`.
+    pub fn label(&self) -> String {
+        let deload = if self.deload { " (deload)" } else { "" };
+        format!(
+            "Week {} · Day {}{}",
+            self.week,
+            day_letter(self.day),
+            deload
+        )
+    }
+}
+
+/// Rotation letter of a day: `A`, `B`, `C`…
+pub fn day_letter(day: usize) -> char {
+    (b'A' + (day % 26) as u8) as char
+}
+
+/// Number of workouts in the whole block.
+pub fn total_workouts(program: &Program) -> u32 {
+    program.weeks * program.days.len() as u32
+}
+
+/// The workout at `index`, or `None` once the block is finished.
+pub fn workout_at(program: &Program, index: u32) -> Option<ProgramWorkout> {
+    if index >= total_workouts(program) {
+        return None;
+    }
+    let days = program.days.len() as u32;
+    let week = index / days + 1;
+    Some(ProgramWorkout {
+        index,
+        week,
+        day: (index % days) as usize,
+        deload: program.deload_week == Some(week),
+    })
+}
+
+/// The workout after the highest index already started (`None` when no
+/// workout of the program has been started yet).
+pub fn next_workout(program: &Program, last_started: Option<u32>) -> Option<ProgramWorkout> {
+    workout_at(program, last_started.map_or(0, |i| i + 1))
+}
+
+/// Planned sets and Slot Targets for `slot` in `workout`, after the week's
+/// progression or deload. `default_rpe` is the global target RPE.
+///
+/// A deload week is shaped by [`deload_slot`] from the week-1 target;
+/// progression does not apply.
+pub fn adjust_slot(
+    program: &Program,
+    workout: &ProgramWorkout,
+    slot: &PlanExercise,
+    default_rpe: f64,
+) -> (u32, SlotTargets) {
+    let weeks_in = workout.week.saturating_sub(1);
+    let base_rpe = match program.progression {
+        ProgressionRule::Rpe { start, .. } => slot.targets.target_rpe.unwrap_or(start),
+        _ => slot.targets.target_rpe_or(default_rpe),
+    };
+
+    if workout.deload {
+        return deload_slot(slot, base_rpe);
+    }
+
+    match program.progression {
+        ProgressionRule::None => (slot.planned_sets, slot.targets),
+        ProgressionRule::Rpe { step, .. } => {
+            let rpe = (base_rpe + step * weeks_in as f64).min(MAX_TARGET_RPE);
+            let targets = SlotTargets {
+                target_rpe: Some(rpe),
+                ..slot.targets
+            };
+            (slot.planned_sets, targets)
+        }
+        ProgressionRule::Volume { step } => (slot.planned_sets + step * weeks_in, slot.targets),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseMetadata, ProgramDay, SetTypeConfig};
+
+    fn program(progression: ProgressionRule) -> Program {
+        Program {
+            id: "p".to_string(),
+            name: "Block".to_string(),
+            weeks: 4,
+            deload_week: Some(4),
+            progression,
+            days: ["a", "b", "c"]
+                .iter()
+                .map(|t| ProgramDay {
+                    id: format!("day-{t}"),
+                    template_id: t.to_string(),
+                    template_name: t.to_uppercase(),
+                })
+                .collect(),
+        }
+    }
+
+    fn slot(planned_sets: u32, target_rpe: Option<f64>) -> PlanExercise {
+        PlanExercise {
+            id: "s".to_string(),
+            exercise: ExerciseMetadata {
+                id: None,
+                name: "Squat".to_string(),
+                set_type_config: SetTypeConfig::Bodyweight,
+                min_reps: 1,
+                max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            },
+            planned_sets,
+            position: 0,
+            group_id: None,
+            targets: SlotTargets {
+                rep_range: Some((3, Some(5))),
+                target_rpe,
+            },
+        }
+    }
+
+    #[test]
+    fn test_workouts_rotate_days_across_weeks() {
+        let p = program(ProgressionRule::None);
+        assert_eq!(total_workouts(&p), 12);
+        let first = next_workout(&p, None).unwrap();
+        assert_eq!((first.week, first.day), (1, 0));
+        let w = next_workout(&p, Some(3)).unwrap();
+        assert_eq!((w.index, w.week, w.day), (4, 2, 1));
+        assert_eq!(w.label(), "Week 2 · Day B");
+        let last = workout_at(&p, 11).unwrap();
+        assert!(last.deload);
+        assert_eq!(last.label(), "Week 4 · Day C (deload)");
+        assert_eq!(next_workout(&p, Some(11)), None, "block finished");
+    }
+
+    #[test]
+    fn test_rpe_progression_rises_weekly_and_caps() {
+        let p = program(ProgressionRule::Rpe {
+            start: 7.0,
+            step: 1.0,
+        });
+        let week3 = workout_at(&p, 6).unwrap();
+        let (sets, targets) = adjust_slot(&p, &week3, &slot(3, None), 8.0);
+        assert_eq!(sets, 3);
+        assert_eq!(targets.target_rpe, Some(9.0));
+        assert_eq!(targets.rep_range, Some((3, Some(5))), "rep range kept");
+
+        // A slot's own RPE is its starting point.
+        let (_, targets) = adjust_slot(&p, &week3, &slot(3, Some(9.5)), 8.0);
+        assert_eq!(targets.target_rpe, Some(10.0));
+    }
+
+    #[test]
+    fn test_volume_progression_adds_sets() {
+        let p = program(ProgressionRule::Volume { step: 1 });
+        let week1 = workout_at(&p, 0).unwrap();
+        let week3 = workout_at(&p, 7).unwrap();
+        assert_eq!(adjust_slot(&p, &week1, &slot(3, None), 8.0).0, 3);
+        let (sets, targets) = adjust_slot(&p, &week3, &slot(3, None), 8.0);
+        assert_eq!(sets, 5);
+        assert_eq!(targets.target_rpe, None);
+    }
+
+    #[test]
+    fn test_deload_halves_sets_and_drops_rpe() {
+        let p = program(ProgressionRule::Volume { step: 1 });
+        let deload = workout_at(&p, 9).unwrap();
+        let (sets, targets) = adjust_slot(&p, &deload, &slot(5, None), 8.0);
+        assert_eq!(sets, 3);
+        assert_eq!(targets.target_rpe, Some(6.0));
+
+        let p = program(ProgressionRule::Rpe {
+            start: 7.0,
+            step: 0.5,
+        });
+        let (sets, targets) = adjust_slot(&p, &deload, &slot(1, None), 8.0);
+        assert_eq!(sets, 1);
+        assert_eq!(targets.target_rpe, Some(5.0));
+    }
+}
@@ -0,0 +1,151 @@
+//! Rule-based Progression Strategies for weighted exercises.
+//!
+//! Linear and double progression look only at the working sets of the
+//! previous Training Day, taken as `(weight, reps)` pairs.  The top set
+//! weight is the one being progressed; lighter sets that day are ignored.
+//! Weights come back unsnapped, so callers snap them to the exercise's
+//! loading grid.
+
+/// Weight and reps prescribed for every working set of the next session.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Prescription {
+    pub weight: f64,
+    pub reps: u32,
+}
+
+/// The heaviest weight of `sets` and the fewest reps done at it.
+fn top_sets(sets: &[(f64, u32)]) -> Option<(f64, u32)> {
+    let top = sets.iter().map(|&(w, _)| w).reduce(f64::max)?;
+    let reps = sets
+        .iter()
+        .filter(|&&(w, _)| w >= top)
+        .map(|&(_, r)| r)
+        .min()?;
+    Some((top, reps))
+}
+
+/// Linear progression: one `increment` more than last session once every
+/// set at its top weight reached `target_reps`, otherwise the same weight
+/// again.  `None` without a previous session.
+pub fn linear(
+    last_session: &[(f64, u32)],
+    target_reps: u32,
+    increment: f64,
+) -> Option<Prescription> {
+    let (weight, reps) = top_sets(last_session)?;
+    let weight = if reps >= target_reps {
+        weight + increment
+    } else {
+        weight
+    };
+    Some(Prescription {
+        weight,
+        reps: target_reps,
+    })
+}
+
+/// Double progression: one rep more than the weakest set at last session's
+/// top weight, until every set there reached `max_reps`; then one
+/// `increment` more and back down to `min_reps`.  Without a `max_reps` the
+/// reps keep climbing.  `None` without a previous session.
+pub fn double(
+    last_session: &[(f64, u32)],
+    min_reps: u32,
+    max_reps: Option<u32>,
+    increment: f64,
+) -> Option<Prescription> {
+    let (weight, reps) = top_sets(last_session)?;
+    Some(match max_reps {
+        Some(max) if reps >= max => Prescription {
+            weight: weight + increment,
+            reps: min_reps,
+        },
+        _ => Prescription {
+            weight,
+            reps: (reps + 1).max(min_reps).min(max_reps.unwrap_or(u32::MAX)),
+        },
+    })
+}
+
+/// `pct` percent of `e1rm`.
+pub fn percent_of_e1rm(e1rm: f64, pct: u32) -> f64 {
+    e1rm * pct as f64 / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_adds_an_increment_when_every_set_hit_target() {
+        let sets = [(100.0, 5), (100.0, 5), (100.0, 6)];
+        assert_eq!(
+            linear(&sets, 5, 2.5),
+            Some(Prescription {
+                weight: 102.5,
+                reps: 5
+            })
+        );
+    }
+
+    #[test]
+    fn linear_repeats_the_weight_after_a_missed_set() {
+        let sets = [(100.0, 5), (100.0, 5), (100.0, 3)];
+        assert_eq!(
+            linear(&sets, 5, 2.5),
+            Some(Prescription {
+                weight: 100.0,
+                reps: 5
+            })
+        );
+    }
+
+    #[test]
+    fn linear_ignores_lighter_sets() {
+        let sets = [(60.0, 2), (100.0, 5), (100.0, 5)];
+        assert_eq!(linear(&sets, 5, 5.0).unwrap().weight, 105.0);
+    }
+
+    #[test]
+    fn no_previous_session_gives_no_prescription() {
+        assert_eq!(linear(&[], 5, 2.5), None);
+        assert_eq!(double(&[], 8, Some(12), 2.5), None);
+    }
+
+    #[test]
+    fn double_adds_a_rep_below_the_top_of_the_range() {
+        let sets = [(40.0, 10), (40.0, 9), (40.0, 9)];
+        assert_eq!(
+            double(&sets, 8, Some(12), 2.5),
+            Some(Prescription {
+                weight: 40.0,
+                reps: 10
+            })
+        );
+    }
+
+    #[test]
+    fn double_adds_weight_and_resets_reps_at_the_top_of_the_range() {
+        let sets = [(40.0, 12), (40.0, 12), (40.0, 13)];
+        assert_eq!(
+            double(&sets, 8, Some(12), 2.5),
+            Some(Prescription {
+                weight: 42.5,
+                reps: 8
+            })
+        );
+    }
+
+    #[test]
+    fn double_keeps_reps_within_the_range() {
+        // A bad day below the range climbs back from its bottom.
+        assert_eq!(double(&[(40.0, 5)], 8, Some(12), 2.5).unwrap().reps, 8);
+        // Without a top of the range, reps keep climbing.
+        assert_eq!(double(&[(40.0, 20)], 8, None, 2.5).unwrap().reps, 21);
+    }
+
+    #[test]
+    fn percent_of_e1rm_scales_the_e1rm() {
+        assert!((percent_of_e1rm(150.0, 80) - 120.0).abs() < 1e-9);
+    }
+}
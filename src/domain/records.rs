@@ -0,0 +1,234 @@
+//! Pure Personal Record detection.
+//!
+//! A new set is compared against every earlier e1RM-eligible set of the same
+//! exercise. A record is only claimed when there is an earlier best to beat,
+//! so the first set of an exercise never fires four records at once.
+
+use crate::domain::e1rm::E1rmModel;
+use crate::models::{CompletedSet, HistorySet, RecordKind, SetKind, SetType};
+
+/// Improvements smaller than this are treated as ties (float noise).
+const EPSILON: f64 = 1e-6;
+
+/// A set reduced to what record detection compares.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordSet {
+    pub reps: u32,
+    pub rpe: f64,
+    /// Load in kg (System Load for loaded bodyweight); `None` for bodyweight
+    /// sets, which compete on Failure Reps instead.
+    pub load: Option<f64>,
+}
+
+impl RecordSet {
+    /// The comparable part of a logged set; `None` for kinds that do not
+    /// count for e1RM and for timed, distance and carry sets.
+    pub fn from_completed(set: &CompletedSet) -> Option<Self> {
+        Self::from_parts(set.kind, &set.set_type, set.reps, set.rpe)
+    }
+
+    /// [`RecordSet::from_completed`] for a stored set.
+    pub fn from_history(set: &HistorySet) -> Option<Self> {
+        Self::from_parts(set.kind, &set.set_type, set.reps, set.rpe)
+    }
+
+    fn from_parts(kind: SetKind, set_type: &SetType, reps: u32, rpe: f32) -> Option<Self> {
+        if !kind.counts_for_e1rm() {
+            return None;
+        }
+        let load = match set_type {
+            SetType::Weighted { .. } | SetType::LoadedBodyweight { .. } => {
+                Some(set_type.system_load()? as f64)
+            }
+            SetType::Bodyweight => None,
+            SetType::Timed { .. } | SetType::Distance { .. } | SetType::Carry { .. } => {
+                return None;
+            }
+        };
+        Some(RecordSet {
+            reps,
+            rpe: rpe as f64,
+            load,
+        })
+    }
+
+    /// `reps + RIR`, the bodyweight analogue of e1RM.
+    pub fn failure_reps(&self) -> f64 {
+        self.reps as f64 + (10.0 - self.rpe)
+    }
+}
+
+/// One record beaten by a set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordHit {
+    pub kind: RecordKind,
+    pub value: f64,
+    pub previous: f64,
+}
+
+/// Records `set` beats over `prior`, in [`RecordKind`] order.
+///
+/// - e1RM: the set's e1RM under `model` beats every earlier e1RM.
+/// - Reps at weight: more reps than any earlier set at this load or heavier.
+/// - Weight at reps: more load than any earlier set of this many reps or more.
+/// - Failure Reps: a bodyweight set's Failure Reps beat every earlier one.
+pub fn detect(set: &RecordSet, prior: &[RecordSet], model: &impl E1rmModel) -> Vec<RecordHit> {
+    let mut hits = Vec::new();
+    let mut push = |kind, value: f64, previous: Option<f64>| {
+        if let Some(previous) = previous
+            && value > previous + EPSILON
+        {
+            hits.push(RecordHit {
+                kind,
+                value,
+                previous,
+            });
+        }
+    };
+
+    match set.load {
+        Some(load) => {
+            let loaded = || prior.iter().filter_map(|p| p.load.map(|l| (l, p)));
+            push(
+                RecordKind::E1rm,
+                model.e1rm(load, set.reps, set.rpe),
+                loaded()
+                    .map(|(l, p)| model.e1rm(l, p.reps, p.rpe))
+                    .reduce(f64::max),
+            );
+            push(
+                RecordKind::RepsAtWeight,
+                set.reps as f64,
+                loaded()
+                    .filter(|(l, _)| *l >= load - EPSILON)
+                    .map(|(_, p)| p.reps as f64)
+                    .reduce(f64::max),
+            );
+            push(
+                RecordKind::WeightAtReps,
+                load,
+                loaded()
+                    .filter(|(_, p)| p.reps >= set.reps)
+                    .map(|(l, _)| l)
+                    .reduce(f64::max),
+            );
+        }
+        None => push(
+            RecordKind::FailureReps,
+            set.failure_reps(),
+            prior
+                .iter()
+                .filter(|p| p.load.is_none())
+                .map(RecordSet::failure_reps)
+                .reduce(f64::max),
+        ),
+    }
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::e1rm::LinearRpe;
+
+    fn loaded(reps: u32, rpe: f64, load: f64) -> RecordSet {
+        RecordSet {
+            reps,
+            rpe,
+            load: Some(load),
+        }
+    }
+
+    fn kinds(hits: &[RecordHit]) -> Vec<RecordKind> {
+        hits.iter().map(|h| h.kind).collect()
+    }
+
+    #[test]
+    fn test_first_set_is_not_a_record() {
+        assert!(detect(&loaded(5, 8.0, 100.0), &[], &LinearRpe).is_empty());
+        let bw = RecordSet {
+            reps: 10,
+            rpe: 8.0,
+            load: None,
+        };
+        assert!(detect(&bw, &[loaded(5, 8.0, 100.0)], &LinearRpe).is_empty());
+    }
+
+    #[test]
+    fn test_heavier_set_beats_e1rm_and_weight_at_reps() {
+        let prior = [loaded(5, 8.0, 100.0), loaded(3, 8.0, 110.0)];
+        let hits = detect(&loaded(5, 8.0, 102.5), &prior, &LinearRpe);
+        assert_eq!(
+            kinds(&hits),
+            vec![RecordKind::RepsAtWeight, RecordKind::WeightAtReps]
+        );
+        assert_eq!((hits[0].value, hits[0].previous), (5.0, 3.0));
+        assert_eq!((hits[1].value, hits[1].previous), (102.5, 100.0));
+
+        let hits = detect(&loaded(5, 8.0, 107.5), &prior, &LinearRpe);
+        assert_eq!(hits[0].kind, RecordKind::E1rm);
+        assert!(hits[0].value > hits[0].previous);
+    }
+
+    #[test]
+    fn test_rep_record_counts_heavier_earlier_sets() {
+        let prior = [loaded(6, 9.0, 100.0), loaded(7, 9.0, 110.0)];
+        // 8 reps at 100 kg beats the 7 reps done at a heavier 110 kg.
+        let hits = detect(&loaded(8, 9.0, 100.0), &prior, &LinearRpe);
+        assert!(kinds(&hits).contains(&RecordKind::RepsAtWeight));
+        let rep = hits
+            .iter()
+            .find(|h| h.kind == RecordKind::RepsAtWeight)
+            .unwrap();
+        assert_eq!((rep.value, rep.previous), (8.0, 7.0));
+
+        // Matching the best is a tie, not a record.
+        assert!(detect(&loaded(7, 9.0, 110.0), &prior, &LinearRpe).is_empty());
+    }
+
+    #[test]
+    fn test_bodyweight_failure_reps_record() {
+        let bw = |reps, rpe| RecordSet {
+            reps,
+            rpe,
+            load: None,
+        };
+        let prior = [bw(12, 8.0), bw(10, 10.0)];
+        let hits = detect(&bw(13, 8.0), &prior, &LinearRpe);
+        assert_eq!(kinds(&hits), vec![RecordKind::FailureReps]);
+        assert_eq!((hits[0].value, hits[0].previous), (15.0, 14.0));
+    }
+
+    #[test]
+    fn test_from_completed_skips_warmups_and_timed_sets() {
+        let set = |set_type, kind| CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type,
+            kind,
+            comment: String::new(),
+        };
+        let weighted = SetType::Weighted { weight: 100.0 };
+        assert_eq!(
+            RecordSet::from_completed(&set(weighted.clone(), SetKind::Working)),
+            Some(loaded(5, 8.0, 100.0))
+        );
+        assert_eq!(
+            RecordSet::from_completed(&set(weighted, SetKind::Warmup)),
+            None
+        );
+        assert_eq!(
+            RecordSet::from_completed(&set(SetType::Timed { duration_secs: 30 }, SetKind::Working)),
+            None
+        );
+        let assisted = SetType::LoadedBodyweight {
+            bodyweight: 80.0,
+            added: -20.0,
+        };
+        assert_eq!(
+            RecordSet::from_completed(&set(assisted, SetKind::Amrap)).map(|s| s.load),
+            Some(Some(60.0))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Pure progression maths for timed, distance and carry sets.
+//!
+//! The measured analogue of Failure Reps: each RPE point below 10 leaves one
+//! exercise increment (seconds or metres) in reserve. A 40 s plank at RPE 8
+//! on a 5 s step had 10 s in reserve, so its Failure Measure is 50 s.
+
+/// Seconds or metres left in reserve at `rpe`, one `increment` per RPE point
+/// below 10.
+pub fn reserve(rpe: f64, increment: f64) -> f64 {
+    (10.0 - rpe).max(0.0) * increment
+}
+
+/// Estimated measure achievable to failure: `measure + reserve(rpe)`.
+pub fn failure_measure(measure: f64, rpe: f64, increment: f64) -> f64 {
+    measure + reserve(rpe, increment)
+}
+
+/// Suggested measure for a set at `target_rpe`: the Failure Measure minus the
+/// reserve at that RPE, snapped to whole increments and never below one
+/// increment.
+pub fn suggested_measure(failure: f64, target_rpe: f64, increment: f64) -> f64 {
+    let raw = failure - reserve(target_rpe, increment);
+    if increment <= 0.0 {
+        return raw.max(0.0);
+    }
+    ((raw / increment).round() * increment).max(increment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn reserve_is_one_increment_per_rpe_point() {
+        assert!((reserve(8.0, 5.0) - 10.0).abs() < EPSILON);
+        assert!(reserve(10.0, 5.0).abs() < EPSILON);
+        assert!((reserve(7.5, 10.0) - 25.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn failure_measure_adds_reserve() {
+        assert!((failure_measure(40.0, 8.0, 5.0) - 50.0).abs() < EPSILON);
+        assert!((failure_measure(40.0, 10.0, 5.0) - 40.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn suggested_measure_round_trips_at_same_rpe() {
+        let failure = failure_measure(40.0, 8.0, 5.0);
+        assert!((suggested_measure(failure, 8.0, 5.0) - 40.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn suggested_measure_snaps_to_increment() {
+        // 47 s failure at RPE 9 target → 42 s raw → 40 s on a 5 s grid.
+        assert!((suggested_measure(47.0, 9.0, 5.0) - 40.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn suggested_measure_never_below_one_increment() {
+        assert!((suggested_measure(5.0, 6.0, 10.0) - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn suggested_measure_zero_increment_is_unsnapped() {
+        assert!((suggested_measure(42.5, 10.0, 0.0) - 42.5).abs() < EPSILON);
+    }
+}
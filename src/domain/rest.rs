@@ -0,0 +1,94 @@
+//! Pure rest-timer arithmetic.
+//!
+//! The countdown is anchored on wall-clock time rather than ticking state, so
+//! it stays correct however long the Record Screen was unmounted. All times
+//! are Unix milliseconds.
+
+/// Longest gap between two sets that still counts as rest; anything longer
+/// is a break between workouts.
+pub const MAX_REST_SECS: u32 = 30 * 60;
+
+/// A running rest countdown, started when a set is logged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestTimer {
+    /// Unix ms at which the set that started the timer was logged.
+    pub started_at: f64,
+    /// Planned rest in seconds, from the exercise's rest duration.
+    pub duration_secs: u32,
+    /// Exercise the rest belongs to, for the expiry notification.
+    pub exercise_name: String,
+}
+
+impl RestTimer {
+    /// Unix ms at which the rest is over.
+    pub fn ends_at(&self) -> f64 {
+        self.started_at + self.duration_secs as f64 * 1000.0
+    }
+
+    /// Whole seconds left, rounded up so the display reaches 0:00 exactly
+    /// when the timer expires.
+    pub fn remaining_secs(&self, now: f64) -> u32 {
+        ((self.ends_at() - now) / 1000.0).ceil().max(0.0) as u32
+    }
+
+    /// Whole seconds since the timer started.
+    pub fn elapsed_secs(&self, now: f64) -> u32 {
+        ((now - self.started_at) / 1000.0).floor().max(0.0) as u32
+    }
+
+    pub fn is_expired(&self, now: f64) -> bool {
+        now >= self.ends_at()
+    }
+}
+
+/// Rest in whole seconds between a set logged at `prev_ms` and the next one
+/// at `now_ms`, or `None` when the gap is too long to be rest.
+pub fn rest_between(prev_ms: f64, now_ms: f64) -> Option<u32> {
+    let secs = ((now_ms - prev_ms) / 1000.0).round();
+    (secs >= 0.0 && secs <= MAX_REST_SECS as f64).then_some(secs as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer() -> RestTimer {
+        RestTimer {
+            started_at: 10_000.0,
+            duration_secs: 90,
+            exercise_name: "Squat".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_remaining_counts_down_and_stops_at_zero() {
+        let t = timer();
+        assert_eq!(t.remaining_secs(10_000.0), 90);
+        assert_eq!(t.remaining_secs(10_500.0), 90, "partial seconds round up");
+        assert_eq!(t.remaining_secs(99_000.0), 1);
+        assert_eq!(t.remaining_secs(100_000.0), 0);
+        assert_eq!(t.remaining_secs(500_000.0), 0);
+    }
+
+    #[test]
+    fn test_expiry_and_elapsed() {
+        let t = timer();
+        assert!(!t.is_expired(99_999.0));
+        assert!(t.is_expired(100_000.0));
+        assert_eq!(t.elapsed_secs(71_900.0), 61);
+    }
+
+    #[test]
+    fn test_rest_between_ignores_long_gaps() {
+        assert_eq!(rest_between(0.0, 95_400.0), Some(95));
+        assert_eq!(
+            rest_between(0.0, MAX_REST_SECS as f64 * 1000.0),
+            Some(MAX_REST_SECS)
+        );
+        assert_eq!(
+            rest_between(0.0, (MAX_REST_SECS as f64 + 1.0) * 1000.0),
+            None
+        );
+        assert_eq!(rest_between(5_000.0, 0.0), None);
+    }
+}
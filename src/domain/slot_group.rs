@@ -0,0 +1,307 @@
+//! Pure superset / circuit grouping of Plan Slots.
+//!
+//! Slots that share a `group_id` and sit next to each other in the plan form
+//! a Slot Group: two slots are a superset, three or more a circuit. A group
+//! is trained in rounds — one set of each member per round — so the Record
+//! Screen advances to the next member after every logged set. A slot whose
+//! group has no other adjacent member behaves as an ordinary slot.
+//!
+//! `counts` arguments are completed set counts indexed like `slots`.
+
+use crate::models::PlanExercise;
+
+/// Round progress of a Slot Group.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GroupRounds {
+    /// Rounds in which every member has logged its set.
+    pub done: u32,
+    /// Rounds planned: the most planned sets of any member. Members with
+    /// fewer planned sets sit out the final rounds.
+    pub planned: u32,
+}
+
+/// Indices of the Slot Group containing `idx`, in plan order; just `[idx]`
+/// for an ungrouped slot.
+pub fn group_members(slots: &[PlanExercise], idx: usize) -> Vec<usize> {
+    let Some(group) = slots.get(idx).and_then(|s| s.group_id.as_deref()) else {
+        return vec![idx];
+    };
+    let same = |i: usize| slots[i].group_id.as_deref() == Some(group);
+    let mut start = idx;
+    while start > 0 && same(start - 1) {
+        start -= 1;
+    }
+    let mut end = idx;
+    while end + 1 < slots.len() && same(end + 1) {
+        end += 1;
+    }
+    (start..=end).collect()
+}
+
+/// Whether `idx` belongs to a Slot Group of two or more slots.
+pub fn is_grouped(slots: &[PlanExercise], idx: usize) -> bool {
+    group_members(slots, idx).len() > 1
+}
+
+/// Display label for a grouped slot, e.g. `"B2"` for the second member of the
+/// plan's second group. `None` for ungrouped slots.
+pub fn group_label(slots: &[PlanExercise], idx: usize) -> Option<String> {
+    let members = group_members(slots, idx);
+    if members.len() < 2 {
+        return None;
+    }
+    let mut letter = 0u8;
+    let mut i = 0;
+    while i < members[0] {
+        let run = group_members(slots, i);
+        if run.len() > 1 {
+            letter += 1;
+        }
+        i = run[run.len() - 1] + 1;
+    }
+    let member = members.iter().position(|&m| m == idx)? + 1;
+    Some(format!("{}{}", (b'A' + letter % 26) as char, member))
+}
+
+fn count(counts: &[u32], idx: usize) -> u32 {
+    counts.get(idx).copied().unwrap_or(0)
+}
+
+/// Round progress of the Slot Group containing `idx`; `None` when ungrouped.
+pub fn group_rounds(slots: &[PlanExercise], counts: &[u32], idx: usize) -> Option<GroupRounds> {
+    let members = group_members(slots, idx);
+    if members.len() < 2 {
+        return None;
+    }
+    let planned = members
+        .iter()
+        .map(|&m| slots[m].planned_sets)
+        .max()
+        .unwrap_or(0);
+    let done = (1..=planned)
+        .take_while(|&round| {
+            members
+                .iter()
+                .all(|&m| count(counts, m) >= round.min(slots[m].planned_sets))
+        })
+        .last()
+        .unwrap_or(0);
+    Some(GroupRounds { done, planned })
+}
+
+/// The slot to record next after a set was logged on `logged_idx`.
+///
+/// Moves on to the next member still owed a set in the current round, or
+/// wraps to the member furthest behind to start the next round. Returns
+/// `None` for ungrouped slots, when the group is finished, or when the
+/// logged slot is the only member with sets left.
+pub fn next_group_slot(slots: &[PlanExercise], counts: &[u32], logged_idx: usize) -> Option<usize> {
+    let members = group_members(slots, logged_idx);
+    if members.len() < 2 {
+        return None;
+    }
+    let unfinished = |m: usize| count(counts, m) < slots[m].planned_sets;
+    let pos = members.iter().position(|&m| m == logged_idx)?;
+    let logged = count(counts, logged_idx);
+
+    let later_in_round = members[pos + 1..]
+        .iter()
+        .copied()
+        .find(|&m| unfinished(m) && count(counts, m) < logged);
+    let next = later_in_round.or_else(|| {
+        members
+            .iter()
+            .copied()
+            .filter(|&m| unfinished(m))
+            .min_by_key(|&m| count(counts, m))
+    })?;
+    (next != logged_idx).then_some(next)
+}
+
+/// `(slot id, new group_id)` updates that join the slot at `idx` to the one
+/// after it, merging their groups. `new_group_id` is used when neither slot
+/// is grouped yet.
+pub fn link_with_next(
+    slots: &[PlanExercise],
+    idx: usize,
+    new_group_id: String,
+) -> Vec<(String, Option<String>)> {
+    if idx + 1 >= slots.len() {
+        return Vec::new();
+    }
+    let group = if is_grouped(slots, idx) {
+        slots[idx].group_id.clone().unwrap_or(new_group_id)
+    } else {
+        new_group_id
+    };
+    let mut members = group_members(slots, idx);
+    members.extend(group_members(slots, idx + 1));
+    members.sort_unstable();
+    members.dedup();
+    members
+        .into_iter()
+        .filter(|&m| slots[m].group_id.as_deref() != Some(group.as_str()))
+        .map(|m| (slots[m].id.clone(), Some(group.clone())))
+        .collect()
+}
+
+/// `(slot id, new group_id)` updates that split the Slot Group between `idx`
+/// and the slot after it. The trailing part keeps grouping under
+/// `new_group_id` when it still has two or more slots; a part left with a
+/// single slot is ungrouped.
+pub fn unlink_from_next(
+    slots: &[PlanExercise],
+    idx: usize,
+    new_group_id: String,
+) -> Vec<(String, Option<String>)> {
+    let members = group_members(slots, idx);
+    if !members.contains(&(idx + 1)) {
+        return Vec::new();
+    }
+    let (head, tail): (Vec<usize>, Vec<usize>) = members.into_iter().partition(|&m| m <= idx);
+    let mut updates = Vec::new();
+    if head.len() == 1 {
+        updates.push((slots[head[0]].id.clone(), None));
+    }
+    let tail_group = (tail.len() > 1).then_some(new_group_id);
+    updates.extend(
+        tail.into_iter()
+            .map(|m| (slots[m].id.clone(), tail_group.clone())),
+    );
+    updates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ExerciseMetadata, SetTypeConfig};
+
+    fn slot(id: &str, group: Option<&str>, planned_sets: u32) -> PlanExercise {
+        PlanExercise {
+            id: id.to_string(),
+            exercise: ExerciseMetadata {
+                id: Some(format!("ex-{id}")),
+                name: id.to_string(),
+                set_type_config: SetTypeConfig::Bodyweight,
+                min_reps: 1,
+                max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            },
+            planned_sets,
+            position: 0,
+            group_id: group.map(str::to_string),
+            targets: Default::default(),
+        }
+    }
+
+    /// Plan: solo, superset g1 (a, b), circuit g2 (c, d, e).
+    fn plan() -> Vec<PlanExercise> {
+        vec![
+            slot("solo", None, 3),
+            slot("a", Some("g1"), 3),
+            slot("b", Some("g1"), 3),
+            slot("c", Some("g2"), 2),
+            slot("d", Some("g2"), 2),
+            slot("e", Some("g2"), 1),
+        ]
+    }
+
+    #[test]
+    fn test_group_members_and_labels() {
+        let slots = plan();
+        assert_eq!(group_members(&slots, 0), vec![0]);
+        assert_eq!(group_members(&slots, 2), vec![1, 2]);
+        assert_eq!(group_members(&slots, 4), vec![3, 4, 5]);
+        assert_eq!(group_label(&slots, 0), None);
+        assert_eq!(group_label(&slots, 2).as_deref(), Some("A2"));
+        assert_eq!(group_label(&slots, 5).as_deref(), Some("B3"));
+    }
+
+    #[test]
+    fn test_lone_group_member_is_ungrouped() {
+        let slots = vec![slot("a", Some("g1"), 3), slot("b", None, 3)];
+        assert!(!is_grouped(&slots, 0));
+        assert_eq!(group_rounds(&slots, &[1, 0], 0), None);
+        assert_eq!(next_group_slot(&slots, &[1, 0], 0), None);
+    }
+
+    #[test]
+    fn test_next_slot_alternates_within_a_round() {
+        let slots = plan();
+        // a logged its first set: b is owed one this round.
+        assert_eq!(next_group_slot(&slots, &[0, 1, 0, 0, 0, 0], 1), Some(2));
+        // b completed the round: wrap back to a.
+        assert_eq!(next_group_slot(&slots, &[0, 1, 1, 0, 0, 0], 2), Some(1));
+        // Ungrouped slots never auto-advance.
+        assert_eq!(next_group_slot(&slots, &[1, 0, 0, 0, 0, 0], 0), None);
+    }
+
+    #[test]
+    fn test_next_slot_skips_members_with_fewer_planned_sets() {
+        let slots = plan();
+        // Round 2 of the circuit: e planned only one set, so d wraps to c.
+        assert_eq!(next_group_slot(&slots, &[0, 0, 0, 2, 2, 1], 4), None);
+        assert_eq!(next_group_slot(&slots, &[0, 0, 0, 2, 1, 1], 3), Some(4));
+        assert_eq!(next_group_slot(&slots, &[0, 0, 0, 1, 1, 0], 4), Some(5));
+        assert_eq!(next_group_slot(&slots, &[0, 0, 0, 1, 1, 1], 5), Some(3));
+    }
+
+    #[test]
+    fn test_group_rounds_count_complete_rounds() {
+        let slots = plan();
+        assert_eq!(
+            group_rounds(&slots, &[0, 2, 1, 0, 0, 0], 1),
+            Some(GroupRounds {
+                done: 1,
+                planned: 3
+            })
+        );
+        assert_eq!(
+            group_rounds(&slots, &[0, 0, 0, 2, 2, 1], 3),
+            Some(GroupRounds {
+                done: 2,
+                planned: 2
+            }),
+            "e sits out round 2"
+        );
+    }
+
+    #[test]
+    fn test_link_and_unlink() {
+        let slots = plan();
+        // Linking solo into superset g1 adopts a fresh id for all three.
+        let updates = link_with_next(&slots, 0, "new".to_string());
+        assert_eq!(
+            updates,
+            vec![
+                ("solo".to_string(), Some("new".to_string())),
+                ("a".to_string(), Some("new".to_string())),
+                ("b".to_string(), Some("new".to_string())),
+            ]
+        );
+        // Linking b (g1) to c (g2) merges g2 into g1.
+        let updates = link_with_next(&slots, 2, "new".to_string());
+        assert_eq!(updates.len(), 3);
+        assert!(updates.iter().all(|(_, g)| g.as_deref() == Some("g1")));
+
+        // Splitting the superset ungroups both halves.
+        assert_eq!(
+            unlink_from_next(&slots, 1, "new".to_string()),
+            vec![("a".to_string(), None), ("b".to_string(), None)]
+        );
+        // Splitting c | d e keeps d and e together under a new id.
+        assert_eq!(
+            unlink_from_next(&slots, 3, "new".to_string()),
+            vec![
+                ("c".to_string(), None),
+                ("d".to_string(), Some("new".to_string())),
+                ("e".to_string(), Some("new".to_string())),
+            ]
+        );
+        assert!(unlink_from_next(&slots, 0, "new".to_string()).is_empty());
+    }
+}
@@ -0,0 +1,138 @@
+//! Pure warm-up ramp generation.
+//!
+//! Turns the configured [`WarmupRamp`] into concrete warm-up sets anchored on
+//! the first predicted working weight, with reps capped by the Blended e1RM
+//! so no warm-up becomes a hard effort. All weights are canonical kilograms.
+
+use crate::domain::e1rm::E1rmModel;
+use crate::models::{SetTypeConfig, WarmupRamp, WarmupSet};
+
+/// RPE recorded for logged warm-up sets, and the effort ceiling used when
+/// capping warm-up reps against the e1RM.
+pub const WARMUP_RPE: f32 = 5.0;
+
+/// Generates the warm-up sets for a weighted exercise.
+///
+/// Each step's load is `percent` of `working_kg` snapped onto the exercise's
+/// loading grid; `bar` steps use the bar weight (or `min_weight` when the
+/// exercise is not plate-loaded). Steps that would reach the working weight
+/// or repeat the previous load are dropped. When `e1rm` is known, a step's
+/// reps are reduced until `model` rates the set no harder than [`WARMUP_RPE`].
+///
+/// Returns an empty list for bodyweight exercises or a non-positive working
+/// weight.
+pub fn generate_warmups(
+    working_kg: f64,
+    e1rm: Option<f64>,
+    model: &impl E1rmModel,
+    config: &SetTypeConfig,
+    ramp: &WarmupRamp,
+) -> Vec<WarmupSet> {
+    let SetTypeConfig::Weighted {
+        min_weight,
+        bar_weight,
+        ..
+    } = *config
+    else {
+        return Vec::new();
+    };
+    if working_kg <= 0.0 {
+        return Vec::new();
+    }
+
+    let empty_bar = bar_weight.unwrap_or(min_weight).max(min_weight) as f64;
+    let mut sets: Vec<WarmupSet> = Vec::new();
+    for step in ramp.steps() {
+        let weight = if step.percent == 0 {
+            empty_bar
+        } else {
+            config.snap_weight(working_kg * step.percent as f64 / 100.0)
+        };
+        if weight >= working_kg - 1e-6 {
+            continue;
+        }
+        if sets
+            .last()
+            .is_some_and(|prev| (prev.weight as f64 - weight).abs() < 1e-6)
+        {
+            continue;
+        }
+
+        let mut reps = step.reps;
+        if let Some(e1rm) = e1rm {
+            while reps > 1 && model.predicted_weight(e1rm, reps, WARMUP_RPE as f64) < weight {
+                reps -= 1;
+            }
+        }
+        sets.push(WarmupSet {
+            weight: weight as f32,
+            reps,
+            done: false,
+        });
+    }
+    sets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::e1rm::LinearRpe;
+
+    fn barbell() -> SetTypeConfig {
+        SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: Some(20.0),
+        }
+    }
+
+    #[test]
+    fn test_default_ramp_on_100kg() {
+        let sets = generate_warmups(100.0, None, &LinearRpe, &barbell(), &WarmupRamp::default());
+        let loads: Vec<(f32, u32)> = sets.iter().map(|s| (s.weight, s.reps)).collect();
+        assert_eq!(loads, vec![(20.0, 10), (50.0, 5), (70.0, 3), (85.0, 1)]);
+        assert!(sets.iter().all(|s| !s.done));
+    }
+
+    #[test]
+    fn test_steps_snap_to_grid_and_skip_duplicates() {
+        // 40 kg working: 50% = 20 (same as bar, dropped), 70% = 28 → 27.5,
+        // 85% = 34 → 35.
+        let sets = generate_warmups(40.0, None, &LinearRpe, &barbell(), &WarmupRamp::default());
+        let loads: Vec<f32> = sets.iter().map(|s| s.weight).collect();
+        assert_eq!(loads, vec![20.0, 27.5, 35.0]);
+    }
+
+    #[test]
+    fn test_steps_at_or_above_working_weight_are_dropped() {
+        // Working weight equals the bar: nothing to warm up with.
+        assert!(
+            generate_warmups(20.0, None, &LinearRpe, &barbell(), &WarmupRamp::default()).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_e1rm_caps_reps_to_light_effort() {
+        // With an e1RM of 100 kg, 10 reps at 85 kg is far above RPE 5.
+        let ramp: WarmupRamp = "85%×10".parse().unwrap();
+        let sets = generate_warmups(100.0, Some(100.0), &LinearRpe, &barbell(), &ramp);
+        assert_eq!(sets.len(), 1);
+        assert!(sets[0].reps < 10);
+        assert!(
+            LinearRpe.predicted_weight(100.0, sets[0].reps, WARMUP_RPE as f64) >= 85.0
+                || sets[0].reps == 1
+        );
+    }
+
+    #[test]
+    fn test_bodyweight_has_no_warmups() {
+        let sets = generate_warmups(
+            100.0,
+            None,
+            &LinearRpe,
+            &SetTypeConfig::Bodyweight,
+            &WarmupRamp::default(),
+        );
+        assert!(sets.is_empty());
+    }
+}
@@ -0,0 +1,389 @@
+//! Plain-text export of the training history for coaches and spreadsheets.
+//!
+//! Rows are serialized to CSV or JSON Lines without touching the browser, so
+//! the format is unit-tested natively; the database query and the download
+//! live in `state::db`.
+
+use crate::domain::e1rm::E1rmModel;
+use crate::models::{E1rmFormula, HistorySet, SetType, WeightUnit};
+use chrono::{DateTime, FixedOffset};
+
+/// File format of a history export.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    JsonLines,
+}
+
+impl ExportFormat {
+    /// Download filename for an export in this format.
+    pub fn filename(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "workout-history.csv",
+            ExportFormat::JsonLines => "workout-history.jsonl",
+        }
+    }
+
+    /// Content type of the downloaded file.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::JsonLines => "application/x-ndjson",
+        }
+    }
+}
+
+/// Which sets a history export includes.  Bounds are Unix milliseconds,
+/// `from_ms` inclusive and `to_ms` exclusive; `None` leaves the side open.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExportFilter {
+    pub from_ms: Option<f64>,
+    pub to_ms: Option<f64>,
+    /// Restrict the export to one exercise (by uuid).
+    pub exercise_id: Option<String>,
+}
+
+/// One exported set: the history row plus the plan it was logged under and
+/// the device's UTC offset at `recorded_at`, which renders the local time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportRow {
+    pub set: HistorySet,
+    pub plan_id: Option<String>,
+    /// Minutes east of UTC (e.g. `60` for CET, `-300` for EST).
+    pub utc_offset_minutes: i32,
+    /// The exercise's e1RM Formula, already resolved against Settings.
+    pub e1rm_formula: E1rmFormula,
+}
+
+/// Stable name of a set's type, matching the exercise `set_type` column.
+pub fn set_type_name(set_type: &SetType) -> &'static str {
+    match set_type {
+        SetType::Weighted { .. } => "weighted",
+        SetType::Bodyweight => "bodyweight",
+        SetType::LoadedBodyweight { added, .. } if *added < 0.0 => "assisted_bodyweight",
+        SetType::LoadedBodyweight { .. } => "loaded_bodyweight",
+        SetType::Timed { .. } => "timed",
+        SetType::Distance { .. } => "distance",
+        SetType::Carry { .. } => "carry",
+    }
+}
+
+/// Column values of a row, in export units; `None` is an empty cell / JSON null.
+struct Fields {
+    weight: Option<f64>,
+    bodyweight: Option<f64>,
+    duration_secs: Option<u32>,
+    distance_m: Option<f64>,
+    e1rm: Option<f64>,
+}
+
+impl Fields {
+    fn of(row: &ExportRow, unit: WeightUnit) -> Self {
+        let set = &row.set;
+        let (weight, bodyweight) = match set.set_type {
+            SetType::Weighted { weight } | SetType::Carry { weight, .. } => (Some(weight), None),
+            SetType::LoadedBodyweight { bodyweight, added } => (Some(added), Some(bodyweight)),
+            _ => (None, None),
+        };
+        let (duration_secs, distance_m) = match set.set_type {
+            SetType::Timed { duration_secs } => (Some(duration_secs), None),
+            SetType::Distance { distance_m } | SetType::Carry { distance_m, .. } => {
+                (None, Some(distance_m as f64))
+            }
+            _ => (None, None),
+        };
+        // Only sets that feed the e1RM elsewhere get one here.
+        let e1rm = set
+            .set_type
+            .system_load()
+            .filter(|_| set.kind.counts_for_e1rm())
+            .map(|load| row.e1rm_formula.e1rm(load as f64, set.reps, set.rpe as f64));
+
+        let to_unit = |kg: f64| round2(unit.from_kg(kg));
+        Fields {
+            weight: weight.map(|w| to_unit(w as f64)),
+            bodyweight: bodyweight.map(|w| to_unit(w as f64)),
+            duration_secs,
+            distance_m,
+            e1rm: e1rm.map(to_unit),
+        }
+    }
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// `recorded_at` in the device's local time, e.g. `2026-03-01T18:30:00+01:00`.
+fn local_timestamp(row: &ExportRow) -> String {
+    let offset = FixedOffset::east_opt(row.utc_offset_minutes * 60)
+        .unwrap_or_else(|| FixedOffset::east_opt(0).expect("zero offset is valid"));
+    DateTime::from_timestamp_millis(row.set.recorded_at as i64)
+        .map(|utc| {
+            utc.with_timezone(&offset)
+                .format("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// Header names, with weights labelled in `unit`.
+fn header(unit: WeightUnit) -> Vec<String> {
+    vec![
+        "exercise".to_string(),
+        "set_type".to_string(),
+        "kind".to_string(),
+        format!("weight_{}", unit),
+        format!("bodyweight_{}", unit),
+        "reps".to_string(),
+        "duration_s".to_string(),
+        "distance_m".to_string(),
+        "rpe".to_string(),
+        format!("e1rm_{}", unit),
+        "plan_id".to_string(),
+        "recorded_at".to_string(),
+        "rest_s".to_string(),
+        "comment".to_string(),
+    ]
+}
+
+/// Quotes a CSV cell when it contains a delimiter, quote or line break, and
+/// defuses text a spreadsheet would run as a formula.
+fn csv_cell(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn opt_cell<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Serializes `rows` as RFC 4180 CSV with a header line and CRLF endings.
+/// Weights are converted to `unit`.
+pub fn to_csv(rows: &[ExportRow], unit: WeightUnit) -> String {
+    let mut out = header(unit).join(",");
+    out.push_str("\r\n");
+    for row in rows {
+        let fields = Fields::of(row, unit);
+        let cells = [
+            csv_cell(&row.set.exercise_name),
+            set_type_name(&row.set.set_type).to_string(),
+            row.set.kind.to_string(),
+            opt_cell(fields.weight),
+            opt_cell(fields.bodyweight),
+            row.set.reps.to_string(),
+            opt_cell(fields.duration_secs),
+            opt_cell(fields.distance_m),
+            row.set.rpe.to_string(),
+            opt_cell(fields.e1rm),
+            csv_cell(row.plan_id.as_deref().unwrap_or_default()),
+            local_timestamp(row),
+            opt_cell(row.set.rest_secs),
+            csv_cell(&row.set.comment),
+        ];
+        out.push_str(&cells.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Serializes `rows` as JSON Lines: one object per set, keyed like the CSV
+/// header.  Weights are converted to `unit`.
+pub fn to_json_lines(rows: &[ExportRow], unit: WeightUnit) -> String {
+    let keys = header(unit);
+    let mut out = String::new();
+    for row in rows {
+        let fields = Fields::of(row, unit);
+        let values = [
+            serde_json::json!(row.set.exercise_name),
+            serde_json::json!(set_type_name(&row.set.set_type)),
+            serde_json::json!(row.set.kind.to_string()),
+            serde_json::json!(fields.weight),
+            serde_json::json!(fields.bodyweight),
+            serde_json::json!(row.set.reps),
+            serde_json::json!(fields.duration_secs),
+            serde_json::json!(fields.distance_m),
+            serde_json::json!(row.set.rpe),
+            serde_json::json!(fields.e1rm),
+            serde_json::json!(row.plan_id),
+            serde_json::json!(local_timestamp(row)),
+            serde_json::json!(row.set.rest_secs),
+            serde_json::json!(row.set.comment),
+        ];
+        let object: serde_json::Map<String, serde_json::Value> =
+            keys.iter().cloned().zip(values).collect();
+        out.push_str(&serde_json::Value::Object(object).to_string());
+        out.push('\n');
+    }
+    out
+}
+
+/// Serializes `rows` in `format`.
+pub fn serialize(rows: &[ExportRow], format: ExportFormat, unit: WeightUnit) -> String {
+    match format {
+        ExportFormat::Csv => to_csv(rows, unit),
+        ExportFormat::JsonLines => to_json_lines(rows, unit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SetKind;
+
+    /// 2026-03-01T17:30:00Z
+    const RECORDED_AT: f64 = 1_772_386_200_000.0;
+
+    fn row(name: &str, set_type: SetType, kind: SetKind) -> ExportRow {
+        ExportRow {
+            set: HistorySet {
+                id: 1,
+                exercise_id: "e1".to_string(),
+                exercise_name: name.to_string(),
+                set_number: 1,
+                reps: 5,
+                rpe: 8.0,
+                set_type,
+                kind,
+                comment: String::new(),
+                rest_secs: Some(150),
+                recorded_at: RECORDED_AT,
+            },
+            plan_id: Some("p1".to_string()),
+            utc_offset_minutes: 60,
+            e1rm_formula: E1rmFormula::LinearRpe,
+        }
+    }
+
+    fn squat() -> ExportRow {
+        row(
+            "Squat",
+            SetType::Weighted { weight: 100.0 },
+            SetKind::Working,
+        )
+    }
+
+    #[test]
+    fn test_csv_header_and_weighted_row() {
+        let csv = to_csv(&[squat()], WeightUnit::Kg);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(
+            lines[0],
+            "exercise,set_type,kind,weight_kg,bodyweight_kg,reps,duration_s,distance_m,rpe,e1rm_kg,plan_id,recorded_at,rest_s,comment"
+        );
+        let e1rm = round2(E1rmFormula::LinearRpe.e1rm(100.0, 5, 8.0));
+        assert_eq!(
+            lines[1],
+            format!("Squat,weighted,working,100,,5,,,8,{e1rm},p1,2026-03-01T18:30:00+01:00,150,")
+        );
+        assert_eq!(lines[2], "", "file ends with a line break");
+    }
+
+    #[test]
+    fn test_csv_quotes_and_defuses_names() {
+        assert_eq!(csv_cell("Press, Behind Neck"), "\"Press, Behind Neck\"");
+        assert_eq!(csv_cell("The \"Big\" Pull"), "\"The \"\"Big\"\" Pull\"");
+        assert_eq!(csv_cell("=SUM(A1)"), "'=SUM(A1)");
+        assert_eq!(csv_cell("Squat"), "Squat");
+    }
+
+    #[test]
+    fn test_csv_converts_weights_to_display_unit() {
+        let csv = to_csv(&[squat()], WeightUnit::Lb);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert!(lines[0].contains("weight_lb"));
+        let weight = lines[1].split(',').nth(3).unwrap();
+        assert_eq!(weight, round2(WeightUnit::Lb.from_kg(100.0)).to_string());
+    }
+
+    /// Warm-ups and timed sets have no e1RM; a loaded bodyweight set reports
+    /// its added weight and bodyweight, and its e1RM uses the System Load.
+    #[test]
+    fn test_e1rm_only_for_sets_that_feed_it() {
+        let warmup = Fields::of(
+            &row("Squat", SetType::Weighted { weight: 60.0 }, SetKind::Warmup),
+            WeightUnit::Kg,
+        );
+        assert_eq!(warmup.e1rm, None);
+
+        let plank = Fields::of(
+            &row(
+                "Plank",
+                SetType::Timed { duration_secs: 45 },
+                SetKind::Working,
+            ),
+            WeightUnit::Kg,
+        );
+        assert_eq!(plank.e1rm, None);
+        assert_eq!(plank.duration_secs, Some(45));
+
+        let dip = Fields::of(
+            &row(
+                "Dip",
+                SetType::LoadedBodyweight {
+                    bodyweight: 80.0,
+                    added: -20.0,
+                },
+                SetKind::Working,
+            ),
+            WeightUnit::Kg,
+        );
+        assert_eq!(dip.weight, Some(-20.0));
+        assert_eq!(dip.bodyweight, Some(80.0));
+        assert_eq!(
+            dip.e1rm,
+            Some(round2(E1rmFormula::LinearRpe.e1rm(60.0, 5, 8.0)))
+        );
+    }
+
+    /// The e1RM column follows the exercise's e1RM Formula.
+    #[test]
+    fn test_e1rm_uses_the_row_formula() {
+        let mut squat = squat();
+        squat.e1rm_formula = E1rmFormula::Brzycki;
+        // 5 reps at RPE 8 is 7 reps to failure: 100 × 36 / 30.
+        assert_eq!(Fields::of(&squat, WeightUnit::Kg).e1rm, Some(120.0));
+    }
+
+    #[test]
+    fn test_json_lines_one_object_per_set() {
+        let mut plank = row(
+            "Plank",
+            SetType::Timed { duration_secs: 45 },
+            SetKind::Working,
+        );
+        plank.plan_id = None;
+        let out = to_json_lines(&[squat(), plank], WeightUnit::Kg);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["exercise"], "Squat");
+        assert_eq!(first["weight_kg"], 100.0);
+        assert_eq!(first["plan_id"], "p1");
+        assert_eq!(first["recorded_at"], "2026-03-01T18:30:00+01:00");
+
+        let second: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(second["set_type"], "timed");
+        assert_eq!(second["duration_s"], 45);
+        assert!(second["weight_kg"].is_null());
+        assert!(second["e1rm_kg"].is_null());
+        assert!(second["plan_id"].is_null());
+    }
+
+    #[test]
+    fn test_local_timestamp_applies_negative_offset() {
+        let mut r = squat();
+        r.utc_offset_minutes = -300;
+        assert_eq!(local_timestamp(&r), "2026-03-01T12:30:00-05:00");
+    }
+}
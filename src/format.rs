@@ -1,4 +1,51 @@
+use crate::models::{SetType, SetTypeConfig, WeightUnit};
+
 pub fn fmt_weight(kg: impl Into<f64>) -> String {
     let kg = kg.into();
     format!("{}", (kg * 100.0).round() / 100.0)
 }
+
+/// Formats a canonical kilogram value in `unit` with its label, e.g. `"225 lb"`.
+pub fn fmt_weight_in(kg: impl Into<f64>, unit: WeightUnit) -> String {
+    format!("{} {}", fmt_weight(unit.from_kg(kg.into())), unit)
+}
+
+/// Formats a duration as `"45 s"` under a minute and `"1:30"` above.
+pub fn fmt_duration(secs: u32) -> String {
+    if secs < 60 {
+        format!("{} s", secs)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+/// Formats a distance in metres with its label, e.g. `"40 m"`.
+pub fn fmt_distance(metres: impl Into<f64>) -> String {
+    format!("{} m", fmt_weight(metres))
+}
+
+/// Formats a timed, distance or carry measure for an exercise of `config`.
+pub fn fmt_measure(config: &SetTypeConfig, measure: f64) -> String {
+    match config {
+        SetTypeConfig::Timed { .. } => fmt_duration(measure.round().max(0.0) as u32),
+        _ => fmt_distance(measure),
+    }
+}
+
+/// Formats the time or distance of a logged set, or `None` for rep-based sets.
+pub fn fmt_set_measure(set_type: &SetType) -> Option<String> {
+    match set_type {
+        SetType::Timed { duration_secs } => Some(fmt_duration(*duration_secs)),
+        SetType::Distance { distance_m } | SetType::Carry { distance_m, .. } => {
+            Some(fmt_distance(*distance_m))
+        }
+        SetType::Weighted { .. } | SetType::Bodyweight | SetType::LoadedBodyweight { .. } => None,
+    }
+}
+
+/// Formats the load of a loaded or assisted bodyweight set relative to
+/// bodyweight, e.g. `"BW +10 kg"` or `"BW −20 kg"`.
+pub fn fmt_bodyweight_load(added: f32, unit: WeightUnit) -> String {
+    let sign = if added < 0.0 { '−' } else { '+' };
+    format!("BW {}{}", sign, fmt_weight_in(added.abs(), unit))
+}
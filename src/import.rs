@@ -0,0 +1,1212 @@
+//! Import of training history exported by other apps.
+//!
+//! Strong and Hevy CSV exports are recognised by their headers; any other CSV
+//! with an exercise and a date column (including our own history export) is
+//! read through a table of common column names.  Parsing and the mapping onto
+//! the exercise library are pure, so the dry-run preview is exactly what gets
+//! written and both are unit-tested natively; the inserts themselves live in
+//! `Database::import_history`, which skips sets that are already logged.
+
+use crate::models::{
+    CompletedSet, ExerciseMetadata, SetKind, SetType, SetTypeConfig, WeightUnit,
+    validate_bodyweight, validate_distance, validate_duration, validate_reps,
+};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Heaviest load accepted from an imported row, matching the manual-entry limit.
+const MAX_WEIGHT: f32 = 500.0;
+
+/// Longest name accepted for an exercise created by an import.
+const MAX_EXERCISE_NAME_LENGTH: usize = 100;
+
+const METRES_PER_KM: f64 = 1000.0;
+const METRES_PER_MILE: f64 = 1609.344;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ImportError {
+    #[error("The file is empty")]
+    Empty,
+
+    #[error(
+        "No {0} column found. Expected a Strong or Hevy export, or a CSV with exercise and date columns."
+    )]
+    MissingColumn(&'static str),
+}
+
+/// App that produced an imported file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportSource {
+    Strong,
+    Hevy,
+    Generic,
+}
+
+impl ImportSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            ImportSource::Strong => "Strong",
+            ImportSource::Hevy => "Hevy",
+            ImportSource::Generic => "Generic CSV",
+        }
+    }
+}
+
+/// When an imported set was done.  Most exports write device-local wall
+/// time without an offset, which only the browser can turn into an instant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImportTime {
+    /// Wall-clock time in the device's time zone
+    Local(NaiveDateTime),
+    /// Unix milliseconds, from a timestamp that carried its offset
+    Instant(f64),
+}
+
+impl ImportTime {
+    /// Approximate ordering key; exact within a single file, which never
+    /// mixes the two forms.
+    fn sort_key(self) -> f64 {
+        match self {
+            ImportTime::Local(dt) => dt.and_utc().timestamp_millis() as f64,
+            ImportTime::Instant(ms) => ms,
+        }
+    }
+
+    /// Calendar day, for numbering sets within a day.
+    fn day(self) -> i64 {
+        match self {
+            ImportTime::Local(dt) => dt.date().num_days_from_ce() as i64,
+            ImportTime::Instant(ms) => (ms / 86_400_000.0).floor() as i64,
+        }
+    }
+
+    fn plus_secs(self, secs: i64) -> Self {
+        match self {
+            ImportTime::Local(dt) => ImportTime::Local(dt + TimeDelta::seconds(secs)),
+            ImportTime::Instant(ms) => ImportTime::Instant(ms + secs as f64 * 1000.0),
+        }
+    }
+}
+
+/// One set read from the file, in canonical units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceRow {
+    /// Line of the file the row starts on (the header is line 1)
+    pub line: usize,
+    pub exercise_name: String,
+    pub recorded_at: ImportTime,
+    pub kind: SetKind,
+    /// Set marked as taken to failure; logged at RPE 10 when it has no RPE
+    pub failure: bool,
+    /// Weight in kg; negative for assistance
+    pub weight_kg: Option<f32>,
+    /// Bodyweight in kg, present in our own export of loaded bodyweight sets
+    pub bodyweight_kg: Option<f32>,
+    pub reps: Option<u32>,
+    /// RPE on the 1–10 scale in 0.5 steps, converted from RIR when needed
+    pub rpe: Option<f32>,
+    pub duration_secs: Option<u32>,
+    pub distance_m: Option<f32>,
+}
+
+/// A row that will not be imported, with the reason shown in the preview.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkippedRow {
+    pub line: usize,
+    pub reason: String,
+}
+
+/// Result of reading an import file, before it is mapped onto the library.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedImport {
+    pub source: ImportSource,
+    pub rows: Vec<SourceRow>,
+    pub skipped: Vec<SkippedRow>,
+    /// False when weights carry no unit and were read in the caller's
+    /// default unit, so the preview should let the user pick it.
+    pub unit_specified: bool,
+}
+
+/// Meaning of a recognised column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Field {
+    Exercise,
+    Date,
+    Kind,
+    /// Weight, in the given unit or the row's / file's unit when `None`
+    Weight(Option<WeightUnit>),
+    Bodyweight(Option<WeightUnit>),
+    Reps,
+    Rpe,
+    Rir,
+    Seconds,
+    /// Distance, in metres per unit when known
+    Distance(Option<f64>),
+    WeightUnit,
+    DistanceUnit,
+}
+
+/// Lowercases a header and joins its words with `_`, so `Weight (kg)`,
+/// `weight_kg` and `Weight KG` all read `weight_kg`.
+fn normalize_header(header: &str) -> String {
+    header
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn detect_source(headers: &[String]) -> ImportSource {
+    let has = |name: &str| headers.iter().any(|h| h == name);
+    if has("exercise_name") && has("set_order") {
+        ImportSource::Strong
+    } else if has("exercise_title") && has("set_index") {
+        ImportSource::Hevy
+    } else {
+        ImportSource::Generic
+    }
+}
+
+fn classify(source: ImportSource, header: &str) -> Option<Field> {
+    // Units and quantities every layout spells the same way.
+    let common = match header {
+        "weight" => Some(Field::Weight(None)),
+        "weight_kg" | "weight_kgs" => Some(Field::Weight(Some(WeightUnit::Kg))),
+        "weight_lb" | "weight_lbs" => Some(Field::Weight(Some(WeightUnit::Lb))),
+        "reps" | "repetitions" => Some(Field::Reps),
+        "rpe" => Some(Field::Rpe),
+        "rir" => Some(Field::Rir),
+        "distance_m" | "distance_meters" | "distance_metres" => Some(Field::Distance(Some(1.0))),
+        "distance_km" => Some(Field::Distance(Some(METRES_PER_KM))),
+        "distance_mi" | "distance_miles" => Some(Field::Distance(Some(METRES_PER_MILE))),
+        "weight_unit" => Some(Field::WeightUnit),
+        "distance_unit" => Some(Field::DistanceUnit),
+        _ => None,
+    };
+    if common.is_some() {
+        return common;
+    }
+    match source {
+        // Strong's `Duration` is the whole workout's; `Seconds` is the set's.
+        ImportSource::Strong => match header {
+            "date" => Some(Field::Date),
+            "exercise_name" => Some(Field::Exercise),
+            "set_order" => Some(Field::Kind),
+            "seconds" => Some(Field::Seconds),
+            "distance" => Some(Field::Distance(None)),
+            _ => None,
+        },
+        ImportSource::Hevy => match header {
+            "start_time" => Some(Field::Date),
+            "exercise_title" => Some(Field::Exercise),
+            "set_type" => Some(Field::Kind),
+            "duration_seconds" => Some(Field::Seconds),
+            _ => None,
+        },
+        // `set_type` is deliberately not a kind here: our own export uses it
+        // for the exercise type.
+        ImportSource::Generic => match header {
+            "exercise" | "exercise_name" | "exercise_title" | "name" => Some(Field::Exercise),
+            "recorded_at" | "date" | "datetime" | "timestamp" | "time" | "start_time" => {
+                Some(Field::Date)
+            }
+            "kind" | "set_kind" => Some(Field::Kind),
+            "duration" | "duration_s" | "duration_secs" | "duration_seconds" | "seconds" => {
+                Some(Field::Seconds)
+            }
+            "distance" => Some(Field::Distance(Some(1.0))),
+            "bodyweight" => Some(Field::Bodyweight(None)),
+            "bodyweight_kg" => Some(Field::Bodyweight(Some(WeightUnit::Kg))),
+            "bodyweight_lb" | "bodyweight_lbs" => Some(Field::Bodyweight(Some(WeightUnit::Lb))),
+            "load" => Some(Field::Weight(None)),
+            "load_kg" => Some(Field::Weight(Some(WeightUnit::Kg))),
+            "load_lb" | "load_lbs" => Some(Field::Weight(Some(WeightUnit::Lb))),
+            "unit" => Some(Field::WeightUnit),
+            _ => None,
+        },
+    }
+}
+
+/// Picks the delimiter that splits the header line the most: comma, or the
+/// semicolon / tab that spreadsheet locales with a decimal comma use.
+fn detect_delimiter(text: &str) -> char {
+    let header = text.lines().next().unwrap_or("");
+    [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| header.matches(*d).count())
+        .unwrap_or(',')
+}
+
+/// Splits RFC 4180 text into records, each with the line it starts on.
+/// Quoted fields may contain the delimiter, doubled quotes and newlines.
+fn parse_csv(text: &str, delimiter: char) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut start_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push((start_line, std::mem::take(&mut record)));
+                line += 1;
+                start_line = line;
+            }
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((start_line, record));
+    }
+    records
+        .into_iter()
+        .filter(|(_, r)| r.iter().any(|f| !f.trim().is_empty()))
+        .collect()
+}
+
+/// Parses a number, accepting a decimal comma.  Empty cells are `Ok(None)`.
+fn parse_number(value: &str) -> Result<Option<f64>, ()> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.replace(',', ".").parse::<f64>() {
+        Ok(n) if n.is_finite() => Ok(Some(n)),
+        _ => Err(()),
+    }
+}
+
+fn parse_time(value: &str) -> Option<ImportTime> {
+    const FORMATS: [&str; 6] = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+        // Hevy: `25 Jan 2024, 18:30`
+        "%d %b %Y, %H:%M",
+        "%d %b %Y %H:%M",
+    ];
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(ImportTime::Instant(dt.timestamp_millis() as f64));
+    }
+    FORMATS
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+        .or_else(|| {
+            // A bare date is taken as local noon, like the history editor.
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .map(|d| d.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default()))
+        })
+        .map(ImportTime::Local)
+}
+
+/// Reads a set-kind cell: Strong's `W` / `D` / `F` or set number, Hevy's
+/// `warmup` / `dropset` / `failure` / `normal`, or our own kind names.
+/// Returns the kind and whether the set was marked as taken to failure.
+fn parse_kind(value: &str) -> Option<(SetKind, bool)> {
+    let key: String = value
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect();
+    if key.chars().all(|c| c.is_ascii_digit()) {
+        return Some((SetKind::Working, false));
+    }
+    match key.as_str() {
+        "normal" | "working" | "work" => Some((SetKind::Working, false)),
+        "w" | "warmup" => Some((SetKind::Warmup, false)),
+        "d" | "drop" | "dropset" => Some((SetKind::Drop, false)),
+        "f" | "failure" => Some((SetKind::Working, true)),
+        "backoff" => Some((SetKind::BackOff, false)),
+        "amrap" => Some((SetKind::Amrap, false)),
+        _ => None,
+    }
+}
+
+fn parse_weight_unit(value: &str) -> Option<WeightUnit> {
+    match value.trim().to_lowercase().as_str() {
+        "kg" | "kgs" => Some(WeightUnit::Kg),
+        "lb" | "lbs" => Some(WeightUnit::Lb),
+        _ => None,
+    }
+}
+
+fn parse_distance_unit(value: &str) -> Option<f64> {
+    match value.trim().to_lowercase().as_str() {
+        "m" | "meters" | "metres" => Some(1.0),
+        "km" => Some(METRES_PER_KM),
+        "mi" | "mile" | "miles" => Some(METRES_PER_MILE),
+        _ => None,
+    }
+}
+
+/// Rounds to the 0.5 RPE grid.
+fn round_rpe(rpe: f64) -> f32 {
+    ((rpe * 2.0).round() / 2.0) as f32
+}
+
+/// Undoes the formula-injection guard of our own CSV export.
+fn clean_name(value: &str) -> String {
+    let name = value.trim();
+    match name.strip_prefix('\'') {
+        Some(rest) if rest.starts_with(['=', '+', '-', '@']) => rest.to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Reads an export file.  `default_unit` applies to weights the file gives
+/// no unit for.  Rows that can't be read are reported in `skipped`; Strong's
+/// rest-timer rows are dropped silently.  Rows sharing a timestamp (Strong
+/// stamps every set with the workout's start) are spread a second apart in
+/// file order so history keeps their order.
+pub fn parse(text: &str, default_unit: WeightUnit) -> Result<ParsedImport, ImportError> {
+    let text = text.trim_start_matches('\u{feff}');
+    let delimiter = detect_delimiter(text);
+    let mut records = parse_csv(text, delimiter).into_iter();
+    let (_, header) = records.next().ok_or(ImportError::Empty)?;
+    let headers: Vec<String> = header.iter().map(|h| normalize_header(h)).collect();
+    let source = detect_source(&headers);
+
+    let mut columns: Vec<(usize, Field)> = Vec::new();
+    for (i, h) in headers.iter().enumerate() {
+        if let Some(field) = classify(source, h) {
+            // The first column of each meaning wins.
+            if !columns.iter().any(|(_, f)| *f == field) {
+                columns.push((i, field));
+            }
+        }
+    }
+    let find = |pred: fn(&Field) -> bool| columns.iter().find(|(_, f)| pred(f)).copied();
+    if find(|f| *f == Field::Exercise).is_none() {
+        return Err(ImportError::MissingColumn("exercise"));
+    }
+    if find(|f| *f == Field::Date).is_none() {
+        return Err(ImportError::MissingColumn("date"));
+    }
+    let unit_specified = find(|f| *f == Field::WeightUnit).is_some()
+        || find(|f| matches!(f, Field::Weight(Some(_)))).is_some()
+        || find(|f| matches!(f, Field::Weight(None))).is_none();
+    // Strong's legacy unitless distance follows the app's unit system.
+    let default_distance = match (source, default_unit) {
+        (ImportSource::Strong, WeightUnit::Kg) => METRES_PER_KM,
+        (ImportSource::Strong, WeightUnit::Lb) => METRES_PER_MILE,
+        _ => 1.0,
+    };
+
+    let mut rows = Vec::new();
+    let mut skipped = Vec::new();
+    let mut same_time: HashMap<u64, i64> = HashMap::new();
+    for (line, record) in records {
+        let cell = |field: Field| {
+            columns
+                .iter()
+                .find(|(_, f)| *f == field)
+                .and_then(|(i, _)| record.get(*i))
+                .map(|v| v.trim())
+                .unwrap_or("")
+        };
+        match read_row(line, &columns, &record, default_unit, default_distance) {
+            Ok(Some(mut row)) => {
+                let offset = same_time
+                    .entry(row.recorded_at.sort_key().to_bits())
+                    .or_insert(0);
+                row.recorded_at = row.recorded_at.plus_secs(*offset);
+                *offset += 1;
+                rows.push(row);
+            }
+            Ok(None) => {}
+            Err(reason) => {
+                let exercise = cell(Field::Exercise);
+                skipped.push(SkippedRow {
+                    line,
+                    reason: if exercise.is_empty() {
+                        reason
+                    } else {
+                        format!("{}: {}", exercise, reason)
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(ParsedImport {
+        source,
+        rows,
+        skipped,
+        unit_specified,
+    })
+}
+
+/// Reads one record; `Ok(None)` for rows that are not sets.
+fn read_row(
+    line: usize,
+    columns: &[(usize, Field)],
+    record: &[String],
+    default_unit: WeightUnit,
+    default_distance: f64,
+) -> Result<Option<SourceRow>, String> {
+    let mut name = String::new();
+    let mut time = None;
+    let mut kind = (SetKind::Working, false);
+    let mut weight = None;
+    let mut bodyweight = None;
+    let mut weight_unit = None;
+    let mut distance = None;
+    let mut distance_unit = None;
+    let mut reps = None;
+    let mut rpe = None;
+    let mut rir = None;
+    let mut seconds = None;
+
+    for (i, field) in columns {
+        let value = record.get(*i).map(|v| v.trim()).unwrap_or("");
+        let number =
+            |label: &str| parse_number(value).map_err(|_| format!("invalid {} '{}'", label, value));
+        match field {
+            Field::Exercise => name = clean_name(value),
+            Field::Date => {
+                time = Some(
+                    parse_time(value).ok_or_else(|| format!("unrecognised date '{}'", value))?,
+                )
+            }
+            Field::Kind if value.eq_ignore_ascii_case("rest timer") => return Ok(None),
+            Field::Kind if value.is_empty() => {}
+            Field::Kind => {
+                kind = parse_kind(value).ok_or_else(|| format!("unknown set type '{}'", value))?
+            }
+            Field::Weight(unit) => weight = number("weight")?.map(|w| (w, *unit)),
+            Field::Bodyweight(unit) => bodyweight = number("bodyweight")?.map(|w| (w, *unit)),
+            Field::WeightUnit => weight_unit = parse_weight_unit(value),
+            Field::Distance(unit) => distance = number("distance")?.map(|d| (d, *unit)),
+            Field::DistanceUnit => distance_unit = parse_distance_unit(value),
+            Field::Reps => reps = number("reps")?,
+            Field::Rpe => rpe = number("RPE")?,
+            Field::Rir => rir = number("RIR")?,
+            Field::Seconds => seconds = number("time")?,
+        }
+    }
+
+    if name.is_empty() {
+        return Err("missing exercise name".to_string());
+    }
+    let recorded_at = time.ok_or("missing date")?;
+    let to_kg = |(w, unit): (f64, Option<WeightUnit>)| {
+        unit.or(weight_unit).unwrap_or(default_unit).to_kg(w) as f32
+    };
+    let rpe = match (rpe, rir) {
+        (Some(rpe), _) => {
+            let rounded = round_rpe(rpe);
+            if !(1.0..=10.0).contains(&rounded) {
+                return Err(format!("RPE {} is outside 1–10", rpe));
+            }
+            Some(rounded)
+        }
+        (None, Some(rir)) if rir >= 0.0 => Some(round_rpe(10.0 - rir).max(1.0)),
+        (None, Some(rir)) => return Err(format!("invalid RIR '{}'", rir)),
+        (None, None) => None,
+    };
+    let positive = |n: Option<f64>| n.filter(|n| *n > 0.0);
+    let row = SourceRow {
+        line,
+        exercise_name: name,
+        recorded_at,
+        kind: kind.0,
+        failure: kind.1,
+        weight_kg: weight.map(to_kg),
+        bodyweight_kg: bodyweight.map(to_kg).filter(|bw| *bw > 0.0),
+        reps: positive(reps).map(|r| r.round() as u32),
+        rpe,
+        duration_secs: positive(seconds).map(|s| s.round() as u32),
+        distance_m: distance
+            .filter(|(d, _)| *d > 0.0)
+            .map(|(d, unit)| (d * unit.or(distance_unit).unwrap_or(default_distance)) as f32),
+    };
+    if row.reps.is_none() && row.duration_secs.is_none() && row.distance_m.is_none() {
+        return Err("no reps, time or distance".to_string());
+    }
+    Ok(Some(row))
+}
+
+/// Library exercise an imported exercise name maps to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExerciseTarget {
+    /// An exercise already in the library
+    Existing(ExerciseMetadata),
+    /// An exercise the import creates, configured from its rows
+    New(ExerciseMetadata),
+}
+
+impl ExerciseTarget {
+    pub fn exercise(&self) -> &ExerciseMetadata {
+        match self {
+            ExerciseTarget::Existing(e) | ExerciseTarget::New(e) => e,
+        }
+    }
+
+    pub fn is_new(&self) -> bool {
+        matches!(self, ExerciseTarget::New(_))
+    }
+}
+
+/// A set ready to be logged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlannedSet {
+    pub recorded_at: ImportTime,
+    pub set: CompletedSet,
+}
+
+/// Everything imported under one source exercise name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExerciseMapping {
+    pub source_name: String,
+    pub target: ExerciseTarget,
+    pub sets: Vec<PlannedSet>,
+}
+
+/// The dry run of an import: what would be created and logged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportPlan {
+    pub source: ImportSource,
+    pub mappings: Vec<ExerciseMapping>,
+    pub skipped: Vec<SkippedRow>,
+    /// Sets with neither RPE nor RIR, logged at the default RPE
+    pub defaulted_rpe: usize,
+}
+
+impl ImportPlan {
+    pub fn set_count(&self) -> usize {
+        self.mappings.iter().map(|m| m.sets.len()).sum()
+    }
+
+    pub fn new_exercise_count(&self) -> usize {
+        self.mappings.iter().filter(|m| m.target.is_new()).count()
+    }
+}
+
+/// What a re-import is recognised by: a set's time, reps and logged weight
+/// (the added weight of a loaded bodyweight set).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoggedSet {
+    /// Unix milliseconds
+    pub recorded_at: f64,
+    pub reps: u32,
+    pub weight: Option<f32>,
+}
+
+impl LoggedSet {
+    pub fn of(set: &CompletedSet, recorded_at: f64) -> Self {
+        let weight = match set.set_type {
+            SetType::LoadedBodyweight { added, .. } => Some(added),
+            ref set_type => set_type.weight(),
+        };
+        LoggedSet {
+            recorded_at,
+            reps: set.reps,
+            weight,
+        }
+    }
+
+    fn matches(&self, other: &LoggedSet) -> bool {
+        (self.recorded_at - other.recorded_at).abs() < 1.0
+            && self.reps == other.reps
+            && match (self.weight, other.weight) {
+                (Some(a), Some(b)) => (a - b).abs() < 0.001,
+                (a, b) => a.is_none() && b.is_none(),
+            }
+    }
+}
+
+/// Which of `planned` are already among one exercise's `logged` sets.  Each
+/// logged set accounts for one planned set, so identical sets of a workout
+/// are only skipped as often as they were logged.
+pub fn already_logged(planned: &[LoggedSet], logged: &[LoggedSet]) -> Vec<bool> {
+    let mut used = vec![false; logged.len()];
+    planned
+        .iter()
+        .map(|set| {
+            let found = logged
+                .iter()
+                .zip(used.iter_mut())
+                .find(|(l, used)| !**used && l.matches(set));
+            found.map(|(_, used)| *used = true).is_some()
+        })
+        .collect()
+}
+
+/// Matching key for exercise names: lowercase letters and digits only, so
+/// `Pull-Up` and `pull up` agree.
+fn name_key(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// Strips a trailing equipment qualifier such as `(Barbell)`.
+fn without_qualifier(name: &str) -> &str {
+    match name.trim_end().strip_suffix(')').and_then(|n| n.rfind('(')) {
+        Some(open) => name[..open].trim_end(),
+        None => name,
+    }
+}
+
+fn find_existing<'a>(name: &str, existing: &'a [ExerciseMetadata]) -> Option<&'a ExerciseMetadata> {
+    [name, without_qualifier(name)]
+        .iter()
+        .find_map(|candidate| {
+            let key = name_key(candidate);
+            existing.iter().find(|e| name_key(&e.name) == key)
+        })
+}
+
+/// Default weight step for a created exercise: the exercise form's default.
+fn default_increment(unit: WeightUnit) -> f32 {
+    match unit {
+        WeightUnit::Kg => 2.5,
+        WeightUnit::Lb => unit.to_kg(5.0) as f32,
+    }
+}
+
+/// Configures a new exercise from what its rows record.
+fn infer_config(rows: &[SourceRow], unit: WeightUnit) -> SetTypeConfig {
+    let loaded = rows.iter().any(|r| r.weight_kg.is_some_and(|w| w > 0.0));
+    let increment = default_increment(unit);
+    // 5 s or 5 m, as in the exercise form.
+    let measure_step = 5.0;
+    if rows.iter().any(|r| r.bodyweight_kg.is_some()) {
+        SetTypeConfig::LoadedBodyweight {
+            increment,
+            assisted: rows.iter().any(|r| r.weight_kg.is_some_and(|w| w < 0.0)),
+        }
+    } else if rows.iter().any(|r| r.reps.is_some()) {
+        if loaded {
+            SetTypeConfig::Weighted {
+                min_weight: 0.0,
+                increment,
+                bar_weight: None,
+            }
+        } else {
+            SetTypeConfig::Bodyweight
+        }
+    } else if rows.iter().any(|r| r.distance_m.is_some()) {
+        if loaded {
+            SetTypeConfig::Carry {
+                min_weight: 0.0,
+                increment,
+                increment_m: measure_step,
+            }
+        } else {
+            SetTypeConfig::Distance {
+                increment_m: measure_step,
+            }
+        }
+    } else {
+        SetTypeConfig::Timed {
+            increment_secs: measure_step as u32,
+        }
+    }
+}
+
+/// Builds the reps and set type of a row logged against `config`; reasons
+/// for skipping it give loads in `unit`.
+fn row_set_type(
+    row: &SourceRow,
+    config: &SetTypeConfig,
+    unit: WeightUnit,
+) -> Result<(u32, SetType), String> {
+    let reps = || row.reps.ok_or_else(|| "no reps".to_string());
+    let weight = || {
+        let weight = row.weight_kg.unwrap_or(0.0);
+        if (0.0..=MAX_WEIGHT).contains(&weight) {
+            Ok(weight)
+        } else {
+            Err(format!(
+                "weight {:.1} {} is out of range",
+                unit.from_kg(weight as f64),
+                unit
+            ))
+        }
+    };
+    let duration = || row.duration_secs.ok_or_else(|| "no time".to_string());
+    let distance = || row.distance_m.ok_or_else(|| "no distance".to_string());
+    let (reps, set_type) = match config {
+        SetTypeConfig::Weighted { .. } => (reps()?, SetType::Weighted { weight: weight()? }),
+        SetTypeConfig::Bodyweight => (reps()?, SetType::Bodyweight),
+        SetTypeConfig::LoadedBodyweight { .. } => {
+            let bodyweight = row
+                .bodyweight_kg
+                .ok_or_else(|| "no bodyweight recorded for a loaded bodyweight set".to_string())?;
+            validate_bodyweight(bodyweight).map_err(|e| e.describe(unit))?;
+            let set_type = SetType::LoadedBodyweight {
+                bodyweight,
+                added: row.weight_kg.unwrap_or(0.0),
+            };
+            (reps()?, set_type)
+        }
+        SetTypeConfig::Timed { .. } => {
+            let duration_secs = duration()?;
+            validate_duration(duration_secs).map_err(|e| e.to_string())?;
+            (1, SetType::Timed { duration_secs })
+        }
+        SetTypeConfig::Distance { .. } => {
+            let distance_m = distance()?;
+            validate_distance(distance_m).map_err(|e| e.to_string())?;
+            (1, SetType::Distance { distance_m })
+        }
+        SetTypeConfig::Carry { .. } => {
+            let distance_m = distance()?;
+            validate_distance(distance_m).map_err(|e| e.to_string())?;
+            let weight = weight()?;
+            (1, SetType::Carry { weight, distance_m })
+        }
+    };
+    validate_reps(reps).map_err(|e| e.to_string())?;
+    Ok((reps, set_type))
+}
+
+/// Maps parsed rows onto the exercise library without writing anything.
+///
+/// Names match case- and punctuation-insensitively, first as written and then
+/// without a trailing `(Equipment)` qualifier; unmatched names become new
+/// exercises configured from their rows, with `unit`'s default increment.
+/// Rows that don't fit their exercise's type are skipped.  Sets without RPE
+/// or RIR are logged at `default_rpe` (RPE 10 when marked as failure), and
+/// are numbered per exercise per day in time order.
+pub fn plan_import(
+    parsed: ParsedImport,
+    existing: &[ExerciseMetadata],
+    default_rpe: f32,
+    unit: WeightUnit,
+) -> ImportPlan {
+    let mut groups: Vec<(String, Vec<SourceRow>)> = Vec::new();
+    for row in parsed.rows {
+        let key = name_key(&row.exercise_name);
+        match groups.iter_mut().find(|(name, _)| name_key(name) == key) {
+            Some((_, rows)) => rows.push(row),
+            None => groups.push((row.exercise_name.clone(), vec![row])),
+        }
+    }
+
+    let mut skipped = parsed.skipped;
+    let mut defaulted_rpe = 0;
+    let mut mappings = Vec::new();
+    for (source_name, mut rows) in groups {
+        let target = match find_existing(&source_name, existing) {
+            Some(exercise) => ExerciseTarget::Existing(exercise.clone()),
+            None => ExerciseTarget::New(ExerciseMetadata {
+                id: None,
+                name: source_name
+                    .chars()
+                    .filter(|c| !matches!(c, '<' | '>'))
+                    .take(MAX_EXERCISE_NAME_LENGTH)
+                    .collect::<String>()
+                    .trim()
+                    .to_string(),
+                set_type_config: infer_config(&rows, unit),
+                min_reps: 1,
+                max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            }),
+        };
+
+        rows.sort_by(|a, b| {
+            a.recorded_at
+                .sort_key()
+                .total_cmp(&b.recorded_at.sort_key())
+                .then(a.line.cmp(&b.line))
+        });
+        let config = &target.exercise().set_type_config;
+        let mut sets: Vec<PlannedSet> = Vec::new();
+        let mut last_day = None;
+        let mut set_number = 0;
+        for row in rows {
+            let (reps, set_type) = match row_set_type(&row, config, unit) {
+                Ok(set) => set,
+                Err(reason) => {
+                    skipped.push(SkippedRow {
+                        line: row.line,
+                        reason: format!("{}: {}", source_name, reason),
+                    });
+                    continue;
+                }
+            };
+            let day = row.recorded_at.day();
+            if last_day != Some(day) {
+                last_day = Some(day);
+                set_number = 0;
+            }
+            set_number += 1;
+            let rpe = match row.rpe {
+                Some(rpe) => rpe,
+                None if row.failure => 10.0,
+                None => {
+                    defaulted_rpe += 1;
+                    default_rpe
+                }
+            };
+            sets.push(PlannedSet {
+                recorded_at: row.recorded_at,
+                set: CompletedSet {
+                    set_number,
+                    reps,
+                    rpe,
+                    set_type,
+                    kind: row.kind,
+                    comment: String::new(),
+                },
+            });
+        }
+        if !sets.is_empty() {
+            mappings.push(ExerciseMapping {
+                source_name,
+                target,
+                sets,
+            });
+        }
+    }
+    skipped.sort_by_key(|s| s.line);
+
+    ImportPlan {
+        source: parsed.source,
+        mappings,
+        skipped,
+        defaulted_rpe,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(s: &str) -> ImportTime {
+        ImportTime::Local(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap())
+    }
+
+    fn bench() -> ExerciseMetadata {
+        ExerciseMetadata {
+            id: Some("bench-id".to_string()),
+            name: "Bench Press".to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: Some(20.0),
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        }
+    }
+
+    const STRONG: &str = "\
+Workout #,Date,Workout Name,Duration (sec),Exercise Name,Set Order,Weight (kg),Reps,RPE,Distance (meters),Seconds,Notes,Workout Notes
+1,2024-01-25 18:30:00,Push,3600,Bench Press (Barbell),W,40,10,,0,0,,
+1,2024-01-25 18:30:00,Push,3600,Bench Press (Barbell),1,80,5,8,0,0,,
+1,2024-01-25 18:30:00,Push,3600,Bench Press (Barbell),Rest Timer,0,0,,0,90,,
+1,2024-01-25 18:30:00,Push,3600,Bench Press (Barbell),F,80,7,,0,0,,
+1,2024-01-25 18:30:00,Push,3600,Plank,1,0,0,,0,60,,
+1,2024-01-25 18:30:00,Push,3600,\"Farmer's Walk, Heavy\",1,32,0,,40,0,,
+";
+
+    #[test]
+    fn parses_strong_export() {
+        let parsed = parse(STRONG, WeightUnit::Lb).unwrap();
+        assert_eq!(parsed.source, ImportSource::Strong);
+        assert!(parsed.unit_specified);
+        assert!(parsed.skipped.is_empty());
+        assert_eq!(parsed.rows.len(), 5, "rest timer rows are dropped");
+
+        let warmup = &parsed.rows[0];
+        assert_eq!(warmup.line, 2);
+        assert_eq!(warmup.exercise_name, "Bench Press (Barbell)");
+        assert_eq!(warmup.kind, SetKind::Warmup);
+        assert_eq!(warmup.weight_kg, Some(40.0));
+        assert_eq!(warmup.reps, Some(10));
+        assert_eq!(warmup.recorded_at, local("2024-01-25 18:30:00"));
+
+        let working = &parsed.rows[1];
+        assert_eq!(working.rpe, Some(8.0));
+        assert_eq!(working.recorded_at, local("2024-01-25 18:30:01"));
+
+        assert!(parsed.rows[2].failure);
+        assert_eq!(parsed.rows[3].duration_secs, Some(60));
+        assert_eq!(parsed.rows[3].reps, None);
+        assert_eq!(parsed.rows[4].exercise_name, "Farmer's Walk, Heavy");
+        assert_eq!(parsed.rows[4].distance_m, Some(40.0));
+    }
+
+    #[test]
+    fn parses_legacy_strong_with_semicolons_and_decimal_commas() {
+        let text = "Date;Workout Name;Exercise Name;Set Order;Weight;Reps;Distance;Seconds;Notes;Workout Notes;RPE\n\
+                    2020-03-01 09:00:00;Legs;Squat;1;135,5;5;0;0;;;7,5\n\
+                    2020-03-01 09:00:00;Legs;Rowing;1;0;0;1,5;0;;;\n";
+        let parsed = parse(text, WeightUnit::Lb).unwrap();
+        assert_eq!(parsed.source, ImportSource::Strong);
+        assert!(!parsed.unit_specified);
+        let squat = &parsed.rows[0];
+        assert!((squat.weight_kg.unwrap() - 61.462).abs() < 0.01);
+        assert_eq!(squat.rpe, Some(7.5));
+        // Unitless Strong distance follows the unit system: miles for lb.
+        assert!((parsed.rows[1].distance_m.unwrap() - 2414.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn parses_hevy_export() {
+        let text = "\
+title,start_time,end_time,description,exercise_title,superset_id,exercise_notes,set_index,set_type,weight_lbs,reps,distance_miles,duration_seconds,rpe
+Upper,\"25 Jan 2024, 18:30\",\"25 Jan 2024, 19:30\",,Pull Up,,,0,warmup,,8,,,
+Upper,\"25 Jan 2024, 18:30\",\"25 Jan 2024, 19:30\",,Pull Up,,,1,dropset,,6,,,9.5
+Upper,\"25 Jan 2024, 18:30\",\"25 Jan 2024, 19:30\",,Curl,,,0,normal,45,10,,,8.3
+";
+        let parsed = parse(text, WeightUnit::Kg).unwrap();
+        assert_eq!(parsed.source, ImportSource::Hevy);
+        assert!(parsed.unit_specified);
+        assert_eq!(parsed.rows[0].kind, SetKind::Warmup);
+        assert_eq!(parsed.rows[0].recorded_at, local("2024-01-25 18:30:00"));
+        assert_eq!(parsed.rows[1].kind, SetKind::Drop);
+        assert_eq!(parsed.rows[1].rpe, Some(9.5));
+        assert!((parsed.rows[2].weight_kg.unwrap() - 20.412).abs() < 0.01);
+        assert_eq!(parsed.rows[2].rpe, Some(8.5), "RPE snaps to the 0.5 grid");
+    }
+
+    #[test]
+    fn generic_csv_converts_rir_and_reports_bad_rows() {
+        let text = "Name,Date,Load (lbs),Reps,RIR\n\
+                    Squat,2024-02-01,225,5,2\n\
+                    Squat,yesterday,225,5,2\n\
+                    Squat,2024-02-01,heavy,5,2\n\
+                    ,2024-02-01,225,5,2\n\
+                    Squat,2024-02-01,225,,\n";
+        let parsed = parse(text, WeightUnit::Kg).unwrap();
+        assert_eq!(parsed.source, ImportSource::Generic);
+        assert_eq!(parsed.rows.len(), 1);
+        assert_eq!(parsed.rows[0].rpe, Some(8.0));
+        assert_eq!(parsed.rows[0].recorded_at, local("2024-02-01 12:00:00"));
+        let reasons: Vec<_> = parsed
+            .skipped
+            .iter()
+            .map(|s| (s.line, s.reason.as_str()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                (3, "Squat: unrecognised date 'yesterday'"),
+                (4, "Squat: invalid weight 'heavy'"),
+                (5, "missing exercise name"),
+                (6, "Squat: no reps, time or distance"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_our_own_history_export() {
+        let text = "exercise,set_type,kind,weight_kg,bodyweight_kg,reps,duration_s,distance_m,rpe,e1rm_kg,plan_id,recorded_at\r\n\
+                    '=Weird,weighted,backoff,100,,5,,,8,,,2024-01-25T18:30:00+01:00\r\n\
+                    Dip,loaded_bodyweight,working,10,80,8,,,9,,,2024-01-25T18:40:00+01:00\r\n";
+        let parsed = parse(text, WeightUnit::Lb).unwrap();
+        assert_eq!(parsed.source, ImportSource::Generic);
+        assert_eq!(parsed.rows[0].exercise_name, "=Weird");
+        assert_eq!(parsed.rows[0].kind, SetKind::BackOff);
+        assert_eq!(parsed.rows[0].weight_kg, Some(100.0));
+        assert_eq!(
+            parsed.rows[0].recorded_at,
+            ImportTime::Instant(1_706_203_800_000.0)
+        );
+        assert_eq!(parsed.rows[1].bodyweight_kg, Some(80.0));
+    }
+
+    #[test]
+    fn rejects_files_without_exercise_or_date() {
+        assert_eq!(parse("", WeightUnit::Kg), Err(ImportError::Empty));
+        assert_eq!(
+            parse("date,reps\n2024-01-01,5\n", WeightUnit::Kg),
+            Err(ImportError::MissingColumn("exercise"))
+        );
+        assert_eq!(
+            parse("exercise,reps\nSquat,5\n", WeightUnit::Kg),
+            Err(ImportError::MissingColumn("date"))
+        );
+    }
+
+    #[test]
+    fn plan_maps_names_and_infers_new_exercises() {
+        let parsed = parse(STRONG, WeightUnit::Kg).unwrap();
+        let plan = plan_import(parsed, &[bench()], 7.0, WeightUnit::Kg);
+        assert_eq!(plan.source, ImportSource::Strong);
+        assert_eq!(plan.mappings.len(), 3);
+        assert_eq!(plan.new_exercise_count(), 2);
+        assert_eq!(plan.set_count(), 5);
+
+        let bench_map = &plan.mappings[0];
+        assert_eq!(bench_map.source_name, "Bench Press (Barbell)");
+        assert_eq!(bench_map.target, ExerciseTarget::Existing(bench()));
+        let numbers: Vec<_> = bench_map.sets.iter().map(|s| s.set.set_number).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        let rpes: Vec<_> = bench_map.sets.iter().map(|s| s.set.rpe).collect();
+        assert_eq!(rpes, vec![7.0, 8.0, 10.0], "default, given, failure");
+        assert_eq!(plan.defaulted_rpe, 3, "warm-up, plank and carry");
+
+        let plank = plan.mappings[1].target.exercise();
+        assert!(plan.mappings[1].target.is_new());
+        assert_eq!(
+            plank.set_type_config,
+            SetTypeConfig::Timed { increment_secs: 5 }
+        );
+        assert_eq!(
+            plan.mappings[1].sets[0].set.set_type,
+            SetType::Timed { duration_secs: 60 }
+        );
+        assert_eq!(
+            plan.mappings[2].target.exercise().set_type_config,
+            SetTypeConfig::Carry {
+                min_weight: 0.0,
+                increment: 2.5,
+                increment_m: 5.0
+            }
+        );
+    }
+
+    #[test]
+    fn plan_skips_rows_that_do_not_fit_the_existing_exercise() {
+        let text = "exercise,date,reps,duration_s\n\
+                    bench press,2024-01-01 10:00:00,5,\n\
+                    Bench-Press,2024-01-01 10:01:00,,30\n\
+                    Bench Press,2024-01-02 10:00:00,150,\n\
+                    Bench Press,2024-01-03 10:00:00,3,\n";
+        let parsed = parse(text, WeightUnit::Kg).unwrap();
+        let plan = plan_import(parsed, &[bench()], 8.0, WeightUnit::Kg);
+        assert_eq!(plan.mappings.len(), 1, "spellings are grouped together");
+        let sets = &plan.mappings[0].sets;
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].set.set_type, SetType::Weighted { weight: 0.0 });
+        assert_eq!(sets[1].set.set_number, 1, "numbering restarts each day");
+        let lines: Vec<_> = plan.skipped.iter().map(|s| s.line).collect();
+        assert_eq!(lines, vec![3, 4]);
+        assert_eq!(plan.skipped[0].reason, "bench press: no reps");
+    }
+
+    #[test]
+    fn already_logged_matches_each_logged_set_once() {
+        let set = |recorded_at, reps, weight| LoggedSet {
+            recorded_at,
+            reps,
+            weight,
+        };
+        let logged = [set(1000.0, 5, Some(100.0)), set(1000.0, 5, Some(100.0))];
+        let planned = [
+            set(1000.0, 5, Some(100.0)),
+            set(1000.0, 5, Some(100.0)),
+            set(1000.0, 5, Some(100.0)),
+            set(1000.0, 5, Some(102.5)),
+            set(2000.0, 5, Some(100.0)),
+            set(1000.0, 5, None),
+        ];
+        assert_eq!(
+            already_logged(&planned, &logged),
+            vec![true, true, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn logged_set_keys_loaded_bodyweight_by_added_weight() {
+        let set = CompletedSet {
+            set_number: 1,
+            reps: 8,
+            rpe: 8.0,
+            set_type: SetType::LoadedBodyweight {
+                bodyweight: 80.0,
+                added: 10.0,
+            },
+            kind: SetKind::Working,
+            comment: String::new(),
+        };
+        assert_eq!(LoggedSet::of(&set, 5.0).weight, Some(10.0));
+        let bodyweight = CompletedSet {
+            set_type: SetType::Bodyweight,
+            ..set
+        };
+        assert_eq!(LoggedSet::of(&bodyweight, 5.0).weight, None);
+    }
+
+    #[test]
+    fn new_exercise_config_follows_its_rows() {
+        let row = |weight, reps, distance| SourceRow {
+            line: 2,
+            exercise_name: "X".to_string(),
+            recorded_at: local("2024-01-01 10:00:00"),
+            kind: SetKind::Working,
+            failure: false,
+            weight_kg: weight,
+            bodyweight_kg: None,
+            reps,
+            rpe: None,
+            duration_secs: None,
+            distance_m: distance,
+        };
+        assert_eq!(
+            infer_config(&[row(None, Some(10), None)], WeightUnit::Kg),
+            SetTypeConfig::Bodyweight
+        );
+        assert_eq!(
+            infer_config(
+                &[
+                    row(Some(0.0), Some(10), None),
+                    row(Some(20.0), Some(8), None)
+                ],
+                WeightUnit::Lb
+            ),
+            SetTypeConfig::Weighted {
+                min_weight: 0.0,
+                increment: WeightUnit::Lb.to_kg(5.0) as f32,
+                bar_weight: None
+            }
+        );
+        assert_eq!(
+            infer_config(&[row(None, None, Some(500.0))], WeightUnit::Kg),
+            SetTypeConfig::Distance { increment_m: 5.0 }
+        );
+        let mut assisted = row(Some(-20.0), Some(8), None);
+        assisted.bodyweight_kg = Some(80.0);
+        assert_eq!(
+            infer_config(&[assisted], WeightUnit::Kg),
+            SetTypeConfig::LoadedBodyweight {
+                increment: 2.5,
+                assisted: true
+            }
+        );
+    }
+}
@@ -1,8 +1,11 @@
 pub mod app;
 pub mod components;
 pub mod domain;
+pub mod export;
 pub mod format;
+pub mod import;
 pub mod log_buffer;
+pub mod merge;
 pub mod models;
 pub mod state;
 pub mod sync;
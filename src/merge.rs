@@ -0,0 +1,488 @@
+//! Merge-import of another copy of the workout database.
+//!
+//! `Database::import` replaces everything; a merge instead unions the incoming
+//! rows with the local ones.  Rows are matched by their sync id and, where both
+//! sides have a row, the one with the later `updated_at` / `deleted_at` wins,
+//! as in CRR sync.  The plan is computed from plain row maps so the summary
+//! shown before committing is exactly what gets written, and is unit-tested
+//! natively; reading and writing live in `state::db`.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A table row keyed by column name, as returned by `execO` / sql.js.
+pub type Row = serde_json::Map<String, Value>;
+
+/// A table taking part in a merge and the column its rows are matched on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MergeTable {
+    pub name: &'static str,
+    /// Plural noun for the summary
+    pub label: &'static str,
+    pub key: &'static str,
+}
+
+/// Merged tables, parents before children so inserted children never point
+/// at a row that isn't there yet.  `settings` is per device and is left alone;
+/// `exercise_muscle_groups` follows its exercise (see [`MergePlan`]).
+pub const MERGE_TABLES: [MergeTable; 11] = [
+    MergeTable {
+        name: "exercises",
+        label: "Exercises",
+        key: "uuid",
+    },
+    MergeTable {
+        name: "completed_sets",
+        label: "Sets",
+        key: "uuid",
+    },
+    MergeTable {
+        name: "workout_plans",
+        label: "Plans",
+        key: "id",
+    },
+    MergeTable {
+        name: "workout_plan_exercises",
+        label: "Plan exercises",
+        key: "id",
+    },
+    MergeTable {
+        name: "workout_templates",
+        label: "Templates",
+        key: "id",
+    },
+    MergeTable {
+        name: "workout_template_exercises",
+        label: "Template exercises",
+        key: "id",
+    },
+    MergeTable {
+        name: "programs",
+        label: "Programs",
+        key: "id",
+    },
+    MergeTable {
+        name: "program_days",
+        label: "Program days",
+        key: "id",
+    },
+    MergeTable {
+        name: "plates",
+        label: "Plates",
+        key: "id",
+    },
+    MergeTable {
+        name: "bodyweight_log",
+        label: "Bodyweight entries",
+        key: "id",
+    },
+    MergeTable {
+        name: "personal_records",
+        label: "Personal records",
+        key: "id",
+    },
+];
+
+/// Muscle-group join table, copied along with the exercises a merge takes.
+pub const MUSCLE_GROUP_TABLE: &str = "exercise_muscle_groups";
+
+/// Columns and rows of a local table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalTable {
+    pub columns: Vec<String>,
+    pub rows: Vec<Row>,
+}
+
+/// A write the merge will make.  Rows hold only columns the local table has.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RowChange {
+    Insert {
+        table: &'static str,
+        row: Row,
+    },
+    /// Overwrite the local row whose `column` equals `id`
+    Update {
+        table: &'static str,
+        column: &'static str,
+        id: Value,
+        row: Row,
+    },
+}
+
+/// Per-table counts for the summary.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableSummary {
+    pub label: &'static str,
+    /// Incoming rows this device doesn't have
+    pub added: usize,
+    /// Rows both have where the incoming copy is newer
+    pub updated: usize,
+    /// Rows where the local copy is as new or newer, and incoming tombstones
+    /// for rows this device never had
+    pub skipped: usize,
+}
+
+/// Everything a merge would write, computed before anything is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergePlan {
+    pub changes: Vec<RowChange>,
+    pub summary: Vec<TableSummary>,
+    /// Muscle groups to set on each exercise the merge adds or updates, when
+    /// the incoming file has any for it.
+    pub muscle_groups: Vec<(String, Vec<Row>)>,
+}
+
+impl MergePlan {
+    /// True when the merge would change nothing.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+fn number(row: &Row, column: &str) -> f64 {
+    row.get(column).and_then(Value::as_f64).unwrap_or(0.0)
+}
+
+/// Last time the row changed: its update or its deletion, whichever is later.
+fn version(row: &Row) -> f64 {
+    number(row, "updated_at").max(number(row, "deleted_at"))
+}
+
+fn is_deleted(row: &Row) -> bool {
+    row.get("deleted_at").is_some_and(|v| !v.is_null())
+}
+
+/// Identity of a row across devices.  Sets logged before they had a uuid
+/// fall back to their exercise and timestamp; their integer ids are local.
+fn row_key(table: &MergeTable, row: &Row) -> Option<String> {
+    match row.get(table.key) {
+        Some(Value::String(s)) if !s.is_empty() => Some(s.clone()),
+        Some(Value::Number(n)) => Some(n.to_string()),
+        _ if table.name == "completed_sets" => {
+            let exercise = row.get("exercise_id")?.as_str()?;
+            Some(format!("{}@{}", exercise, number(row, "recorded_at")))
+        }
+        _ => None,
+    }
+}
+
+/// Keeps the local table's columns, minus `drop`.
+fn project(row: &Row, columns: &[String], drop: &[&str]) -> Row {
+    row.iter()
+        .filter(|(k, _)| columns.contains(k) && !drop.contains(&k.as_str()))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+/// Plans the merge of `incoming` (rows by table name) into `local`.
+///
+/// An incoming row that matches no local row is added unless it is a
+/// tombstone; one that matches replaces the local row only when its version
+/// is strictly newer, so re-merging the same file is a no-op.  Columns the
+/// local schema lacks are dropped and columns the incoming file lacks keep
+/// their local values or defaults, so older and newer backups both merge.
+pub fn plan_merge(
+    local: &HashMap<String, LocalTable>,
+    incoming: &HashMap<String, Vec<Row>>,
+) -> MergePlan {
+    let empty = LocalTable::default();
+    let mut changes = Vec::new();
+    let mut summary = Vec::new();
+    let mut taken_exercises = Vec::new();
+
+    for table in MERGE_TABLES.iter() {
+        let local_table = local.get(table.name).unwrap_or(&empty);
+        let by_key: HashMap<String, &Row> = local_table
+            .rows
+            .iter()
+            .filter_map(|r| row_key(table, r).map(|k| (k, r)))
+            .collect();
+        // Integer set ids are per device, so inserts take a fresh one.
+        let drop: &[&str] = if table.name == "completed_sets" {
+            &["id"]
+        } else {
+            &[]
+        };
+        let mut counts = TableSummary {
+            label: table.label,
+            added: 0,
+            updated: 0,
+            skipped: 0,
+        };
+
+        for row in incoming.get(table.name).into_iter().flatten() {
+            let Some(key) = row_key(table, row) else {
+                counts.skipped += 1;
+                continue;
+            };
+            match by_key.get(&key) {
+                None if is_deleted(row) => counts.skipped += 1,
+                None => {
+                    counts.added += 1;
+                    if table.name == "exercises" {
+                        taken_exercises.push(key);
+                    }
+                    changes.push(RowChange::Insert {
+                        table: table.name,
+                        row: project(row, &local_table.columns, drop),
+                    });
+                }
+                Some(existing) if version(row) > version(existing) => {
+                    counts.updated += 1;
+                    if table.name == "exercises" {
+                        taken_exercises.push(key);
+                    }
+                    // Legacy sets are addressed by their local id.
+                    let (column, id) = match existing.get(table.key) {
+                        Some(Value::String(s)) if !s.is_empty() => {
+                            (table.key, Value::String(s.clone()))
+                        }
+                        _ => ("id", existing.get("id").cloned().unwrap_or(Value::Null)),
+                    };
+                    changes.push(RowChange::Update {
+                        table: table.name,
+                        column,
+                        id,
+                        row: project(row, &local_table.columns, &[table.key, "id"]),
+                    });
+                }
+                Some(_) => counts.skipped += 1,
+            }
+        }
+        summary.push(counts);
+    }
+
+    let incoming_groups = incoming
+        .get(MUSCLE_GROUP_TABLE)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let muscle_groups = taken_exercises
+        .into_iter()
+        .filter_map(|exercise_id| {
+            let rows: Vec<Row> = incoming_groups
+                .iter()
+                .filter(|r| r.get("exercise_id").and_then(Value::as_str) == Some(&exercise_id))
+                .cloned()
+                .collect();
+            (!rows.is_empty()).then_some((exercise_id, rows))
+        })
+        .collect();
+
+    MergePlan {
+        changes,
+        summary,
+        muscle_groups,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(value: Value) -> Row {
+        value.as_object().unwrap().clone()
+    }
+
+    fn local_table(columns: &[&str], rows: Vec<Value>) -> LocalTable {
+        LocalTable {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: rows.into_iter().map(row).collect(),
+        }
+    }
+
+    fn counts<'a>(plan: &'a MergePlan, label: &str) -> &'a TableSummary {
+        plan.summary.iter().find(|s| s.label == label).unwrap()
+    }
+
+    const EXERCISE_COLUMNS: [&str; 4] = ["uuid", "name", "updated_at", "deleted_at"];
+
+    #[test]
+    fn newer_incoming_rows_win_and_older_ones_are_skipped() {
+        let local = HashMap::from([(
+            "exercises".to_string(),
+            local_table(
+                &EXERCISE_COLUMNS,
+                vec![
+                    json!({"uuid": "a", "name": "Squat", "updated_at": 100, "deleted_at": null}),
+                    json!({"uuid": "b", "name": "Bench", "updated_at": 300, "deleted_at": null}),
+                ],
+            ),
+        )]);
+        let incoming = HashMap::from([(
+            "exercises".to_string(),
+            vec![
+                row(
+                    json!({"uuid": "a", "name": "Back Squat", "updated_at": 200, "deleted_at": null}),
+                ),
+                row(
+                    json!({"uuid": "b", "name": "Bench Press", "updated_at": 200, "deleted_at": null}),
+                ),
+                row(
+                    json!({"uuid": "c", "name": "Row", "updated_at": 50, "deleted_at": null, "extra": 1}),
+                ),
+            ],
+        )]);
+
+        let plan = plan_merge(&local, &incoming);
+        let exercises = counts(&plan, "Exercises");
+        assert_eq!(
+            (exercises.added, exercises.updated, exercises.skipped),
+            (1, 1, 1)
+        );
+        assert_eq!(
+            plan.changes,
+            vec![
+                RowChange::Update {
+                    table: "exercises",
+                    column: "uuid",
+                    id: json!("a"),
+                    row: row(json!({"name": "Back Squat", "updated_at": 200, "deleted_at": null})),
+                },
+                RowChange::Insert {
+                    table: "exercises",
+                    row: row(
+                        json!({"uuid": "c", "name": "Row", "updated_at": 50, "deleted_at": null})
+                    ),
+                },
+            ],
+            "columns the local schema lacks are dropped"
+        );
+    }
+
+    #[test]
+    fn deletions_count_as_changes_and_unknown_tombstones_are_skipped() {
+        let local = HashMap::from([(
+            "plates".to_string(),
+            local_table(
+                &["id", "weight", "updated_at", "deleted_at"],
+                vec![json!({"id": "p1", "weight": 20, "updated_at": 100, "deleted_at": null})],
+            ),
+        )]);
+        let incoming = HashMap::from([(
+            "plates".to_string(),
+            vec![
+                row(json!({"id": "p1", "weight": 20, "updated_at": 100, "deleted_at": 150})),
+                row(json!({"id": "p2", "weight": 5, "updated_at": 100, "deleted_at": 120})),
+            ],
+        )]);
+
+        let plan = plan_merge(&local, &incoming);
+        let plates = counts(&plan, "Plates");
+        assert_eq!((plates.added, plates.updated, plates.skipped), (0, 1, 1));
+        assert!(matches!(
+            &plan.changes[..],
+            [RowChange::Update { id, row, .. }] if *id == json!("p1") && row["deleted_at"] == json!(150)
+        ));
+    }
+
+    #[test]
+    fn sets_match_by_uuid_or_by_exercise_and_time_and_take_fresh_ids() {
+        let columns = [
+            "id",
+            "exercise_id",
+            "reps",
+            "recorded_at",
+            "uuid",
+            "updated_at",
+            "deleted_at",
+        ];
+        let local = HashMap::from([(
+            "completed_sets".to_string(),
+            local_table(
+                &columns,
+                vec![
+                    json!({"id": 1, "exercise_id": "a", "reps": 5, "recorded_at": 1000, "uuid": "s1", "updated_at": 1000, "deleted_at": null}),
+                    json!({"id": 2, "exercise_id": "a", "reps": 5, "recorded_at": 2000, "uuid": "", "updated_at": 2000, "deleted_at": null}),
+                ],
+            ),
+        )]);
+        let incoming = HashMap::from([(
+            "completed_sets".to_string(),
+            vec![
+                // Same set, same id on both phones by coincidence only.
+                row(
+                    json!({"id": 1, "exercise_id": "a", "reps": 5, "recorded_at": 1000, "uuid": "s1", "updated_at": 1000, "deleted_at": null}),
+                ),
+                // Legacy set edited on the other phone.
+                row(
+                    json!({"id": 7, "exercise_id": "a", "reps": 6, "recorded_at": 2000, "uuid": "", "updated_at": 2500, "deleted_at": null}),
+                ),
+                // A set logged only on the other phone, with a colliding id.
+                row(
+                    json!({"id": 2, "exercise_id": "a", "reps": 8, "recorded_at": 3000, "uuid": "s3", "updated_at": 3000, "deleted_at": null}),
+                ),
+            ],
+        )]);
+
+        let plan = plan_merge(&local, &incoming);
+        let sets = counts(&plan, "Sets");
+        assert_eq!((sets.added, sets.updated, sets.skipped), (1, 1, 1));
+        match &plan.changes[..] {
+            [
+                RowChange::Update {
+                    column, id, row: u, ..
+                },
+                RowChange::Insert { row: i, .. },
+            ] => {
+                assert_eq!((*column, id), ("id", &json!(2)));
+                assert_eq!(u["reps"], json!(6));
+                assert!(!u.contains_key("id"));
+                assert!(!i.contains_key("id"), "inserted sets get a fresh local id");
+                assert_eq!(i["uuid"], json!("s3"));
+            }
+            other => panic!("unexpected changes: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn merging_the_same_data_changes_nothing() {
+        let rows =
+            vec![json!({"uuid": "a", "name": "Squat", "updated_at": 100, "deleted_at": null})];
+        let local = HashMap::from([(
+            "exercises".to_string(),
+            local_table(&EXERCISE_COLUMNS, rows.clone()),
+        )]);
+        let incoming =
+            HashMap::from([("exercises".to_string(), rows.into_iter().map(row).collect())]);
+        let plan = plan_merge(&local, &incoming);
+        assert!(plan.is_empty());
+        assert_eq!(plan.summary.len(), MERGE_TABLES.len());
+    }
+
+    #[test]
+    fn muscle_groups_follow_taken_exercises() {
+        let local = HashMap::from([(
+            "exercises".to_string(),
+            local_table(
+                &EXERCISE_COLUMNS,
+                vec![json!({"uuid": "a", "name": "Squat", "updated_at": 500, "deleted_at": null})],
+            ),
+        )]);
+        let incoming = HashMap::from([
+            (
+                "exercises".to_string(),
+                vec![
+                    row(
+                        json!({"uuid": "a", "name": "Squat", "updated_at": 100, "deleted_at": null}),
+                    ),
+                    row(json!({"uuid": "b", "name": "Dip", "updated_at": 100, "deleted_at": null})),
+                ],
+            ),
+            (
+                MUSCLE_GROUP_TABLE.to_string(),
+                vec![
+                    row(json!({"exercise_id": "a", "muscle_group": "Quads", "tier": "Primary"})),
+                    row(json!({"exercise_id": "b", "muscle_group": "Chest", "tier": "Primary"})),
+                    row(
+                        json!({"exercise_id": "b", "muscle_group": "Triceps", "tier": "Secondary"}),
+                    ),
+                ],
+            ),
+        ]);
+        let plan = plan_merge(&local, &incoming);
+        assert_eq!(plan.muscle_groups.len(), 1);
+        assert_eq!(plan.muscle_groups[0].0, "b");
+        assert_eq!(plan.muscle_groups[0].1.len(), 2);
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// One entry of the bodyweight log: the trainee's body mass on a date.
+///
+/// `mass` is canonical kilograms regardless of the display unit.  Loaded and
+/// assisted bodyweight sets snapshot the entry in force when they are logged.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BodyweightEntry {
+    /// Optional database ID for the log row (UUID string)
+    pub id: Option<String>,
+    /// When the mass was measured, in milliseconds since the epoch
+    pub recorded_at: f64,
+    /// Body mass in kg
+    pub mass: f32,
+}
@@ -1,4 +1,8 @@
+use super::set::SetType;
+use super::settings::{E1rmFormula, WeightUnit};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// Configuration for the type of set an exercise uses.
 ///
@@ -14,9 +18,238 @@ pub enum SetTypeConfig {
         min_weight: f32,
         /// Weight increment (e.g., 2.5kg for standard plates)
         increment: f32,
+        /// Empty bar weight for plate-loaded exercises (None = not plate-loaded)
+        #[serde(default)]
+        bar_weight: Option<f32>,
     },
     /// Configuration for bodyweight exercises
     Bodyweight,
+    /// Configuration for duration-based exercises (planks, dead hangs)
+    Timed {
+        /// Duration step in seconds used for predictions and the stepper
+        increment_secs: u32,
+    },
+    /// Configuration for distance-based exercises (sled pushes, rowing
+    /// intervals)
+    Distance {
+        /// Distance step in metres
+        increment_m: f32,
+    },
+    /// Configuration for loaded carries: a weight moved over a distance
+    Carry {
+        /// Minimum allowed weight for this exercise
+        min_weight: f32,
+        /// Weight increment
+        increment: f32,
+        /// Distance step in metres
+        increment_m: f32,
+    },
+    /// Configuration for bodyweight exercises loaded with a belt or vest
+    /// (`assisted: false`) or unloaded by a band or machine (`assisted: true`).
+    /// The system load of a set is the logged bodyweight ± that weight.
+    LoadedBodyweight {
+        /// Step of the added or assisting weight
+        increment: f32,
+        /// True when the weight offsets bodyweight instead of adding to it
+        assisted: bool,
+    },
+}
+
+impl SetTypeConfig {
+    /// Snaps a kilogram weight onto this exercise's loading grid
+    /// (`min_weight + n × increment`), never going below `min_weight`.
+    ///
+    /// The grid is stored in canonical kg but captured in the user's display
+    /// unit, so a 45 lb bar with 5 lb steps snaps to whole 5 lb loads.
+    /// Loaded-bodyweight configs snap the added or assisting weight onto
+    /// `n × increment` from zero.  Bodyweight configs return `kg` unchanged.
+    pub fn snap_weight(&self, kg: f64) -> f64 {
+        match self {
+            SetTypeConfig::Weighted {
+                min_weight,
+                increment,
+                ..
+            }
+            | SetTypeConfig::Carry {
+                min_weight,
+                increment,
+                ..
+            } => {
+                let min = *min_weight as f64;
+                let increment = *increment as f64;
+                if kg <= min {
+                    min
+                } else if increment <= 0.0 {
+                    kg
+                } else {
+                    min + ((kg - min) / increment).round() * increment
+                }
+            }
+            SetTypeConfig::LoadedBodyweight { increment, .. } => {
+                let increment = *increment as f64;
+                if kg <= 0.0 {
+                    0.0
+                } else if increment <= 0.0 {
+                    kg
+                } else {
+                    (kg / increment).round() * increment
+                }
+            }
+            SetTypeConfig::Bodyweight
+            | SetTypeConfig::Timed { .. }
+            | SetTypeConfig::Distance { .. } => kg,
+        }
+    }
+
+    /// Step of the measured quantity — seconds for `Timed`, metres for
+    /// `Distance` and `Carry` — or `None` for rep-based exercises.
+    pub fn measure_increment(&self) -> Option<f64> {
+        match self {
+            SetTypeConfig::Timed { increment_secs } => Some(*increment_secs as f64),
+            SetTypeConfig::Distance { increment_m } | SetTypeConfig::Carry { increment_m, .. } => {
+                Some(*increment_m as f64)
+            }
+            SetTypeConfig::Weighted { .. }
+            | SetTypeConfig::Bodyweight
+            | SetTypeConfig::LoadedBodyweight { .. } => None,
+        }
+    }
+
+    /// Builds the `SetType` of a set logged against this exercise from the
+    /// Record Screen inputs (`weight` in kg, `measure` in seconds or metres)
+    /// and the trainee's `bodyweight` in kg.  For loaded-bodyweight exercises
+    /// `weight` is the added or assisting weight, always entered as a
+    /// positive number.  Inputs this config doesn't track are ignored.
+    pub fn set_type(&self, weight: f32, measure: f64, bodyweight: f32) -> SetType {
+        match self {
+            SetTypeConfig::Weighted { .. } => SetType::Weighted { weight },
+            SetTypeConfig::LoadedBodyweight { assisted, .. } => SetType::LoadedBodyweight {
+                bodyweight,
+                added: if *assisted { -weight } else { weight },
+            },
+            SetTypeConfig::Bodyweight => SetType::Bodyweight,
+            SetTypeConfig::Timed { .. } => SetType::Timed {
+                duration_secs: measure.round().max(0.0) as u32,
+            },
+            SetTypeConfig::Distance { .. } => SetType::Distance {
+                distance_m: measure as f32,
+            },
+            SetTypeConfig::Carry { .. } => SetType::Carry {
+                weight,
+                distance_m: measure as f32,
+            },
+        }
+    }
+
+    /// This config's loading grid as seen in `unit`, for a config whose
+    /// weights were entered in `entered`.  Within the same unit it is
+    /// unchanged; across units the minimum, increment and bar weight are
+    /// rounded to whole plate steps of `unit` (an increment never to less
+    /// than one step), so suggestions and the dial move in `unit`'s steps.
+    pub fn in_unit(&self, entered: WeightUnit, unit: WeightUnit) -> SetTypeConfig {
+        if entered == unit {
+            return self.clone();
+        }
+        let convert = |kg: f32| unit.to_kg(unit.from_kg_entered_in(kg as f64, entered)) as f32;
+        let step = |kg: f32| {
+            if kg > 0.0 {
+                convert(kg).max(unit.to_kg(unit.plate_step()) as f32)
+            } else {
+                kg
+            }
+        };
+        match *self {
+            SetTypeConfig::Weighted {
+                min_weight,
+                increment,
+                bar_weight,
+            } => SetTypeConfig::Weighted {
+                min_weight: convert(min_weight),
+                increment: step(increment),
+                bar_weight: bar_weight.map(convert),
+            },
+            SetTypeConfig::Carry {
+                min_weight,
+                increment,
+                increment_m,
+            } => SetTypeConfig::Carry {
+                min_weight: convert(min_weight),
+                increment: step(increment),
+                increment_m,
+            },
+            SetTypeConfig::LoadedBodyweight {
+                increment,
+                assisted,
+            } => SetTypeConfig::LoadedBodyweight {
+                increment: step(increment),
+                assisted,
+            },
+            ref other => other.clone(),
+        }
+    }
+
+    /// Minimum, increment and bar weight in kg, where this kind has them.
+    fn loading(&self) -> [Option<f32>; 3] {
+        match *self {
+            SetTypeConfig::Weighted {
+                min_weight,
+                increment,
+                bar_weight,
+            } => [Some(min_weight), Some(increment), bar_weight],
+            SetTypeConfig::Carry {
+                min_weight,
+                increment,
+                ..
+            } => [Some(min_weight), Some(increment), None],
+            SetTypeConfig::LoadedBodyweight { increment, .. } => [None, Some(increment), None],
+            SetTypeConfig::Bodyweight
+            | SetTypeConfig::Timed { .. }
+            | SetTypeConfig::Distance { .. } => [None; 3],
+        }
+    }
+
+    /// This config with the minimum, increment and bar weight of `source`,
+    /// when `source` is the same kind.
+    fn with_loading_of(&self, source: &SetTypeConfig) -> SetTypeConfig {
+        match (self, source) {
+            (SetTypeConfig::Weighted { .. }, SetTypeConfig::Weighted { .. }) => source.clone(),
+            (
+                SetTypeConfig::Carry { increment_m, .. },
+                SetTypeConfig::Carry {
+                    min_weight,
+                    increment,
+                    ..
+                },
+            ) => SetTypeConfig::Carry {
+                min_weight: *min_weight,
+                increment: *increment,
+                increment_m: *increment_m,
+            },
+            (
+                SetTypeConfig::LoadedBodyweight { assisted, .. },
+                SetTypeConfig::LoadedBodyweight { increment, .. },
+            ) => SetTypeConfig::LoadedBodyweight {
+                increment: *increment,
+                assisted: *assisted,
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Stable identifier persisted in `exercises.set_type`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            SetTypeConfig::Weighted { .. } => "weighted",
+            SetTypeConfig::Bodyweight => "bodyweight",
+            SetTypeConfig::Timed { .. } => "timed",
+            SetTypeConfig::Distance { .. } => "distance",
+            SetTypeConfig::Carry { .. } => "carry",
+            SetTypeConfig::LoadedBodyweight {
+                assisted: false, ..
+            } => "loaded_bodyweight",
+            SetTypeConfig::LoadedBodyweight { assisted: true, .. } => "assisted_bodyweight",
+        }
+    }
 }
 
 /// Metadata describing an exercise and its configuration.
@@ -38,6 +271,156 @@ pub struct ExerciseMetadata {
     /// Maximum number of reps for this exercise (None = unlimited)
     #[serde(default)]
     pub max_reps: Option<i32>,
+    /// Free-text cues for the exercise ("elbows tucked"); empty when none
+    #[serde(default)]
+    pub notes: String,
+    /// Rest between sets in seconds (None = [`DEFAULT_REST_SECS`]; 0 turns
+    /// the rest timer off)
+    #[serde(default)]
+    pub rest_secs: Option<u32>,
+    /// e1RM Formula for this exercise (None = the global one in Settings)
+    #[serde(default)]
+    pub e1rm_formula: Option<E1rmFormula>,
+    /// How the weight and reps of the next Suggestion are chosen
+    #[serde(default)]
+    pub progression: ProgressionStrategy,
+    /// Unit the weights of `set_type_config` were entered in
+    #[serde(default)]
+    pub weight_unit: WeightUnit,
+}
+
+/// How an exercise's Suggestions move from session to session.
+///
+/// Only weighted exercises follow a rule; every other set type is always
+/// autoregulated.  The rules themselves live in `domain::progression`.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProgressionStrategy {
+    /// Blended e1RM projected onto the rep with the best Per-Rep PB Margin.
+    #[default]
+    Autoregulated,
+    /// Add one increment each session once every working set hit the
+    /// bottom of the rep range; otherwise repeat the weight.
+    Linear,
+    /// Add a rep each session until every working set reaches `max_reps`,
+    /// then add one increment and drop back to `min_reps`.
+    Double,
+    /// A fixed percentage of the Blended e1RM for the bottom of the rep range.
+    PercentOfE1rm(u32),
+}
+
+impl ProgressionStrategy {
+    /// Percentage used when switching to [`ProgressionStrategy::PercentOfE1rm`].
+    pub const DEFAULT_PERCENT: u32 = 80;
+
+    /// Every strategy, in the order offered in the exercise form.
+    pub const ALL: [ProgressionStrategy; 4] = [
+        ProgressionStrategy::Autoregulated,
+        ProgressionStrategy::Linear,
+        ProgressionStrategy::Double,
+        ProgressionStrategy::PercentOfE1rm(Self::DEFAULT_PERCENT),
+    ];
+
+    /// Identifier of the strategy without its percentage.
+    pub fn key(self) -> &'static str {
+        match self {
+            ProgressionStrategy::Autoregulated => "autoregulated",
+            ProgressionStrategy::Linear => "linear",
+            ProgressionStrategy::Double => "double",
+            ProgressionStrategy::PercentOfE1rm(_) => "percent",
+        }
+    }
+
+    /// Name shown in the exercise form.
+    pub fn label(self) -> &'static str {
+        match self {
+            ProgressionStrategy::Autoregulated => "Autoregulated (e1RM)",
+            ProgressionStrategy::Linear => "Linear progression",
+            ProgressionStrategy::Double => "Double progression",
+            ProgressionStrategy::PercentOfE1rm(_) => "Percentage of e1RM",
+        }
+    }
+}
+
+/// `autoregulated`, `linear`, `double` or `percent:PCT`, e.g. `percent:80`.
+impl fmt::Display for ProgressionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgressionStrategy::PercentOfE1rm(pct) => write!(f, "{}:{}", self.key(), pct),
+            _ => write!(f, "{}", self.key()),
+        }
+    }
+}
+
+impl FromStr for ProgressionStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "autoregulated" => Ok(ProgressionStrategy::Autoregulated),
+            "linear" => Ok(ProgressionStrategy::Linear),
+            "double" => Ok(ProgressionStrategy::Double),
+            other => {
+                let pct = other
+                    .strip_prefix("percent:")
+                    .ok_or_else(|| format!("Unknown progression strategy: '{}'", other))?;
+                match pct.parse::<u32>() {
+                    Ok(pct @ 1..=100) => Ok(ProgressionStrategy::PercentOfE1rm(pct)),
+                    _ => Err(format!("Invalid e1RM percentage: '{}'", pct)),
+                }
+            }
+        }
+    }
+}
+
+/// Rest timer length for exercises without their own rest duration.
+pub const DEFAULT_REST_SECS: u32 = 120;
+
+impl ExerciseMetadata {
+    /// Rest timer length in seconds; 0 when the timer is off.
+    pub fn rest_duration_secs(&self) -> u32 {
+        self.rest_secs.unwrap_or(DEFAULT_REST_SECS)
+    }
+
+    /// The exercise's loading grid in the display `unit`
+    /// ([`SetTypeConfig::in_unit`]).
+    pub fn loading_config(&self, unit: WeightUnit) -> SetTypeConfig {
+        self.set_type_config.in_unit(self.weight_unit, unit)
+    }
+
+    /// The config and entry unit to store after an edit in `unit` produced
+    /// `edited`.  While its weights are still the ones [`Self::loading_config`]
+    /// showed, the stored weights and unit are kept, so saving in the other
+    /// unit never rewrites them; otherwise `edited` counts as entered in
+    /// `unit`.
+    pub fn edited_loading(
+        &self,
+        edited: SetTypeConfig,
+        unit: WeightUnit,
+    ) -> (SetTypeConfig, WeightUnit) {
+        let shown = self.loading_config(unit);
+        let unchanged = edited.type_name() == shown.type_name()
+            && edited
+                .loading()
+                .iter()
+                .zip(shown.loading())
+                .all(|(a, b)| match (a, b) {
+                    (Some(a), Some(b)) => (a - b).abs() < 1e-3,
+                    (a, b) => a.is_none() && b.is_none(),
+                });
+        if unchanged {
+            (
+                edited.with_loading_of(&self.set_type_config),
+                self.weight_unit,
+            )
+        } else {
+            (edited, unit)
+        }
+    }
+
+    /// The exercise's own e1RM Formula, or `global` when it has none.
+    pub fn e1rm_formula_or(&self, global: E1rmFormula) -> E1rmFormula {
+        self.e1rm_formula.unwrap_or(global)
+    }
 }
 
 fn default_min_reps() -> i32 {
@@ -56,9 +439,15 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 20.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         assert_eq!(exercise.name, "Bench Press");
@@ -66,11 +455,12 @@ mod tests {
             SetTypeConfig::Weighted {
                 min_weight,
                 increment,
+                ..
             } => {
                 assert_eq!(min_weight, 20.0);
                 assert_eq!(increment, 2.5);
             }
-            SetTypeConfig::Bodyweight => panic!("Expected Weighted config"),
+            _ => panic!("Expected Weighted config"),
         }
     }
 
@@ -82,6 +472,11 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         assert_eq!(exercise.name, "Pull-ups");
@@ -89,7 +484,7 @@ mod tests {
             SetTypeConfig::Bodyweight => {
                 // Success - bodyweight config matched
             }
-            SetTypeConfig::Weighted { .. } => panic!("Expected Bodyweight config"),
+            _ => panic!("Expected Bodyweight config"),
         }
     }
 
@@ -101,9 +496,15 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 20.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let json = serde_json::to_string(&original).expect("Serialization failed");
@@ -122,6 +523,11 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let json = serde_json::to_string(&original).expect("Serialization failed");
@@ -141,9 +547,15 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 20.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let cloned = original.clone();
@@ -160,9 +572,15 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 20.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 3,
             max_reps: Some(8),
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let json = serde_json::to_string(&original).expect("Serialization failed");
@@ -183,5 +601,253 @@ mod tests {
 
         assert_eq!(deserialized.min_reps, 1);
         assert_eq!(deserialized.max_reps, None);
+        assert_eq!(
+            deserialized.set_type_config,
+            SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_snap_weight_kg_grid() {
+        let config = SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        };
+        assert!((config.snap_weight(83.7) - 82.5).abs() < 1e-6);
+        assert!((config.snap_weight(84.0) - 85.0).abs() < 1e-6);
+        // Below the minimum clamps to the minimum.
+        assert!((config.snap_weight(10.0) - 20.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_snap_weight_lb_grid_lands_on_whole_pound_steps() {
+        use crate::models::WeightUnit;
+
+        let unit = WeightUnit::Lb;
+        // 45 lb bar with a 5 lb increment, captured in pounds and stored as kg.
+        let config = SetTypeConfig::Weighted {
+            min_weight: unit.to_kg(45.0) as f32,
+            increment: unit.to_kg(5.0) as f32,
+            bar_weight: None,
+        };
+        let snapped_lb = unit.from_kg(config.snap_weight(unit.to_kg(187.0)));
+        assert!((snapped_lb - 185.0).abs() < 1e-3, "got {snapped_lb} lb");
+    }
+
+    #[test]
+    fn test_serde_round_trip_measured_configs() {
+        for config in [
+            SetTypeConfig::Timed { increment_secs: 5 },
+            SetTypeConfig::Distance { increment_m: 10.0 },
+            SetTypeConfig::Carry {
+                min_weight: 16.0,
+                increment: 4.0,
+                increment_m: 5.0,
+            },
+        ] {
+            let json = serde_json::to_string(&config).expect("Serialization failed");
+            let deserialized: SetTypeConfig =
+                serde_json::from_str(&json).expect("Deserialization failed");
+            assert_eq!(deserialized, config);
+        }
+    }
+
+    #[test]
+    fn test_measure_increment_only_for_measured_configs() {
+        assert_eq!(SetTypeConfig::Bodyweight.measure_increment(), None);
+        assert_eq!(
+            SetTypeConfig::Timed { increment_secs: 5 }.measure_increment(),
+            Some(5.0)
+        );
+        assert_eq!(
+            SetTypeConfig::Carry {
+                min_weight: 16.0,
+                increment: 4.0,
+                increment_m: 5.0,
+            }
+            .measure_increment(),
+            Some(5.0)
+        );
+    }
+
+    #[test]
+    fn test_set_type_from_inputs() {
+        assert_eq!(
+            SetTypeConfig::Timed { increment_secs: 5 }.set_type(0.0, 44.6, 0.0),
+            SetType::Timed { duration_secs: 45 }
+        );
+        let carry = SetTypeConfig::Carry {
+            min_weight: 16.0,
+            increment: 4.0,
+            increment_m: 5.0,
+        };
+        assert_eq!(
+            carry.set_type(32.0, 40.0, 0.0),
+            SetType::Carry {
+                weight: 32.0,
+                distance_m: 40.0
+            }
+        );
+        assert!((carry.snap_weight(33.0) - 32.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_loaded_bodyweight_set_type_signs_assistance() {
+        let weighted = SetTypeConfig::LoadedBodyweight {
+            increment: 2.5,
+            assisted: false,
+        };
+        assert_eq!(
+            weighted.set_type(10.0, 0.0, 80.0),
+            SetType::LoadedBodyweight {
+                bodyweight: 80.0,
+                added: 10.0
+            }
+        );
+        let assisted = SetTypeConfig::LoadedBodyweight {
+            increment: 5.0,
+            assisted: true,
+        };
+        assert_eq!(
+            assisted.set_type(25.0, 0.0, 80.0),
+            SetType::LoadedBodyweight {
+                bodyweight: 80.0,
+                added: -25.0
+            }
+        );
+        assert_eq!(assisted.type_name(), "assisted_bodyweight");
+        assert!((assisted.snap_weight(23.0) - 25.0).abs() < 1e-6);
+        assert!(weighted.snap_weight(-3.0).abs() < 1e-6);
+    }
+
+    /// An exercise entered in kg suggests whole 5 lb steps once the display
+    /// unit is lb, and keeps its exact grid in the unit it was entered in.
+    #[test]
+    fn test_in_unit_snaps_to_plate_steps_of_the_display_unit() {
+        let lb = WeightUnit::Lb;
+        let config = SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: Some(20.0),
+        };
+        let SetTypeConfig::Weighted {
+            min_weight,
+            increment,
+            bar_weight,
+        } = config.in_unit(WeightUnit::Kg, lb)
+        else {
+            panic!("still weighted");
+        };
+        assert!((lb.from_kg(min_weight as f64) - 45.0).abs() < 1e-3);
+        assert!((lb.from_kg(increment as f64) - 5.0).abs() < 1e-3);
+        assert!((lb.from_kg(bar_weight.unwrap() as f64) - 45.0).abs() < 1e-3);
+
+        let snapped_lb = lb.from_kg(config.in_unit(WeightUnit::Kg, lb).snap_weight(102.2));
+        assert!((snapped_lb - 225.0).abs() < 1e-3, "got {snapped_lb} lb");
+
+        // A 1 kg step is kept as entered, and rounds up to one plate step in lb.
+        let fine = SetTypeConfig::LoadedBodyweight {
+            increment: 1.0,
+            assisted: false,
+        };
+        assert_eq!(fine.in_unit(WeightUnit::Kg, WeightUnit::Kg), fine);
+        let SetTypeConfig::LoadedBodyweight { increment, .. } = fine.in_unit(WeightUnit::Kg, lb)
+        else {
+            panic!("still loaded bodyweight");
+        };
+        assert!((lb.from_kg(increment as f64) - 2.5).abs() < 1e-3);
+    }
+
+    /// Saving a kg exercise from the lb form without touching its weights
+    /// keeps the stored kg values and unit; editing one takes the lb entry.
+    #[test]
+    fn test_edited_loading_keeps_stored_weights_across_units() {
+        let lb = WeightUnit::Lb;
+        let exercise = ExerciseMetadata {
+            id: Some("bench".to_string()),
+            name: "Bench Press".to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: Some(20.0),
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: WeightUnit::Kg,
+        };
+        // What the form writes back from the lb fields it was seeded with.
+        let from_fields = |config: SetTypeConfig| match config {
+            SetTypeConfig::Weighted {
+                min_weight,
+                increment,
+                bar_weight,
+            } => {
+                let round_trip = |kg: f32| lb.to_kg(lb.from_kg(kg as f64) as f32 as f64) as f32;
+                SetTypeConfig::Weighted {
+                    min_weight: round_trip(min_weight),
+                    increment: round_trip(increment),
+                    bar_weight: bar_weight.map(round_trip),
+                }
+            }
+            other => other,
+        };
+        let unchanged = from_fields(exercise.loading_config(lb));
+        assert_eq!(
+            exercise.edited_loading(unchanged, lb),
+            (exercise.set_type_config.clone(), WeightUnit::Kg)
+        );
+
+        let heavier = SetTypeConfig::Weighted {
+            min_weight: lb.to_kg(95.0) as f32,
+            increment: lb.to_kg(5.0) as f32,
+            bar_weight: Some(lb.to_kg(45.0) as f32),
+        };
+        assert_eq!(exercise.edited_loading(heavier.clone(), lb), (heavier, lb));
+
+        let same_unit = exercise.set_type_config.clone();
+        assert_eq!(
+            exercise.edited_loading(same_unit.clone(), WeightUnit::Kg),
+            (same_unit, WeightUnit::Kg)
+        );
+    }
+
+    #[test]
+    fn test_snap_weight_zero_increment_and_bodyweight_unchanged() {
+        let config = SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 0.0,
+            bar_weight: None,
+        };
+        assert!((config.snap_weight(50.3) - 50.3).abs() < 1e-9);
+        assert_eq!(SetTypeConfig::Bodyweight.snap_weight(50.3), 50.3);
+    }
+
+    #[test]
+    fn test_progression_strategy_display_from_str_round_trip() {
+        for strategy in [
+            ProgressionStrategy::Autoregulated,
+            ProgressionStrategy::Linear,
+            ProgressionStrategy::Double,
+            ProgressionStrategy::PercentOfE1rm(75),
+        ] {
+            assert_eq!(strategy.to_string().parse(), Ok(strategy));
+        }
+        assert_eq!(
+            ProgressionStrategy::PercentOfE1rm(80).to_string(),
+            "percent:80"
+        );
+        assert!("".parse::<ProgressionStrategy>().is_err());
+        assert!("percent:0".parse::<ProgressionStrategy>().is_err());
+        assert!("percent:120".parse::<ProgressionStrategy>().is_err());
+        assert!("percent:x".parse::<ProgressionStrategy>().is_err());
     }
 }
@@ -2,32 +2,54 @@
 ///
 /// This module provides type-safe data structures for representing exercises,
 /// sets, and workout data, along with validation logic to ensure data integrity.
+pub mod bodyweight;
 pub mod exercise;
 pub mod muscle_group;
+pub mod plate;
+pub mod program;
 pub mod progress;
+pub mod record;
+pub mod rpe_calibration;
 pub mod set;
 pub mod settings;
 pub mod validation;
+pub mod volume_target;
+pub mod warmup;
 pub mod workout_plan;
 
 // Re-export commonly used types for easier access
 // Allow unused imports as these are re-exported for public use by consumers of this module
 #[allow(unused_imports)]
-pub use exercise::{ExerciseMetadata, SetTypeConfig};
+pub use bodyweight::BodyweightEntry;
+#[allow(unused_imports)]
+pub use exercise::{DEFAULT_REST_SECS, ExerciseMetadata, ProgressionStrategy, SetTypeConfig};
 #[allow(unused_imports)]
 pub use muscle_group::{
     ContributionTier, ExerciseMuscleGroup, MuscleGroup, MuscleGroupVolume, validate_muscle_groups,
 };
 #[allow(unused_imports)]
+pub use plate::Plate;
+#[allow(unused_imports)]
+pub use program::{Program, ProgramDay, ProgramPosition, ProgressionRule};
+#[allow(unused_imports)]
 pub use progress::ProgressState;
 #[allow(unused_imports)]
-pub use set::{CompletedSet, HistorySet, SetType};
+pub use record::{PersonalRecord, RecordKind};
+#[allow(unused_imports)]
+pub use rpe_calibration::RpeCalibration;
 #[allow(unused_imports)]
-pub use settings::Settings;
+pub use set::{CompletedSet, HistorySet, SetKind, SetType};
+#[allow(unused_imports)]
+pub use settings::{E1rmFormula, Settings, WeightUnit};
 #[allow(unused_imports)]
 pub use validation::{
-    ValidationError, validate_completed_set, validate_reps, validate_rpe, validate_set_number,
-    validate_weight,
+    MAX_BODYWEIGHT, MAX_DISTANCE_M, MAX_DURATION_SECS, ValidationError, validate_bodyweight,
+    validate_completed_set, validate_distance, validate_duration, validate_reps, validate_rpe,
+    validate_set_number, validate_weight,
 };
 #[allow(unused_imports)]
-pub use workout_plan::{PlanExercise, WorkoutPlan, WorkoutTemplate};
+pub use volume_target::{VolumeTarget, VolumeTargets, VolumeZone};
+#[allow(unused_imports)]
+pub use warmup::{WarmupRamp, WarmupSet, WarmupStep};
+#[allow(unused_imports)]
+pub use workout_plan::{PlanExercise, SlotTargets, WorkoutPlan, WorkoutTemplate};
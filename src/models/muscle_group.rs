@@ -2,11 +2,12 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
-/// The 12 supported muscle groups for exercise tagging.
+/// The muscle groups supported for exercise tagging: 12 top-level groups and
+/// their Sub-Muscles.
 ///
-/// Each variant maps to a TEXT representation in SQLite.
-/// The `parent_id` concept (sub-muscle hierarchy) is reserved for v2;
-/// all variants return `None` in v1.
+/// Each variant maps to a TEXT representation in SQLite. Sub-Muscles name
+/// their top-level group through [`MuscleGroup::parent_id`], so Volume logged
+/// against a Sub-Muscle also counts towards its parent.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MuscleGroup {
     Chest,
@@ -21,13 +22,91 @@ pub enum MuscleGroup {
     Core,
     Forearms,
     Traps,
+    UpperChest,
+    LowerChest,
+    Lats,
+    MidBack,
+    LowerBack,
+    FrontDelts,
+    SideDelts,
+    RearDelts,
+    Adductors,
+    Abductors,
+    Abs,
+    Obliques,
 }
 
 impl MuscleGroup {
-    /// Returns the parent muscle group for sub-muscle hierarchy.
-    /// Always `None` in v1; reserved for future hierarchy support.
+    /// Every top-level muscle group, in canonical display order.
+    pub const ALL: [MuscleGroup; 12] = [
+        MuscleGroup::Chest,
+        MuscleGroup::Back,
+        MuscleGroup::Shoulders,
+        MuscleGroup::Biceps,
+        MuscleGroup::Triceps,
+        MuscleGroup::Quads,
+        MuscleGroup::Hamstrings,
+        MuscleGroup::Glutes,
+        MuscleGroup::Calves,
+        MuscleGroup::Core,
+        MuscleGroup::Forearms,
+        MuscleGroup::Traps,
+    ];
+
+    /// Every Sub-Muscle, grouped by parent in [`MuscleGroup::ALL`] order.
+    pub const SUB_MUSCLES: [MuscleGroup; 12] = [
+        MuscleGroup::UpperChest,
+        MuscleGroup::LowerChest,
+        MuscleGroup::Lats,
+        MuscleGroup::MidBack,
+        MuscleGroup::LowerBack,
+        MuscleGroup::FrontDelts,
+        MuscleGroup::SideDelts,
+        MuscleGroup::RearDelts,
+        MuscleGroup::Adductors,
+        MuscleGroup::Abductors,
+        MuscleGroup::Abs,
+        MuscleGroup::Obliques,
+    ];
+
+    /// Returns the parent muscle group of a Sub-Muscle, or `None` for a
+    /// top-level group.
     pub fn parent_id(&self) -> Option<MuscleGroup> {
-        None
+        match self {
+            MuscleGroup::UpperChest | MuscleGroup::LowerChest => Some(MuscleGroup::Chest),
+            MuscleGroup::Lats | MuscleGroup::MidBack | MuscleGroup::LowerBack => {
+                Some(MuscleGroup::Back)
+            }
+            MuscleGroup::FrontDelts | MuscleGroup::SideDelts | MuscleGroup::RearDelts => {
+                Some(MuscleGroup::Shoulders)
+            }
+            MuscleGroup::Adductors => Some(MuscleGroup::Quads),
+            MuscleGroup::Abductors => Some(MuscleGroup::Glutes),
+            MuscleGroup::Abs | MuscleGroup::Obliques => Some(MuscleGroup::Core),
+            _ => None,
+        }
+    }
+
+    /// The top-level group this one rolls up to: its parent, or itself.
+    pub fn top_level(&self) -> MuscleGroup {
+        self.parent_id().unwrap_or_else(|| self.clone())
+    }
+
+    /// The Sub-Muscles of this group, empty for a Sub-Muscle or a group
+    /// without any.
+    pub fn sub_muscles(&self) -> Vec<MuscleGroup> {
+        Self::SUB_MUSCLES
+            .into_iter()
+            .filter(|sub| sub.parent_id().as_ref() == Some(self))
+            .collect()
+    }
+
+    /// This group and its Sub-Muscles: every group whose Volume counts
+    /// towards it.
+    pub fn with_sub_muscles(&self) -> Vec<MuscleGroup> {
+        std::iter::once(self.clone())
+            .chain(self.sub_muscles())
+            .collect()
     }
 }
 
@@ -46,6 +125,18 @@ impl fmt::Display for MuscleGroup {
             MuscleGroup::Core => "Core",
             MuscleGroup::Forearms => "Forearms",
             MuscleGroup::Traps => "Traps",
+            MuscleGroup::UpperChest => "Upper Chest",
+            MuscleGroup::LowerChest => "Lower Chest",
+            MuscleGroup::Lats => "Lats",
+            MuscleGroup::MidBack => "Mid Back",
+            MuscleGroup::LowerBack => "Lower Back",
+            MuscleGroup::FrontDelts => "Front Delts",
+            MuscleGroup::SideDelts => "Side Delts",
+            MuscleGroup::RearDelts => "Rear Delts",
+            MuscleGroup::Adductors => "Adductors",
+            MuscleGroup::Abductors => "Abductors",
+            MuscleGroup::Abs => "Abs",
+            MuscleGroup::Obliques => "Obliques",
         };
         write!(f, "{}", name)
     }
@@ -68,6 +159,18 @@ impl FromStr for MuscleGroup {
             "Core" => Ok(MuscleGroup::Core),
             "Forearms" => Ok(MuscleGroup::Forearms),
             "Traps" => Ok(MuscleGroup::Traps),
+            "Upper Chest" => Ok(MuscleGroup::UpperChest),
+            "Lower Chest" => Ok(MuscleGroup::LowerChest),
+            "Lats" => Ok(MuscleGroup::Lats),
+            "Mid Back" => Ok(MuscleGroup::MidBack),
+            "Lower Back" => Ok(MuscleGroup::LowerBack),
+            "Front Delts" => Ok(MuscleGroup::FrontDelts),
+            "Side Delts" => Ok(MuscleGroup::SideDelts),
+            "Rear Delts" => Ok(MuscleGroup::RearDelts),
+            "Adductors" => Ok(MuscleGroup::Adductors),
+            "Abductors" => Ok(MuscleGroup::Abductors),
+            "Abs" => Ok(MuscleGroup::Abs),
+            "Obliques" => Ok(MuscleGroup::Obliques),
             other => Err(format!("Unknown muscle group: '{}'", other)),
         }
     }
@@ -142,7 +245,7 @@ mod tests {
     // ── MuscleGroup variant coverage ──────────────────────────────────────────
 
     #[test]
-    fn test_muscle_group_has_exactly_12_variants() {
+    fn test_muscle_group_has_exactly_12_top_level_variants() {
         let all = [
             MuscleGroup::Chest,
             MuscleGroup::Back,
@@ -207,31 +310,50 @@ mod tests {
         assert!(result.is_err());
     }
 
-    // ── parent_id always None in v1 ───────────────────────────────────────────
+    // ── Sub-Muscle hierarchy ──────────────────────────────────────────────────
 
     #[test]
-    fn test_muscle_group_parent_id_always_none() {
-        let groups = [
-            MuscleGroup::Chest,
-            MuscleGroup::Back,
-            MuscleGroup::Shoulders,
-            MuscleGroup::Biceps,
-            MuscleGroup::Triceps,
-            MuscleGroup::Quads,
-            MuscleGroup::Hamstrings,
-            MuscleGroup::Glutes,
-            MuscleGroup::Calves,
-            MuscleGroup::Core,
-            MuscleGroup::Forearms,
-            MuscleGroup::Traps,
-        ];
-        for group in &groups {
-            assert_eq!(
-                group.parent_id(),
-                None,
-                "parent_id should be None for {:?} in v1",
-                group
-            );
+    fn test_top_level_groups_have_no_parent() {
+        for group in &MuscleGroup::ALL {
+            assert_eq!(group.parent_id(), None, "{:?} is top-level", group);
+            assert_eq!(&group.top_level(), group);
+        }
+    }
+
+    #[test]
+    fn test_sub_muscles_have_top_level_parents() {
+        for sub in &MuscleGroup::SUB_MUSCLES {
+            let parent = sub.parent_id().expect("every Sub-Muscle has a parent");
+            assert!(MuscleGroup::ALL.contains(&parent));
+            assert_eq!(sub.top_level(), parent);
+            assert!(parent.sub_muscles().contains(sub));
+            assert!(sub.sub_muscles().is_empty());
+        }
+        assert_eq!(
+            MuscleGroup::Shoulders.sub_muscles(),
+            vec![
+                MuscleGroup::FrontDelts,
+                MuscleGroup::SideDelts,
+                MuscleGroup::RearDelts
+            ]
+        );
+        assert!(MuscleGroup::Biceps.sub_muscles().is_empty());
+        assert_eq!(
+            MuscleGroup::Chest.with_sub_muscles(),
+            vec![
+                MuscleGroup::Chest,
+                MuscleGroup::UpperChest,
+                MuscleGroup::LowerChest
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sub_muscles_round_trip_through_text() {
+        assert_eq!(MuscleGroup::SideDelts.to_string(), "Side Delts");
+        for sub in &MuscleGroup::SUB_MUSCLES {
+            let parsed: MuscleGroup = sub.to_string().parse().expect("round-trip parse failed");
+            assert_eq!(&parsed, sub);
         }
     }
 
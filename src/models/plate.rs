@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// One line of the user's plate inventory: how many plates of a given
+/// weight they own.
+///
+/// `weight` is canonical kilograms regardless of the display unit, and
+/// `count` is the total number of plates (a bar is loaded in pairs, so
+/// `count / 2` pairs are usable).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Plate {
+    /// Optional database ID for the inventory row (UUID string)
+    pub id: Option<String>,
+    /// Weight of a single plate in kg
+    pub weight: f32,
+    /// Total number of plates of this weight owned
+    pub count: u32,
+}
+
+impl Plate {
+    /// Number of matched pairs available for loading both sides of a bar.
+    pub fn pairs(&self) -> u32 {
+        self.count / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs_rounds_down_odd_counts() {
+        let plate = Plate {
+            id: None,
+            weight: 20.0,
+            count: 5,
+        };
+        assert_eq!(plate.pairs(), 2);
+    }
+}
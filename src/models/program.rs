@@ -0,0 +1,103 @@
+/// How a Program's workouts change from one week to the next.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ProgressionRule {
+    /// Every week repeats the templates as saved.
+    #[default]
+    None,
+    /// Target RPE starts at `start` in week 1 and rises by `step` each week,
+    /// capped at RPE 10. A slot with its own target RPE starts from that.
+    Rpe { start: f64, step: f64 },
+    /// Every slot gains `step` planned sets each week after the first.
+    Volume { step: u32 },
+}
+
+impl ProgressionRule {
+    /// Storage tag for the `programs.progression` column.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            ProgressionRule::None => "none",
+            ProgressionRule::Rpe { .. } => "rpe",
+            ProgressionRule::Volume { .. } => "volume",
+        }
+    }
+
+    /// Rebuilds a rule from its storage tag and `(start, step)` columns;
+    /// unknown tags fall back to [`ProgressionRule::None`].
+    pub fn from_parts(tag: &str, start: Option<f64>, step: Option<f64>) -> Self {
+        match tag {
+            "rpe" => ProgressionRule::Rpe {
+                start: start.unwrap_or(7.0),
+                step: step.unwrap_or(0.5),
+            },
+            "volume" => ProgressionRule::Volume {
+                step: step.unwrap_or(1.0) as u32,
+            },
+            _ => ProgressionRule::None,
+        }
+    }
+
+    /// `(start, step)` column values.
+    pub fn parts(&self) -> (Option<f64>, Option<f64>) {
+        match *self {
+            ProgressionRule::None => (None, None),
+            ProgressionRule::Rpe { start, step } => (Some(start), Some(step)),
+            ProgressionRule::Volume { step } => (None, Some(step as f64)),
+        }
+    }
+}
+
+/// One day of a Program's rotation: the template trained that day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgramDay {
+    pub id: String,
+    pub template_id: String,
+    /// Name of the template, for display; empty once the template is deleted.
+    pub template_name: String,
+}
+
+/// A multi-week training block: template days rotated week after week, with
+/// planned progression and an optional deload week.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Program {
+    pub id: String,
+    pub name: String,
+    /// Length of the block in weeks, deload included.
+    pub weeks: u32,
+    /// 1-based week run as a deload, usually the last one.
+    pub deload_week: Option<u32>,
+    pub progression: ProgressionRule,
+    /// Template days in rotation order (day A, B, C…).
+    pub days: Vec<ProgramDay>,
+}
+
+/// Where a Plan sits in the Program it was materialized from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgramPosition {
+    pub program_id: String,
+    /// 0-based workout index within the program, counting across weeks.
+    pub index: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progression_rule_round_trips_through_columns() {
+        for rule in [
+            ProgressionRule::None,
+            ProgressionRule::Rpe {
+                start: 7.0,
+                step: 0.5,
+            },
+            ProgressionRule::Volume { step: 1 },
+        ] {
+            let (start, step) = rule.parts();
+            assert_eq!(ProgressionRule::from_parts(rule.tag(), start, step), rule);
+        }
+        assert_eq!(
+            ProgressionRule::from_parts("bogus", None, None),
+            ProgressionRule::None
+        );
+    }
+}
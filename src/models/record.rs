@@ -0,0 +1,152 @@
+use super::WeightUnit;
+use std::fmt;
+use std::str::FromStr;
+
+/// What a Personal Record beat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RecordKind {
+    /// Highest estimated one-rep max.
+    E1rm,
+    /// Most reps at a load at least this heavy.
+    RepsAtWeight,
+    /// Heaviest load for at least this many reps.
+    WeightAtReps,
+    /// Highest Failure Reps on a bodyweight set.
+    FailureReps,
+}
+
+impl fmt::Display for RecordKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = match self {
+            RecordKind::E1rm => "e1rm",
+            RecordKind::RepsAtWeight => "reps_at_weight",
+            RecordKind::WeightAtReps => "weight_at_reps",
+            RecordKind::FailureReps => "failure_reps",
+        };
+        write!(f, "{}", tag)
+    }
+}
+
+impl FromStr for RecordKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e1rm" => Ok(RecordKind::E1rm),
+            "reps_at_weight" => Ok(RecordKind::RepsAtWeight),
+            "weight_at_reps" => Ok(RecordKind::WeightAtReps),
+            "failure_reps" => Ok(RecordKind::FailureReps),
+            other => Err(format!("Unknown record kind: '{}'", other)),
+        }
+    }
+}
+
+/// A set that beat the exercise's previous best of one [`RecordKind`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PersonalRecord {
+    pub id: String,
+    pub exercise_id: String,
+    pub kind: RecordKind,
+    /// The new best: kg for e1RM and weight records, reps otherwise.
+    pub value: f64,
+    /// The best it beat, in the same unit as `value`.
+    pub previous: f64,
+    /// Load of the set in kg; `None` for bodyweight sets.
+    pub weight: Option<f64>,
+    pub reps: u32,
+    /// Unix ms the set was logged.
+    pub recorded_at: f64,
+}
+
+impl PersonalRecord {
+    /// One-line description such as `"8 reps at 100 kg (was 6)"`, loads in
+    /// `unit`.
+    pub fn describe(&self, unit: WeightUnit) -> String {
+        let load = |kg: f64| format!("{} {}", trim(unit.from_kg(kg)), unit);
+        match self.kind {
+            RecordKind::E1rm => format!(
+                "e1RM {} (was {})",
+                load(self.value),
+                trim(unit.from_kg(self.previous))
+            ),
+            RecordKind::RepsAtWeight => format!(
+                "{} reps at {} (was {})",
+                self.value,
+                load(self.weight.unwrap_or_default()),
+                self.previous
+            ),
+            RecordKind::WeightAtReps => format!(
+                "{} for {} reps (was {})",
+                load(self.value),
+                self.reps,
+                trim(unit.from_kg(self.previous))
+            ),
+            RecordKind::FailureReps => format!(
+                "{} failure reps (was {})",
+                trim(self.value),
+                trim(self.previous)
+            ),
+        }
+    }
+}
+
+/// Formats with at most one decimal, dropping a trailing `.0`.
+fn trim(value: f64) -> String {
+    let rounded = (value * 10.0).round() / 10.0;
+    if rounded.fract() == 0.0 {
+        format!("{:.0}", rounded)
+    } else {
+        format!("{:.1}", rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(kind: RecordKind, value: f64, previous: f64) -> PersonalRecord {
+        PersonalRecord {
+            id: "r".to_string(),
+            exercise_id: "e".to_string(),
+            kind,
+            value,
+            previous,
+            weight: Some(100.0),
+            reps: 5,
+            recorded_at: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_record_kind_round_trips_through_tag() {
+        for kind in [
+            RecordKind::E1rm,
+            RecordKind::RepsAtWeight,
+            RecordKind::WeightAtReps,
+            RecordKind::FailureReps,
+        ] {
+            assert_eq!(kind.to_string().parse::<RecordKind>(), Ok(kind));
+        }
+        assert!("bogus".parse::<RecordKind>().is_err());
+    }
+
+    #[test]
+    fn test_describe_records() {
+        let kg = WeightUnit::Kg;
+        assert_eq!(
+            record(RecordKind::E1rm, 116.67, 112.5).describe(kg),
+            "e1RM 116.7 kg (was 112.5)"
+        );
+        assert_eq!(
+            record(RecordKind::RepsAtWeight, 8.0, 6.0).describe(kg),
+            "8 reps at 100 kg (was 6)"
+        );
+        assert_eq!(
+            record(RecordKind::WeightAtReps, 105.0, 100.0).describe(kg),
+            "105 kg for 5 reps (was 100)"
+        );
+        let mut bw = record(RecordKind::FailureReps, 18.0, 16.5);
+        bw.weight = None;
+        assert_eq!(bw.describe(kg), "18 failure reps (was 16.5)");
+    }
+}
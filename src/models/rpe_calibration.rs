@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Parameters of the linear-RPE, exponential-rep curve fitted to the
+/// trainee's own Sets: `(1 − rpe_step × (10 − rpe)) × rep_decay^(reps − 1)`.
+///
+/// [`RpeCalibration::POPULATION`] is the curve behind the Linear RPE e1RM
+/// Formula; a fit (`domain::calibration`) starts from it and moves only as
+/// far as the data allows.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RpeCalibration {
+    /// Fraction of 1RM lost per RPE point below 10.
+    pub rpe_step: f64,
+    /// Fraction of the previous rep's load kept for each extra rep.
+    pub rep_decay: f64,
+    /// Sets that informed the fit; zero for the population curve.
+    pub sets: u32,
+}
+
+impl RpeCalibration {
+    /// The population-average curve of the Linear RPE formula.
+    pub const POPULATION: RpeCalibration = RpeCalibration {
+        rpe_step: 0.03269803,
+        rep_decay: 0.970546521,
+        sets: 0,
+    };
+
+    /// Percent of 1RM lost per RPE point, for display.
+    pub fn rpe_step_pct(&self) -> f64 {
+        self.rpe_step * 100.0
+    }
+
+    /// Percent of load lost per extra rep, for display.
+    pub fn rep_step_pct(&self) -> f64 {
+        (1.0 - self.rep_decay) * 100.0
+    }
+}
+
+impl Default for RpeCalibration {
+    fn default() -> Self {
+        Self::POPULATION
+    }
+}
+
+/// `RPE_STEP/REP_DECAY/SETS`, e.g. `0.03269803/0.970546521/0`.
+impl fmt::Display for RpeCalibration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.rpe_step, self.rep_decay, self.sets)
+    }
+}
+
+impl FromStr for RpeCalibration {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split('/').map(str::trim).collect();
+        let [rpe_step, rep_decay, sets] = parts[..] else {
+            return Err(format!("Expected RPE_STEP/REP_DECAY/SETS, got: {}", s));
+        };
+        let calibration = RpeCalibration {
+            rpe_step: rpe_step
+                .parse()
+                .map_err(|_| format!("Invalid RPE step: {}", rpe_step))?,
+            rep_decay: rep_decay
+                .parse()
+                .map_err(|_| format!("Invalid rep decay: {}", rep_decay))?,
+            sets: sets
+                .parse()
+                .map_err(|_| format!("Invalid set count: {}", sets))?,
+        };
+        // Outside these the curve stops falling or goes negative within the
+        // RPE scale.
+        if !(calibration.rpe_step > 0.0 && calibration.rpe_step < 0.1) {
+            return Err(format!("RPE step out of range: {}", rpe_step));
+        }
+        if !(calibration.rep_decay > 0.0 && calibration.rep_decay < 1.0) {
+            return Err(format!("Rep decay out of range: {}", rep_decay));
+        }
+        Ok(calibration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_from_str_round_trip() {
+        let calibration = RpeCalibration {
+            rpe_step: 0.041,
+            rep_decay: 0.9612345,
+            sets: 37,
+        };
+        assert_eq!(calibration.to_string(), "0.041/0.9612345/37");
+        assert_eq!(
+            calibration.to_string().parse::<RpeCalibration>().unwrap(),
+            calibration
+        );
+    }
+
+    #[test]
+    fn test_from_str_rejects_invalid() {
+        assert!("".parse::<RpeCalibration>().is_err());
+        assert!("0.03/0.97".parse::<RpeCalibration>().is_err());
+        assert!("0.03/1.2/4".parse::<RpeCalibration>().is_err());
+        assert!("-0.01/0.97/4".parse::<RpeCalibration>().is_err());
+        assert!("a/0.97/4".parse::<RpeCalibration>().is_err());
+    }
+}
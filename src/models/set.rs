@@ -1,4 +1,115 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The intent behind a set, which decides where it counts.
+///
+/// | Kind      | e1RM / suggestions / progress | Volume |
+/// | --------- | ----------------------------- | ------ |
+/// | Working   | yes                           | yes    |
+/// | Warm-up   | no                            | no     |
+/// | Drop      | no                            | yes    |
+/// | Back-off  | yes                           | yes    |
+/// | AMRAP     | yes                           | yes    |
+///
+/// Drop sets are taken to failure on pre-fatigued muscle, so their e1RM
+/// understates strength; they still represent hard work for volume.
+/// Back-off and AMRAP sets carry an honest RPE and estimate e1RM like any
+/// working set.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SetKind {
+    #[default]
+    Working,
+    Warmup,
+    Drop,
+    BackOff,
+    Amrap,
+}
+
+impl SetKind {
+    /// Every kind, in the order offered on the Record Screen.
+    pub const ALL: [SetKind; 5] = [
+        SetKind::Working,
+        SetKind::Warmup,
+        SetKind::Drop,
+        SetKind::BackOff,
+        SetKind::Amrap,
+    ];
+
+    /// Whether sets of this kind feed e1RM: suggestions, per-rep maxima and
+    /// progress detection.
+    pub fn counts_for_e1rm(self) -> bool {
+        matches!(self, SetKind::Working | SetKind::BackOff | SetKind::Amrap)
+    }
+
+    /// Whether sets of this kind count towards muscle-group volume and plan
+    /// progress.
+    pub fn counts_for_volume(self) -> bool {
+        !matches!(self, SetKind::Warmup)
+    }
+
+    /// SQL list of stored kinds for which `pred` holds, e.g. `'working', 'amrap'`,
+    /// for use in `kind IN (...)` filters.
+    pub fn sql_list(pred: fn(SetKind) -> bool) -> String {
+        Self::ALL
+            .iter()
+            .filter(|k| pred(**k))
+            .map(|k| format!("'{}'", k))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Human-readable label for UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            SetKind::Working => "Working",
+            SetKind::Warmup => "Warm-up",
+            SetKind::Drop => "Drop",
+            SetKind::BackOff => "Back-off",
+            SetKind::Amrap => "AMRAP",
+        }
+    }
+
+    /// Short prefix shown before the set number in history; empty for
+    /// working sets.
+    pub fn badge(self) -> &'static str {
+        match self {
+            SetKind::Working => "",
+            SetKind::Warmup => "W",
+            SetKind::Drop => "D",
+            SetKind::BackOff => "B",
+            SetKind::Amrap => "A",
+        }
+    }
+}
+
+impl fmt::Display for SetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SetKind::Working => "working",
+            SetKind::Warmup => "warmup",
+            SetKind::Drop => "drop",
+            SetKind::BackOff => "backoff",
+            SetKind::Amrap => "amrap",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for SetKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "working" => Ok(SetKind::Working),
+            "warmup" => Ok(SetKind::Warmup),
+            "drop" => Ok(SetKind::Drop),
+            "backoff" => Ok(SetKind::BackOff),
+            "amrap" => Ok(SetKind::Amrap),
+            other => Err(format!("Unknown set kind: '{}'", other)),
+        }
+    }
+}
 
 /// A set returned from the history queries, including its DB id and timestamp.
 #[derive(Clone, Debug, PartialEq)]
@@ -11,14 +122,36 @@ pub struct HistorySet {
     pub reps: u32,
     pub rpe: f32,
     pub set_type: SetType,
+    /// Intent of the set (working, warm-up, drop, …)
+    pub kind: SetKind,
+    /// Free-text comment on the set ("left knee twinge"); empty when none
+    pub comment: String,
+    /// Seconds rested since the previous set, when it was close enough to
+    /// count as rest
+    pub rest_secs: Option<u32>,
     /// Unix milliseconds (device local time is applied when grouping)
     pub recorded_at: f64,
 }
 
-/// Distinguishes between weighted and bodyweight exercises with type safety.
+impl HistorySet {
+    /// The set as it was logged, without its storage details.
+    pub fn to_completed(&self) -> CompletedSet {
+        CompletedSet {
+            set_number: self.set_number,
+            reps: self.reps,
+            rpe: self.rpe,
+            set_type: self.set_type.clone(),
+            kind: self.kind,
+            comment: self.comment.clone(),
+        }
+    }
+}
+
+/// Distinguishes between weighted, bodyweight, timed, distance and carry sets
+/// with type safety.
 ///
-/// The enum forces compile-time handling of both exercise types, ensuring
-/// that weight-related fields are only present for weighted exercises.
+/// The enum forces compile-time handling of every exercise type, ensuring
+/// that weight-related fields are only present for loaded sets.
 /// Validation constraints (min_weight, increment) live in ExerciseMetadata's SetTypeConfig.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[allow(dead_code)]
@@ -30,6 +163,72 @@ pub enum SetType {
     },
     /// A bodyweight exercise with no additional weight
     Bodyweight,
+    /// A duration-based set (plank, dead hang)
+    Timed {
+        /// Time held, in seconds
+        duration_secs: u32,
+    },
+    /// A distance-based set (sled push, rowing interval)
+    Distance {
+        /// Distance covered, in metres
+        distance_m: f32,
+    },
+    /// A loaded carry: a weight moved over a distance
+    Carry {
+        /// Weight carried for this set
+        weight: f32,
+        /// Distance covered, in metres
+        distance_m: f32,
+    },
+    /// A bodyweight set with weight added (belt, vest) or taken off (band,
+    /// machine assistance)
+    LoadedBodyweight {
+        /// Trainee's logged bodyweight when the set was done
+        bodyweight: f32,
+        /// Added weight; negative for assistance
+        added: f32,
+    },
+}
+
+impl SetType {
+    /// External load of the set, if it has one.
+    pub fn weight(&self) -> Option<f32> {
+        match self {
+            SetType::Weighted { weight } | SetType::Carry { weight, .. } => Some(*weight),
+            SetType::Bodyweight
+            | SetType::Timed { .. }
+            | SetType::Distance { .. }
+            | SetType::LoadedBodyweight { .. } => None,
+        }
+    }
+
+    /// Load the e1RM is computed from: the weight of a weighted set, or the
+    /// System Load (bodyweight ± added weight) of a loaded-bodyweight set.
+    /// `None` for sets that are not rep-based lifts of a known load.
+    pub fn system_load(&self) -> Option<f32> {
+        match self {
+            SetType::Weighted { weight } => Some(*weight),
+            SetType::LoadedBodyweight { bodyweight, added } => Some(bodyweight + added),
+            SetType::Bodyweight
+            | SetType::Timed { .. }
+            | SetType::Distance { .. }
+            | SetType::Carry { .. } => None,
+        }
+    }
+
+    /// Measured quantity of a non-rep set — seconds for `Timed`, metres for
+    /// `Distance` and `Carry` — or `None` when the set is measured in reps.
+    pub fn measure(&self) -> Option<f64> {
+        match self {
+            SetType::Timed { duration_secs } => Some(*duration_secs as f64),
+            SetType::Distance { distance_m } | SetType::Carry { distance_m, .. } => {
+                Some(*distance_m as f64)
+            }
+            SetType::Weighted { .. } | SetType::Bodyweight | SetType::LoadedBodyweight { .. } => {
+                None
+            }
+        }
+    }
 }
 
 /// Represents a completed set in a workout.
@@ -41,12 +240,19 @@ pub enum SetType {
 pub struct CompletedSet {
     /// Sequential set number (1, 2, 3, ...)
     pub set_number: u32,
-    /// Number of repetitions completed
+    /// Number of repetitions completed (always 1 for timed, distance and
+    /// carry sets, whose effort lives in `set_type`)
     pub reps: u32,
     /// Rate of Perceived Exertion (1.0 to 10.0, in 0.5 increments)
     pub rpe: f32,
     /// Type of set (weighted or bodyweight) with associated data
     pub set_type: SetType,
+    /// Intent of the set; decides whether it feeds e1RM and volume
+    #[serde(default)]
+    pub kind: SetKind,
+    /// Free-text comment on the set; empty when none
+    #[serde(default)]
+    pub comment: String,
 }
 
 #[cfg(test)]
@@ -61,7 +267,7 @@ mod tests {
             SetType::Weighted { weight } => {
                 assert_eq!(weight, 100.0);
             }
-            SetType::Bodyweight => panic!("Expected Weighted variant"),
+            _ => panic!("Expected Weighted variant"),
         }
     }
 
@@ -70,10 +276,10 @@ mod tests {
         let set_type = SetType::Bodyweight;
 
         match set_type {
-            SetType::Weighted { .. } => panic!("Expected Bodyweight variant"),
             SetType::Bodyweight => {
                 // Success - bodyweight variant matched
             }
+            _ => panic!("Expected Bodyweight variant"),
         }
     }
 
@@ -84,6 +290,8 @@ mod tests {
             reps: 10,
             rpe: 7.5,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
         let json = serde_json::to_string(&original_set).expect("Serialization failed");
@@ -103,6 +311,8 @@ mod tests {
             reps: 15,
             rpe: 8.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
         let json = serde_json::to_string(&original_set).expect("Serialization failed");
@@ -115,6 +325,94 @@ mod tests {
         assert_eq!(deserialized.set_type, original_set.set_type);
     }
 
+    #[test]
+    fn test_deserialize_without_kind_defaults_to_working() {
+        let json = r#"{"set_number":1,"reps":5,"rpe":8.0,"set_type":"Bodyweight"}"#;
+        let set: CompletedSet = serde_json::from_str(json).expect("Deserialization failed");
+        assert_eq!(set.kind, SetKind::Working);
+    }
+
+    #[test]
+    fn test_set_kind_display_from_str_round_trip() {
+        for kind in SetKind::ALL {
+            assert_eq!(kind.to_string().parse::<SetKind>().unwrap(), kind);
+        }
+        assert!("top".parse::<SetKind>().is_err());
+    }
+
+    #[test]
+    fn test_set_kind_rules() {
+        assert!(SetKind::Working.counts_for_e1rm());
+        assert!(!SetKind::Warmup.counts_for_e1rm());
+        assert!(!SetKind::Warmup.counts_for_volume());
+        assert!(!SetKind::Drop.counts_for_e1rm());
+        assert!(SetKind::Drop.counts_for_volume());
+        assert!(SetKind::BackOff.counts_for_e1rm());
+        assert!(SetKind::Amrap.counts_for_e1rm());
+    }
+
+    #[test]
+    fn test_set_kind_sql_list() {
+        assert_eq!(
+            SetKind::sql_list(SetKind::counts_for_e1rm),
+            "'working', 'backoff', 'amrap'"
+        );
+        assert_eq!(
+            SetKind::sql_list(SetKind::counts_for_volume),
+            "'working', 'drop', 'backoff', 'amrap'"
+        );
+    }
+
+    #[test]
+    fn test_serde_round_trip_measured_set_types() {
+        for set_type in [
+            SetType::Timed { duration_secs: 45 },
+            SetType::Distance { distance_m: 250.0 },
+            SetType::Carry {
+                weight: 32.0,
+                distance_m: 40.0,
+            },
+        ] {
+            let json = serde_json::to_string(&set_type).expect("Serialization failed");
+            let deserialized: SetType =
+                serde_json::from_str(&json).expect("Deserialization failed");
+            assert_eq!(deserialized, set_type);
+        }
+    }
+
+    #[test]
+    fn test_set_type_weight_and_measure() {
+        assert_eq!(SetType::Weighted { weight: 100.0 }.weight(), Some(100.0));
+        assert_eq!(SetType::Weighted { weight: 100.0 }.measure(), None);
+        assert_eq!(SetType::Bodyweight.weight(), None);
+        assert_eq!(SetType::Timed { duration_secs: 60 }.measure(), Some(60.0));
+        assert_eq!(SetType::Distance { distance_m: 500.0 }.weight(), None);
+        let carry = SetType::Carry {
+            weight: 40.0,
+            distance_m: 30.0,
+        };
+        assert_eq!(carry.weight(), Some(40.0));
+        assert_eq!(carry.measure(), Some(30.0));
+    }
+
+    #[test]
+    fn test_system_load_of_loaded_bodyweight() {
+        let weighted_pullup = SetType::LoadedBodyweight {
+            bodyweight: 80.0,
+            added: 20.0,
+        };
+        assert_eq!(weighted_pullup.system_load(), Some(100.0));
+        assert_eq!(weighted_pullup.weight(), None);
+
+        let assisted_dip = SetType::LoadedBodyweight {
+            bodyweight: 80.0,
+            added: -30.0,
+        };
+        assert_eq!(assisted_dip.system_load(), Some(50.0));
+        assert_eq!(SetType::Weighted { weight: 60.0 }.system_load(), Some(60.0));
+        assert_eq!(SetType::Bodyweight.system_load(), None);
+    }
+
     #[test]
     fn test_set_type_equality() {
         let weighted1 = SetType::Weighted { weight: 100.0 };
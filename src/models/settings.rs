@@ -1,4 +1,145 @@
+use super::{RpeCalibration, VolumeTargets, WarmupRamp};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Exact number of kilograms in one international avoirdupois pound.
+const KG_PER_LB: f64 = 0.453_592_37;
+
+/// The unit weights are displayed and entered in.
+///
+/// Stored sets and exercise configuration are always canonical kilograms;
+/// the unit only applies at the display and input boundary, so switching
+/// units never rewrites historical data.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WeightUnit {
+    #[default]
+    Kg,
+    Lb,
+}
+
+impl WeightUnit {
+    /// Converts a canonical kilogram value into this unit.
+    pub fn from_kg(self, kg: f64) -> f64 {
+        match self {
+            WeightUnit::Kg => kg,
+            WeightUnit::Lb => kg / KG_PER_LB,
+        }
+    }
+
+    /// Converts a value expressed in this unit back into canonical kilograms.
+    pub fn to_kg(self, value: f64) -> f64 {
+        match self {
+            WeightUnit::Kg => value,
+            WeightUnit::Lb => value * KG_PER_LB,
+        }
+    }
+
+    /// Smallest loading step commonly available in this unit: a pair of
+    /// the lightest change plates.
+    pub fn plate_step(self) -> f64 {
+        match self {
+            WeightUnit::Kg => 1.25,
+            WeightUnit::Lb => 2.5,
+        }
+    }
+
+    /// Converts a kilogram value entered in `entered` into this unit.
+    /// Across units the result is rounded to whole [`Self::plate_step`]s,
+    /// so a 2.5 kg increment reads as 5 lb and a 45 lb bar as 20 kg.
+    pub fn from_kg_entered_in(self, kg: f64, entered: WeightUnit) -> f64 {
+        let value = self.from_kg(kg);
+        if entered == self {
+            value
+        } else {
+            (value / self.plate_step()).round() * self.plate_step()
+        }
+    }
+}
+
+impl fmt::Display for WeightUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            WeightUnit::Kg => "kg",
+            WeightUnit::Lb => "lb",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FromStr for WeightUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "kg" => Ok(WeightUnit::Kg),
+            "lb" => Ok(WeightUnit::Lb),
+            other => Err(format!("Unknown weight unit: '{}'", other)),
+        }
+    }
+}
+
+/// How e1RM is estimated from a set and weight predicted from an e1RM.
+///
+/// Chosen globally in [`Settings`] and overridable per exercise; the models
+/// themselves live in `domain::e1rm`.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum E1rmFormula {
+    /// RPE-linear, rep-exponential model from the reference implementation.
+    #[default]
+    LinearRpe,
+    /// Epley, `1RM = w × (1 + r / 30)`, with r the reps to failure.
+    Epley,
+    /// Brzycki, `1RM = w × 36 / (37 − r)`, with r the reps to failure.
+    Brzycki,
+    /// RTS RPE chart percentages, interpolated between half RPEs.
+    RpeChart,
+}
+
+impl E1rmFormula {
+    pub const ALL: [E1rmFormula; 4] = [
+        E1rmFormula::LinearRpe,
+        E1rmFormula::Epley,
+        E1rmFormula::Brzycki,
+        E1rmFormula::RpeChart,
+    ];
+
+    /// Name shown in the settings and exercise form.
+    pub fn label(self) -> &'static str {
+        match self {
+            E1rmFormula::LinearRpe => "Linear RPE",
+            E1rmFormula::Epley => "Epley",
+            E1rmFormula::Brzycki => "Brzycki",
+            E1rmFormula::RpeChart => "RPE chart (RTS)",
+        }
+    }
+}
+
+impl fmt::Display for E1rmFormula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self {
+            E1rmFormula::LinearRpe => "linear_rpe",
+            E1rmFormula::Epley => "epley",
+            E1rmFormula::Brzycki => "brzycki",
+            E1rmFormula::RpeChart => "rpe_chart",
+        };
+        write!(f, "{}", key)
+    }
+}
+
+impl FromStr for E1rmFormula {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear_rpe" => Ok(E1rmFormula::LinearRpe),
+            "epley" => Ok(E1rmFormula::Epley),
+            "brzycki" => Ok(E1rmFormula::Brzycki),
+            "rpe_chart" => Ok(E1rmFormula::RpeChart),
+            other => Err(format!("Unknown e1RM formula: '{}'", other)),
+        }
+    }
+}
 
 /// Global application settings, stored as a single row in the `settings` table.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
@@ -18,6 +159,26 @@ pub struct Settings {
     pub min_sessions_for_regression: i64,
     /// Rolling lookback window for progress detection, in weeks (default: 12)
     pub training_window_weeks: i64,
+    /// Unit used to display and enter weights (default: kg)
+    #[serde(default)]
+    pub weight_unit: WeightUnit,
+    /// Warm-up ramp generated before the first working set
+    /// (default: bar×10, 50%×5, 70%×3, 85%×1)
+    #[serde(default)]
+    pub warmup_ramp: WarmupRamp,
+    /// Weekly MEV/MAV/MRV per Muscle Group
+    #[serde(default)]
+    pub volume_targets: VolumeTargets,
+    /// e1RM Formula for exercises without their own (default: Linear RPE)
+    #[serde(default)]
+    pub e1rm_formula: E1rmFormula,
+    /// RPE Calibration fitted to all of the trainee's exercises, if any
+    #[serde(default)]
+    pub rpe_calibration: Option<RpeCalibration>,
+    /// Project Suggestions with the RPE Calibration instead of the e1RM
+    /// Formula (default: off)
+    #[serde(default)]
+    pub use_rpe_calibration: bool,
 }
 
 impl Default for Settings {
@@ -30,6 +191,12 @@ impl Default for Settings {
             default_bodyweight_reps: 10,
             min_sessions_for_regression: 3,
             training_window_weeks: 12,
+            weight_unit: WeightUnit::Kg,
+            warmup_ramp: WarmupRamp::default(),
+            volume_targets: VolumeTargets::default(),
+            e1rm_formula: E1rmFormula::LinearRpe,
+            rpe_calibration: None,
+            use_rpe_calibration: false,
         }
     }
 }
@@ -48,6 +215,12 @@ mod tests {
         assert_eq!(s.default_bodyweight_reps, 10);
         assert_eq!(s.min_sessions_for_regression, 3);
         assert_eq!(s.training_window_weeks, 12);
+        assert_eq!(s.weight_unit, WeightUnit::Kg);
+        assert_eq!(s.warmup_ramp, WarmupRamp::default());
+        assert_eq!(s.volume_targets, VolumeTargets::default());
+        assert_eq!(s.e1rm_formula, E1rmFormula::LinearRpe);
+        assert_eq!(s.rpe_calibration, None);
+        assert!(!s.use_rpe_calibration);
     }
 
     #[test]
@@ -60,6 +233,16 @@ mod tests {
             default_bodyweight_reps: 15,
             min_sessions_for_regression: 5,
             training_window_weeks: 8,
+            weight_unit: WeightUnit::Lb,
+            warmup_ramp: "bar×8, 60%×3".parse().unwrap(),
+            volume_targets: "Chest 10/16/24".parse().unwrap(),
+            e1rm_formula: E1rmFormula::Brzycki,
+            rpe_calibration: Some(RpeCalibration {
+                rpe_step: 0.04,
+                rep_decay: 0.96,
+                sets: 24,
+            }),
+            use_rpe_calibration: true,
         };
         let json = serde_json::to_string(&original).expect("serialize");
         let deserialized: Settings = serde_json::from_str(&json).expect("deserialize");
@@ -74,4 +257,34 @@ mod tests {
         // training_window_weeks must be positive
         assert!(s.training_window_weeks > 0);
     }
+
+    #[test]
+    fn test_settings_deserialize_without_weight_unit_defaults_to_kg() {
+        let json = r#"{"target_rpe":8.0,"history_window_days":30,"today_blend_factor":0.5,"default_planned_sets":3,"default_bodyweight_reps":10,"min_sessions_for_regression":3,"training_window_weeks":12}"#;
+        let s: Settings = serde_json::from_str(json).expect("deserialize");
+        assert_eq!(s.weight_unit, WeightUnit::Kg);
+    }
+
+    #[test]
+    fn test_weight_unit_display_from_str_round_trip() {
+        for unit in [WeightUnit::Kg, WeightUnit::Lb] {
+            assert_eq!(unit.to_string().parse::<WeightUnit>(), Ok(unit));
+        }
+        assert!("stone".parse::<WeightUnit>().is_err());
+    }
+
+    #[test]
+    fn test_e1rm_formula_display_from_str_round_trip() {
+        for formula in E1rmFormula::ALL {
+            assert_eq!(formula.to_string().parse::<E1rmFormula>(), Ok(formula));
+        }
+        assert!("wathan".parse::<E1rmFormula>().is_err());
+    }
+
+    #[test]
+    fn test_weight_unit_conversion_round_trip() {
+        assert_eq!(WeightUnit::Kg.from_kg(100.0), 100.0);
+        assert!((WeightUnit::Lb.from_kg(100.0) - 220.462_262).abs() < 1e-5);
+        assert!((WeightUnit::Lb.to_kg(WeightUnit::Lb.from_kg(82.5)) - 82.5).abs() < 1e-9);
+    }
 }
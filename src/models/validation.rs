@@ -1,5 +1,6 @@
 use super::exercise::{ExerciseMetadata, SetTypeConfig};
 use super::set::{CompletedSet, SetType};
+use super::settings::WeightUnit;
 
 /// Validation errors that can occur when validating exercise data.
 #[derive(Debug, PartialEq)]
@@ -21,55 +22,118 @@ pub enum ValidationError {
     RepsExceedLimit { reps: u32, limit: u32 },
     /// Set number is zero
     ZeroSetNumber,
+    /// Duration of a timed set is zero
+    ZeroDuration,
+    /// Duration exceeds sanity check limit
+    DurationExceedsLimit { secs: u32, limit: u32 },
+    /// Distance is zero or negative
+    DistanceNotPositive { distance_m: f32 },
+    /// Distance exceeds sanity check limit
+    DistanceExceedsLimit { distance_m: f32, limit: f32 },
+    /// No bodyweight logged (or a non-positive one) for a loaded-bodyweight set
+    BodyweightNotPositive { bodyweight: f32 },
+    /// Bodyweight exceeds sanity check limit
+    BodyweightExceedsLimit { bodyweight: f32, limit: f32 },
+    /// Assistance leaves no System Load to lift
+    AssistanceExceedsBodyweight { assistance: f32, bodyweight: f32 },
+    /// The set's type does not match the exercise's set type configuration
+    SetTypeMismatch { exercise_type: &'static str },
 }
 
-impl std::fmt::Display for ValidationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ValidationError {
+    /// User-facing message, loads in `unit`.
+    pub fn describe(&self, unit: WeightUnit) -> String {
+        let load = |kg: &f32| format!("{:.1}{}", unit.from_kg(*kg as f64), unit);
         match self {
             ValidationError::WeightBelowMinimum { weight, min_weight } => {
-                write!(
-                    f,
-                    "Weight {:.1}kg is below minimum {:.1}kg",
-                    weight, min_weight
+                format!(
+                    "Weight {} is below minimum {}",
+                    load(weight),
+                    load(min_weight)
                 )
             }
             ValidationError::WeightExceedsMaximum { weight, max_weight } => {
-                write!(
-                    f,
-                    "Weight {:.1}kg exceeds maximum sanity check limit {:.1}kg",
-                    weight, max_weight
+                format!(
+                    "Weight {} exceeds maximum sanity check limit {}",
+                    load(weight),
+                    load(max_weight)
                 )
             }
             ValidationError::WeightNotMultipleOfIncrement { weight, increment } => {
-                write!(
-                    f,
-                    "Weight {:.1}kg is not a multiple of increment {:.1}kg",
-                    weight, increment
+                format!(
+                    "Weight {} is not a multiple of increment {}",
+                    load(weight),
+                    load(increment)
                 )
             }
             ValidationError::RpeOutOfBounds { rpe } => {
-                write!(f, "RPE {:.1} is outside valid range (1.0 to 10.0)", rpe)
+                format!("RPE {:.1} is outside valid range (1.0 to 10.0)", rpe)
             }
             ValidationError::RpeInvalidStep { rpe } => {
-                write!(f, "RPE {:.1} must be in 0.5 increments", rpe)
-            }
-            ValidationError::ZeroReps => {
-                write!(f, "Number of reps must be greater than 0")
+                format!("RPE {:.1} must be in 0.5 increments", rpe)
             }
+            ValidationError::ZeroReps => "Number of reps must be greater than 0".to_string(),
             ValidationError::RepsExceedLimit { reps, limit } => {
-                write!(
-                    f,
+                format!(
                     "Number of reps ({}) exceeds sanity check limit ({})",
                     reps, limit
                 )
             }
-            ValidationError::ZeroSetNumber => {
-                write!(f, "Set number must be greater than 0")
+            ValidationError::ZeroSetNumber => "Set number must be greater than 0".to_string(),
+            ValidationError::ZeroDuration => "Duration must be greater than 0 seconds".to_string(),
+            ValidationError::DurationExceedsLimit { secs, limit } => {
+                format!(
+                    "Duration ({}s) exceeds sanity check limit ({}s)",
+                    secs, limit
+                )
+            }
+            ValidationError::DistanceNotPositive { distance_m } => {
+                format!("Distance {:.1}m must be greater than 0", distance_m)
+            }
+            ValidationError::DistanceExceedsLimit { distance_m, limit } => {
+                format!(
+                    "Distance {:.1}m exceeds sanity check limit {:.1}m",
+                    distance_m, limit
+                )
+            }
+            ValidationError::BodyweightNotPositive { .. } => {
+                "Log your bodyweight before recording this exercise".to_string()
+            }
+            ValidationError::BodyweightExceedsLimit { bodyweight, limit } => {
+                format!(
+                    "Bodyweight {} exceeds sanity check limit {}",
+                    load(bodyweight),
+                    load(limit)
+                )
+            }
+            ValidationError::AssistanceExceedsBodyweight {
+                assistance,
+                bodyweight,
+            } => {
+                format!(
+                    "Assistance {} must be less than bodyweight {}",
+                    load(assistance),
+                    load(bodyweight)
+                )
+            }
+            ValidationError::SetTypeMismatch { exercise_type } => {
+                format!(
+                    "Set type does not match the exercise's {} configuration",
+                    exercise_type
+                )
             }
         }
     }
 }
 
+/// Loads read in kg; [`ValidationError::describe`] gives them in the user's
+/// unit.
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.describe(WeightUnit::Kg))
+    }
+}
+
 impl std::error::Error for ValidationError {}
 
 /// Validates that a weight is at or above the minimum and is a valid multiple of the increment.
@@ -178,6 +242,88 @@ pub fn validate_set_number(set_number: u32) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Upper bound for a timed set: one hour covers any plank or hang.
+pub const MAX_DURATION_SECS: u32 = 3600;
+
+/// Upper bound for a distance set: 10km covers any sled push, carry or
+/// rowing interval.
+pub const MAX_DISTANCE_M: f32 = 10_000.0;
+
+/// Validates that the duration of a timed set is positive and within
+/// reasonable bounds.
+///
+/// # Arguments
+/// * `secs` - The time held, in seconds
+///
+/// # Returns
+/// `Ok(())` if valid, otherwise a `ValidationError`
+#[allow(dead_code)]
+pub fn validate_duration(secs: u32) -> Result<(), ValidationError> {
+    if secs == 0 {
+        return Err(ValidationError::ZeroDuration);
+    }
+
+    if secs > MAX_DURATION_SECS {
+        return Err(ValidationError::DurationExceedsLimit {
+            secs,
+            limit: MAX_DURATION_SECS,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that the distance of a distance or carry set is positive and
+/// within reasonable bounds.
+///
+/// # Arguments
+/// * `distance_m` - The distance covered, in metres
+///
+/// # Returns
+/// `Ok(())` if valid, otherwise a `ValidationError`
+#[allow(dead_code)]
+pub fn validate_distance(distance_m: f32) -> Result<(), ValidationError> {
+    if distance_m <= 0.0 {
+        return Err(ValidationError::DistanceNotPositive { distance_m });
+    }
+
+    if distance_m > MAX_DISTANCE_M {
+        return Err(ValidationError::DistanceExceedsLimit {
+            distance_m,
+            limit: MAX_DISTANCE_M,
+        });
+    }
+
+    Ok(())
+}
+
+/// Upper bound for a logged bodyweight.
+pub const MAX_BODYWEIGHT: f32 = 500.0;
+
+/// Validates that a logged bodyweight is positive and within reasonable
+/// bounds.
+///
+/// # Arguments
+/// * `bodyweight` - The body mass, in kg
+///
+/// # Returns
+/// `Ok(())` if valid, otherwise a `ValidationError`
+#[allow(dead_code)]
+pub fn validate_bodyweight(bodyweight: f32) -> Result<(), ValidationError> {
+    if bodyweight <= 0.0 {
+        return Err(ValidationError::BodyweightNotPositive { bodyweight });
+    }
+
+    if bodyweight > MAX_BODYWEIGHT {
+        return Err(ValidationError::BodyweightExceedsLimit {
+            bodyweight,
+            limit: MAX_BODYWEIGHT,
+        });
+    }
+
+    Ok(())
+}
+
 /// Validates a complete set, checking all fields according to their respective rules.
 ///
 /// # Arguments
@@ -195,13 +341,14 @@ pub fn validate_completed_set(
     validate_reps(set.reps)?;
     validate_rpe(set.rpe)?;
 
-    // Validate weight if this is a weighted set
+    // Validate the load and measured quantity for the exercise's set type
     match (&set.set_type, &exercise.set_type_config) {
         (
             SetType::Weighted { weight },
             SetTypeConfig::Weighted {
                 min_weight,
                 increment,
+                ..
             },
         ) => {
             validate_weight(*weight, *min_weight, *increment)?;
@@ -209,15 +356,61 @@ pub fn validate_completed_set(
         (SetType::Bodyweight, SetTypeConfig::Bodyweight) => {
             // Valid combination - no weight to validate
         }
-        (SetType::Weighted { .. }, SetTypeConfig::Bodyweight) => {
-            // This shouldn't happen - weighted set for bodyweight exercise
-            // TODO: Track as a future ValidationError variant (e.g. SetTypeMismatch)
-            // to make the contract explicit rather than silently valid.
+        (SetType::Timed { duration_secs }, SetTypeConfig::Timed { .. }) => {
+            validate_duration(*duration_secs)?;
+        }
+        (SetType::Distance { distance_m }, SetTypeConfig::Distance { .. }) => {
+            validate_distance(*distance_m)?;
+        }
+        (
+            SetType::Carry { weight, distance_m },
+            SetTypeConfig::Carry {
+                min_weight,
+                increment,
+                ..
+            },
+        ) => {
+            validate_weight(*weight, *min_weight, *increment)?;
+            validate_distance(*distance_m)?;
+        }
+        (
+            SetType::LoadedBodyweight { bodyweight, added },
+            SetTypeConfig::LoadedBodyweight {
+                increment,
+                assisted,
+            },
+        ) => {
+            validate_bodyweight(*bodyweight)?;
+            // Added weight and assistance are both entered as a positive
+            // amount on a grid starting at zero.
+            let amount = if *assisted { -*added } else { *added };
+            validate_weight(amount, 0.0, *increment)?;
+            if *assisted && amount >= *bodyweight {
+                return Err(ValidationError::AssistanceExceedsBodyweight {
+                    assistance: amount,
+                    bodyweight: *bodyweight,
+                });
+            }
         }
-        (SetType::Bodyweight, SetTypeConfig::Weighted { .. }) => {
-            // This shouldn't happen - bodyweight set for weighted exercise
-            // TODO: Track as a future ValidationError variant (e.g. SetTypeMismatch)
-            // to make the contract explicit rather than silently valid.
+        // Every remaining pairing is a mismatch.  Both sides are spelled out
+        // so a new variant fails to compile until it is paired up above.
+        (
+            SetType::Weighted { .. }
+            | SetType::Bodyweight
+            | SetType::Timed { .. }
+            | SetType::Distance { .. }
+            | SetType::Carry { .. }
+            | SetType::LoadedBodyweight { .. },
+            config @ (SetTypeConfig::Weighted { .. }
+            | SetTypeConfig::Bodyweight
+            | SetTypeConfig::Timed { .. }
+            | SetTypeConfig::Distance { .. }
+            | SetTypeConfig::Carry { .. }
+            | SetTypeConfig::LoadedBodyweight { .. }),
+        ) => {
+            return Err(ValidationError::SetTypeMismatch {
+                exercise_type: config.type_name(),
+            });
         }
     }
 
@@ -227,6 +420,7 @@ pub fn validate_completed_set(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::SetKind;
 
     #[test]
     fn test_validate_weight_below_minimum() {
@@ -385,9 +579,15 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 20.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let set = CompletedSet {
@@ -395,6 +595,8 @@ mod tests {
             reps: 10,
             rpe: 7.5,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
         assert!(validate_completed_set(&set, &exercise).is_ok());
@@ -408,6 +610,11 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let set = CompletedSet {
@@ -415,6 +622,8 @@ mod tests {
             reps: 15,
             rpe: 8.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
         assert!(validate_completed_set(&set, &exercise).is_ok());
@@ -428,9 +637,15 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 20.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let set = CompletedSet {
@@ -438,6 +653,8 @@ mod tests {
             reps: 10,
             rpe: 7.5,
             set_type: SetType::Weighted { weight: 15.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
         let result = validate_completed_set(&set, &exercise);
@@ -458,6 +675,11 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let set = CompletedSet {
@@ -465,6 +687,8 @@ mod tests {
             reps: 10,
             rpe: 11.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
         let result = validate_completed_set(&set, &exercise);
@@ -479,6 +703,11 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let set = CompletedSet {
@@ -486,12 +715,244 @@ mod tests {
             reps: 0,
             rpe: 7.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
         let result = validate_completed_set(&set, &exercise);
         assert_eq!(result, Err(ValidationError::ZeroReps));
     }
 
+    #[test]
+    fn test_validate_duration_bounds() {
+        assert_eq!(validate_duration(0), Err(ValidationError::ZeroDuration));
+        assert!(validate_duration(1).is_ok());
+        assert!(validate_duration(3600).is_ok());
+        assert_eq!(
+            validate_duration(3601),
+            Err(ValidationError::DurationExceedsLimit {
+                secs: 3601,
+                limit: 3600
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_distance_bounds() {
+        assert_eq!(
+            validate_distance(0.0),
+            Err(ValidationError::DistanceNotPositive { distance_m: 0.0 })
+        );
+        assert!(validate_distance(0.5).is_ok());
+        assert!(validate_distance(10_000.0).is_ok());
+        assert_eq!(
+            validate_distance(10_001.0),
+            Err(ValidationError::DistanceExceedsLimit {
+                distance_m: 10_001.0,
+                limit: 10_000.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_completed_set_timed_zero_duration() {
+        let exercise = ExerciseMetadata {
+            id: None,
+            name: "Plank".to_string(),
+            set_type_config: SetTypeConfig::Timed { increment_secs: 5 },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        };
+
+        let set = CompletedSet {
+            set_number: 1,
+            reps: 1,
+            rpe: 8.0,
+            set_type: SetType::Timed { duration_secs: 0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        };
+
+        let result = validate_completed_set(&set, &exercise);
+        assert_eq!(result, Err(ValidationError::ZeroDuration));
+    }
+
+    #[test]
+    fn test_validate_completed_set_rejects_mismatched_set_type() {
+        let exercise = ExerciseMetadata {
+            id: None,
+            name: "Bench Press".to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: None,
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        };
+
+        let set = CompletedSet {
+            set_number: 1,
+            reps: 1,
+            rpe: 8.0,
+            set_type: SetType::Timed { duration_secs: 30 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        };
+        assert_eq!(
+            validate_completed_set(&set, &exercise),
+            Err(ValidationError::SetTypeMismatch {
+                exercise_type: "weighted"
+            })
+        );
+
+        let bodyweight = CompletedSet {
+            set_type: SetType::Bodyweight,
+            ..set
+        };
+        assert!(matches!(
+            validate_completed_set(&bodyweight, &exercise),
+            Err(ValidationError::SetTypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_completed_set_carry_checks_weight_and_distance() {
+        let exercise = ExerciseMetadata {
+            id: None,
+            name: "Farmer Carry".to_string(),
+            set_type_config: SetTypeConfig::Carry {
+                min_weight: 16.0,
+                increment: 4.0,
+                increment_m: 5.0,
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        };
+
+        let mut set = CompletedSet {
+            set_number: 1,
+            reps: 1,
+            rpe: 8.0,
+            set_type: SetType::Carry {
+                weight: 32.0,
+                distance_m: 40.0,
+            },
+            kind: SetKind::Working,
+            comment: String::new(),
+        };
+        assert!(validate_completed_set(&set, &exercise).is_ok());
+
+        set.set_type = SetType::Carry {
+            weight: 30.0,
+            distance_m: 40.0,
+        };
+        assert_eq!(
+            validate_completed_set(&set, &exercise),
+            Err(ValidationError::WeightNotMultipleOfIncrement {
+                weight: 30.0,
+                increment: 4.0
+            })
+        );
+
+        set.set_type = SetType::Carry {
+            weight: 32.0,
+            distance_m: 0.0,
+        };
+        assert_eq!(
+            validate_completed_set(&set, &exercise),
+            Err(ValidationError::DistanceNotPositive { distance_m: 0.0 })
+        );
+    }
+
+    #[test]
+    fn test_validate_completed_set_assisted_bodyweight() {
+        let exercise = ExerciseMetadata {
+            id: None,
+            name: "Assisted Dip".to_string(),
+            set_type_config: SetTypeConfig::LoadedBodyweight {
+                increment: 5.0,
+                assisted: true,
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        };
+
+        let mut set = CompletedSet {
+            set_number: 1,
+            reps: 8,
+            rpe: 8.0,
+            set_type: SetType::LoadedBodyweight {
+                bodyweight: 80.0,
+                added: -30.0,
+            },
+            kind: SetKind::Working,
+            comment: String::new(),
+        };
+        assert!(validate_completed_set(&set, &exercise).is_ok());
+
+        set.set_type = SetType::LoadedBodyweight {
+            bodyweight: 0.0,
+            added: -30.0,
+        };
+        assert_eq!(
+            validate_completed_set(&set, &exercise),
+            Err(ValidationError::BodyweightNotPositive { bodyweight: 0.0 })
+        );
+
+        set.set_type = SetType::LoadedBodyweight {
+            bodyweight: 80.0,
+            added: -80.0,
+        };
+        assert_eq!(
+            validate_completed_set(&set, &exercise),
+            Err(ValidationError::AssistanceExceedsBodyweight {
+                assistance: 80.0,
+                bodyweight: 80.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_validation_error_describe_in_lb() {
+        let lb = WeightUnit::Lb;
+        let err = ValidationError::WeightBelowMinimum {
+            weight: lb.to_kg(45.0) as f32,
+            min_weight: lb.to_kg(50.0) as f32,
+        };
+        assert_eq!(err.describe(lb), "Weight 45.0lb is below minimum 50.0lb");
+
+        let err = ValidationError::AssistanceExceedsBodyweight {
+            assistance: lb.to_kg(200.0) as f32,
+            bodyweight: lb.to_kg(180.0) as f32,
+        };
+        assert_eq!(
+            err.describe(lb),
+            "Assistance 200.0lb must be less than bodyweight 180.0lb"
+        );
+        assert_eq!(err.describe(WeightUnit::Kg), err.to_string());
+    }
+
     #[test]
     fn test_validation_error_display() {
         let err = ValidationError::WeightBelowMinimum {
@@ -0,0 +1,221 @@
+use super::MuscleGroup;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Weekly Volume landmarks for one Muscle Group, in Intensity-Adjusted Sets.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VolumeTarget {
+    /// Minimum Effective Volume: less than this barely moves the needle.
+    pub mev: u32,
+    /// Maximum Adaptive Volume: the weekly target.
+    pub mav: u32,
+    /// Maximum Recoverable Volume: more than this outpaces recovery.
+    pub mrv: u32,
+}
+
+impl VolumeTarget {
+    pub const fn new(mev: u32, mav: u32, mrv: u32) -> Self {
+        Self { mev, mav, mrv }
+    }
+
+    /// Where `weekly` Volume sits against these landmarks.
+    pub fn zone(&self, weekly: f64) -> VolumeZone {
+        if weekly < self.mev as f64 {
+            VolumeZone::Under
+        } else if weekly < self.mav as f64 {
+            VolumeZone::Building
+        } else if weekly <= self.mrv as f64 {
+            VolumeZone::OnTarget
+        } else {
+            VolumeZone::Over
+        }
+    }
+}
+
+/// Where a Muscle Group's 7-day Volume sits against its [`VolumeTarget`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VolumeZone {
+    /// Below MEV.
+    Under,
+    /// Between MEV and MAV.
+    Building,
+    /// Between MAV and MRV.
+    OnTarget,
+    /// Above MRV.
+    Over,
+}
+
+impl VolumeZone {
+    pub fn label(self) -> &'static str {
+        match self {
+            VolumeZone::Under => "Under MEV",
+            VolumeZone::Building => "Building",
+            VolumeZone::OnTarget => "On target",
+            VolumeZone::Over => "Over MRV",
+        }
+    }
+
+    /// Hex colour used by the Body Diagram and tier cards.
+    pub fn color(self) -> &'static str {
+        match self {
+            VolumeZone::Under => "#f59e0b",
+            VolumeZone::Building => "#84cc16",
+            VolumeZone::OnTarget => "#16a34a",
+            VolumeZone::Over => "#dc2626",
+        }
+    }
+
+    /// True for the zones worth flagging before the next plan is built.
+    pub fn is_flagged(self) -> bool {
+        matches!(self, VolumeZone::Under | VolumeZone::Over)
+    }
+}
+
+/// A [`VolumeTarget`] per Muscle Group, e.g. `Chest 8/14/22, Back 10/18/25, …`.
+///
+/// Stored inline, in [`MuscleGroup::ALL`] order, so that
+/// [`Settings`](super::Settings) stays `Copy`, and persisted as its display
+/// string.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VolumeTargets {
+    targets: [VolumeTarget; MuscleGroup::ALL.len()],
+}
+
+impl VolumeTargets {
+    pub fn get(&self, group: &MuscleGroup) -> VolumeTarget {
+        self.targets[Self::index(group)]
+    }
+
+    pub fn set(&mut self, group: &MuscleGroup, target: VolumeTarget) {
+        self.targets[Self::index(group)] = target;
+    }
+
+    /// Zone of `weekly` Volume for `group`.
+    pub fn zone(&self, group: &MuscleGroup, weekly: f64) -> VolumeZone {
+        self.get(group).zone(weekly)
+    }
+
+    fn index(group: &MuscleGroup) -> usize {
+        MuscleGroup::ALL
+            .iter()
+            .position(|g| g == group)
+            .expect("every muscle group is in MuscleGroup::ALL")
+    }
+}
+
+impl Default for VolumeTargets {
+    /// Common hypertrophy landmarks in working sets per week.
+    fn default() -> Self {
+        Self {
+            targets: [
+                VolumeTarget::new(8, 14, 22),  // Chest
+                VolumeTarget::new(10, 18, 25), // Back
+                VolumeTarget::new(8, 16, 26),  // Shoulders
+                VolumeTarget::new(8, 14, 26),  // Biceps
+                VolumeTarget::new(6, 10, 18),  // Triceps
+                VolumeTarget::new(8, 15, 20),  // Quads
+                VolumeTarget::new(6, 12, 20),  // Hamstrings
+                VolumeTarget::new(0, 8, 16),   // Glutes
+                VolumeTarget::new(8, 12, 20),  // Calves
+                VolumeTarget::new(0, 16, 25),  // Core
+                VolumeTarget::new(2, 10, 25),  // Forearms
+                VolumeTarget::new(0, 12, 26),  // Traps
+            ],
+        }
+    }
+}
+
+impl fmt::Display for VolumeTargets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (group, t)) in MuscleGroup::ALL.iter().zip(&self.targets).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}/{}/{}", group, t.mev, t.mav, t.mrv)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for VolumeTargets {
+    type Err = String;
+
+    /// Parses `Chest 8/14/22, Back 10/18/25, …`. Groups left out keep their
+    /// defaults, so an empty string is the default targets.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut targets = Self::default();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, values) = part
+                .rsplit_once(' ')
+                .ok_or_else(|| format!("Expected GROUP MEV/MAV/MRV, got: {}", part))?;
+            let group = name.trim().parse::<MuscleGroup>()?;
+            let numbers = values
+                .split('/')
+                .map(|v| v.trim().parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| format!("Invalid volume target: {}", values))?;
+            let [mev, mav, mrv] = numbers[..] else {
+                return Err(format!("Expected MEV/MAV/MRV, got: {}", values));
+            };
+            if mev > mav || mav > mrv {
+                return Err(format!(
+                    "{} targets must satisfy MEV ≤ MAV ≤ MRV, got: {}",
+                    group, values
+                ));
+            }
+            targets.set(&group, VolumeTarget::new(mev, mav, mrv));
+        }
+        Ok(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_boundaries() {
+        let t = VolumeTarget::new(8, 14, 22);
+        assert_eq!(t.zone(7.9), VolumeZone::Under);
+        assert_eq!(t.zone(8.0), VolumeZone::Building);
+        assert_eq!(t.zone(14.0), VolumeZone::OnTarget);
+        assert_eq!(t.zone(22.0), VolumeZone::OnTarget);
+        assert_eq!(t.zone(22.1), VolumeZone::Over);
+        // A zero MEV never flags a rest week as under-trained.
+        assert_eq!(VolumeTarget::new(0, 8, 16).zone(0.0), VolumeZone::Building);
+    }
+
+    #[test]
+    fn test_targets_display_from_str_round_trip() {
+        let targets = VolumeTargets::default();
+        let text = targets.to_string();
+        assert!(text.starts_with("Chest 8/14/22, Back 10/18/25"));
+        assert_eq!(text.parse::<VolumeTargets>().unwrap(), targets);
+    }
+
+    #[test]
+    fn test_targets_from_str_overrides_listed_groups() {
+        let targets: VolumeTargets = "Quads 10/16/24".parse().unwrap();
+        assert_eq!(
+            targets.get(&MuscleGroup::Quads),
+            VolumeTarget::new(10, 16, 24)
+        );
+        assert_eq!(
+            targets.get(&MuscleGroup::Chest),
+            VolumeTargets::default().get(&MuscleGroup::Chest)
+        );
+        assert_eq!(
+            "".parse::<VolumeTargets>().unwrap(),
+            VolumeTargets::default()
+        );
+    }
+
+    #[test]
+    fn test_targets_from_str_rejects_invalid() {
+        assert!("Chest 8/14".parse::<VolumeTargets>().is_err());
+        assert!("Neck 1/2/3".parse::<VolumeTargets>().is_err());
+        assert!("Chest 14/8/22".parse::<VolumeTargets>().is_err());
+        assert!("Chest".parse::<VolumeTargets>().is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Maximum number of steps a warm-up ramp can hold.
+pub const MAX_WARMUP_STEPS: usize = 6;
+
+/// One step of a warm-up ramp: a load relative to the first working weight
+/// and a target rep count.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WarmupStep {
+    /// Percentage of the first working weight; 0 means the empty bar (or the
+    /// exercise's minimum weight when it is not plate-loaded).
+    pub percent: u32,
+    /// Target reps for this step
+    pub reps: u32,
+}
+
+/// The configurable warm-up ramp, e.g. `bar×10, 50%×5, 70%×3, 85%×1`.
+///
+/// Stored inline (fixed capacity) so that [`Settings`](super::Settings) stays
+/// `Copy`, and persisted as its display string.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WarmupRamp {
+    steps: [WarmupStep; MAX_WARMUP_STEPS],
+    len: usize,
+}
+
+impl WarmupRamp {
+    /// Builds a ramp from up to [`MAX_WARMUP_STEPS`] steps; extra steps are
+    /// dropped.
+    pub fn new(steps: &[WarmupStep]) -> Self {
+        let mut ramp = Self {
+            steps: [WarmupStep::default(); MAX_WARMUP_STEPS],
+            len: steps.len().min(MAX_WARMUP_STEPS),
+        };
+        ramp.steps[..ramp.len].copy_from_slice(&steps[..ramp.len]);
+        ramp
+    }
+
+    pub fn steps(&self) -> &[WarmupStep] {
+        &self.steps[..self.len]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Default for WarmupRamp {
+    fn default() -> Self {
+        Self::new(&[
+            WarmupStep {
+                percent: 0,
+                reps: 10,
+            },
+            WarmupStep {
+                percent: 50,
+                reps: 5,
+            },
+            WarmupStep {
+                percent: 70,
+                reps: 3,
+            },
+            WarmupStep {
+                percent: 85,
+                reps: 1,
+            },
+        ])
+    }
+}
+
+impl fmt::Display for WarmupRamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, step) in self.steps().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            if step.percent == 0 {
+                write!(f, "bar×{}", step.reps)?;
+            } else {
+                write!(f, "{}%×{}", step.percent, step.reps)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for WarmupRamp {
+    type Err = String;
+
+    /// Parses `bar×10, 50%×5, …`; `x` is accepted in place of `×` and an
+    /// empty string is an empty ramp (warm-ups off).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut steps = Vec::new();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (load, reps) = part
+                .split_once(['×', 'x', 'X'])
+                .ok_or_else(|| format!("Expected LOAD×REPS, got: {}", part))?;
+            let load = load.trim();
+            let percent = if load.eq_ignore_ascii_case("bar") {
+                0
+            } else {
+                load.trim_end_matches('%')
+                    .trim()
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|p| (1..100).contains(p))
+                    .ok_or_else(|| format!("Invalid warm-up percentage: {}", load))?
+            };
+            let reps = reps
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|r| *r > 0)
+                .ok_or_else(|| format!("Invalid warm-up reps: {}", reps.trim()))?;
+            steps.push(WarmupStep { percent, reps });
+        }
+        if steps.len() > MAX_WARMUP_STEPS {
+            return Err(format!(
+                "A warm-up ramp can have at most {} steps",
+                MAX_WARMUP_STEPS
+            ));
+        }
+        Ok(Self::new(&steps))
+    }
+}
+
+/// A generated warm-up pre-set shown on the Record Screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WarmupSet {
+    /// Load in kg
+    pub weight: f32,
+    pub reps: u32,
+    /// True once the warm-up has been checked off (and logged)
+    pub done: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_ramp_display() {
+        assert_eq!(
+            WarmupRamp::default().to_string(),
+            "bar×10, 50%×5, 70%×3, 85%×1"
+        );
+    }
+
+    #[test]
+    fn test_ramp_display_from_str_round_trip() {
+        let ramp = WarmupRamp::default();
+        assert_eq!(ramp.to_string().parse::<WarmupRamp>().unwrap(), ramp);
+    }
+
+    #[test]
+    fn test_ramp_from_str_accepts_ascii_x_and_empty() {
+        let ramp: WarmupRamp = "Bar x 8, 60% x 4".parse().unwrap();
+        assert_eq!(
+            ramp.steps(),
+            &[
+                WarmupStep {
+                    percent: 0,
+                    reps: 8
+                },
+                WarmupStep {
+                    percent: 60,
+                    reps: 4
+                },
+            ]
+        );
+        assert!("".parse::<WarmupRamp>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ramp_from_str_rejects_invalid() {
+        assert!("50%".parse::<WarmupRamp>().is_err());
+        assert!("120%×3".parse::<WarmupRamp>().is_err());
+        assert!("50%×0".parse::<WarmupRamp>().is_err());
+        assert!(
+            "bar×1, bar×1, bar×1, bar×1, bar×1, bar×1, bar×1"
+                .parse::<WarmupRamp>()
+                .is_err()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::models::ExerciseMetadata;
+use crate::models::{ExerciseMetadata, ProgramPosition};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct PlanExercise {
@@ -6,6 +6,57 @@ pub struct PlanExercise {
     pub exercise: ExerciseMetadata,
     pub planned_sets: u32,
     pub position: u32,
+    /// Slots sharing a group id and adjacent in the plan form a superset or
+    /// circuit (see [`crate::domain::slot_group`]); `None` when ungrouped.
+    pub group_id: Option<String>,
+    /// Rep Range and target RPE for this slot, overriding the exercise's and
+    /// the global settings'.
+    pub targets: SlotTargets,
+}
+
+/// Optional per-slot overrides of the Rep Range and target RPE, so a heavy
+/// and a light day on the same lift get different Suggestions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SlotTargets {
+    /// `(min_reps, max_reps)` replacing the exercise's Rep Range; a `None`
+    /// max leaves the range open-ended.
+    pub rep_range: Option<(i32, Option<i32>)>,
+    /// Replaces `Settings::target_rpe` for sets of this slot.
+    pub target_rpe: Option<f64>,
+}
+
+impl SlotTargets {
+    pub fn is_empty(&self) -> bool {
+        self.rep_range.is_none() && self.target_rpe.is_none()
+    }
+
+    /// The Rep Range in effect for `exercise` in this slot.
+    pub fn rep_range_for(&self, exercise: &ExerciseMetadata) -> (i32, Option<i32>) {
+        self.rep_range
+            .unwrap_or((exercise.min_reps, exercise.max_reps))
+    }
+
+    /// The target RPE in effect, given the global default.
+    pub fn target_rpe_or(&self, default: f64) -> f64 {
+        self.target_rpe.unwrap_or(default)
+    }
+
+    /// Short description such as `"3–5 reps @ RPE 9"`; empty when no
+    /// override is set.
+    pub fn summary(&self) -> String {
+        let reps = self.rep_range.map(|range| match range {
+            (min, Some(max)) if max == min => format!("{min} reps"),
+            (min, Some(max)) => format!("{min}–{max} reps"),
+            (min, None) => format!("{min}+ reps"),
+        });
+        let rpe = self.target_rpe.map(|rpe| format!("RPE {rpe}"));
+        match (reps, rpe) {
+            (Some(reps), Some(rpe)) => format!("{reps} @ {rpe}"),
+            (Some(reps), None) => reps,
+            (None, Some(rpe)) => format!("@ {rpe}"),
+            (None, None) => String::new(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -14,6 +65,8 @@ pub struct WorkoutPlan {
     pub started_at: Option<f64>,
     pub ended_at: Option<f64>,
     pub exercises: Vec<PlanExercise>,
+    /// Set when the plan was materialized from a Program.
+    pub program: Option<ProgramPosition>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -22,3 +75,51 @@ pub struct WorkoutTemplate {
     pub name: String,
     pub exercises: Vec<PlanExercise>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SetTypeConfig;
+
+    fn exercise() -> ExerciseMetadata {
+        ExerciseMetadata {
+            id: None,
+            name: "Squat".to_string(),
+            set_type_config: SetTypeConfig::Bodyweight,
+            min_reps: 5,
+            max_reps: Some(8),
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_slot_targets_fall_back_to_exercise_and_settings() {
+        let targets = SlotTargets::default();
+        assert!(targets.is_empty());
+        assert_eq!(targets.rep_range_for(&exercise()), (5, Some(8)));
+        assert_eq!(targets.target_rpe_or(8.0), 8.0);
+        assert_eq!(targets.summary(), "");
+    }
+
+    #[test]
+    fn test_slot_targets_override() {
+        let targets = SlotTargets {
+            rep_range: Some((3, Some(5))),
+            target_rpe: Some(9.0),
+        };
+        assert_eq!(targets.rep_range_for(&exercise()), (3, Some(5)));
+        assert_eq!(targets.target_rpe_or(8.0), 9.0);
+        assert_eq!(targets.summary(), "3–5 reps @ RPE 9");
+
+        let open = SlotTargets {
+            rep_range: Some((12, None)),
+            target_rpe: None,
+        };
+        assert_eq!(open.rep_range_for(&exercise()), (12, None));
+        assert_eq!(open.summary(), "12+ reps");
+    }
+}
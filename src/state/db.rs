@@ -1,6 +1,13 @@
+use crate::domain::calibration::CalibrationSet;
+use crate::domain::e1rm::E1rmModel;
+use crate::export::{ExportFilter, ExportRow};
+use crate::import::{ExerciseMapping, ExerciseTarget, ImportPlan, ImportTime, LoggedSet};
+use crate::merge::{LocalTable, MERGE_TABLES, MUSCLE_GROUP_TABLE, MergePlan, Row, RowChange};
 use crate::models::{
-    CompletedSet, ContributionTier, ExerciseMetadata, ExerciseMuscleGroup, HistorySet, MuscleGroup,
-    MuscleGroupVolume, PlanExercise, SetType, SetTypeConfig, WorkoutPlan, WorkoutTemplate,
+    BodyweightEntry, CompletedSet, ContributionTier, E1rmFormula, ExerciseMetadata,
+    ExerciseMuscleGroup, HistorySet, MuscleGroup, MuscleGroupVolume, PersonalRecord, PlanExercise,
+    Plate, Program, ProgramDay, ProgramPosition, ProgressionRule, ProgressionStrategy, RecordKind,
+    RpeCalibration, SetKind, SetType, SetTypeConfig, SlotTargets, WorkoutPlan, WorkoutTemplate,
 };
 use std::str::FromStr;
 use thiserror::Error;
@@ -48,11 +55,17 @@ extern "C" {
     async fn export_database() -> JsValue;
 
     #[wasm_bindgen(js_name = downloadBytes)]
-    async fn download_bytes(data: &[u8], filename: &str) -> JsValue;
+    async fn download_bytes(data: &[u8], filename: &str, mime_type: &str) -> JsValue;
 
     #[wasm_bindgen(js_name = importDatabase)]
     async fn import_database(file_data: Vec<u8>) -> JsValue;
 
+    #[wasm_bindgen(js_name = readDatabaseTables, catch)]
+    async fn read_database_tables(
+        file_data: Vec<u8>,
+        tables: js_sys::Array,
+    ) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_name = ensureCrrTables)]
     async fn ensure_crr_tables() -> JsValue;
 
@@ -68,7 +81,7 @@ extern "C" {
 }
 
 /// Current schema version. Bump this when the schema changes.
-const SCHEMA_VERSION: i64 = 10;
+const SCHEMA_VERSION: i64 = 27;
 
 #[derive(Clone, PartialEq)]
 pub struct Database {
@@ -133,6 +146,134 @@ impl Database {
         }
     }
 
+    /// Reads another copy of the database and plans merging it into this
+    /// one (see `crate::merge`).  Nothing is written.
+    pub async fn preview_merge(&self, file_data: Vec<u8>) -> Result<MergePlan, DatabaseError> {
+        let names = js_sys::Array::new();
+        for table in MERGE_TABLES.iter() {
+            names.push(&JsValue::from_str(table.name));
+        }
+        names.push(&JsValue::from_str(MUSCLE_GROUP_TABLE));
+        let tables = read_database_tables(file_data, names.clone()).await?;
+
+        let mut local = std::collections::HashMap::new();
+        let mut incoming = std::collections::HashMap::new();
+        for name in names.iter().filter_map(|n| n.as_string()) {
+            let rows = js_sys::Reflect::get(&tables, &JsValue::from_str(&name))?;
+            incoming.insert(name.clone(), Self::rows_from_js(&rows)?);
+            let columns = self
+                .execute(&format!("PRAGMA table_info(\"{}\")", name), &[])
+                .await?;
+            let columns = Self::rows_from_js(&columns)?
+                .iter()
+                .filter_map(|c| c.get("name").and_then(|n| n.as_str()).map(String::from))
+                .collect();
+            let rows = self
+                .execute(&format!("SELECT * FROM \"{}\"", name), &[])
+                .await?;
+            let rows = Self::rows_from_js(&rows)?;
+            local.insert(name, LocalTable { columns, rows });
+        }
+        Ok(crate::merge::plan_merge(&local, &incoming))
+    }
+
+    /// Writes a merge planned by [`Database::preview_merge`] in one
+    /// transaction.
+    pub async fn apply_merge(&self, plan: &MergePlan) -> Result<(), DatabaseError> {
+        self.execute("BEGIN", &[]).await?;
+        match self.apply_merge_inner(plan).await {
+            Ok(()) => {
+                self.execute("COMMIT", &[]).await?;
+                Ok(())
+            }
+            Err(e) => {
+                let _ = self.execute("ROLLBACK", &[]).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn apply_merge_inner(&self, plan: &MergePlan) -> Result<(), DatabaseError> {
+        for change in &plan.changes {
+            match change {
+                RowChange::Insert { table, row } => {
+                    let columns: Vec<String> = row.keys().map(|c| format!("\"{}\"", c)).collect();
+                    let placeholders = vec!["?"; columns.len()].join(", ");
+                    let sql = format!(
+                        "INSERT INTO \"{}\" ({}) VALUES ({})",
+                        table,
+                        columns.join(", "),
+                        placeholders
+                    );
+                    let params: Vec<JsValue> = row.values().map(Self::json_to_js).collect();
+                    self.execute(&sql, &params).await?;
+                }
+                RowChange::Update {
+                    table,
+                    column,
+                    id,
+                    row,
+                } => {
+                    if row.is_empty() {
+                        continue;
+                    }
+                    let assignments: Vec<String> =
+                        row.keys().map(|c| format!("\"{}\" = ?", c)).collect();
+                    let sql = format!(
+                        "UPDATE \"{}\" SET {} WHERE \"{}\" = ?",
+                        table,
+                        assignments.join(", "),
+                        column
+                    );
+                    let mut params: Vec<JsValue> = row.values().map(Self::json_to_js).collect();
+                    params.push(Self::json_to_js(id));
+                    self.execute(&sql, &params).await?;
+                }
+            }
+        }
+
+        for (exercise_id, groups) in &plan.muscle_groups {
+            self.execute(
+                "DELETE FROM exercise_muscle_groups WHERE exercise_id = ?",
+                &[JsValue::from_str(exercise_id)],
+            )
+            .await?;
+            for group in groups {
+                self.execute(
+                    "INSERT INTO exercise_muscle_groups (exercise_id, muscle_group, tier) VALUES (?, ?, ?)",
+                    &[
+                        JsValue::from_str(exercise_id),
+                        Self::json_to_js(group.get("muscle_group").unwrap_or(&serde_json::Value::Null)),
+                        Self::json_to_js(group.get("tier").unwrap_or(&serde_json::Value::Null)),
+                    ],
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts an array of row objects into JSON maps.
+    fn rows_from_js(value: &JsValue) -> Result<Vec<Row>, DatabaseError> {
+        if value.is_undefined() || value.is_null() {
+            return Ok(Vec::new());
+        }
+        let json = js_sys::JSON::stringify(value)?
+            .as_string()
+            .unwrap_or_default();
+        serde_json::from_str(&json).map_err(|e| DatabaseError::QueryError(e.to_string()))
+    }
+
+    fn json_to_js(value: &serde_json::Value) -> JsValue {
+        match value {
+            serde_json::Value::Null => JsValue::NULL,
+            serde_json::Value::Bool(b) => JsValue::from_bool(*b),
+            serde_json::Value::Number(n) => JsValue::from_f64(n.as_f64().unwrap_or(0.0)),
+            serde_json::Value::String(s) => JsValue::from_str(s),
+            other => JsValue::from_str(&other.to_string()),
+        }
+    }
+
     pub async fn export(&self) -> Result<Vec<u8>, DatabaseError> {
         if !self.initialized {
             return Err(DatabaseError::NotInitialized);
@@ -154,7 +295,17 @@ impl Database {
     /// Returns an error if all strategies fail.
     pub async fn download(&self, filename: &str) -> Result<(), DatabaseError> {
         let data = self.export().await?;
-        let result = download_bytes(&data, filename).await;
+        Self::download_file(&data, filename, "application/x-sqlite3").await
+    }
+
+    /// Triggers a browser download of `data` as `filename` with content type
+    /// `mime_type`, using the same strategies as [`Database::download`].
+    pub async fn download_file(
+        data: &[u8],
+        filename: &str,
+        mime_type: &str,
+    ) -> Result<(), DatabaseError> {
+        let result = download_bytes(data, filename, mime_type).await;
 
         // downloadBytes returns { ok: bool, method?: string, error?: string }
         let ok = js_sys::Reflect::get(&result, &JsValue::from_str("ok"))
@@ -282,6 +433,108 @@ impl Database {
             self.apply_v10_migration().await?;
         }
 
+        // ── v11 migration: weight_unit setting ────────────────────────────
+        if current_version < 11 {
+            log::debug!("[DB] Applying v11 migration: weight_unit setting");
+            self.apply_v11_migration().await?;
+        }
+
+        // ── v12 migration: bar weight + plate inventory ───────────────────
+        if current_version < 12 {
+            log::debug!("[DB] Applying v12 migration: bar weight and plates table");
+            self.apply_v12_migration().await?;
+        }
+
+        // ── v13 migration: warm-up sets + warm-up ramp setting ────────────
+        if current_version < 13 {
+            log::debug!("[DB] Applying v13 migration: warm-up sets and ramp setting");
+            self.apply_v13_migration().await?;
+        }
+
+        // ── v14 migration: set kind ───────────────────────────────────────
+        if current_version < 14 {
+            log::debug!("[DB] Applying v14 migration: set kind");
+            self.apply_v14_migration().await?;
+        }
+
+        // ── v15 migration: timed, distance and carry set types ────────────
+        if current_version < 15 {
+            log::debug!("[DB] Applying v15 migration: timed, distance and carry set types");
+            self.apply_v15_migration().await?;
+        }
+
+        // ── v16 migration: loaded bodyweight sets + bodyweight log ────────
+        if current_version < 16 {
+            log::debug!("[DB] Applying v16 migration: bodyweight column and bodyweight log");
+            self.apply_v16_migration().await?;
+        }
+
+        // ── v17 migration: exercise notes + set comments ──────────────────
+        if current_version < 17 {
+            log::debug!("[DB] Applying v17 migration: exercise notes and set comments");
+            self.apply_v17_migration().await?;
+        }
+
+        // ── v18 migration: rest timer defaults + recorded rest ────────────
+        if current_version < 18 {
+            log::debug!("[DB] Applying v18 migration: rest durations");
+            self.apply_v18_migration().await?;
+        }
+
+        // ── v19 migration: superset / circuit slot groups ─────────────────
+        if current_version < 19 {
+            log::debug!("[DB] Applying v19 migration: plan slot groups");
+            self.apply_v19_migration().await?;
+        }
+
+        // ── v20 migration: per-slot rep range / RPE overrides ─────────────
+        if current_version < 20 {
+            log::debug!("[DB] Applying v20 migration: slot targets");
+            self.apply_v20_migration().await?;
+        }
+
+        // ── v21 migration: multi-week programs ────────────────────────────
+        if current_version < 21 {
+            log::debug!("[DB] Applying v21 migration: programs");
+            self.apply_v21_migration().await?;
+        }
+
+        // ── v22 migration: personal records ───────────────────────────────
+        if current_version < 22 {
+            log::debug!("[DB] Applying v22 migration: personal records");
+            self.apply_v22_migration().await?;
+        }
+
+        // ── v23 migration: volume_targets setting ─────────────────────────
+        if current_version < 23 {
+            log::debug!("[DB] Applying v23 migration: volume_targets setting");
+            self.apply_v23_migration().await?;
+        }
+
+        // ── v24 migration: e1RM formulas ──────────────────────────────────
+        if current_version < 24 {
+            log::debug!("[DB] Applying v24 migration: e1RM formulas");
+            self.apply_v24_migration().await?;
+        }
+
+        // ── v25 migration: RPE calibration ────────────────────────────────
+        if current_version < 25 {
+            log::debug!("[DB] Applying v25 migration: RPE calibration");
+            self.apply_v25_migration().await?;
+        }
+
+        // ── v26 migration: progression strategies ─────────────────────────
+        if current_version < 26 {
+            log::debug!("[DB] Applying v26 migration: progression strategies");
+            self.apply_v26_migration().await?;
+        }
+
+        // ── v27 migration: exercise weight units ──────────────────────────
+        if current_version < 27 {
+            log::debug!("[DB] Applying v27 migration: exercise weight units");
+            self.apply_v27_migration().await?;
+        }
+
         // Stamp the new version
         self.execute_internal(&format!("PRAGMA user_version = {}", SCHEMA_VERSION), &[])
             .await?;
@@ -740,179 +993,585 @@ impl Database {
         Ok(())
     }
 
-    /// Inserts the default settings row if no row exists yet.
-    /// Uses a SELECT guard instead of INSERT OR IGNORE because CRR tables
-    /// don't support ON CONFLICT clauses.
-    async fn seed_settings(&self) -> Result<(), DatabaseError> {
-        let existing = self
-            .execute_internal("SELECT id FROM settings WHERE id = 1", &[])
-            .await?;
-        let has_row = existing
-            .dyn_ref::<js_sys::Array>()
-            .map(|a| a.length() > 0)
-            .unwrap_or(false);
-        if !has_row {
-            self.execute_internal(
-                "INSERT INTO settings (id, target_rpe, history_window_days, today_blend_factor) VALUES (1, 8.0, 30, 0.5)",
-                &[],
-            )
-            .await?;
-        }
-        Ok(())
-    }
+    /// Adds `weight_unit` column to settings table.  Existing users keep
+    /// kilograms; stored weights are canonical kg regardless of this value.
+    async fn apply_v11_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE settings ADD COLUMN weight_unit TEXT NOT NULL DEFAULT 'kg'",
+        )
+        .await?;
 
-    /// Executes an ALTER TABLE ADD COLUMN statement, suppressing only
-    /// "duplicate column" errors (which mean the column already exists).
-    /// Any other error is propagated.
-    ///
-    /// Note: Detection relies on the English error message "duplicate column"
-    /// returned by SQLite for `SQLITE_ERROR` on `ALTER TABLE ADD COLUMN` when
-    /// the column already exists.  SQLite error messages are not localised, so
-    /// this is stable across platforms, but it is version-sensitive in
-    /// principle. The crsqlite-wasm build pins the SQLite version, mitigating
-    /// this risk.
-    async fn add_column_if_missing(&self, sql: &str) -> Result<(), DatabaseError> {
-        match self.execute_internal(sql, &[]).await {
-            Ok(_) => Ok(()),
-            Err(DatabaseError::QueryError(msg)) if msg.contains("duplicate column") => {
-                log::debug!("[DB] Column already exists, skipping: {}", sql);
-                Ok(())
-            }
-            Err(e) => Err(e),
-        }
+        log::debug!("[DB] v11 migration complete — weight_unit added");
+        Ok(())
     }
 
-    /// Generate a UUID v4 string using the browser's crypto API.
-    fn generate_uuid() -> String {
-        // Use crypto.randomUUID() if available (all modern browsers).
-        let global = js_sys::global();
-        if let Ok(crypto) = js_sys::Reflect::get(&global, &JsValue::from_str("crypto"))
-            && let Ok(uuid_fn) = js_sys::Reflect::get(&crypto, &JsValue::from_str("randomUUID"))
-            && let Some(f) = uuid_fn.dyn_ref::<js_sys::Function>()
-            && let Ok(result) = f.call0(&crypto)
-            && let Some(s) = result.as_string()
-        {
-            return s;
-        }
+    /// Adds `bar_weight` to exercises and creates the CRR-compatible `plates`
+    /// inventory table.  Existing exercises stay non-plate-loaded (NULL).
+    async fn apply_v12_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing("ALTER TABLE exercises ADD COLUMN bar_weight REAL")
+            .await?;
 
-        // Fallback: construct a UUID-shaped string from Math.random().
-        let r = || (js_sys::Math::random() * 65535.0_f64).floor() as u32;
-        format!(
-            "{:04x}{:04x}-{:04x}-4{:03x}-{:04x}-{:04x}{:04x}{:04x}",
-            r(),
-            r(),
-            r(),
-            r() & 0x0fff,
-            (r() & 0x3fff) | 0x8000,
-            r(),
-            r(),
-            r()
+        self.execute_internal(
+            "CREATE TABLE IF NOT EXISTS plates (
+                id TEXT PRIMARY KEY NOT NULL,
+                weight REAL NOT NULL DEFAULT 0,
+                count INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL DEFAULT 0,
+                deleted_at INTEGER
+            )",
+            &[],
         )
-    }
+        .await?;
 
-    async fn get_schema_version(&self) -> Result<i64, DatabaseError> {
-        let result = self.execute_internal("PRAGMA user_version", &[]).await?;
-        let array = match result.dyn_ref::<js_sys::Array>() {
-            Some(a) => a,
-            None => return Ok(0),
-        };
-        if array.length() == 0 {
-            return Ok(0);
-        }
-        let row = array.get(0);
-        let version = js_sys::Reflect::get(&row, &JsValue::from_str("user_version"))
-            .unwrap_or(JsValue::from_f64(0.0))
-            .as_f64()
-            .unwrap_or(0.0) as i64;
-        Ok(version)
+        log::debug!("[DB] v12 migration complete — bar_weight and plates table added");
+        Ok(())
     }
 
-    pub async fn execute(&self, sql: &str, params: &[JsValue]) -> Result<JsValue, DatabaseError> {
-        if !self.initialized {
-            return Err(DatabaseError::NotInitialized);
-        }
+    /// Adds the `is_warmup` tag to completed_sets and the `warmup_ramp`
+    /// setting.  Existing sets are all working sets.
+    async fn apply_v13_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE completed_sets ADD COLUMN is_warmup INTEGER NOT NULL DEFAULT 0",
+        )
+        .await?;
+        self.add_column_if_missing(
+            "ALTER TABLE settings ADD COLUMN warmup_ramp TEXT NOT NULL DEFAULT 'bar×10, 50%×5, 70%×3, 85%×1'",
+        )
+        .await?;
 
-        self.execute_internal(sql, params).await
+        log::debug!("[DB] v13 migration complete — is_warmup and warmup_ramp added");
+        Ok(())
     }
 
-    async fn execute_internal(
-        &self,
-        sql: &str,
-        params: &[JsValue],
-    ) -> Result<JsValue, DatabaseError> {
-        let params_array = js_sys::Array::new();
-        for param in params {
-            params_array.push(param);
-        }
+    /// Adds the `kind` column to completed_sets and back-fills warm-ups from
+    /// the v13 `is_warmup` tag.  `is_warmup` is still written alongside `kind`
+    /// so peers on older versions keep excluding warm-ups.
+    async fn apply_v14_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE completed_sets ADD COLUMN kind TEXT NOT NULL DEFAULT 'working'",
+        )
+        .await?;
+        self.execute_internal(
+            "UPDATE completed_sets SET kind = 'warmup' WHERE is_warmup = 1 AND kind = 'working'",
+            &[],
+        )
+        .await?;
 
-        let result = execute_query(sql, params_array.into()).await;
+        log::debug!("[DB] v14 migration complete — kind added");
+        Ok(())
+    }
 
-        if let Some(error) = result.dyn_ref::<js_sys::Error>() {
-            return Err(DatabaseError::QueryError(
-                error.message().as_string().unwrap_or_default(),
-            ));
-        }
+    /// Adds `set_type` and `measure_increment` to exercises and the measured
+    /// `duration_secs` / `distance_m` columns to completed_sets.  Existing
+    /// exercises are back-filled from `is_weighted`; rows written by peers
+    /// that predate v15 leave `set_type` empty and are read the same way.
+    async fn apply_v15_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE exercises ADD COLUMN set_type TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+        self.add_column_if_missing("ALTER TABLE exercises ADD COLUMN measure_increment REAL")
+            .await?;
+        self.execute_internal(
+            "UPDATE exercises SET set_type = CASE WHEN is_weighted = 1 THEN 'weighted' ELSE 'bodyweight' END WHERE set_type = ''",
+            &[],
+        )
+        .await?;
+        self.add_column_if_missing("ALTER TABLE completed_sets ADD COLUMN duration_secs INTEGER")
+            .await?;
+        self.add_column_if_missing("ALTER TABLE completed_sets ADD COLUMN distance_m REAL")
+            .await?;
 
-        Ok(result)
+        log::debug!("[DB] v15 migration complete — set_type and measured columns added");
+        Ok(())
     }
 
-    /// Log a single set for the given exercise. Records the current timestamp.
-    pub async fn log_set(
-        &self,
-        exercise_id: &str,
-        set: &CompletedSet,
-    ) -> Result<i64, DatabaseError> {
-        let (weight, is_bodyweight) = match set.set_type {
-            SetType::Weighted { weight } => (Some(weight), false),
-            SetType::Bodyweight => (None, true),
-        };
+    /// Adds the `bodyweight` snapshot column to completed_sets and creates the
+    /// CRR-compatible `bodyweight_log` table.  Existing sets keep NULL, which
+    /// marks them as not loaded-bodyweight.
+    async fn apply_v16_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing("ALTER TABLE completed_sets ADD COLUMN bodyweight REAL")
+            .await?;
 
-        let now = js_sys::Date::now();
-        let uuid = Self::generate_uuid();
+        self.execute_internal(
+            "CREATE TABLE IF NOT EXISTS bodyweight_log (
+                id TEXT PRIMARY KEY NOT NULL,
+                recorded_at INTEGER NOT NULL DEFAULT 0,
+                mass REAL NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL DEFAULT 0,
+                deleted_at INTEGER
+            )",
+            &[],
+        )
+        .await?;
 
-        let sql = r#"
-            INSERT INTO completed_sets (exercise_id, set_number, reps, rpe, weight, is_bodyweight, recorded_at, uuid, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
-            RETURNING id
-        "#;
+        log::debug!("[DB] v16 migration complete — bodyweight column and bodyweight_log added");
+        Ok(())
+    }
 
-        let params = vec![
-            JsValue::from_str(exercise_id),
-            JsValue::from_f64(set.set_number as f64),
-            JsValue::from_f64(set.reps as f64),
-            JsValue::from_f64(set.rpe as f64),
-            weight
-                .map(|w| JsValue::from_f64(w as f64))
-                .unwrap_or(JsValue::NULL),
-            JsValue::from_bool(is_bodyweight),
-            JsValue::from_f64(now),
-            JsValue::from_str(&uuid),
-            JsValue::from_f64(now),
-        ];
+    /// v17 migration: free-text `exercises.notes` and `completed_sets.comment`.
+    async fn apply_v17_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE exercises ADD COLUMN notes TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+        self.add_column_if_missing(
+            "ALTER TABLE completed_sets ADD COLUMN comment TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+        log::debug!("[DB] v17 migration complete — notes and comment columns added");
+        Ok(())
+    }
+
+    /// v18 migration: per-exercise `exercises.rest_secs` (NULL = default rest)
+    /// and the actual rest taken before each set, `completed_sets.rest_secs`.
+    async fn apply_v18_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing("ALTER TABLE exercises ADD COLUMN rest_secs INTEGER")
+            .await?;
+        self.add_column_if_missing("ALTER TABLE completed_sets ADD COLUMN rest_secs INTEGER")
+            .await?;
+        log::debug!("[DB] v18 migration complete — rest_secs columns added");
+        Ok(())
+    }
+
+    /// v19 migration: `group_id` on plan and template slots, linking adjacent
+    /// slots into supersets and circuits.
+    async fn apply_v19_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing("ALTER TABLE workout_plan_exercises ADD COLUMN group_id TEXT")
+            .await?;
+        self.add_column_if_missing(
+            "ALTER TABLE workout_template_exercises ADD COLUMN group_id TEXT",
+        )
+        .await?;
+        log::debug!("[DB] v19 migration complete — group_id columns added");
+        Ok(())
+    }
+
+    /// v20 migration: Slot Targets — rep range and target RPE overrides on
+    /// plan and template slots. NULL columns inherit the exercise's Rep Range
+    /// and the global target RPE.
+    async fn apply_v20_migration(&self) -> Result<(), DatabaseError> {
+        for table in ["workout_plan_exercises", "workout_template_exercises"] {
+            for column in [
+                "target_min_reps INTEGER",
+                "target_max_reps INTEGER",
+                "target_rpe REAL",
+            ] {
+                self.add_column_if_missing(&format!("ALTER TABLE {table} ADD COLUMN {column}"))
+                    .await?;
+            }
+        }
+        log::debug!("[DB] v20 migration complete — slot target columns added");
+        Ok(())
+    }
+
+    /// v21 migration: CRR-compatible `programs` and `program_days` tables,
+    /// plus the program a plan was materialized from.
+    async fn apply_v21_migration(&self) -> Result<(), DatabaseError> {
+        self.execute_internal(
+            "CREATE TABLE IF NOT EXISTS programs (
+                id TEXT PRIMARY KEY NOT NULL,
+                name TEXT NOT NULL DEFAULT '',
+                weeks INTEGER NOT NULL DEFAULT 4,
+                deload_week INTEGER,
+                progression TEXT NOT NULL DEFAULT 'none',
+                progression_start REAL,
+                progression_step REAL,
+                updated_at INTEGER NOT NULL DEFAULT 0,
+                deleted_at INTEGER
+            )",
+            &[],
+        )
+        .await?;
+        self.execute_internal(
+            "CREATE TABLE IF NOT EXISTS program_days (
+                id TEXT PRIMARY KEY NOT NULL,
+                program_id TEXT NOT NULL DEFAULT '',
+                template_id TEXT NOT NULL DEFAULT '',
+                position INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL DEFAULT 0,
+                deleted_at INTEGER
+            )",
+            &[],
+        )
+        .await?;
+        self.add_column_if_missing("ALTER TABLE workout_plans ADD COLUMN program_id TEXT")
+            .await?;
+        self.add_column_if_missing("ALTER TABLE workout_plans ADD COLUMN program_index INTEGER")
+            .await?;
+        log::debug!("[DB] v21 migration complete — programs and program_days added");
+        Ok(())
+    }
+
+    /// v22 migration: CRR-compatible `personal_records` table. Each row
+    /// points at the set that earned it by `set_uuid`, so deleting the set
+    /// hides the record.
+    async fn apply_v22_migration(&self) -> Result<(), DatabaseError> {
+        self.execute_internal(
+            "CREATE TABLE IF NOT EXISTS personal_records (
+                id TEXT PRIMARY KEY NOT NULL,
+                exercise_id TEXT NOT NULL DEFAULT '',
+                set_uuid TEXT NOT NULL DEFAULT '',
+                kind TEXT NOT NULL DEFAULT '',
+                value REAL NOT NULL DEFAULT 0,
+                previous REAL NOT NULL DEFAULT 0,
+                weight REAL,
+                reps INTEGER NOT NULL DEFAULT 0,
+                recorded_at INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL DEFAULT 0,
+                deleted_at INTEGER
+            )",
+            &[],
+        )
+        .await?;
+        log::debug!("[DB] v22 migration complete — personal_records added");
+        Ok(())
+    }
+
+    /// Adds the `volume_targets` setting.  An empty string parses as the
+    /// default targets, so existing users start from the defaults.
+    async fn apply_v23_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE settings ADD COLUMN volume_targets TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+
+        log::debug!("[DB] v23 migration complete — volume_targets added");
+        Ok(())
+    }
+
+    /// Adds the global `settings.e1rm_formula` and the per-exercise
+    /// `exercises.e1rm_formula` override.  Empty strings mean the default
+    /// formula and "use the global one", so existing e1RMs are unchanged.
+    async fn apply_v24_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE settings ADD COLUMN e1rm_formula TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+        self.add_column_if_missing(
+            "ALTER TABLE exercises ADD COLUMN e1rm_formula TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+
+        log::debug!("[DB] v24 migration complete — e1rm_formula columns added");
+        Ok(())
+    }
+
+    /// Adds the fitted RPE Calibrations — the trainee's on `settings`, each
+    /// exercise's own on `exercises` — and the opt-in flag.  Empty strings
+    /// mean "not fitted yet" and the flag starts off, so Suggestions are
+    /// unchanged until the trainee opts in.
+    async fn apply_v25_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE settings ADD COLUMN rpe_calibration TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+        self.add_column_if_missing(
+            "ALTER TABLE settings ADD COLUMN use_rpe_calibration INTEGER NOT NULL DEFAULT 0",
+        )
+        .await?;
+        self.add_column_if_missing(
+            "ALTER TABLE exercises ADD COLUMN rpe_calibration TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+
+        log::debug!("[DB] v25 migration complete — rpe_calibration columns added");
+        Ok(())
+    }
+
+    /// Adds the per-exercise `exercises.progression` strategy.  Empty strings
+    /// mean autoregulated, so existing Suggestions are unchanged.
+    async fn apply_v26_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE exercises ADD COLUMN progression TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+
+        log::debug!("[DB] v26 migration complete — progression column added");
+        Ok(())
+    }
+
+    /// Adds `exercises.weight_unit`, the unit an exercise's weights were
+    /// entered in.  Existing rows read as kilograms.
+    async fn apply_v27_migration(&self) -> Result<(), DatabaseError> {
+        self.add_column_if_missing(
+            "ALTER TABLE exercises ADD COLUMN weight_unit TEXT NOT NULL DEFAULT 'kg'",
+        )
+        .await?;
+
+        log::debug!("[DB] v27 migration complete — weight_unit column added");
+        Ok(())
+    }
+
+    /// Inserts the default settings row if no row exists yet.
+    /// Uses a SELECT guard instead of INSERT OR IGNORE because CRR tables
+    /// don't support ON CONFLICT clauses.
+    async fn seed_settings(&self) -> Result<(), DatabaseError> {
+        let existing = self
+            .execute_internal("SELECT id FROM settings WHERE id = 1", &[])
+            .await?;
+        let has_row = existing
+            .dyn_ref::<js_sys::Array>()
+            .map(|a| a.length() > 0)
+            .unwrap_or(false);
+        if !has_row {
+            self.execute_internal(
+                "INSERT INTO settings (id, target_rpe, history_window_days, today_blend_factor) VALUES (1, 8.0, 30, 0.5)",
+                &[],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Executes an ALTER TABLE ADD COLUMN statement, suppressing only
+    /// "duplicate column" errors (which mean the column already exists).
+    /// Any other error is propagated.
+    ///
+    /// Note: Detection relies on the English error message "duplicate column"
+    /// returned by SQLite for `SQLITE_ERROR` on `ALTER TABLE ADD COLUMN` when
+    /// the column already exists.  SQLite error messages are not localised, so
+    /// this is stable across platforms, but it is version-sensitive in
+    /// principle. The crsqlite-wasm build pins the SQLite version, mitigating
+    /// this risk.
+    async fn add_column_if_missing(&self, sql: &str) -> Result<(), DatabaseError> {
+        match self.execute_internal(sql, &[]).await {
+            Ok(_) => Ok(()),
+            Err(DatabaseError::QueryError(msg)) if msg.contains("duplicate column") => {
+                log::debug!("[DB] Column already exists, skipping: {}", sql);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Generate a UUID v4 string using the browser's crypto API.
+    fn generate_uuid() -> String {
+        // Use crypto.randomUUID() if available (all modern browsers).
+        let global = js_sys::global();
+        if let Ok(crypto) = js_sys::Reflect::get(&global, &JsValue::from_str("crypto"))
+            && let Ok(uuid_fn) = js_sys::Reflect::get(&crypto, &JsValue::from_str("randomUUID"))
+            && let Some(f) = uuid_fn.dyn_ref::<js_sys::Function>()
+            && let Ok(result) = f.call0(&crypto)
+            && let Some(s) = result.as_string()
+        {
+            return s;
+        }
+
+        // Fallback: construct a UUID-shaped string from Math.random().
+        let r = || (js_sys::Math::random() * 65535.0_f64).floor() as u32;
+        format!(
+            "{:04x}{:04x}-{:04x}-4{:03x}-{:04x}-{:04x}{:04x}{:04x}",
+            r(),
+            r(),
+            r(),
+            r() & 0x0fff,
+            (r() & 0x3fff) | 0x8000,
+            r(),
+            r(),
+            r()
+        )
+    }
+
+    async fn get_schema_version(&self) -> Result<i64, DatabaseError> {
+        let result = self.execute_internal("PRAGMA user_version", &[]).await?;
+        let array = match result.dyn_ref::<js_sys::Array>() {
+            Some(a) => a,
+            None => return Ok(0),
+        };
+        if array.length() == 0 {
+            return Ok(0);
+        }
+        let row = array.get(0);
+        let version = js_sys::Reflect::get(&row, &JsValue::from_str("user_version"))
+            .unwrap_or(JsValue::from_f64(0.0))
+            .as_f64()
+            .unwrap_or(0.0) as i64;
+        Ok(version)
+    }
+
+    pub async fn execute(&self, sql: &str, params: &[JsValue]) -> Result<JsValue, DatabaseError> {
+        if !self.initialized {
+            return Err(DatabaseError::NotInitialized);
+        }
+
+        self.execute_internal(sql, params).await
+    }
+
+    async fn execute_internal(
+        &self,
+        sql: &str,
+        params: &[JsValue],
+    ) -> Result<JsValue, DatabaseError> {
+        let params_array = js_sys::Array::new();
+        for param in params {
+            params_array.push(param);
+        }
+
+        let result = execute_query(sql, params_array.into()).await;
+
+        if let Some(error) = result.dyn_ref::<js_sys::Error>() {
+            return Err(DatabaseError::QueryError(
+                error.message().as_string().unwrap_or_default(),
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Rest taken before a set logged at `recorded_at`: the gap since the
+    /// previous set of any exercise, or NULL when there is none close enough
+    /// to count as rest.
+    async fn rest_before(&self, recorded_at: f64) -> Result<JsValue, DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT MAX(recorded_at) AS prev FROM completed_sets WHERE deleted_at IS NULL AND recorded_at < ?",
+                &[JsValue::from_f64(recorded_at)],
+            )
+            .await?;
+        let prev = result
+            .dyn_ref::<js_sys::Array>()
+            .filter(|a| a.length() > 0)
+            .and_then(|a| js_sys::Reflect::get(&a.get(0), &JsValue::from_str("prev")).ok())
+            .and_then(|v| v.as_f64());
+        Ok(prev
+            .and_then(|prev| crate::domain::rest::rest_between(prev, recorded_at))
+            .map(|secs| JsValue::from_f64(secs as f64))
+            .unwrap_or(JsValue::NULL))
+    }
+
+    /// Unix ms of the earliest live set of any exercise, `None` before the
+    /// first set is logged.
+    pub async fn first_set_at(&self) -> Result<Option<f64>, DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT MIN(recorded_at) AS first FROM completed_sets WHERE deleted_at IS NULL",
+                &[],
+            )
+            .await?;
+        Ok(result
+            .dyn_ref::<js_sys::Array>()
+            .filter(|a| a.length() > 0)
+            .and_then(|a| js_sys::Reflect::get(&a.get(0), &JsValue::from_str("first")).ok())
+            .and_then(|v| v.as_f64()))
+    }
+
+    /// Log a single set for the given exercise. Records the current timestamp.
+    pub async fn log_set(
+        &self,
+        exercise_id: &str,
+        set: &CompletedSet,
+    ) -> Result<i64, DatabaseError> {
+        let (weight, is_bodyweight, duration_secs, distance_m, bodyweight) =
+            Self::set_type_columns(&set.set_type);
+
+        let now = js_sys::Date::now();
+        let uuid = Self::generate_uuid();
+        let rest_secs = self.rest_before(now).await?;
+
+        let sql = r#"
+            INSERT INTO completed_sets (exercise_id, set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind, is_warmup, comment, rest_secs, recorded_at, uuid, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING id
+        "#;
+
+        let params = vec![
+            JsValue::from_str(exercise_id),
+            JsValue::from_f64(set.set_number as f64),
+            JsValue::from_f64(set.reps as f64),
+            JsValue::from_f64(set.rpe as f64),
+            weight,
+            JsValue::from_bool(is_bodyweight),
+            duration_secs,
+            distance_m,
+            bodyweight,
+            JsValue::from_str(&set.kind.to_string()),
+            JsValue::from_bool(set.kind == SetKind::Warmup),
+            JsValue::from_str(&set.comment),
+            rest_secs,
+            JsValue::from_f64(now),
+            JsValue::from_str(&uuid),
+            JsValue::from_f64(now),
+        ];
 
         let result = self.execute(sql, &params).await?;
         self.extract_id(&result, "set")
     }
 
+    /// Re-derives the stored rest of set `id`, logged at `recorded_at`,
+    /// from the sets now before it.
+    async fn refresh_rest(&self, id: f64, recorded_at: f64, now: f64) -> Result<(), DatabaseError> {
+        let rest_secs = self.rest_before(recorded_at).await?;
+        self.execute(
+            "UPDATE completed_sets SET rest_secs = ?, updated_at = ? WHERE id = ?",
+            &[rest_secs, JsValue::from_f64(now), JsValue::from_f64(id)],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Re-derives the rest of the first set logged after `recorded_at`, whose
+    /// previous set a set logged, moved or deleted there may have changed.
+    async fn refresh_rest_after(&self, recorded_at: f64, now: f64) -> Result<(), DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT id, recorded_at FROM completed_sets WHERE deleted_at IS NULL AND recorded_at > ? ORDER BY recorded_at, id LIMIT 1",
+                &[JsValue::from_f64(recorded_at)],
+            )
+            .await?;
+        let Some(next) = result
+            .dyn_ref::<js_sys::Array>()
+            .filter(|a| a.length() > 0)
+            .map(|a| a.get(0))
+        else {
+            return Ok(());
+        };
+        let get = |key: &str| {
+            js_sys::Reflect::get(&next, &JsValue::from_str(key))
+                .ok()
+                .and_then(|v| v.as_f64())
+        };
+        match (get("id"), get("recorded_at")) {
+            (Some(id), Some(next_at)) => self.refresh_rest(id, next_at, now).await,
+            _ => Ok(()),
+        }
+    }
+
+    /// `recorded_at` of the set with local id `set_id`.
+    async fn set_recorded_at(&self, set_id: i64) -> Result<Option<f64>, DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT recorded_at FROM completed_sets WHERE id = ?",
+                &[JsValue::from_f64(set_id as f64)],
+            )
+            .await?;
+        Ok(result
+            .dyn_ref::<js_sys::Array>()
+            .filter(|a| a.length() > 0)
+            .and_then(|a| js_sys::Reflect::get(&a.get(0), &JsValue::from_str("recorded_at")).ok())
+            .and_then(|v| v.as_f64()))
+    }
+
     /// Log a single set with an explicit timestamp (Unix ms). Used in tests and
-    /// data-import scenarios where the recording time is known.
+    /// data-import scenarios where the recording time is known.  Its rest is
+    /// the gap since the set before it, and the set after it has its rest
+    /// re-derived, so sets backfilled out of order keep their rests right.
     pub async fn log_set_at(
         &self,
         exercise_id: &str,
         set: &CompletedSet,
         recorded_at: f64,
     ) -> Result<i64, DatabaseError> {
-        let (weight, is_bodyweight) = match set.set_type {
-            SetType::Weighted { weight } => (Some(weight), false),
-            SetType::Bodyweight => (None, true),
-        };
+        let (weight, is_bodyweight, duration_secs, distance_m, bodyweight) =
+            Self::set_type_columns(&set.set_type);
 
         let now = js_sys::Date::now();
         let uuid = Self::generate_uuid();
+        let rest_secs = self.rest_before(recorded_at).await?;
 
         let sql = r#"
-            INSERT INTO completed_sets (exercise_id, set_number, reps, rpe, weight, is_bodyweight, recorded_at, uuid, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO completed_sets (exercise_id, set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind, is_warmup, comment, rest_secs, recorded_at, uuid, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             RETURNING id
         "#;
 
@@ -921,17 +1580,140 @@ impl Database {
             JsValue::from_f64(set.set_number as f64),
             JsValue::from_f64(set.reps as f64),
             JsValue::from_f64(set.rpe as f64),
-            weight
-                .map(|w| JsValue::from_f64(w as f64))
-                .unwrap_or(JsValue::NULL),
+            weight,
             JsValue::from_bool(is_bodyweight),
+            duration_secs,
+            distance_m,
+            bodyweight,
+            JsValue::from_str(&set.kind.to_string()),
+            JsValue::from_bool(set.kind == SetKind::Warmup),
+            JsValue::from_str(&set.comment),
+            rest_secs,
             JsValue::from_f64(recorded_at),
             JsValue::from_str(&uuid),
             JsValue::from_f64(now),
         ];
 
         let result = self.execute(sql, &params).await?;
-        self.extract_id(&result, "set")
+        let id = self.extract_id(&result, "set")?;
+        self.refresh_rest_after(recorded_at, now).await?;
+        Ok(id)
+    }
+
+    /// Unix milliseconds of an imported set's time; wall-clock times are read
+    /// in the device's time zone.
+    fn import_time_ms(time: ImportTime) -> f64 {
+        match time {
+            ImportTime::Local(dt) => js_sys::Date::new(&JsValue::from_str(
+                &dt.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            ))
+            .get_time(),
+            ImportTime::Instant(ms) => ms,
+        }
+    }
+
+    /// Which of a mapping's planned sets are already logged against its
+    /// existing exercise; none are for an exercise the import creates.
+    async fn already_imported(
+        &self,
+        mapping: &ExerciseMapping,
+    ) -> Result<Vec<bool>, DatabaseError> {
+        let ExerciseTarget::Existing(ExerciseMetadata { id: Some(id), .. }) = &mapping.target
+        else {
+            return Ok(vec![false; mapping.sets.len()]);
+        };
+        let result = self
+            .execute(
+                "SELECT recorded_at, reps, weight FROM completed_sets WHERE exercise_id = ? AND deleted_at IS NULL",
+                &[JsValue::from_str(id)],
+            )
+            .await?;
+        let logged: Vec<LoggedSet> = result
+            .dyn_ref::<js_sys::Array>()
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| {
+                        let get = |key: &str| {
+                            js_sys::Reflect::get(&row, &JsValue::from_str(key))
+                                .ok()
+                                .and_then(|v| v.as_f64())
+                        };
+                        LoggedSet {
+                            recorded_at: get("recorded_at").unwrap_or_default(),
+                            reps: get("reps").unwrap_or_default() as u32,
+                            weight: get("weight").map(|w| w as f32),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let planned: Vec<LoggedSet> = mapping
+            .sets
+            .iter()
+            .map(|p| LoggedSet::of(&p.set, Self::import_time_ms(p.recorded_at)))
+            .collect();
+        Ok(crate::import::already_logged(&planned, &logged))
+    }
+
+    /// Number of a previewed import's sets that are already logged and would
+    /// be skipped.
+    pub async fn count_already_imported(&self, plan: &ImportPlan) -> Result<usize, DatabaseError> {
+        let mut count = 0;
+        for mapping in &plan.mappings {
+            count += self
+                .already_imported(mapping)
+                .await?
+                .into_iter()
+                .filter(|&logged| logged)
+                .count();
+        }
+        Ok(count)
+    }
+
+    /// Writes a previewed history import in one transaction: creates its new
+    /// exercises, then logs every planned set that isn't already logged at
+    /// its recorded time.  Returns the number of sets logged.
+    pub async fn import_history(&self, plan: &ImportPlan) -> Result<usize, DatabaseError> {
+        self.execute("BEGIN", &[]).await?;
+        match self.import_history_inner(plan).await {
+            Ok(logged) => {
+                self.execute("COMMIT", &[]).await?;
+                Ok(logged)
+            }
+            Err(e) => {
+                let _ = self.execute("ROLLBACK", &[]).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn import_history_inner(&self, plan: &ImportPlan) -> Result<usize, DatabaseError> {
+        let mut logged = 0;
+        for mapping in &plan.mappings {
+            let already = self.already_imported(mapping).await?;
+            let exercise_id = match &mapping.target {
+                ExerciseTarget::Existing(exercise) => exercise.id.clone().ok_or_else(|| {
+                    DatabaseError::ValidationError(format!(
+                        "Exercise '{}' has no id",
+                        exercise.name
+                    ))
+                })?,
+                ExerciseTarget::New(exercise) => self.save_exercise(exercise).await?,
+            };
+            for (planned, already) in mapping.sets.iter().zip(already) {
+                if already {
+                    continue;
+                }
+                self.log_set_at(
+                    &exercise_id,
+                    &planned.set,
+                    Self::import_time_ms(planned.recorded_at),
+                )
+                .await?;
+                logged += 1;
+            }
+        }
+        Ok(logged)
     }
 
     /// Returns sets for one exercise in reverse-chronological order with pagination.
@@ -943,7 +1725,7 @@ impl Database {
     ) -> Result<Vec<HistorySet>, DatabaseError> {
         let sql = r#"
             SELECT cs.id, cs.exercise_id, e.name AS exercise_name,
-                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.recorded_at
+                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.duration_secs, cs.distance_m, cs.bodyweight, cs.kind, cs.comment, cs.rest_secs, cs.recorded_at
             FROM completed_sets cs
             JOIN exercises e ON cs.exercise_id = e.uuid
             WHERE cs.exercise_id = ? AND cs.deleted_at IS NULL
@@ -974,7 +1756,7 @@ impl Database {
     ) -> Result<Vec<HistorySet>, DatabaseError> {
         let sql = r#"
             SELECT cs.id, cs.exercise_id, e.name AS exercise_name,
-                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.recorded_at
+                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.duration_secs, cs.distance_m, cs.bodyweight, cs.kind, cs.comment, cs.rest_secs, cs.recorded_at
             FROM completed_sets cs
             JOIN exercises e ON cs.exercise_id = e.uuid
             WHERE cs.exercise_id = ? AND cs.recorded_at < ? AND cs.deleted_at IS NULL
@@ -1001,7 +1783,7 @@ impl Database {
     ) -> Result<Vec<HistorySet>, DatabaseError> {
         let sql = r#"
             SELECT cs.id, cs.exercise_id, e.name AS exercise_name,
-                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.recorded_at
+                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.duration_secs, cs.distance_m, cs.bodyweight, cs.kind, cs.comment, cs.rest_secs, cs.recorded_at
             FROM completed_sets cs
             JOIN exercises e ON cs.exercise_id = e.uuid
             WHERE cs.deleted_at IS NULL
@@ -1009,55 +1791,158 @@ impl Database {
             LIMIT ? OFFSET ?
         "#;
 
-        let params = vec![
-            JsValue::from_f64(limit as f64),
-            JsValue::from_f64(offset as f64),
-        ];
+        let params = vec![
+            JsValue::from_f64(limit as f64),
+            JsValue::from_f64(offset as f64),
+        ];
+
+        let result = self.execute(sql, &params).await?;
+        self.parse_history_sets(&result)
+    }
+
+    /// Returns every live set matching `filter`, oldest first, for the
+    /// history export.  Each row carries the plan it was logged under — the
+    /// started plan with a slot for the exercise whose run covers the set —
+    /// the device's UTC offset at the time, for local timestamps, and the
+    /// exercise's e1RM Formula.
+    pub async fn get_sets_for_export(
+        &self,
+        filter: &ExportFilter,
+    ) -> Result<Vec<ExportRow>, DatabaseError> {
+        let mut conditions = vec!["cs.deleted_at IS NULL".to_string()];
+        let mut params = Vec::new();
+        if let Some(from_ms) = filter.from_ms {
+            conditions.push("cs.recorded_at >= ?".to_string());
+            params.push(JsValue::from_f64(from_ms));
+        }
+        if let Some(to_ms) = filter.to_ms {
+            conditions.push("cs.recorded_at < ?".to_string());
+            params.push(JsValue::from_f64(to_ms));
+        }
+        if let Some(ref exercise_id) = filter.exercise_id {
+            conditions.push("cs.exercise_id = ?".to_string());
+            params.push(JsValue::from_str(exercise_id));
+        }
+
+        let sql = format!(
+            r#"
+            SELECT cs.id, cs.exercise_id, e.name AS exercise_name,
+                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.duration_secs, cs.distance_m, cs.bodyweight, cs.kind, cs.comment, cs.rest_secs, cs.recorded_at,
+                   (SELECT p.id
+                    FROM workout_plans p
+                    JOIN workout_plan_exercises pe ON pe.plan_id = p.id
+                    WHERE pe.exercise_id = cs.exercise_id
+                      AND pe.deleted_at IS NULL
+                      AND p.deleted_at IS NULL
+                      AND p.started_at IS NOT NULL
+                      AND cs.recorded_at >= p.started_at
+                      AND (p.ended_at IS NULL OR cs.recorded_at <= p.ended_at)
+                    ORDER BY p.started_at DESC
+                    LIMIT 1) AS plan_id,
+                   COALESCE(NULLIF(e.e1rm_formula, ''),
+                            (SELECT e1rm_formula FROM settings WHERE id = 1)) AS e1rm_formula
+            FROM completed_sets cs
+            JOIN exercises e ON cs.exercise_id = e.uuid
+            WHERE {}
+            ORDER BY cs.recorded_at ASC, cs.id ASC
+            "#,
+            conditions.join(" AND ")
+        );
+
+        let result = self.execute(&sql, &params).await?;
+        let sets = self.parse_history_sets(&result)?;
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
 
-        let result = self.execute(sql, &params).await?;
-        self.parse_history_sets(&result)
+        Ok(sets
+            .into_iter()
+            .enumerate()
+            .map(|(i, set)| {
+                let row = array.get(i as u32);
+                let plan_id = js_sys::Reflect::get(&row, &JsValue::from_str("plan_id"))
+                    .ok()
+                    .and_then(|v| v.as_string());
+                // getTimezoneOffset is minutes *behind* UTC.
+                let utc_offset_minutes = -js_sys::Date::new(&JsValue::from_f64(set.recorded_at))
+                    .get_timezone_offset() as i32;
+                ExportRow {
+                    set,
+                    plan_id,
+                    utc_offset_minutes,
+                    e1rm_formula: Self::parse_e1rm_formula(&row).unwrap_or_default(),
+                }
+            })
+            .collect())
     }
 
-    /// Updates reps, rpe, weight, and recorded_at for an existing set.
+    /// Updates reps, rpe, set type (weight, duration or distance), and
+    /// recorded_at for an existing set.  The Personal Records it earned no
+    /// longer hold as recorded, so they are soft-deleted; callers re-detect
+    /// them for the edited set.  Its rest, and that of the sets after its old
+    /// and new times, are re-derived.
     pub async fn update_set(
         &self,
         set_id: i64,
         reps: u32,
         rpe: f32,
-        weight: Option<f32>,
+        set_type: &SetType,
+        comment: &str,
         recorded_at: f64,
     ) -> Result<(), DatabaseError> {
-        let (weight_val, is_bodyweight) = match weight {
-            Some(w) => (JsValue::from_f64(w as f64), false),
-            None => (JsValue::NULL, true),
-        };
+        let (weight, is_bodyweight, duration_secs, distance_m, bodyweight) =
+            Self::set_type_columns(set_type);
 
         let now = js_sys::Date::now();
+        let previous_at = self.set_recorded_at(set_id).await?;
 
         let sql = r#"
             UPDATE completed_sets
-            SET reps = ?, rpe = ?, weight = ?, is_bodyweight = ?, recorded_at = ?, updated_at = ?
+            SET reps = ?, rpe = ?, weight = ?, is_bodyweight = ?, duration_secs = ?, distance_m = ?, bodyweight = ?, comment = ?, recorded_at = ?, updated_at = ?
             WHERE id = ?
         "#;
 
         let params = vec![
             JsValue::from_f64(reps as f64),
             JsValue::from_f64(rpe as f64),
-            weight_val,
+            weight,
             JsValue::from_bool(is_bodyweight),
+            duration_secs,
+            distance_m,
+            bodyweight,
+            JsValue::from_str(comment),
             JsValue::from_f64(recorded_at),
             JsValue::from_f64(now),
             JsValue::from_f64(set_id as f64),
         ];
 
         self.execute(sql, &params).await?;
+        self.execute(
+            "UPDATE personal_records SET deleted_at = ?, updated_at = ?
+             WHERE deleted_at IS NULL
+               AND set_uuid = (SELECT uuid FROM completed_sets WHERE id = ?)",
+            &[
+                JsValue::from_f64(now),
+                JsValue::from_f64(now),
+                JsValue::from_f64(set_id as f64),
+            ],
+        )
+        .await?;
+
+        self.refresh_rest(set_id as f64, recorded_at, now).await?;
+        if let Some(previous_at) = previous_at {
+            self.refresh_rest_after(previous_at, now).await?;
+        }
+        self.refresh_rest_after(recorded_at, now).await?;
         Ok(())
     }
 
     /// Soft-deletes a set by setting its `deleted_at` timestamp.
     /// The row is retained in the database but excluded from all normal queries.
+    /// The set after it has its rest re-derived from the sets left before it.
     pub async fn delete_set(&self, set_id: i64) -> Result<(), DatabaseError> {
         let now = js_sys::Date::now();
+        let recorded_at = self.set_recorded_at(set_id).await?;
         let sql = "UPDATE completed_sets SET deleted_at = ?, updated_at = ? WHERE id = ?";
         let params = vec![
             JsValue::from_f64(now),
@@ -1065,6 +1950,9 @@ impl Database {
             JsValue::from_f64(set_id as f64),
         ];
         self.execute(sql, &params).await?;
+        if let Some(recorded_at) = recorded_at {
+            self.refresh_rest_after(recorded_at, now).await?;
+        }
         Ok(())
     }
 
@@ -1072,13 +1960,35 @@ impl Database {
         &self,
         exercise: &ExerciseMetadata,
     ) -> Result<String, DatabaseError> {
-        let (is_weighted, min_weight, increment) = match exercise.set_type_config {
-            crate::models::SetTypeConfig::Weighted {
+        // `is_weighted` is still written so peers that predate v15 read a
+        // carry as weighted and timed / distance exercises as bodyweight.
+        let (is_weighted, min_weight, increment, bar_weight) = match exercise.set_type_config {
+            SetTypeConfig::Weighted {
+                min_weight,
+                increment,
+                bar_weight,
+            } => (true, Some(min_weight), Some(increment), bar_weight),
+            SetTypeConfig::Carry {
                 min_weight,
                 increment,
-            } => (true, Some(min_weight), Some(increment)),
-            crate::models::SetTypeConfig::Bodyweight => (false, None, None),
+                ..
+            } => (true, Some(min_weight), Some(increment), None),
+            SetTypeConfig::LoadedBodyweight { increment, .. } => {
+                (false, None, Some(increment), None)
+            }
+            SetTypeConfig::Bodyweight
+            | SetTypeConfig::Timed { .. }
+            | SetTypeConfig::Distance { .. } => (false, None, None, None),
         };
+        let bar_weight_val = bar_weight
+            .map(|w| JsValue::from_f64(w as f64))
+            .unwrap_or(JsValue::NULL);
+        let set_type_val = JsValue::from_str(exercise.set_type_config.type_name());
+        let measure_increment_val = exercise
+            .set_type_config
+            .measure_increment()
+            .map(JsValue::from_f64)
+            .unwrap_or(JsValue::NULL);
 
         let now = js_sys::Date::now();
         let min_reps_val = JsValue::from_f64(exercise.min_reps as f64);
@@ -1086,24 +1996,44 @@ impl Database {
             .max_reps
             .map(|r| JsValue::from_f64(r as f64))
             .unwrap_or(JsValue::NULL);
+        let rest_secs_val = exercise
+            .rest_secs
+            .map(|r| JsValue::from_f64(r as f64))
+            .unwrap_or(JsValue::NULL);
+        let e1rm_formula_val = JsValue::from_str(
+            &exercise
+                .e1rm_formula
+                .map(|f| f.to_string())
+                .unwrap_or_default(),
+        );
+        let progression_val = JsValue::from_str(&exercise.progression.to_string());
+        let weight_unit_val = JsValue::from_str(&exercise.weight_unit.to_string());
 
         let result = if let Some(ref id) = exercise.id {
             let sql = r#"
-                UPDATE exercises SET name = ?, is_weighted = ?, min_weight = ?, increment = ?, min_reps = ?, max_reps = ?, updated_at = ?
+                UPDATE exercises SET name = ?, is_weighted = ?, set_type = ?, min_weight = ?, increment = ?, bar_weight = ?, measure_increment = ?, min_reps = ?, max_reps = ?, notes = ?, rest_secs = ?, e1rm_formula = ?, progression = ?, weight_unit = ?, updated_at = ?
                 WHERE uuid = ?
                 RETURNING uuid
             "#;
             let params = vec![
                 JsValue::from_str(&exercise.name),
                 JsValue::from_bool(is_weighted),
+                set_type_val,
                 min_weight
                     .map(|w| JsValue::from_f64(w as f64))
                     .unwrap_or(JsValue::NULL),
                 increment
                     .map(|i| JsValue::from_f64(i as f64))
                     .unwrap_or(JsValue::NULL),
+                bar_weight_val,
+                measure_increment_val,
                 min_reps_val,
                 max_reps_val,
+                JsValue::from_str(&exercise.notes),
+                rest_secs_val,
+                e1rm_formula_val,
+                progression_val,
+                weight_unit_val,
                 JsValue::from_f64(now),
                 JsValue::from_str(id),
             ];
@@ -1130,20 +2060,28 @@ impl Database {
             if let Some(euuid) = existing_uuid {
                 // Update existing exercise by name match.
                 let sql = r#"
-                    UPDATE exercises SET is_weighted = ?, min_weight = ?, increment = ?, min_reps = ?, max_reps = ?, updated_at = ?
+                    UPDATE exercises SET is_weighted = ?, set_type = ?, min_weight = ?, increment = ?, bar_weight = ?, measure_increment = ?, min_reps = ?, max_reps = ?, notes = ?, rest_secs = ?, e1rm_formula = ?, progression = ?, weight_unit = ?, updated_at = ?
                     WHERE uuid = ?
                     RETURNING uuid
                 "#;
                 let params = vec![
                     JsValue::from_bool(is_weighted),
+                    set_type_val,
                     min_weight
                         .map(|w| JsValue::from_f64(w as f64))
                         .unwrap_or(JsValue::NULL),
                     increment
                         .map(|i| JsValue::from_f64(i as f64))
                         .unwrap_or(JsValue::NULL),
+                    bar_weight_val,
+                    measure_increment_val,
                     min_reps_val,
                     max_reps_val,
+                    JsValue::from_str(&exercise.notes),
+                    rest_secs_val,
+                    e1rm_formula_val,
+                    progression_val,
+                    weight_unit_val,
                     JsValue::from_f64(now),
                     JsValue::from_str(&euuid),
                 ];
@@ -1151,22 +2089,30 @@ impl Database {
             } else {
                 let uuid = Self::generate_uuid();
                 let sql = r#"
-                    INSERT INTO exercises (uuid, name, is_weighted, min_weight, increment, min_reps, max_reps, updated_at)
-                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                    INSERT INTO exercises (uuid, name, is_weighted, set_type, min_weight, increment, bar_weight, measure_increment, min_reps, max_reps, notes, rest_secs, e1rm_formula, progression, weight_unit, updated_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                     RETURNING uuid
                 "#;
                 let params = vec![
                     JsValue::from_str(&uuid),
                     JsValue::from_str(&exercise.name),
                     JsValue::from_bool(is_weighted),
+                    set_type_val,
                     min_weight
                         .map(|w| JsValue::from_f64(w as f64))
                         .unwrap_or(JsValue::NULL),
                     increment
                         .map(|i| JsValue::from_f64(i as f64))
                         .unwrap_or(JsValue::NULL),
+                    bar_weight_val,
+                    measure_increment_val,
                     min_reps_val,
                     max_reps_val,
+                    JsValue::from_str(&exercise.notes),
+                    rest_secs_val,
+                    e1rm_formula_val,
+                    progression_val,
+                    weight_unit_val,
                     JsValue::from_f64(now),
                 ];
                 self.execute(sql, &params).await?
@@ -1193,7 +2139,7 @@ impl Database {
     }
 
     pub async fn get_archived_exercises(&self) -> Result<Vec<ExerciseMetadata>, DatabaseError> {
-        let sql = "SELECT uuid, name, is_weighted, min_weight, increment, min_reps, max_reps FROM exercises WHERE deleted_at IS NOT NULL ORDER BY name";
+        let sql = "SELECT uuid, name, is_weighted, set_type, min_weight, increment, bar_weight, measure_increment, min_reps, max_reps, notes, rest_secs, e1rm_formula, progression, weight_unit FROM exercises WHERE deleted_at IS NOT NULL ORDER BY name";
         self.fetch_exercises_with_sql(sql).await
     }
 
@@ -1528,7 +2474,7 @@ impl Database {
     }
 
     pub async fn get_exercises(&self) -> Result<Vec<ExerciseMetadata>, DatabaseError> {
-        let sql = "SELECT uuid, name, is_weighted, min_weight, increment, min_reps, max_reps FROM exercises WHERE deleted_at IS NULL ORDER BY name";
+        let sql = "SELECT uuid, name, is_weighted, set_type, min_weight, increment, bar_weight, measure_increment, min_reps, max_reps, notes, rest_secs, e1rm_formula, progression, weight_unit FROM exercises WHERE deleted_at IS NULL ORDER BY name";
         self.fetch_exercises_with_sql(sql).await
     }
 
@@ -1551,35 +2497,7 @@ impl Database {
                 .as_string()
                 .ok_or_else(|| DatabaseError::QueryError("Failed to get name".to_string()))?;
 
-            let is_weighted_val = js_sys::Reflect::get(&row, &JsValue::from_str("is_weighted"))?;
-            let is_weighted = if let Some(b) = is_weighted_val.as_bool() {
-                b
-            } else if let Some(f) = is_weighted_val.as_f64() {
-                f == 1.0
-            } else {
-                return Err(DatabaseError::QueryError(
-                    "Failed to get is_weighted as bool or number".to_string(),
-                ));
-            };
-
-            let set_type_config = if is_weighted {
-                let min_weight = js_sys::Reflect::get(&row, &JsValue::from_str("min_weight"))?
-                    .as_f64()
-                    .ok_or_else(|| {
-                        DatabaseError::QueryError("Failed to get min_weight".to_string())
-                    })? as f32;
-                let increment = js_sys::Reflect::get(&row, &JsValue::from_str("increment"))?
-                    .as_f64()
-                    .ok_or_else(|| {
-                        DatabaseError::QueryError("Failed to get increment".to_string())
-                    })? as f32;
-                crate::models::SetTypeConfig::Weighted {
-                    min_weight,
-                    increment,
-                }
-            } else {
-                crate::models::SetTypeConfig::Bodyweight
-            };
+            let set_type_config = self.parse_set_type_config(&row)?;
 
             let min_reps = js_sys::Reflect::get(&row, &JsValue::from_str("min_reps"))?
                 .as_f64()
@@ -1591,12 +2509,24 @@ impl Database {
                 max_reps_val.as_f64().map(|v| v as i32)
             };
 
+            let notes = js_sys::Reflect::get(&row, &JsValue::from_str("notes"))?
+                .as_string()
+                .unwrap_or_default();
+            let rest_secs = js_sys::Reflect::get(&row, &JsValue::from_str("rest_secs"))?
+                .as_f64()
+                .map(|v| v as u32);
+
             exercises.push(ExerciseMetadata {
                 id,
                 name,
                 set_type_config,
                 min_reps,
                 max_reps,
+                notes,
+                rest_secs,
+                e1rm_formula: Self::parse_e1rm_formula(&row),
+                progression: Self::parse_progression(&row),
+                weight_unit: Self::parse_exercise_weight_unit(&row),
             });
         }
 
@@ -1608,7 +2538,7 @@ impl Database {
         // Ensure the settings row exists (idempotent).
         self.seed_settings().await?;
 
-        let sql = "SELECT target_rpe, history_window_days, today_blend_factor, default_planned_sets, default_bodyweight_reps, min_sessions_for_regression, training_window_weeks FROM settings WHERE id = 1";
+        let sql = "SELECT target_rpe, history_window_days, today_blend_factor, default_planned_sets, default_bodyweight_reps, min_sessions_for_regression, training_window_weeks, weight_unit, warmup_ramp, volume_targets, e1rm_formula, rpe_calibration, use_rpe_calibration FROM settings WHERE id = 1";
         let result = self.execute(sql, &[]).await?;
 
         let array = result
@@ -1656,6 +2586,38 @@ impl Database {
                 .and_then(|v| v.as_f64())
                 .unwrap_or(12.0) as i64;
 
+        let weight_unit = js_sys::Reflect::get(&row, &JsValue::from_str("weight_unit"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse::<crate::models::WeightUnit>().ok())
+            .unwrap_or_default();
+
+        let warmup_ramp = js_sys::Reflect::get(&row, &JsValue::from_str("warmup_ramp"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse::<crate::models::WarmupRamp>().ok())
+            .unwrap_or_default();
+
+        let volume_targets = js_sys::Reflect::get(&row, &JsValue::from_str("volume_targets"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse::<crate::models::VolumeTargets>().ok())
+            .unwrap_or_default();
+
+        let e1rm_formula = js_sys::Reflect::get(&row, &JsValue::from_str("e1rm_formula"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse::<E1rmFormula>().ok())
+            .unwrap_or_default();
+
+        let rpe_calibration = Self::parse_rpe_calibration(&row);
+
+        let use_rpe_calibration =
+            js_sys::Reflect::get(&row, &JsValue::from_str("use_rpe_calibration"))
+                .ok()
+                .and_then(|v| v.as_f64())
+                .is_some_and(|v| v != 0.0);
+
         Ok(crate::models::Settings {
             target_rpe,
             history_window_days,
@@ -1664,6 +2626,12 @@ impl Database {
             default_bodyweight_reps,
             min_sessions_for_regression,
             training_window_weeks,
+            weight_unit,
+            warmup_ramp,
+            volume_targets,
+            e1rm_formula,
+            rpe_calibration,
+            use_rpe_calibration,
         })
     }
 
@@ -1672,7 +2640,7 @@ impl Database {
         &self,
         settings: &crate::models::Settings,
     ) -> Result<(), DatabaseError> {
-        let sql = "UPDATE settings SET target_rpe = ?, history_window_days = ?, today_blend_factor = ?, default_planned_sets = ?, default_bodyweight_reps = ?, min_sessions_for_regression = ?, training_window_weeks = ? WHERE id = 1";
+        let sql = "UPDATE settings SET target_rpe = ?, history_window_days = ?, today_blend_factor = ?, default_planned_sets = ?, default_bodyweight_reps = ?, min_sessions_for_regression = ?, training_window_weeks = ?, weight_unit = ?, warmup_ramp = ?, volume_targets = ?, e1rm_formula = ?, rpe_calibration = ?, use_rpe_calibration = ? WHERE id = 1";
         self.execute(
             sql,
             &[
@@ -1683,6 +2651,21 @@ impl Database {
                 JsValue::from_f64(settings.default_bodyweight_reps as f64),
                 JsValue::from_f64(settings.min_sessions_for_regression as f64),
                 JsValue::from_f64(settings.training_window_weeks as f64),
+                JsValue::from_str(&settings.weight_unit.to_string()),
+                JsValue::from_str(&settings.warmup_ramp.to_string()),
+                JsValue::from_str(&settings.volume_targets.to_string()),
+                JsValue::from_str(&settings.e1rm_formula.to_string()),
+                JsValue::from_str(
+                    &settings
+                        .rpe_calibration
+                        .map(|c| c.to_string())
+                        .unwrap_or_default(),
+                ),
+                JsValue::from_f64(if settings.use_rpe_calibration {
+                    1.0
+                } else {
+                    0.0
+                }),
             ],
         )
         .await?;
@@ -1694,16 +2677,19 @@ impl Database {
         &self,
         exercise_id: &str,
     ) -> Result<Option<crate::models::CompletedSet>, DatabaseError> {
-        let sql = r#"
-            SELECT set_number, reps, rpe, weight, is_bodyweight
+        let sql = format!(
+            r#"
+            SELECT set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind
             FROM completed_sets
-            WHERE exercise_id = ? AND deleted_at IS NULL
+            WHERE exercise_id = ? AND deleted_at IS NULL AND kind IN ({})
             ORDER BY recorded_at DESC, id DESC
             LIMIT 1
-        "#;
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
 
         let params = vec![JsValue::from_str(exercise_id)];
-        let result = self.execute(sql, &params).await?;
+        let result = self.execute(&sql, &params).await?;
 
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -1729,23 +2715,15 @@ impl Database {
             .ok_or_else(|| DatabaseError::QueryError("Failed to get rpe".to_string()))?
             as f32;
 
-        let is_bodyweight = self.parse_bool_field(&row, "is_bodyweight")?;
-
-        let set_type = if is_bodyweight {
-            crate::models::SetType::Bodyweight
-        } else {
-            let weight = js_sys::Reflect::get(&row, &JsValue::from_str("weight"))?
-                .as_f64()
-                .ok_or_else(|| DatabaseError::QueryError("Failed to get weight".to_string()))?
-                as f32;
-            crate::models::SetType::Weighted { weight }
-        };
+        let set_type = self.parse_set_type(&row)?;
 
         Ok(Some(crate::models::CompletedSet {
             set_number,
             reps,
             rpe,
             set_type,
+            kind: Self::parse_kind(&row),
+            comment: String::new(),
         }))
     }
 
@@ -1753,26 +2731,35 @@ impl Database {
     /// within the history window `[since_ms, now)`, **excluding** sets whose
     /// `recorded_at` falls in `[exclude_start_ms, exclude_end_ms)`.
     ///
-    /// e1RM comparison is performed in Rust using `domain::e1rm::e1rm()` so
+    /// e1RM comparison is performed in Rust with the exercise's `formula` so
     /// that the ranking logic stays in one place.
     ///
-    /// Bodyweight sets are skipped because e1RM is undefined without a weight.
+    /// Bodyweight sets are skipped because e1RM is undefined without a weight,
+    /// unless they carry a bodyweight snapshot, in which case they are ranked
+    /// on their System Load; carries are skipped because a distance is not a
+    /// rep count.
     pub async fn get_best_set_for_exercise(
         &self,
         exercise_id: &str,
+        formula: E1rmFormula,
         since_ms: f64,
         exclude_start_ms: f64,
         exclude_end_ms: f64,
     ) -> Result<Option<CompletedSet>, DatabaseError> {
-        let sql = r#"
-            SELECT set_number, reps, rpe, weight, is_bodyweight
+        let sql = format!(
+            r#"
+            SELECT set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind
             FROM completed_sets
             WHERE exercise_id = ?
               AND deleted_at IS NULL
+              AND kind IN ({})
               AND recorded_at >= ?
               AND (recorded_at < ? OR recorded_at >= ?)
-              AND is_bodyweight = 0
-        "#;
+              AND (is_bodyweight = 0 OR bodyweight IS NOT NULL)
+              AND distance_m IS NULL
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
 
         let params = vec![
             JsValue::from_str(exercise_id),
@@ -1781,7 +2768,7 @@ impl Database {
             JsValue::from_f64(exclude_end_ms),
         ];
 
-        let result = self.execute(sql, &params).await?;
+        let result = self.execute(&sql, &params).await?;
 
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -1793,9 +2780,8 @@ impl Database {
             let row = array.get(i);
             let completed = self.parse_completed_set_row(&row)?;
 
-            if let SetType::Weighted { weight } = completed.set_type {
-                let estimate =
-                    crate::domain::e1rm::e1rm(weight as f64, completed.reps, completed.rpe as f64);
+            if let Some(load) = completed.set_type.system_load() {
+                let estimate = formula.e1rm(load as f64, completed.reps, completed.rpe as f64);
                 match &best {
                     Some((_, best_e1rm)) if estimate <= *best_e1rm => {}
                     _ => best = Some((completed, estimate)),
@@ -1822,15 +2808,22 @@ impl Database {
         exclude_start_ms: f64,
         exclude_end_ms: f64,
     ) -> Result<Option<CompletedSet>, DatabaseError> {
-        let sql = r#"
-            SELECT set_number, reps, rpe, weight, is_bodyweight
+        let sql = format!(
+            r#"
+            SELECT set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind
             FROM completed_sets
             WHERE exercise_id = ?
               AND deleted_at IS NULL
+              AND kind IN ({})
               AND recorded_at >= ?
               AND (recorded_at < ? OR recorded_at >= ?)
               AND is_bodyweight = 1
-        "#;
+              AND duration_secs IS NULL
+              AND distance_m IS NULL
+              AND bodyweight IS NULL
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
 
         let params = vec![
             JsValue::from_str(exercise_id),
@@ -1839,7 +2832,7 @@ impl Database {
             JsValue::from_f64(exclude_end_ms),
         ];
 
-        let result = self.execute(sql, &params).await?;
+        let result = self.execute(&sql, &params).await?;
 
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -1862,17 +2855,85 @@ impl Database {
         Ok(best.map(|(set, _)| set))
     }
 
+    /// Returns the best timed, distance or carry set for the given exercise
+    /// within the history window `[since_ms, now)`, **excluding** sets whose
+    /// `recorded_at` falls in `[exclude_start_ms, exclude_end_ms)`.
+    ///
+    /// "Best" is the highest Failure Measure (`domain::reserve::failure_measure`):
+    /// the seconds or metres done plus one `increment` per RPE point below 10.
+    ///
+    /// When multiple sets tie, the first encountered is returned.
+    pub async fn get_best_measured_set_for_exercise(
+        &self,
+        exercise_id: &str,
+        increment: f64,
+        since_ms: f64,
+        exclude_start_ms: f64,
+        exclude_end_ms: f64,
+    ) -> Result<Option<CompletedSet>, DatabaseError> {
+        let sql = format!(
+            r#"
+            SELECT set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind
+            FROM completed_sets
+            WHERE exercise_id = ?
+              AND deleted_at IS NULL
+              AND kind IN ({})
+              AND recorded_at >= ?
+              AND (recorded_at < ? OR recorded_at >= ?)
+              AND (duration_secs IS NOT NULL OR distance_m IS NOT NULL)
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
+
+        let params = vec![
+            JsValue::from_str(exercise_id),
+            JsValue::from_f64(since_ms),
+            JsValue::from_f64(exclude_start_ms),
+            JsValue::from_f64(exclude_end_ms),
+        ];
+
+        let result = self.execute(&sql, &params).await?;
+
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
+
+        let mut best: Option<(CompletedSet, f64)> = None;
+
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let completed = self.parse_completed_set_row(&row)?;
+
+            if let Some(measure) = completed.set_type.measure() {
+                let failure = crate::domain::reserve::failure_measure(
+                    measure,
+                    completed.rpe as f64,
+                    increment,
+                );
+                match &best {
+                    Some((_, best_failure)) if failure <= *best_failure => {}
+                    _ => best = Some((completed, failure)),
+                }
+            }
+        }
+
+        Ok(best.map(|(set, _)| set))
+    }
+
     /// Unified entry point to retrieve the historical best set for any exercise type.
     ///
     /// Dispatches internally based on `exercise.set_type_config`:
     /// - `Weighted` → `get_best_set_for_exercise` (ranked by e1RM)
     /// - `Bodyweight` → `get_best_bodyweight_set_for_exercise` (ranked by failure_reps)
+    /// - `Timed` / `Distance` / `Carry` → `get_best_measured_set_for_exercise`
+    ///   (ranked by Failure Measure)
     ///
     /// Call sites in `log_set` and similar should prefer this over calling the
     /// type-specific functions directly.
     pub async fn get_historical_best_for_exercise(
         &self,
         exercise: &ExerciseMetadata,
+        formula: E1rmFormula,
         since_ms: f64,
         exclude_start_ms: f64,
         exclude_end_ms: f64,
@@ -1883,9 +2944,10 @@ impl Database {
             .ok_or_else(|| DatabaseError::QueryError("Exercise has no id".to_string()))?;
 
         match exercise.set_type_config {
-            SetTypeConfig::Weighted { .. } => {
+            SetTypeConfig::Weighted { .. } | SetTypeConfig::LoadedBodyweight { .. } => {
                 self.get_best_set_for_exercise(
                     exercise_id,
+                    formula,
                     since_ms,
                     exclude_start_ms,
                     exclude_end_ms,
@@ -1901,9 +2963,63 @@ impl Database {
                 )
                 .await
             }
+            ref config @ (SetTypeConfig::Timed { .. }
+            | SetTypeConfig::Distance { .. }
+            | SetTypeConfig::Carry { .. }) => {
+                self.get_best_measured_set_for_exercise(
+                    exercise_id,
+                    config.measure_increment().unwrap_or(1.0),
+                    since_ms,
+                    exclude_start_ms,
+                    exclude_end_ms,
+                )
+                .await
+            }
         }
     }
 
+    /// Returns the working sets of the exercise's most recent Training Day
+    /// (UTC date) before `before_ms`, oldest first, for the Linear and
+    /// Double progression rules.  Empty when it was never trained before.
+    pub async fn get_previous_session_working_sets(
+        &self,
+        exercise_id: &str,
+        before_ms: f64,
+    ) -> Result<Vec<CompletedSet>, DatabaseError> {
+        let sql = r#"
+            SELECT set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind
+            FROM completed_sets
+            WHERE exercise_id = ?
+              AND deleted_at IS NULL
+              AND kind = 'working'
+              AND recorded_at < ?
+              AND CAST(recorded_at / 86400000 AS INTEGER) = (
+                  SELECT MAX(CAST(recorded_at / 86400000 AS INTEGER))
+                  FROM completed_sets
+                  WHERE exercise_id = ?
+                    AND deleted_at IS NULL
+                    AND kind = 'working'
+                    AND recorded_at < ?)
+            ORDER BY recorded_at ASC, id ASC
+        "#;
+
+        let params = vec![
+            JsValue::from_str(exercise_id),
+            JsValue::from_f64(before_ms),
+            JsValue::from_str(exercise_id),
+            JsValue::from_f64(before_ms),
+        ];
+
+        let result = self.execute(sql, &params).await?;
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
+
+        (0..array.length())
+            .map(|i| self.parse_completed_set_row(&array.get(i)))
+            .collect()
+    }
+
     /// Returns the most recently logged set for the given exercise on "today",
     /// defined as the half-open interval `[today_start_ms, today_end_ms)`.
     ///
@@ -1914,16 +3030,20 @@ impl Database {
         today_start_ms: f64,
         today_end_ms: f64,
     ) -> Result<Option<CompletedSet>, DatabaseError> {
-        let sql = r#"
-            SELECT set_number, reps, rpe, weight, is_bodyweight
+        let sql = format!(
+            r#"
+            SELECT set_number, reps, rpe, weight, is_bodyweight, duration_secs, distance_m, bodyweight, kind
             FROM completed_sets
             WHERE exercise_id = ?
               AND deleted_at IS NULL
+              AND kind IN ({})
               AND recorded_at >= ?
               AND recorded_at < ?
             ORDER BY recorded_at DESC, id DESC
             LIMIT 1
-        "#;
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
 
         let params = vec![
             JsValue::from_str(exercise_id),
@@ -1931,7 +3051,7 @@ impl Database {
             JsValue::from_f64(today_end_ms),
         ];
 
-        let result = self.execute(sql, &params).await?;
+        let result = self.execute(&sql, &params).await?;
 
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -1945,8 +3065,8 @@ impl Database {
         Ok(Some(self.parse_completed_set_row(&row)?))
     }
 
-    /// Parses a single row (with set_number, reps, rpe, weight, is_bodyweight)
-    /// into a `CompletedSet`.
+    /// Parses a single row (with set_number, reps, rpe, weight, is_bodyweight,
+    /// duration_secs, distance_m) into a `CompletedSet`.
     fn parse_completed_set_row(&self, row: &JsValue) -> Result<CompletedSet, DatabaseError> {
         let set_number = js_sys::Reflect::get(row, &JsValue::from_str("set_number"))?
             .as_f64()
@@ -1963,23 +3083,15 @@ impl Database {
             .ok_or_else(|| DatabaseError::QueryError("Failed to get rpe".to_string()))?
             as f32;
 
-        let is_bodyweight = self.parse_bool_field(row, "is_bodyweight")?;
-
-        let set_type = if is_bodyweight {
-            SetType::Bodyweight
-        } else {
-            let weight = js_sys::Reflect::get(row, &JsValue::from_str("weight"))?
-                .as_f64()
-                .ok_or_else(|| DatabaseError::QueryError("Failed to get weight".to_string()))?
-                as f32;
-            SetType::Weighted { weight }
-        };
+        let set_type = self.parse_set_type(row)?;
 
         Ok(CompletedSet {
             set_number,
             reps,
             rpe,
             set_type,
+            kind: Self::parse_kind(row),
+            comment: String::new(),
         })
     }
 
@@ -2030,6 +3142,16 @@ impl Database {
         Ok(id)
     }
 
+    /// Reads the `kind` column, treating missing or unknown values as
+    /// working sets.
+    fn parse_kind(row: &JsValue) -> SetKind {
+        js_sys::Reflect::get(row, &JsValue::from_str("kind"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse::<SetKind>().ok())
+            .unwrap_or_default()
+    }
+
     fn parse_bool_field(&self, row: &JsValue, field: &str) -> Result<bool, DatabaseError> {
         let val = js_sys::Reflect::get(row, &JsValue::from_str(field))?;
         if let Some(b) = val.as_bool() {
@@ -2044,6 +3166,179 @@ impl Database {
         }
     }
 
+    /// Column values `(weight, is_bodyweight, duration_secs, distance_m,
+    /// bodyweight)` for a set of the given type.  Unloaded sets keep
+    /// `is_bodyweight = 1` so peers that predate v15 leave them out of
+    /// weight-based queries; loaded bodyweight sets do too, storing the added
+    /// load in `weight` and the bodyweight snapshot alongside it.
+    fn set_type_columns(set_type: &SetType) -> (JsValue, bool, JsValue, JsValue, JsValue) {
+        if let SetType::LoadedBodyweight { bodyweight, added } = set_type {
+            return (
+                JsValue::from_f64(*added as f64),
+                true,
+                JsValue::NULL,
+                JsValue::NULL,
+                JsValue::from_f64(*bodyweight as f64),
+            );
+        }
+        let weight = set_type
+            .weight()
+            .map(|w| JsValue::from_f64(w as f64))
+            .unwrap_or(JsValue::NULL);
+        let (duration_secs, distance_m) = match set_type {
+            SetType::Timed { duration_secs } => {
+                (JsValue::from_f64(*duration_secs as f64), JsValue::NULL)
+            }
+            SetType::Distance { distance_m } | SetType::Carry { distance_m, .. } => {
+                (JsValue::NULL, JsValue::from_f64(*distance_m as f64))
+            }
+            SetType::Weighted { .. } | SetType::Bodyweight | SetType::LoadedBodyweight { .. } => {
+                (JsValue::NULL, JsValue::NULL)
+            }
+        };
+        (
+            weight,
+            set_type.weight().is_none(),
+            duration_secs,
+            distance_m,
+            JsValue::NULL,
+        )
+    }
+
+    /// Reads a set row's `SetType`: a `duration_secs` value marks a timed
+    /// set and a `distance_m` value a distance set (a carry when it also has
+    /// a weight); a `bodyweight` snapshot marks a loaded bodyweight set;
+    /// otherwise `is_bodyweight` decides, as before v15.
+    fn parse_set_type(&self, row: &JsValue) -> Result<SetType, DatabaseError> {
+        let get_f64 = |key: &str| {
+            js_sys::Reflect::get(row, &JsValue::from_str(key))
+                .ok()
+                .and_then(|v| v.as_f64())
+        };
+        let weight = get_f64("weight").map(|w| w as f32);
+
+        if let Some(secs) = get_f64("duration_secs") {
+            return Ok(SetType::Timed {
+                duration_secs: secs as u32,
+            });
+        }
+        if let Some(distance) = get_f64("distance_m") {
+            let distance_m = distance as f32;
+            return Ok(match weight {
+                Some(weight) => SetType::Carry { weight, distance_m },
+                None => SetType::Distance { distance_m },
+            });
+        }
+        if let Some(bodyweight) = get_f64("bodyweight") {
+            return Ok(SetType::LoadedBodyweight {
+                bodyweight: bodyweight as f32,
+                added: weight.unwrap_or(0.0),
+            });
+        }
+
+        if self.parse_bool_field(row, "is_bodyweight")? {
+            Ok(SetType::Bodyweight)
+        } else {
+            let weight = weight
+                .ok_or_else(|| DatabaseError::QueryError("Failed to get weight".to_string()))?;
+            Ok(SetType::Weighted { weight })
+        }
+    }
+
+    /// Reads a row's `rpe_calibration`; empty (or unparseable) means none
+    /// has been fitted.
+    fn parse_rpe_calibration(row: &JsValue) -> Option<RpeCalibration> {
+        js_sys::Reflect::get(row, &JsValue::from_str("rpe_calibration"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse().ok())
+    }
+
+    /// Reads an exercise row's `e1rm_formula` override; empty (or a formula
+    /// this build does not know) means the global one.
+    fn parse_e1rm_formula(row: &JsValue) -> Option<E1rmFormula> {
+        js_sys::Reflect::get(row, &JsValue::from_str("e1rm_formula"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse().ok())
+    }
+
+    /// Reads an exercise row's `progression`; empty (or a strategy this
+    /// build does not know) means autoregulated.
+    fn parse_progression(row: &JsValue) -> ProgressionStrategy {
+        js_sys::Reflect::get(row, &JsValue::from_str("progression"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Reads the unit an exercise row's weights were entered in; rows from
+    /// peers that predate v27 read as kilograms.
+    fn parse_exercise_weight_unit(row: &JsValue) -> crate::models::WeightUnit {
+        js_sys::Reflect::get(row, &JsValue::from_str("weight_unit"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Reads an exercise row's `SetTypeConfig` from `set_type` and the
+    /// columns that type uses.  An empty `set_type` (rows from peers that
+    /// predate v15) falls back to `is_weighted`.
+    fn parse_set_type_config(&self, row: &JsValue) -> Result<SetTypeConfig, DatabaseError> {
+        let get_f64 = |key: &str| {
+            js_sys::Reflect::get(row, &JsValue::from_str(key))
+                .ok()
+                .and_then(|v| v.as_f64())
+        };
+        let require = |key: &str| {
+            get_f64(key).ok_or_else(|| DatabaseError::QueryError(format!("Failed to get {}", key)))
+        };
+
+        let set_type = js_sys::Reflect::get(row, &JsValue::from_str("set_type"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .unwrap_or_default();
+        let set_type = if set_type.is_empty() {
+            if self.parse_bool_field(row, "is_weighted")? {
+                "weighted".to_string()
+            } else {
+                "bodyweight".to_string()
+            }
+        } else {
+            set_type
+        };
+
+        match set_type.as_str() {
+            "weighted" => Ok(SetTypeConfig::Weighted {
+                min_weight: require("min_weight")? as f32,
+                increment: require("increment")? as f32,
+                bar_weight: get_f64("bar_weight").map(|w| w as f32),
+            }),
+            "bodyweight" => Ok(SetTypeConfig::Bodyweight),
+            "loaded_bodyweight" | "assisted_bodyweight" => Ok(SetTypeConfig::LoadedBodyweight {
+                increment: require("increment")? as f32,
+                assisted: set_type == "assisted_bodyweight",
+            }),
+            "timed" => Ok(SetTypeConfig::Timed {
+                increment_secs: require("measure_increment")? as u32,
+            }),
+            "distance" => Ok(SetTypeConfig::Distance {
+                increment_m: require("measure_increment")? as f32,
+            }),
+            "carry" => Ok(SetTypeConfig::Carry {
+                min_weight: require("min_weight")? as f32,
+                increment: require("increment")? as f32,
+                increment_m: require("measure_increment")? as f32,
+            }),
+            other => Err(DatabaseError::QueryError(format!(
+                "Unknown set type: '{}'",
+                other
+            ))),
+        }
+    }
+
     fn parse_history_sets(&self, result: &JsValue) -> Result<Vec<HistorySet>, DatabaseError> {
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -2089,17 +3384,16 @@ impl Database {
                     DatabaseError::QueryError("Failed to get recorded_at".to_string())
                 })?;
 
-            let is_bodyweight = self.parse_bool_field(&row, "is_bodyweight")?;
+            let set_type = self.parse_set_type(&row)?;
 
-            let set_type = if is_bodyweight {
-                SetType::Bodyweight
-            } else {
-                let weight = js_sys::Reflect::get(&row, &JsValue::from_str("weight"))?
-                    .as_f64()
-                    .ok_or_else(|| DatabaseError::QueryError("Failed to get weight".to_string()))?
-                    as f32;
-                SetType::Weighted { weight }
-            };
+            let kind = Self::parse_kind(&row);
+
+            let comment = js_sys::Reflect::get(&row, &JsValue::from_str("comment"))?
+                .as_string()
+                .unwrap_or_default();
+            let rest_secs = js_sys::Reflect::get(&row, &JsValue::from_str("rest_secs"))?
+                .as_f64()
+                .map(|v| v as u32);
 
             sets.push(HistorySet {
                 id,
@@ -2109,6 +3403,9 @@ impl Database {
                 reps,
                 rpe,
                 set_type,
+                kind,
+                comment,
+                rest_secs,
                 recorded_at,
             });
         }
@@ -2236,6 +3533,92 @@ impl Database {
         Ok(id)
     }
 
+    /// Applies `(plan slot id, group_id)` updates produced by
+    /// [`crate::domain::slot_group`].
+    pub async fn set_plan_slot_groups(
+        &self,
+        updates: &[(String, Option<String>)],
+    ) -> Result<(), DatabaseError> {
+        let now = js_sys::Date::now();
+        for (slot_id, group_id) in updates {
+            self.execute(
+                "UPDATE workout_plan_exercises SET group_id = ?, updated_at = ? WHERE id = ?",
+                &[
+                    group_id
+                        .as_deref()
+                        .map(JsValue::from_str)
+                        .unwrap_or(JsValue::NULL),
+                    JsValue::from_f64(now),
+                    JsValue::from_str(slot_id),
+                ],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Stores the Slot Targets of a plan slot; empty targets clear the
+    /// overrides.
+    pub async fn set_plan_slot_targets(
+        &self,
+        slot_id: &str,
+        targets: &SlotTargets,
+    ) -> Result<(), DatabaseError> {
+        let [min_reps, max_reps, rpe] = Self::slot_target_params(targets);
+        self.execute(
+            "UPDATE workout_plan_exercises SET target_min_reps = ?, target_max_reps = ?, target_rpe = ?, updated_at = ? WHERE id = ?",
+            &[
+                min_reps,
+                max_reps,
+                rpe,
+                JsValue::from_f64(js_sys::Date::now()),
+                JsValue::from_str(slot_id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn set_plan_slot_planned_sets(
+        &self,
+        slot_id: &str,
+        planned_sets: u32,
+    ) -> Result<(), DatabaseError> {
+        self.execute(
+            "UPDATE workout_plan_exercises SET planned_sets = ?, updated_at = ? WHERE id = ?",
+            &[
+                JsValue::from_f64(planned_sets as f64),
+                JsValue::from_f64(js_sys::Date::now()),
+                JsValue::from_str(slot_id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// `(target_min_reps, target_max_reps, target_rpe)` bind values.
+    fn slot_target_params(targets: &SlotTargets) -> [JsValue; 3] {
+        let num = |v: Option<f64>| v.map(JsValue::from_f64).unwrap_or(JsValue::NULL);
+        [
+            num(targets.rep_range.map(|(min, _)| min as f64)),
+            num(targets.rep_range.and_then(|(_, max)| max).map(|m| m as f64)),
+            num(targets.target_rpe),
+        ]
+    }
+
+    fn parse_slot_targets(row: &JsValue) -> SlotTargets {
+        let get = |key: &str| {
+            js_sys::Reflect::get(row, &JsValue::from_str(key))
+                .ok()
+                .and_then(|v| v.as_f64())
+        };
+        SlotTargets {
+            rep_range: get("target_min_reps")
+                .map(|min| (min as i32, get("target_max_reps").map(|max| max as i32))),
+            target_rpe: get("target_rpe"),
+        }
+    }
+
     pub async fn remove_exercise_from_plan(
         &self,
         plan_exercise_id: &str,
@@ -2356,7 +3739,7 @@ impl Database {
     pub async fn get_active_plan(&self) -> Result<Option<WorkoutPlan>, DatabaseError> {
         let result = self
             .execute(
-                "SELECT id, started_at, ended_at FROM workout_plans WHERE started_at IS NOT NULL AND ended_at IS NULL AND deleted_at IS NULL LIMIT 1",
+                "SELECT id, started_at, ended_at, program_id, program_index FROM workout_plans WHERE started_at IS NOT NULL AND ended_at IS NULL AND deleted_at IS NULL LIMIT 1",
                 &[],
             )
             .await?;
@@ -2386,13 +3769,14 @@ impl Database {
             started_at,
             ended_at,
             exercises,
+            program: Self::parse_program_position(&row),
         }))
     }
 
     pub async fn get_plan(&self, plan_id: &str) -> Result<Option<WorkoutPlan>, DatabaseError> {
         let result = self
             .execute(
-                "SELECT id, started_at, ended_at FROM workout_plans WHERE id = ? AND deleted_at IS NULL",
+                "SELECT id, started_at, ended_at, program_id, program_index FROM workout_plans WHERE id = ? AND deleted_at IS NULL",
                 &[JsValue::from_str(plan_id)],
             )
             .await?;
@@ -2417,6 +3801,7 @@ impl Database {
             started_at,
             ended_at,
             exercises,
+            program: Self::parse_program_position(&row),
         }))
     }
 
@@ -2424,8 +3809,9 @@ impl Database {
         let result = self
             .execute(
                 r#"
-                SELECT pe.id, pe.exercise_id, pe.planned_sets, pe.position,
-                       e.name, e.is_weighted, e.min_weight, e.increment, e.min_reps, e.max_reps
+                SELECT pe.id, pe.exercise_id, pe.planned_sets, pe.position, pe.group_id,
+                       pe.target_min_reps, pe.target_max_reps, pe.target_rpe,
+                       e.name, e.is_weighted, e.set_type, e.min_weight, e.increment, e.bar_weight, e.measure_increment, e.min_reps, e.max_reps, e.notes, e.rest_secs, e.e1rm_formula, e.progression, e.weight_unit
                 FROM workout_plan_exercises pe
                 JOIN exercises e ON pe.exercise_id = e.uuid
                 WHERE pe.plan_id = ? AND pe.deleted_at IS NULL
@@ -2456,15 +3842,7 @@ impl Database {
                     .unwrap_or(0.0)
             };
 
-            let is_weighted = get_f64("is_weighted") != 0.0;
-            let set_type_config = if is_weighted {
-                SetTypeConfig::Weighted {
-                    min_weight: get_f64("min_weight") as f32,
-                    increment: get_f64("increment") as f32,
-                }
-            } else {
-                SetTypeConfig::Bodyweight
-            };
+            let set_type_config = self.parse_set_type_config(&row)?;
 
             let exercise_id = get_str("exercise_id");
             let max_reps_val = js_sys::Reflect::get(&row, &JsValue::from_str("max_reps"))
@@ -2479,9 +3857,21 @@ impl Database {
                     set_type_config,
                     min_reps: get_f64("min_reps") as i32,
                     max_reps: max_reps_val.map(|v| v as i32),
+                    notes: get_str("notes"),
+                    rest_secs: js_sys::Reflect::get(&row, &JsValue::from_str("rest_secs"))
+                        .ok()
+                        .and_then(|v| v.as_f64())
+                        .map(|v| v as u32),
+                    e1rm_formula: Self::parse_e1rm_formula(&row),
+                    progression: Self::parse_progression(&row),
+                    weight_unit: Self::parse_exercise_weight_unit(&row),
                 },
                 planned_sets: get_f64("planned_sets") as u32,
                 position: get_f64("position") as u32,
+                group_id: js_sys::Reflect::get(&row, &JsValue::from_str("group_id"))
+                    .ok()
+                    .and_then(|v| v.as_string()),
+                targets: Self::parse_slot_targets(&row),
             });
         }
 
@@ -2492,7 +3882,7 @@ impl Database {
     pub async fn get_unstarted_plan(&self) -> Result<Option<WorkoutPlan>, DatabaseError> {
         let result = self
             .execute(
-                "SELECT id, started_at, ended_at FROM workout_plans WHERE started_at IS NULL AND deleted_at IS NULL ORDER BY rowid DESC LIMIT 1",
+                "SELECT id, started_at, ended_at, program_id, program_index FROM workout_plans WHERE started_at IS NULL AND deleted_at IS NULL ORDER BY rowid DESC LIMIT 1",
                 &[],
             )
             .await?;
@@ -2515,6 +3905,7 @@ impl Database {
             started_at: None,
             ended_at: None,
             exercises,
+            program: Self::parse_program_position(&row),
         }))
     }
 
@@ -2531,8 +3922,9 @@ impl Database {
 
         let placeholders: Vec<&str> = exercise_ids.iter().map(|_| "?").collect();
         let sql = format!(
-            "SELECT exercise_id, COUNT(*) as cnt FROM completed_sets WHERE exercise_id IN ({}) AND recorded_at >= ? AND deleted_at IS NULL GROUP BY exercise_id",
-            placeholders.join(",")
+            "SELECT exercise_id, COUNT(*) as cnt FROM completed_sets WHERE exercise_id IN ({}) AND recorded_at >= ? AND deleted_at IS NULL AND kind IN ({}) GROUP BY exercise_id",
+            placeholders.join(","),
+            SetKind::sql_list(SetKind::counts_for_volume)
         );
 
         let mut params: Vec<JsValue> = exercise_ids
@@ -2584,16 +3976,378 @@ impl Database {
             .collect();
         params.push(JsValue::from_f64(since_ms));
 
-        let result = self.execute(&sql, &params).await?;
-        let array = match result.dyn_ref::<js_sys::Array>() {
-            Some(a) if a.length() > 0 => a,
-            _ => return Ok(None),
-        };
-        let row = array.get(0);
-        let latest = js_sys::Reflect::get(&row, &JsValue::from_str("latest"))
-            .ok()
-            .and_then(|v| v.as_f64());
-        Ok(latest)
+        let result = self.execute(&sql, &params).await?;
+        let array = match result.dyn_ref::<js_sys::Array>() {
+            Some(a) if a.length() > 0 => a,
+            _ => return Ok(None),
+        };
+        let row = array.get(0);
+        let latest = js_sys::Reflect::get(&row, &JsValue::from_str("latest"))
+            .ok()
+            .and_then(|v| v.as_f64());
+        Ok(latest)
+    }
+
+    // ── Plate inventory CRUD ─────────────────────────────────────────────────
+
+    /// Returns the live plate inventory, heaviest plates first.
+    pub async fn get_plates(&self) -> Result<Vec<Plate>, DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT id, weight, count FROM plates WHERE deleted_at IS NULL ORDER BY weight DESC",
+                &[],
+            )
+            .await?;
+
+        let array = match result.dyn_ref::<js_sys::Array>() {
+            Some(a) => a,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut plates = Vec::new();
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let id = js_sys::Reflect::get(&row, &JsValue::from_str("id"))?.as_string();
+            let weight = js_sys::Reflect::get(&row, &JsValue::from_str("weight"))?
+                .as_f64()
+                .unwrap_or(0.0) as f32;
+            let count = js_sys::Reflect::get(&row, &JsValue::from_str("count"))?
+                .as_f64()
+                .unwrap_or(0.0) as u32;
+            plates.push(Plate { id, weight, count });
+        }
+        Ok(plates)
+    }
+
+    /// Inserts a new inventory row, or updates the row matching `plate.id`.
+    /// Returns the row's id.
+    pub async fn save_plate(&self, plate: &Plate) -> Result<String, DatabaseError> {
+        let now = js_sys::Date::now();
+        if let Some(ref id) = plate.id {
+            self.execute(
+                "UPDATE plates SET weight = ?, count = ?, updated_at = ? WHERE id = ?",
+                &[
+                    JsValue::from_f64(plate.weight as f64),
+                    JsValue::from_f64(plate.count as f64),
+                    JsValue::from_f64(now),
+                    JsValue::from_str(id),
+                ],
+            )
+            .await?;
+            return Ok(id.clone());
+        }
+
+        let id = Self::generate_uuid();
+        self.execute(
+            "INSERT INTO plates (id, weight, count, updated_at) VALUES (?, ?, ?, ?)",
+            &[
+                JsValue::from_str(&id),
+                JsValue::from_f64(plate.weight as f64),
+                JsValue::from_f64(plate.count as f64),
+                JsValue::from_f64(now),
+            ],
+        )
+        .await?;
+        Ok(id)
+    }
+
+    /// Soft-deletes an inventory row.
+    pub async fn delete_plate(&self, plate_id: &str) -> Result<(), DatabaseError> {
+        let now = js_sys::Date::now();
+        self.execute(
+            "UPDATE plates SET deleted_at = ?, updated_at = ? WHERE id = ? AND deleted_at IS NULL",
+            &[
+                JsValue::from_f64(now),
+                JsValue::from_f64(now),
+                JsValue::from_str(plate_id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    // ── Bodyweight log CRUD ──────────────────────────────────────────────────
+
+    /// Returns the live bodyweight log, newest entry first.
+    pub async fn get_bodyweight_log(&self) -> Result<Vec<BodyweightEntry>, DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT id, recorded_at, mass FROM bodyweight_log WHERE deleted_at IS NULL ORDER BY recorded_at DESC",
+                &[],
+            )
+            .await?;
+
+        let array = match result.dyn_ref::<js_sys::Array>() {
+            Some(a) => a,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut entries = Vec::new();
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let id = js_sys::Reflect::get(&row, &JsValue::from_str("id"))?.as_string();
+            let recorded_at = js_sys::Reflect::get(&row, &JsValue::from_str("recorded_at"))?
+                .as_f64()
+                .unwrap_or(0.0);
+            let mass = js_sys::Reflect::get(&row, &JsValue::from_str("mass"))?
+                .as_f64()
+                .unwrap_or(0.0) as f32;
+            entries.push(BodyweightEntry {
+                id,
+                recorded_at,
+                mass,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Inserts a new bodyweight entry, or updates the row matching
+    /// `entry.id`.  Returns the row's id.
+    pub async fn save_bodyweight_entry(
+        &self,
+        entry: &BodyweightEntry,
+    ) -> Result<String, DatabaseError> {
+        let now = js_sys::Date::now();
+        if let Some(ref id) = entry.id {
+            self.execute(
+                "UPDATE bodyweight_log SET recorded_at = ?, mass = ?, updated_at = ? WHERE id = ?",
+                &[
+                    JsValue::from_f64(entry.recorded_at),
+                    JsValue::from_f64(entry.mass as f64),
+                    JsValue::from_f64(now),
+                    JsValue::from_str(id),
+                ],
+            )
+            .await?;
+            return Ok(id.clone());
+        }
+
+        let id = Self::generate_uuid();
+        self.execute(
+            "INSERT INTO bodyweight_log (id, recorded_at, mass, updated_at) VALUES (?, ?, ?, ?)",
+            &[
+                JsValue::from_str(&id),
+                JsValue::from_f64(entry.recorded_at),
+                JsValue::from_f64(entry.mass as f64),
+                JsValue::from_f64(now),
+            ],
+        )
+        .await?;
+        Ok(id)
+    }
+
+    /// Soft-deletes a bodyweight entry.
+    pub async fn delete_bodyweight_entry(&self, entry_id: &str) -> Result<(), DatabaseError> {
+        let now = js_sys::Date::now();
+        self.execute(
+            "UPDATE bodyweight_log SET deleted_at = ?, updated_at = ? WHERE id = ? AND deleted_at IS NULL",
+            &[
+                JsValue::from_f64(now),
+                JsValue::from_f64(now),
+                JsValue::from_str(entry_id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    fn parse_program_position(row: &JsValue) -> Option<ProgramPosition> {
+        let program_id = js_sys::Reflect::get(row, &JsValue::from_str("program_id"))
+            .ok()
+            .and_then(|v| v.as_string())?;
+        let index = js_sys::Reflect::get(row, &JsValue::from_str("program_index"))
+            .ok()
+            .and_then(|v| v.as_f64())?;
+        Some(ProgramPosition {
+            program_id,
+            index: index as u32,
+        })
+    }
+
+    // ── Program CRUD ─────────────────────────────────────────────────────────
+
+    /// Saves a new program and its template days; returns the program id.
+    /// The ids of `program` and its days are ignored.
+    pub async fn create_program(&self, program: &Program) -> Result<String, DatabaseError> {
+        let name = program.name.trim();
+        if name.is_empty() {
+            return Err(DatabaseError::ValidationError(
+                "Program name must not be empty".to_string(),
+            ));
+        }
+        if program.weeks == 0 || program.days.is_empty() {
+            return Err(DatabaseError::ValidationError(
+                "A program needs at least one week and one day".to_string(),
+            ));
+        }
+        let id = Self::generate_uuid();
+        let now = js_sys::Date::now();
+        let (start, step) = program.progression.parts();
+        let num = |v: Option<f64>| v.map(JsValue::from_f64).unwrap_or(JsValue::NULL);
+        self.execute(
+            "INSERT INTO programs (id, name, weeks, deload_week, progression, progression_start, progression_step, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            &[
+                JsValue::from_str(&id),
+                JsValue::from_str(name),
+                JsValue::from_f64(program.weeks as f64),
+                num(program.deload_week.map(|w| w as f64)),
+                JsValue::from_str(program.progression.tag()),
+                num(start),
+                num(step),
+                JsValue::from_f64(now),
+            ],
+        )
+        .await?;
+
+        for (pos, day) in program.days.iter().enumerate() {
+            self.execute(
+                "INSERT INTO program_days (id, program_id, template_id, position, updated_at) VALUES (?, ?, ?, ?, ?)",
+                &[
+                    JsValue::from_str(&Self::generate_uuid()),
+                    JsValue::from_str(&id),
+                    JsValue::from_str(&day.template_id),
+                    JsValue::from_f64(pos as f64),
+                    JsValue::from_f64(now),
+                ],
+            )
+            .await?;
+        }
+        Ok(id)
+    }
+
+    pub async fn list_programs(&self) -> Result<Vec<Program>, DatabaseError> {
+        let rows = self
+            .execute(
+                "SELECT id, name, weeks, deload_week, progression, progression_start, progression_step
+                 FROM programs WHERE deleted_at IS NULL ORDER BY updated_at DESC",
+                &[],
+            )
+            .await?;
+        let day_rows = self
+            .execute(
+                "SELECT d.id, d.program_id, d.template_id, t.name AS template_name
+                 FROM program_days d
+                 LEFT JOIN workout_templates t ON t.id = d.template_id AND t.deleted_at IS NULL
+                 WHERE d.deleted_at IS NULL
+                 ORDER BY d.position ASC",
+                &[],
+            )
+            .await?;
+
+        let get_str = |row: &JsValue, key: &str| -> String {
+            js_sys::Reflect::get(row, &JsValue::from_str(key))
+                .ok()
+                .and_then(|v| v.as_string())
+                .unwrap_or_default()
+        };
+        let get_f64 = |row: &JsValue, key: &str| -> Option<f64> {
+            js_sys::Reflect::get(row, &JsValue::from_str(key))
+                .ok()
+                .and_then(|v| v.as_f64())
+        };
+
+        let mut days: std::collections::HashMap<String, Vec<ProgramDay>> =
+            std::collections::HashMap::new();
+        if let Some(array) = day_rows.dyn_ref::<js_sys::Array>() {
+            for row in array.iter() {
+                days.entry(get_str(&row, "program_id"))
+                    .or_default()
+                    .push(ProgramDay {
+                        id: get_str(&row, "id"),
+                        template_id: get_str(&row, "template_id"),
+                        template_name: get_str(&row, "template_name"),
+                    });
+            }
+        }
+
+        let Some(array) = rows.dyn_ref::<js_sys::Array>() else {
+            return Ok(Vec::new());
+        };
+        Ok(array
+            .iter()
+            .map(|row| {
+                let id = get_str(&row, "id");
+                Program {
+                    name: get_str(&row, "name"),
+                    weeks: get_f64(&row, "weeks").unwrap_or(0.0) as u32,
+                    deload_week: get_f64(&row, "deload_week").map(|w| w as u32),
+                    progression: ProgressionRule::from_parts(
+                        &get_str(&row, "progression"),
+                        get_f64(&row, "progression_start"),
+                        get_f64(&row, "progression_step"),
+                    ),
+                    days: days.remove(&id).unwrap_or_default(),
+                    id,
+                }
+            })
+            .collect())
+    }
+
+    /// Soft-deletes a program and its days. Plans already materialized from
+    /// it keep their slots.
+    pub async fn delete_program(&self, program_id: &str) -> Result<(), DatabaseError> {
+        let now = js_sys::Date::now();
+        self.execute(
+            "UPDATE program_days SET deleted_at = ?, updated_at = ?
+             WHERE program_id = ? AND deleted_at IS NULL",
+            &[
+                JsValue::from_f64(now),
+                JsValue::from_f64(now),
+                JsValue::from_str(program_id),
+            ],
+        )
+        .await?;
+        self.execute(
+            "UPDATE programs SET deleted_at = ?, updated_at = ?
+             WHERE id = ? AND deleted_at IS NULL",
+            &[
+                JsValue::from_f64(now),
+                JsValue::from_f64(now),
+                JsValue::from_str(program_id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Highest workout index of the program among started plans; `None`
+    /// when none has been started.
+    pub async fn last_started_program_index(
+        &self,
+        program_id: &str,
+    ) -> Result<Option<u32>, DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT MAX(program_index) AS idx FROM workout_plans
+                 WHERE program_id = ? AND started_at IS NOT NULL AND deleted_at IS NULL",
+                &[JsValue::from_str(program_id)],
+            )
+            .await?;
+        Ok(result
+            .dyn_ref::<js_sys::Array>()
+            .filter(|a| a.length() > 0)
+            .and_then(|a| js_sys::Reflect::get(&a.get(0), &JsValue::from_str("idx")).ok())
+            .and_then(|v| v.as_f64())
+            .map(|v| v as u32))
+    }
+
+    /// Records which program workout a plan was materialized from.
+    pub async fn set_plan_program(
+        &self,
+        plan_id: &str,
+        position: &ProgramPosition,
+    ) -> Result<(), DatabaseError> {
+        self.execute(
+            "UPDATE workout_plans SET program_id = ?, program_index = ?, updated_at = ? WHERE id = ?",
+            &[
+                JsValue::from_str(&position.program_id),
+                JsValue::from_f64(position.index as f64),
+                JsValue::from_f64(js_sys::Date::now()),
+                JsValue::from_str(plan_id),
+            ],
+        )
+        .await?;
+        Ok(())
     }
 
     // ── Workout Template CRUD ────────────────────────────────────────────────
@@ -2619,14 +4373,22 @@ impl Database {
         for (pos, pe) in exercises.iter().enumerate() {
             let te_id = Self::generate_uuid();
             let exercise_id = pe.exercise.id.clone().unwrap_or_default();
+            let [min_reps, max_reps, rpe] = Self::slot_target_params(&pe.targets);
             self.execute(
-                "INSERT INTO workout_template_exercises (id, template_id, exercise_id, planned_sets, position, updated_at) VALUES (?, ?, ?, ?, ?, ?)",
+                "INSERT INTO workout_template_exercises (id, template_id, exercise_id, planned_sets, position, group_id, target_min_reps, target_max_reps, target_rpe, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
                 &[
                     JsValue::from_str(&te_id),
                     JsValue::from_str(&id),
                     JsValue::from_str(&exercise_id),
                     JsValue::from_f64(pe.planned_sets as f64),
                     JsValue::from_f64(pos as f64),
+                    pe.group_id
+                        .as_deref()
+                        .map(JsValue::from_str)
+                        .unwrap_or(JsValue::NULL),
+                    min_reps,
+                    max_reps,
+                    rpe,
                     JsValue::from_f64(now),
                 ],
             )
@@ -2678,8 +4440,9 @@ impl Database {
         let result = self
             .execute(
                 r#"
-                SELECT te.id, te.exercise_id, te.planned_sets, te.position,
-                       e.name, e.is_weighted, e.min_weight, e.increment, e.min_reps, e.max_reps
+                SELECT te.id, te.exercise_id, te.planned_sets, te.position, te.group_id,
+                       te.target_min_reps, te.target_max_reps, te.target_rpe,
+                       e.name, e.is_weighted, e.set_type, e.min_weight, e.increment, e.bar_weight, e.measure_increment, e.min_reps, e.max_reps, e.notes, e.rest_secs, e.e1rm_formula, e.progression, e.weight_unit
                 FROM workout_template_exercises te
                 JOIN exercises e ON te.exercise_id = e.uuid
                 WHERE te.template_id = ? AND te.deleted_at IS NULL
@@ -2710,15 +4473,7 @@ impl Database {
                     .unwrap_or(0.0)
             };
 
-            let is_weighted = get_f64("is_weighted") != 0.0;
-            let set_type_config = if is_weighted {
-                SetTypeConfig::Weighted {
-                    min_weight: get_f64("min_weight") as f32,
-                    increment: get_f64("increment") as f32,
-                }
-            } else {
-                SetTypeConfig::Bodyweight
-            };
+            let set_type_config = self.parse_set_type_config(&row)?;
 
             let exercise_id = get_str("exercise_id");
             let max_reps_val = js_sys::Reflect::get(&row, &JsValue::from_str("max_reps"))
@@ -2733,9 +4488,21 @@ impl Database {
                     set_type_config,
                     min_reps: get_f64("min_reps") as i32,
                     max_reps: max_reps_val.map(|v| v as i32),
+                    notes: get_str("notes"),
+                    rest_secs: js_sys::Reflect::get(&row, &JsValue::from_str("rest_secs"))
+                        .ok()
+                        .and_then(|v| v.as_f64())
+                        .map(|v| v as u32),
+                    e1rm_formula: Self::parse_e1rm_formula(&row),
+                    progression: Self::parse_progression(&row),
+                    weight_unit: Self::parse_exercise_weight_unit(&row),
                 },
                 planned_sets: get_f64("planned_sets") as u32,
                 position: get_f64("position") as u32,
+                group_id: js_sys::Reflect::get(&row, &JsValue::from_str("group_id"))
+                    .ok()
+                    .and_then(|v| v.as_string()),
+                targets: Self::parse_slot_targets(&row),
             });
         }
         Ok(exercises)
@@ -2760,19 +4527,35 @@ impl Database {
         )
         .await?;
 
+        // The plan now holds a plain template, not a program workout;
+        // program materialization re-links it afterwards.
+        self.execute(
+            "UPDATE workout_plans SET program_id = NULL, program_index = NULL, updated_at = ? WHERE id = ?",
+            &[JsValue::from_f64(now), JsValue::from_str(plan_id)],
+        )
+        .await?;
+
         // Copy template exercises into plan
         let template_exercises = self.get_template_exercises(template_id).await?;
         for (pos, te) in template_exercises.iter().enumerate() {
             let pe_id = Self::generate_uuid();
             let exercise_id = te.exercise.id.clone().unwrap_or_default();
+            let [min_reps, max_reps, rpe] = Self::slot_target_params(&te.targets);
             self.execute(
-                "INSERT INTO workout_plan_exercises (id, plan_id, exercise_id, planned_sets, position, updated_at) VALUES (?, ?, ?, ?, ?, ?)",
+                "INSERT INTO workout_plan_exercises (id, plan_id, exercise_id, planned_sets, position, group_id, target_min_reps, target_max_reps, target_rpe, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
                 &[
                     JsValue::from_str(&pe_id),
                     JsValue::from_str(plan_id),
                     JsValue::from_str(&exercise_id),
                     JsValue::from_f64(te.planned_sets as f64),
                     JsValue::from_f64(pos as f64),
+                    te.group_id
+                        .as_deref()
+                        .map(JsValue::from_str)
+                        .unwrap_or(JsValue::NULL),
+                    min_reps,
+                    max_reps,
+                    rpe,
                     JsValue::from_f64(now),
                 ],
             )
@@ -3056,24 +4839,32 @@ impl Database {
         Ok(map)
     }
 
+    /// Returns the heaviest load per rep count, folded so each rep count
+    /// holds the best load lifted for at least that many reps.  Loaded
+    /// bodyweight sets contribute their System Load (bodyweight + added).
     pub async fn get_max_weight_per_rep(
         &self,
         exercise_id: &str,
         since_ms: f64,
     ) -> Result<std::collections::HashMap<u32, f64>, DatabaseError> {
-        let sql = r#"
-            SELECT reps, MAX(weight) AS max_weight
+        let sql = format!(
+            r#"
+            SELECT reps, MAX(weight + COALESCE(bodyweight, 0)) AS max_weight
             FROM completed_sets
             WHERE exercise_id = ?
               AND recorded_at >= ?
               AND deleted_at IS NULL
-              AND is_bodyweight = 0
+              AND kind IN ({})
+              AND (is_bodyweight = 0 OR bodyweight IS NOT NULL)
+              AND distance_m IS NULL
             GROUP BY reps
-        "#;
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
 
         let params = vec![JsValue::from_str(exercise_id), JsValue::from_f64(since_ms)];
 
-        let result = self.execute(sql, &params).await?;
+        let result = self.execute(&sql, &params).await?;
 
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -3120,31 +4911,39 @@ impl Database {
     /// covering the rolling window of `training_window_weeks` weeks ending now.
     ///
     /// - Sets are grouped by UTC calendar date (`recorded_at` → date); same date = same session.
-    /// - Per session the highest e1RM value across all weighted sets is kept.
+    /// - Per session the highest e1RM under `formula` across all weighted sets is kept.
     /// - Returns pairs sorted oldest-first, suitable for passing directly to `e1rm_trend`.
-    /// - Bodyweight sets are excluded (no meaningful e1RM without an external load).
+    /// - Bodyweight sets are excluded (no meaningful e1RM without an external load),
+    ///   as are carries (weight over a distance, not reps).  Loaded bodyweight
+    ///   sets count with their System Load.
     pub async fn get_e1rm_session_history(
         &self,
         exercise_id: &str,
+        formula: E1rmFormula,
         training_window_weeks: u32,
     ) -> Result<Vec<(chrono::NaiveDate, f64)>, DatabaseError> {
         let now_ms = js_sys::Date::now();
         let window_ms = (training_window_weeks as f64) * 7.0 * 24.0 * 3600.0 * 1000.0;
         let since_ms = now_ms - window_ms;
 
-        let sql = r#"
-            SELECT reps, rpe, weight, recorded_at
+        let sql = format!(
+            r#"
+            SELECT reps, rpe, weight + COALESCE(bodyweight, 0) AS weight, recorded_at
             FROM completed_sets
             WHERE exercise_id = ?
               AND recorded_at >= ?
               AND deleted_at IS NULL
-              AND is_bodyweight = 0
+              AND kind IN ({})
+              AND (is_bodyweight = 0 OR bodyweight IS NOT NULL)
+              AND distance_m IS NULL
             ORDER BY recorded_at ASC
-        "#;
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
 
         let params = vec![JsValue::from_str(exercise_id), JsValue::from_f64(since_ms)];
 
-        let result = self.execute(sql, &params).await?;
+        let result = self.execute(&sql, &params).await?;
 
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -3175,7 +4974,7 @@ impl Database {
                 .ok_or_else(|| DatabaseError::QueryError("invalid timestamp".to_string()))?
                 .date_naive();
 
-            let set_e1rm = crate::domain::e1rm::e1rm(weight, reps, rpe);
+            let set_e1rm = formula.e1rm(weight, reps, rpe);
             let entry = sessions.entry(date).or_insert(f64::NEG_INFINITY);
             if set_e1rm > *entry {
                 *entry = set_e1rm;
@@ -3190,7 +4989,10 @@ impl Database {
     /// Per-set contribution formula:
     /// `(rpe / 10.0) × (tier_weight / sum_of_all_tier_weights_for_exercise)`
     ///
-    /// Bodyweight sets and deleted sets are excluded.
+    /// Sets tagged with a Sub-Muscle of `muscle_group` roll up into it, so a
+    /// parent's volume covers its whole hierarchy.
+    /// Every set type counts, bodyweight sets included, so pull-ups add to
+    /// Back the same way weighted rows do; deleted sets are excluded.
     /// The longest horizon is `training_window_weeks` weeks; `daily` and
     /// `rolling_7d` are computed from the same fetched rows.
     pub async fn get_muscle_group_volume(
@@ -3206,10 +5008,13 @@ impl Database {
         let today_start_ms = (now_ms / 86_400_000.0).floor() * 86_400_000.0;
         let seven_days_ago_ms = now_ms - 7.0 * 24.0 * 3600.0 * 1000.0;
 
+        let groups = muscle_group.with_sub_muscles();
+
         // Fetch all contributing sets within the training window.
         // The correlated subquery computes the total tier-weight across all muscle
         // groups for the same exercise, so we can normalise the target group's weight.
-        let sql = r#"
+        let sql = format!(
+            r#"
             SELECT
                 cs.recorded_at,
                 cs.rpe,
@@ -3224,18 +5029,22 @@ impl Database {
                  WHERE emg2.exercise_id = cs.exercise_id) AS sum_weights
             FROM completed_sets cs
             JOIN exercise_muscle_groups emg ON cs.exercise_id = emg.exercise_id
-            WHERE emg.muscle_group = ?
+            WHERE emg.muscle_group IN ({})
               AND cs.deleted_at IS NULL
+              AND cs.kind IN ({})
               AND cs.recorded_at >= ?
-              AND cs.is_bodyweight = 0
-        "#;
+            "#,
+            vec!["?"; groups.len()].join(", "),
+            SetKind::sql_list(SetKind::counts_for_volume)
+        );
 
-        let params = vec![
-            JsValue::from_str(&muscle_group.to_string()),
-            JsValue::from_f64(since_ms),
-        ];
+        let mut params: Vec<JsValue> = groups
+            .iter()
+            .map(|g| JsValue::from_str(&g.to_string()))
+            .collect();
+        params.push(JsValue::from_f64(since_ms));
 
-        let result = self.execute(sql, &params).await?;
+        let result = self.execute(&sql, &params).await?;
 
         let array = result
             .dyn_ref::<js_sys::Array>()
@@ -3298,10 +5107,11 @@ impl Database {
     pub async fn get_progress_state(
         &self,
         exercise_id: &str,
+        formula: E1rmFormula,
         settings: &crate::models::Settings,
     ) -> Result<crate::models::ProgressState, DatabaseError> {
         let sessions = self
-            .get_e1rm_session_history(exercise_id, settings.training_window_weeks as u32)
+            .get_e1rm_session_history(exercise_id, formula, settings.training_window_weeks as u32)
             .await?;
         if (sessions.len() as i64) < settings.min_sessions_for_regression {
             return Ok(crate::models::ProgressState::InsufficientData);
@@ -3313,6 +5123,350 @@ impl Database {
             Ok(crate::models::ProgressState::Stalled { slope })
         }
     }
+
+    /// Load and RPE of the exercise's weighted working sets over the last
+    /// `weeks` weeks, oldest first, for the deload advisor's RPE-creep rule.
+    pub async fn get_load_rpe_history(
+        &self,
+        exercise_id: &str,
+        weeks: u32,
+    ) -> Result<Vec<crate::domain::deload::LoadRpe>, DatabaseError> {
+        let since_ms = js_sys::Date::now() - (weeks as f64) * 7.0 * 24.0 * 3600.0 * 1000.0;
+
+        let sql = format!(
+            r#"
+            SELECT recorded_at, weight + COALESCE(bodyweight, 0) AS load, rpe
+            FROM completed_sets
+            WHERE exercise_id = ?
+              AND recorded_at >= ?
+              AND deleted_at IS NULL
+              AND kind IN ({})
+              AND (is_bodyweight = 0 OR bodyweight IS NOT NULL)
+              AND distance_m IS NULL
+            ORDER BY recorded_at ASC
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
+
+        let params = vec![JsValue::from_str(exercise_id), JsValue::from_f64(since_ms)];
+        let result = self.execute(&sql, &params).await?;
+
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
+
+        let mut sets = Vec::with_capacity(array.length() as usize);
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let field = |name: &str| -> Result<f64, DatabaseError> {
+                js_sys::Reflect::get(&row, &JsValue::from_str(name))?
+                    .as_f64()
+                    .ok_or_else(|| DatabaseError::QueryError(format!("{} missing", name)))
+            };
+            sets.push(crate::domain::deload::LoadRpe {
+                recorded_at: field("recorded_at")?,
+                load: field("load")?,
+                rpe: field("rpe")?,
+            });
+        }
+        Ok(sets)
+    }
+
+    /// UTC dates of every Training Day in the last `weeks` weeks, oldest
+    /// first, for the Analysis dashboard's training frequency.
+    pub async fn get_training_days(
+        &self,
+        weeks: u32,
+    ) -> Result<Vec<chrono::NaiveDate>, DatabaseError> {
+        let since_ms = js_sys::Date::now() - (weeks as f64) * 7.0 * 24.0 * 3600.0 * 1000.0;
+
+        let sql = r#"
+            SELECT DISTINCT CAST(recorded_at / 86400000 AS INTEGER) AS day
+            FROM completed_sets
+            WHERE recorded_at >= ?
+              AND deleted_at IS NULL
+            ORDER BY day ASC
+        "#;
+
+        let result = self.execute(sql, &[JsValue::from_f64(since_ms)]).await?;
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
+
+        let mut days = Vec::with_capacity(array.length() as usize);
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let day = js_sys::Reflect::get(&row, &JsValue::from_str("day"))?
+                .as_f64()
+                .ok_or_else(|| DatabaseError::QueryError("day missing".to_string()))?;
+            let date = chrono::DateTime::from_timestamp_millis(day as i64 * 86_400_000)
+                .ok_or_else(|| DatabaseError::QueryError("invalid timestamp".to_string()))?
+                .date_naive();
+            days.push(date);
+        }
+        Ok(days)
+    }
+
+    // ── RPE calibration ───────────────────────────────────────────────────
+
+    /// The e1RM-eligible sets of the last `weeks` weeks as RPE Calibration
+    /// sessions: `(exercise_id, sets)` per exercise per UTC day, grouped by
+    /// exercise.  Sets of archived exercises still count.
+    pub async fn get_calibration_sessions(
+        &self,
+        weeks: u32,
+    ) -> Result<Vec<(String, Vec<CalibrationSet>)>, DatabaseError> {
+        let since_ms = js_sys::Date::now() - (weeks as f64) * 7.0 * 24.0 * 3600.0 * 1000.0;
+
+        let sql = format!(
+            r#"
+            SELECT exercise_id, CAST(recorded_at / 86400000 AS INTEGER) AS day,
+                   reps, rpe, weight + COALESCE(bodyweight, 0) AS load
+            FROM completed_sets
+            WHERE recorded_at >= ?
+              AND deleted_at IS NULL
+              AND kind IN ({})
+              AND weight IS NOT NULL
+              AND (is_bodyweight = 0 OR bodyweight IS NOT NULL)
+              AND weight + COALESCE(bodyweight, 0) > 0
+              AND distance_m IS NULL
+            ORDER BY exercise_id, day, recorded_at
+            "#,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
+
+        let result = self.execute(&sql, &[JsValue::from_f64(since_ms)]).await?;
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
+
+        let mut sessions: Vec<(String, f64, Vec<CalibrationSet>)> = Vec::new();
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let field = |name: &str| -> Result<f64, DatabaseError> {
+                js_sys::Reflect::get(&row, &JsValue::from_str(name))?
+                    .as_f64()
+                    .ok_or_else(|| DatabaseError::QueryError(format!("{} missing", name)))
+            };
+            let exercise_id = js_sys::Reflect::get(&row, &JsValue::from_str("exercise_id"))?
+                .as_string()
+                .ok_or_else(|| DatabaseError::QueryError("exercise_id missing".to_string()))?;
+            let day = field("day")?;
+            let set = CalibrationSet {
+                load: field("load")?,
+                reps: field("reps")? as u32,
+                rpe: field("rpe")?,
+            };
+            match sessions.last_mut() {
+                Some((id, d, sets)) if *id == exercise_id && *d == day => sets.push(set),
+                _ => sessions.push((exercise_id, day, vec![set])),
+            }
+        }
+        Ok(sessions
+            .into_iter()
+            .map(|(exercise_id, _, sets)| (exercise_id, sets))
+            .collect())
+    }
+
+    /// Every exercise's own RPE Calibration, keyed by exercise id.
+    pub async fn get_exercise_rpe_calibrations(
+        &self,
+    ) -> Result<Vec<(String, RpeCalibration)>, DatabaseError> {
+        let result = self
+            .execute(
+                "SELECT uuid, rpe_calibration FROM exercises WHERE rpe_calibration != ''",
+                &[],
+            )
+            .await?;
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
+
+        let mut calibrations = Vec::with_capacity(array.length() as usize);
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let uuid = js_sys::Reflect::get(&row, &JsValue::from_str("uuid"))?
+                .as_string()
+                .ok_or_else(|| DatabaseError::QueryError("uuid missing".to_string()))?;
+            if let Some(calibration) = Self::parse_rpe_calibration(&row) {
+                calibrations.push((uuid, calibration));
+            }
+        }
+        Ok(calibrations)
+    }
+
+    /// Stores (or, with `None`, clears) an exercise's own RPE Calibration.
+    /// Kept out of `save_exercise` so editing an exercise never discards it.
+    pub async fn set_exercise_rpe_calibration(
+        &self,
+        exercise_id: &str,
+        calibration: Option<RpeCalibration>,
+    ) -> Result<(), DatabaseError> {
+        self.execute(
+            "UPDATE exercises SET rpe_calibration = ?, updated_at = ? WHERE uuid = ?",
+            &[
+                JsValue::from_str(&calibration.map(|c| c.to_string()).unwrap_or_default()),
+                JsValue::from_f64(js_sys::Date::now()),
+                JsValue::from_str(exercise_id),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    // ── Personal records ──────────────────────────────────────────────────
+
+    /// Every e1RM-eligible set of the exercise logged before `before_ms`,
+    /// the history a new set's Personal Records are judged against.
+    pub async fn get_record_sets_before(
+        &self,
+        exercise_id: &str,
+        before_ms: f64,
+    ) -> Result<Vec<HistorySet>, DatabaseError> {
+        self.record_sets(exercise_id, "<", before_ms).await
+    }
+
+    /// Every e1RM-eligible set of the exercise logged at or after `from_ms`,
+    /// oldest first: the sets whose Personal Records an edit or delete at
+    /// `from_ms` can change.
+    pub async fn get_record_sets_from(
+        &self,
+        exercise_id: &str,
+        from_ms: f64,
+    ) -> Result<Vec<HistorySet>, DatabaseError> {
+        self.record_sets(exercise_id, ">=", from_ms).await
+    }
+
+    async fn record_sets(
+        &self,
+        exercise_id: &str,
+        comparison: &str,
+        ms: f64,
+    ) -> Result<Vec<HistorySet>, DatabaseError> {
+        let sql = format!(
+            r#"
+            SELECT cs.id, cs.exercise_id, e.name AS exercise_name,
+                   cs.set_number, cs.reps, cs.rpe, cs.weight, cs.is_bodyweight, cs.duration_secs, cs.distance_m, cs.bodyweight, cs.kind, cs.comment, cs.rest_secs, cs.recorded_at
+            FROM completed_sets cs
+            JOIN exercises e ON cs.exercise_id = e.uuid
+            WHERE cs.exercise_id = ?
+              AND cs.recorded_at {} ?
+              AND cs.deleted_at IS NULL
+              AND cs.kind IN ({})
+              AND cs.duration_secs IS NULL
+              AND cs.distance_m IS NULL
+            ORDER BY cs.recorded_at ASC, cs.id ASC
+            "#,
+            comparison,
+            SetKind::sql_list(SetKind::counts_for_e1rm)
+        );
+        let params = vec![JsValue::from_str(exercise_id), JsValue::from_f64(ms)];
+        let result = self.execute(&sql, &params).await?;
+        self.parse_history_sets(&result)
+    }
+
+    /// Soft-deletes the Personal Records of the exercise's sets logged at or
+    /// after `from_ms`, before they are judged again.
+    pub async fn withdraw_personal_records_from(
+        &self,
+        exercise_id: &str,
+        from_ms: f64,
+    ) -> Result<(), DatabaseError> {
+        let now = js_sys::Date::now();
+        self.execute(
+            "UPDATE personal_records SET deleted_at = ?, updated_at = ?
+             WHERE deleted_at IS NULL
+               AND exercise_id = ?
+               AND set_uuid IN (SELECT uuid FROM completed_sets WHERE exercise_id = ? AND recorded_at >= ?)",
+            &[
+                JsValue::from_f64(now),
+                JsValue::from_f64(now),
+                JsValue::from_str(exercise_id),
+                JsValue::from_str(exercise_id),
+                JsValue::from_f64(from_ms),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Stores the Personal Records earned by the set with local id `set_id`,
+    /// linked to the set by its uuid.
+    pub async fn save_personal_records(
+        &self,
+        set_id: i64,
+        records: &[PersonalRecord],
+    ) -> Result<(), DatabaseError> {
+        let now = js_sys::Date::now();
+        for record in records {
+            self.execute(
+                "INSERT INTO personal_records (id, exercise_id, set_uuid, kind, value, previous, weight, reps, recorded_at, updated_at)
+                 SELECT ?, ?, uuid, ?, ?, ?, ?, ?, ?, ? FROM completed_sets WHERE id = ?",
+                &[
+                    JsValue::from_str(&record.id),
+                    JsValue::from_str(&record.exercise_id),
+                    JsValue::from_str(&record.kind.to_string()),
+                    JsValue::from_f64(record.value),
+                    JsValue::from_f64(record.previous),
+                    record.weight.map(JsValue::from_f64).unwrap_or(JsValue::NULL),
+                    JsValue::from_f64(record.reps as f64),
+                    JsValue::from_f64(record.recorded_at),
+                    JsValue::from_f64(now),
+                    JsValue::from_f64(set_id as f64),
+                ],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// The exercise's Personal Records, newest first. Records whose set has
+    /// been deleted are left out.
+    pub async fn get_personal_records(
+        &self,
+        exercise_id: &str,
+    ) -> Result<Vec<PersonalRecord>, DatabaseError> {
+        let sql = r#"
+            SELECT pr.id, pr.exercise_id, pr.kind, pr.value, pr.previous, pr.weight, pr.reps, pr.recorded_at
+            FROM personal_records pr
+            JOIN completed_sets cs ON cs.uuid = pr.set_uuid
+            WHERE pr.exercise_id = ?
+              AND pr.deleted_at IS NULL
+              AND cs.deleted_at IS NULL
+            ORDER BY pr.recorded_at DESC, pr.kind ASC
+        "#;
+        let result = self.execute(sql, &[JsValue::from_str(exercise_id)]).await?;
+        let array = result
+            .dyn_ref::<js_sys::Array>()
+            .ok_or_else(|| DatabaseError::QueryError("Expected array result".to_string()))?;
+
+        let mut records = Vec::with_capacity(array.length() as usize);
+        for i in 0..array.length() {
+            let row = array.get(i);
+            let get = |name: &str| js_sys::Reflect::get(&row, &JsValue::from_str(name));
+            let text = |name: &str| -> Result<String, DatabaseError> {
+                get(name)?
+                    .as_string()
+                    .ok_or_else(|| DatabaseError::QueryError(format!("{} missing", name)))
+            };
+            let number = |name: &str| -> Result<f64, DatabaseError> {
+                get(name)?
+                    .as_f64()
+                    .ok_or_else(|| DatabaseError::QueryError(format!("{} missing", name)))
+            };
+            records.push(PersonalRecord {
+                id: text("id")?,
+                exercise_id: text("exercise_id")?,
+                kind: RecordKind::from_str(&text("kind")?).map_err(DatabaseError::QueryError)?,
+                value: number("value")?,
+                previous: number("previous")?,
+                weight: get("weight")?.as_f64(),
+                reps: number("reps")? as u32,
+                recorded_at: number("recorded_at")?,
+            });
+        }
+        Ok(records)
+    }
 }
 
 impl Default for Database {
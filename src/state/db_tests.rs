@@ -1,6 +1,6 @@
 use crate::models::{
-    CompletedSet, ContributionTier, ExerciseMetadata, ExerciseMuscleGroup, MuscleGroup, SetType,
-    SetTypeConfig,
+    CompletedSet, ContributionTier, E1rmFormula, ExerciseMetadata, ExerciseMuscleGroup,
+    MuscleGroup, SetKind, SetType, SetTypeConfig,
 };
 use crate::state::{Database, DatabaseError};
 use wasm_bindgen::JsValue;
@@ -15,6 +15,7 @@ wasm_bindgen_test_configure!(run_in_browser);
 // Run with: wasm-pack test --headless --chrome
 mod workout_state_manager_tests {
     use super::*;
+    use crate::models::HistorySet;
     use crate::state::{WorkoutState, WorkoutStateManager};
 
     /// Helper: creates a fully initialised `WorkoutState` with a real
@@ -54,9 +55,15 @@ mod workout_state_manager_tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
         WorkoutStateManager::start_session(&state, exercise_a)
             .await
@@ -68,12 +75,16 @@ mod workout_state_manager_tests {
             reps: 8,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         };
         let set2 = CompletedSet {
             set_number: 2,
             reps: 6,
             rpe: 7.5,
             set_type: SetType::Weighted { weight: 105.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         };
         WorkoutStateManager::log_set(&state, set1)
             .await
@@ -96,9 +107,15 @@ mod workout_state_manager_tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
         WorkoutStateManager::start_session(&state, exercise_b)
             .await
@@ -154,9 +171,15 @@ mod workout_state_manager_tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 20.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
         WorkoutStateManager::start_session(&state, exercise)
             .await
@@ -170,6 +193,363 @@ mod workout_state_manager_tests {
             "New session should have zero completed sets"
         );
     }
+
+    /// A calisthenics-only user's weekly volume, which drives the dashboard
+    /// and the tier-card zones, is no longer zero.
+    #[wasm_bindgen_test]
+    async fn test_weekly_volume_counts_bodyweight_sets() {
+        let state = make_ready_state().await;
+        let db = state.database().expect("Database should be present");
+
+        let exercise = ExerciseMetadata {
+            id: None,
+            name: "Pull-up".to_string(),
+            set_type_config: SetTypeConfig::Bodyweight,
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        };
+        let eid = db.save_exercise(&exercise).await.expect("save exercise");
+        db.set_muscle_groups(
+            &eid,
+            &[ExerciseMuscleGroup {
+                exercise_id: eid.clone(),
+                muscle_group: MuscleGroup::Lats,
+                tier: ContributionTier::Primary,
+            }],
+        )
+        .await
+        .expect("set_muscle_groups");
+        db.log_set_at(
+            &eid,
+            &CompletedSet {
+                set_number: 1,
+                reps: 10,
+                rpe: 8.0,
+                set_type: SetType::Bodyweight,
+                kind: SetKind::Working,
+                comment: String::new(),
+            },
+            js_sys::Date::now() - 1_000.0,
+        )
+        .await
+        .expect("log set");
+
+        let weekly = WorkoutStateManager::weekly_volume(&state)
+            .await
+            .expect("weekly_volume");
+        let (_, back, _) = weekly
+            .iter()
+            .find(|(group, _, _)| *group == MuscleGroup::Back)
+            .expect("Back is listed");
+        assert!(
+            (back - 0.8).abs() < 1e-9,
+            "pull-ups should give Back 0.8 sets, got {}",
+            back
+        );
+    }
+
+    /// Recalibrating fits the trainee's RPE Calibration from every exercise,
+    /// gives only a well-logged exercise its own, and a new session of that
+    /// exercise picks its own up.
+    #[wasm_bindgen_test]
+    async fn test_calibrate_rpe_fits_trainee_and_exercise_curves() {
+        use crate::domain::calibration::MIN_EXERCISE_SETS;
+        use crate::domain::e1rm::E1rmModel;
+        use crate::models::RpeCalibration;
+
+        let state = make_ready_state().await;
+        let db = state.database().expect("Database should be present");
+
+        let weighted = |name: &str| ExerciseMetadata {
+            id: None,
+            name: name.to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 0.0,
+                increment: 2.5,
+                bar_weight: None,
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        };
+        let squat = db.save_exercise(&weighted("Squat")).await.expect("squat");
+        let curl = db.save_exercise(&weighted("Curl")).await.expect("curl");
+
+        // A lifter who grinds out more reps than the population curve allows.
+        let grinder = RpeCalibration {
+            rep_decay: 0.985,
+            ..RpeCalibration::POPULATION
+        };
+        let today = (js_sys::Date::now() / 86_400_000.0).floor() * 86_400_000.0;
+        let log_session = |exercise_id: String, days_ago: f64, sets: Vec<(u32, f32)>| {
+            let db = db.clone();
+            async move {
+                for (i, (reps, rpe)) in sets.into_iter().enumerate() {
+                    let weight = grinder.predicted_weight(100.0, reps, rpe as f64) as f32;
+                    db.log_set_at(
+                        &exercise_id,
+                        &CompletedSet {
+                            set_number: i as u32 + 1,
+                            reps,
+                            rpe,
+                            set_type: SetType::Weighted { weight },
+                            kind: SetKind::Working,
+                            comment: String::new(),
+                        },
+                        today - days_ago * 86_400_000.0 + 60_000.0 * i as f64,
+                    )
+                    .await
+                    .expect("log set");
+                }
+            }
+        };
+        for days_ago in 1..=4 {
+            log_session(
+                squat.clone(),
+                days_ago as f64,
+                vec![(3, 9.0), (5, 8.0), (8, 7.5), (10, 9.5)],
+            )
+            .await;
+        }
+        log_session(curl.clone(), 1.0, vec![(8, 8.0), (12, 9.0)]).await;
+
+        WorkoutStateManager::calibrate_rpe(&state)
+            .await
+            .expect("calibrate_rpe");
+
+        let trainee = state
+            .settings()
+            .rpe_calibration
+            .expect("trainee calibration");
+        assert_eq!(trainee.sets, 18);
+        assert!(trainee.rep_decay > RpeCalibration::POPULATION.rep_decay);
+        assert_eq!(
+            db.get_settings().await.unwrap().rpe_calibration,
+            Some(trainee)
+        );
+
+        let own = db
+            .get_exercise_rpe_calibrations()
+            .await
+            .expect("get_exercise_rpe_calibrations");
+        assert_eq!(own.len(), 1, "only Squat has {MIN_EXERCISE_SETS}+ sets");
+        assert_eq!(own[0].0, squat);
+        assert_eq!(own[0].1.sets, 16);
+
+        let mut exercise = weighted("Squat");
+        exercise.id = Some(squat);
+        WorkoutStateManager::start_session(&state, exercise)
+            .await
+            .expect("start_session");
+        assert_eq!(
+            state.current_session().unwrap().rpe_calibration,
+            Some(own[0].1)
+        );
+    }
+
+    /// Editing a set that earned a Personal Record withdraws the record and
+    /// re-detects it from the edited values.
+    #[wasm_bindgen_test]
+    async fn test_update_set_redetects_personal_records() {
+        use crate::models::{E1rmFormula, PersonalRecord, RecordKind};
+
+        let state = make_ready_state().await;
+        let db = state.database().expect("database");
+        let eid = db
+            .save_exercise(&ExerciseMetadata {
+                id: None,
+                name: "Edited Squat".to_string(),
+                set_type_config: SetTypeConfig::Weighted {
+                    min_weight: 20.0,
+                    increment: 2.5,
+                    bar_weight: None,
+                },
+                min_reps: 1,
+                max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            })
+            .await
+            .expect("save_exercise");
+        let set = |weight: f32| CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight },
+            kind: SetKind::Working,
+            comment: String::new(),
+        };
+        db.log_set_at(&eid, &set(100.0), 1_000.0)
+            .await
+            .expect("log first set");
+        let set_id = db
+            .log_set_at(&eid, &set(105.0), 2_000.0)
+            .await
+            .expect("log record set");
+        db.save_personal_records(
+            set_id,
+            &[PersonalRecord {
+                id: "pr-edited".to_string(),
+                exercise_id: eid.clone(),
+                kind: RecordKind::WeightAtReps,
+                value: 105.0,
+                previous: 100.0,
+                weight: Some(105.0),
+                reps: 5,
+                recorded_at: 2_000.0,
+            }],
+        )
+        .await
+        .expect("save_personal_records");
+
+        let original = db
+            .get_sets_for_exercise(&eid, 1, 0)
+            .await
+            .expect("history")
+            .remove(0);
+        WorkoutStateManager::update_set(&state, &original, &set(95.0), 2_000.0, E1rmFormula::Epley)
+            .await
+            .expect("edit down");
+        assert!(
+            db.get_personal_records(&eid).await.unwrap().is_empty(),
+            "95 kg beats nothing"
+        );
+
+        WorkoutStateManager::update_set(
+            &state,
+            &original,
+            &set(110.0),
+            2_000.0,
+            E1rmFormula::Epley,
+        )
+        .await
+        .expect("edit up");
+        let records = db.get_personal_records(&eid).await.unwrap();
+        assert!(
+            records
+                .iter()
+                .any(|r| r.kind == RecordKind::WeightAtReps && r.value == 110.0)
+        );
+        assert!(records.iter().all(|r| r.id != "pr-edited"));
+    }
+
+    /// Logs 100 kg, a 1000 kg typo and 110 kg, and judges their records the
+    /// way the app does after an edit; returns the exercise and typo set.
+    async fn log_typo_session(state: &WorkoutState, name: &str) -> (String, HistorySet) {
+        let db = state.database().expect("database");
+        let eid = make_weighted_exercise(&db, name).await;
+        for (weight, at) in [(100.0, 1_000.0), (1000.0, 2_000.0), (110.0, 3_000.0)] {
+            db.log_set_at(
+                &eid,
+                &CompletedSet {
+                    set_number: 1,
+                    reps: 5,
+                    rpe: 8.0,
+                    set_type: SetType::Weighted { weight },
+                    kind: SetKind::Working,
+                    comment: String::new(),
+                },
+                at,
+            )
+            .await
+            .expect("log set");
+        }
+        let typo = db
+            .get_sets_for_exercise(&eid, 10, 0)
+            .await
+            .expect("history")
+            .into_iter()
+            .find(|s| s.recorded_at == 2_000.0)
+            .expect("typo set");
+        WorkoutStateManager::update_set(
+            state,
+            &typo,
+            &typo.to_completed(),
+            typo.recorded_at,
+            crate::models::E1rmFormula::Epley,
+        )
+        .await
+        .expect("judge records");
+        let records = db.get_personal_records(&eid).await.unwrap();
+        assert!(
+            records.iter().all(|r| r.recorded_at == 2_000.0),
+            "only the typo is a PR"
+        );
+        (eid, typo)
+    }
+
+    /// Correcting a typo re-judges the later sets: a genuine PR measured
+    /// against the typo is restored.
+    #[wasm_bindgen_test]
+    async fn test_update_set_rejudges_later_personal_records() {
+        use crate::models::{E1rmFormula, RecordKind};
+
+        let state = make_ready_state().await;
+        let (eid, typo) = log_typo_session(&state, "Typo Squat").await;
+        let mut fixed = typo.to_completed();
+        fixed.set_type = SetType::Weighted { weight: 105.0 };
+        WorkoutStateManager::update_set(
+            &state,
+            &typo,
+            &fixed,
+            typo.recorded_at,
+            E1rmFormula::Epley,
+        )
+        .await
+        .expect("correct typo");
+
+        let records = state
+            .database()
+            .unwrap()
+            .get_personal_records(&eid)
+            .await
+            .unwrap();
+        let weight_at = |at: f64| {
+            records
+                .iter()
+                .find(|r| r.recorded_at == at && r.kind == RecordKind::WeightAtReps)
+                .map(|r| r.value)
+        };
+        assert_eq!(weight_at(2_000.0), Some(105.0));
+        assert_eq!(weight_at(3_000.0), Some(110.0));
+        assert!(records.iter().all(|r| r.value < 1000.0));
+    }
+
+    /// Deleting a typo re-judges the later sets against what is left.
+    #[wasm_bindgen_test]
+    async fn test_delete_set_rejudges_later_personal_records() {
+        use crate::models::{E1rmFormula, RecordKind};
+
+        let state = make_ready_state().await;
+        let (eid, typo) = log_typo_session(&state, "Deleted Typo Squat").await;
+        WorkoutStateManager::delete_set(&state, &typo, E1rmFormula::Epley)
+            .await
+            .expect("delete typo");
+
+        let records = state
+            .database()
+            .unwrap()
+            .get_personal_records(&eid)
+            .await
+            .unwrap();
+        assert!(!records.is_empty());
+        assert!(records.iter().all(|r| r.recorded_at == 3_000.0));
+        assert!(records.iter().any(|r| r.kind == RecordKind::WeightAtReps
+            && r.value == 110.0
+            && r.previous == 100.0));
+    }
 }
 
 // These tests require a proper WASM test environment with crsqlite-wasm loaded
@@ -197,9 +577,15 @@ async fn test_database_initialization_with_existing_data() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     db1.save_exercise(&exercise)
         .await
@@ -232,9 +618,15 @@ async fn test_log_set_weighted() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -246,6 +638,8 @@ async fn test_log_set_weighted() {
         reps: 8,
         rpe: 7.5,
         set_type: SetType::Weighted { weight: 135.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
 
     let set_id = db
@@ -268,6 +662,11 @@ async fn test_log_set_bodyweight() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -279,6 +678,8 @@ async fn test_log_set_bodyweight() {
         reps: 10,
         rpe: 8.0,
         set_type: SetType::Bodyweight,
+        kind: SetKind::Working,
+        comment: String::new(),
     };
 
     let set_id = db
@@ -302,9 +703,15 @@ async fn test_get_sets_for_exercise_pagination() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -320,6 +727,8 @@ async fn test_get_sets_for_exercise_pagination() {
             set_type: SetType::Weighted {
                 weight: 60.0 + (i as f32 * 5.0),
             },
+            kind: SetKind::Working,
+            comment: String::new(),
         };
         db.log_set(&exercise_id, &set)
             .await
@@ -364,9 +773,15 @@ async fn test_get_sets_for_exercise_isolation() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let id_a = db.save_exercise(&ex_a).await.expect("Save A failed");
 
@@ -376,9 +791,15 @@ async fn test_get_sets_for_exercise_isolation() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let id_b = db.save_exercise(&ex_b).await.expect("Save B failed");
 
@@ -389,6 +810,8 @@ async fn test_get_sets_for_exercise_isolation() {
             reps: 5,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
     )
     .await
@@ -400,6 +823,8 @@ async fn test_get_sets_for_exercise_isolation() {
             reps: 3,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 150.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
     )
     .await
@@ -426,9 +851,15 @@ async fn test_get_sets_for_exercise_before_excludes_today() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -448,6 +879,8 @@ async fn test_get_sets_for_exercise_before_excludes_today() {
         reps: 5,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     // A set recorded right now (today).
     let today_set = CompletedSet {
@@ -455,6 +888,8 @@ async fn test_get_sets_for_exercise_before_excludes_today() {
         reps: 3,
         rpe: 8.0,
         set_type: SetType::Weighted { weight: 110.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
 
     db.log_set_at(&exercise_id, &yesterday_set, yesterday_ms)
@@ -489,6 +924,11 @@ async fn test_get_all_sets_paginated() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let id1 = db.save_exercise(&ex1).await.expect("Save 1 failed");
 
@@ -498,6 +938,11 @@ async fn test_get_all_sets_paginated() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let id2 = db.save_exercise(&ex2).await.expect("Save 2 failed");
 
@@ -508,6 +953,8 @@ async fn test_get_all_sets_paginated() {
             reps: 10,
             rpe: 7.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         },
     )
     .await
@@ -519,6 +966,8 @@ async fn test_get_all_sets_paginated() {
             reps: 8,
             rpe: 7.5,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         },
     )
     .await
@@ -547,9 +996,15 @@ async fn test_update_set() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -561,6 +1016,8 @@ async fn test_update_set() {
         reps: 8,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 50.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     let set_id = db
         .log_set(&exercise_id, &set)
@@ -569,9 +1026,16 @@ async fn test_update_set() {
 
     // Update: change reps to 10, rpe to 8.0, weight to 55.0 (keep same recorded_at)
     let original_recorded_at = 1_700_000_000_000.0_f64;
-    db.update_set(set_id, 10, 8.0, Some(55.0), original_recorded_at)
-        .await
-        .expect("update_set failed");
+    db.update_set(
+        set_id,
+        10,
+        8.0,
+        &SetType::Weighted { weight: 55.0 },
+        "left knee twinge",
+        original_recorded_at,
+    )
+    .await
+    .expect("update_set failed");
 
     let updated = db
         .get_sets_for_exercise(&exercise_id, 1, 0)
@@ -586,6 +1050,7 @@ async fn test_update_set() {
         SetType::Weighted { weight: 55.0 },
         "weight should be updated to 55.0"
     );
+    assert_eq!(updated[0].comment, "left knee twinge");
 }
 
 /// RED: update_set can change recorded_at; subsequent reads reflect the new timestamp.
@@ -600,9 +1065,15 @@ async fn test_update_set_recorded_at() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -616,6 +1087,8 @@ async fn test_update_set_recorded_at() {
         reps: 5,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     let set_id = db
         .log_set_at(&exercise_id, &set, yesterday_ms)
@@ -624,9 +1097,16 @@ async fn test_update_set_recorded_at() {
 
     // Update recorded_at to a different timestamp (two days ago)
     let two_days_ago_ms = 500_000_000.0_f64;
-    db.update_set(set_id, 5, 7.0, Some(100.0), two_days_ago_ms)
-        .await
-        .expect("update_set failed");
+    db.update_set(
+        set_id,
+        5,
+        7.0,
+        &SetType::Weighted { weight: 100.0 },
+        "",
+        two_days_ago_ms,
+    )
+    .await
+    .expect("update_set failed");
 
     let updated = db
         .get_sets_for_exercise(&exercise_id, 1, 0)
@@ -652,9 +1132,15 @@ async fn test_delete_set() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -669,6 +1155,8 @@ async fn test_delete_set() {
                 reps: 8,
                 rpe: 7.0,
                 set_type: SetType::Weighted { weight: 80.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await
@@ -696,9 +1184,15 @@ async fn test_get_last_set_for_exercise_new_schema() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -713,6 +1207,8 @@ async fn test_get_last_set_for_exercise_new_schema() {
             reps: 8,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
     )
     .await
@@ -725,6 +1221,8 @@ async fn test_get_last_set_for_exercise_new_schema() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 110.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
     )
     .await
@@ -769,9 +1267,15 @@ async fn test_save_exercise() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 135.0,
             increment: 10.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
 
     let result = db.save_exercise(&exercise).await;
@@ -786,9 +1290,15 @@ async fn test_save_exercise() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 145.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
 
     let result_id_update = db.save_exercise(&updated_exercise_with_id).await;
@@ -809,9 +1319,15 @@ async fn test_save_exercise() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 150.0,
             increment: 10.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
 
     let result2 = db.save_exercise(&updated_exercise).await;
@@ -835,6 +1351,11 @@ async fn test_export_database() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     db.save_exercise(&exercise)
         .await
@@ -876,9 +1397,15 @@ async fn test_sql_injection_protection() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
 
     let result = db.save_exercise(&exercise).await;
@@ -909,9 +1436,15 @@ async fn test_export_import_round_trip() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db1
         .save_exercise(&exercise)
@@ -923,6 +1456,8 @@ async fn test_export_import_round_trip() {
         reps: 8,
         rpe: 7.5,
         set_type: SetType::Weighted { weight: 135.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db1.log_set(&exercise_id, &set)
         .await
@@ -959,6 +1494,8 @@ async fn test_export_import_round_trip() {
                 reps: 6,
                 rpe: 8.0,
                 set_type: SetType::Weighted { weight: 140.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await;
@@ -989,9 +1526,15 @@ async fn test_exercises_restored_after_open_existing_database() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise2 = ExerciseMetadata {
         id: None,
@@ -999,6 +1542,11 @@ async fn test_exercises_restored_after_open_existing_database() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
 
     db1.save_exercise(&exercise1)
@@ -1092,9 +1640,15 @@ async fn test_new_exercise_has_uuid() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -1146,9 +1700,15 @@ async fn test_new_set_has_uuid_and_updated_at() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -1160,6 +1720,8 @@ async fn test_new_set_has_uuid_and_updated_at() {
         reps: 8,
         rpe: 7.5,
         set_type: SetType::Weighted { weight: 80.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     let set_id = db
         .log_set(&exercise_id, &set)
@@ -1208,9 +1770,15 @@ async fn test_update_set_updates_updated_at() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -1225,6 +1793,8 @@ async fn test_update_set_updates_updated_at() {
                 reps: 8,
                 rpe: 7.0,
                 set_type: SetType::Weighted { weight: 50.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await
@@ -1249,9 +1819,16 @@ async fn test_update_set_updates_updated_at() {
     .as_f64()
     .expect("updated_at before should be a number");
 
-    db.update_set(set_id, 10, 8.0, Some(55.0), 1_700_000_000_000.0)
-        .await
-        .expect("update_set failed");
+    db.update_set(
+        set_id,
+        10,
+        8.0,
+        &SetType::Weighted { weight: 55.0 },
+        "",
+        1_700_000_000_000.0,
+    )
+    .await
+    .expect("update_set failed");
 
     let after_result = db
         .execute(
@@ -1294,9 +1871,15 @@ async fn test_delete_set_is_soft_delete() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -1311,6 +1894,8 @@ async fn test_delete_set_is_soft_delete() {
                 reps: 8,
                 rpe: 7.0,
                 set_type: SetType::Weighted { weight: 80.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await
@@ -1371,9 +1956,15 @@ async fn test_uuids_are_unique_across_records() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db
         .save_exercise(&exercise)
@@ -1388,6 +1979,8 @@ async fn test_uuids_are_unique_across_records() {
                 reps: 5,
                 rpe: 7.0,
                 set_type: SetType::Weighted { weight: 100.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await
@@ -1400,6 +1993,8 @@ async fn test_uuids_are_unique_across_records() {
                 reps: 5,
                 rpe: 7.5,
                 set_type: SetType::Weighted { weight: 105.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await
@@ -1451,9 +2046,15 @@ async fn test_edit_exercise_updates_updated_at() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let exercise_id = db.save_exercise(&exercise).await.expect("save failed");
 
@@ -1479,10 +2080,16 @@ async fn test_edit_exercise_updates_updated_at() {
         name: "Squat".to_string(),
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
-            increment: 5.0, // changed
+            increment: 5.0, // changed,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     db.save_exercise(&updated).await.expect("update failed");
 
@@ -1525,9 +2132,15 @@ async fn setup_exercise_for_history() -> (Database, String) {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let id = db
         .save_exercise(&exercise)
@@ -1555,6 +2168,8 @@ async fn test_best_set_returns_highest_e1rm() {
         reps: 5,
         rpe: 8.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_a, day(3) + 1000.0)
         .await
@@ -1566,6 +2181,8 @@ async fn test_best_set_returns_highest_e1rm() {
         reps: 3,
         rpe: 9.0,
         set_type: SetType::Weighted { weight: 120.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_b, day(5) + 1000.0)
         .await
@@ -1577,13 +2194,15 @@ async fn test_best_set_returns_highest_e1rm() {
         reps: 8,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 80.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_c, day(7) + 1000.0)
         .await
         .expect("log C");
 
     let best = db
-        .get_best_set_for_exercise(&eid, day(0), today_start, today_end)
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), day(0), today_start, today_end)
         .await
         .expect("query failed");
 
@@ -1613,6 +2232,8 @@ async fn test_best_set_excludes_today() {
         reps: 1,
         rpe: 10.0,
         set_type: SetType::Weighted { weight: 200.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_today, today_start + 5000.0)
         .await
@@ -1624,13 +2245,15 @@ async fn test_best_set_excludes_today() {
         reps: 5,
         rpe: 8.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_history, day(5) + 1000.0)
         .await
         .expect("log history");
 
     let best = db
-        .get_best_set_for_exercise(&eid, day(0), today_start, today_end)
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), day(0), today_start, today_end)
         .await
         .expect("query failed");
 
@@ -1652,7 +2275,7 @@ async fn test_best_set_empty_history() {
     let day = |d: i64| d as f64 * MS_PER_DAY;
 
     let best = db
-        .get_best_set_for_exercise(&eid, day(0), day(10), day(11))
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), day(0), day(10), day(11))
         .await
         .expect("query failed");
 
@@ -1674,13 +2297,15 @@ async fn test_best_set_all_sets_today() {
         reps: 5,
         rpe: 8.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set, today_start + 1000.0)
         .await
         .expect("log");
 
     let best = db
-        .get_best_set_for_exercise(&eid, day(0), today_start, today_end)
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), day(0), today_start, today_end)
         .await
         .expect("query failed");
 
@@ -1700,6 +2325,8 @@ async fn test_best_set_respects_since_date() {
         reps: 1,
         rpe: 10.0,
         set_type: SetType::Weighted { weight: 200.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &old_set, day(2) + 1000.0)
         .await
@@ -1711,6 +2338,8 @@ async fn test_best_set_respects_since_date() {
         reps: 5,
         rpe: 8.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &recent_set, day(8) + 1000.0)
         .await
@@ -1718,7 +2347,7 @@ async fn test_best_set_respects_since_date() {
 
     // Window starts at day 5, so old_set (day 2) is excluded
     let best = db
-        .get_best_set_for_exercise(&eid, day(5), day(10), day(11))
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), day(5), day(10), day(11))
         .await
         .expect("query failed");
 
@@ -1741,6 +2370,11 @@ async fn setup_bodyweight_exercise_for_history()
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let id = db
         .save_exercise(&exercise)
@@ -1765,9 +2399,15 @@ async fn setup_weighted_exercise_for_history_full()
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let id = db
         .save_exercise(&exercise)
@@ -1795,6 +2435,8 @@ async fn test_best_bodyweight_set_returns_highest_failure_reps() {
         reps: 8,
         rpe: 8.0,
         set_type: SetType::Bodyweight,
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_a, day(3) + 1000.0)
         .await
@@ -1806,6 +2448,8 @@ async fn test_best_bodyweight_set_returns_highest_failure_reps() {
         reps: 10,
         rpe: 9.0,
         set_type: SetType::Bodyweight,
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_b, day(5) + 1000.0)
         .await
@@ -1817,6 +2461,8 @@ async fn test_best_bodyweight_set_returns_highest_failure_reps() {
         reps: 5,
         rpe: 7.0,
         set_type: SetType::Bodyweight,
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_c, day(7) + 1000.0)
         .await
@@ -1850,6 +2496,8 @@ async fn test_best_bodyweight_set_tie_on_failure_reps_no_panic() {
             reps: 10,
             rpe: 8.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         };
         db.log_set_at(&eid, &set, day(day_offset) + 1000.0)
             .await
@@ -1895,18 +2543,26 @@ async fn test_historical_best_weighted_matches_direct_call() {
         reps: 3,
         rpe: 9.0,
         set_type: SetType::Weighted { weight: 120.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set, day(5) + 1000.0)
         .await
         .expect("log set");
 
     let via_dispatch = db
-        .get_historical_best_for_exercise(&exercise, day(0), today_start, today_end)
+        .get_historical_best_for_exercise(
+            &exercise,
+            E1rmFormula::default(),
+            day(0),
+            today_start,
+            today_end,
+        )
         .await
         .expect("dispatch failed");
 
     let direct = db
-        .get_best_set_for_exercise(&eid, day(0), today_start, today_end)
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), day(0), today_start, today_end)
         .await
         .expect("direct failed");
 
@@ -1936,6 +2592,8 @@ async fn test_historical_best_bodyweight_ranked_by_failure_reps() {
         reps: 6,
         rpe: 9.0,
         set_type: SetType::Bodyweight,
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_a, day(3) + 1000.0)
         .await
@@ -1947,13 +2605,21 @@ async fn test_historical_best_bodyweight_ranked_by_failure_reps() {
         reps: 12,
         rpe: 8.0,
         set_type: SetType::Bodyweight,
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_b, day(5) + 1000.0)
         .await
         .expect("log B");
 
     let best = db
-        .get_historical_best_for_exercise(&exercise, day(0), today_start, today_end)
+        .get_historical_best_for_exercise(
+            &exercise,
+            E1rmFormula::default(),
+            day(0),
+            today_start,
+            today_end,
+        )
         .await
         .expect("query failed");
 
@@ -1963,6 +2629,165 @@ async fn test_historical_best_bodyweight_ranked_by_failure_reps() {
     assert_eq!(best.set_type, SetType::Bodyweight);
 }
 
+/// Timed, distance and carry configs round-trip through `save_exercise`.
+#[wasm_bindgen_test]
+async fn test_measured_exercise_configs_round_trip() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init failed");
+
+    let configs = [
+        ("Plank", SetTypeConfig::Timed { increment_secs: 5 }),
+        ("Row", SetTypeConfig::Distance { increment_m: 50.0 }),
+        (
+            "Farmer Carry",
+            SetTypeConfig::Carry {
+                min_weight: 16.0,
+                increment: 4.0,
+                increment_m: 5.0,
+            },
+        ),
+    ];
+    for (name, config) in configs.iter() {
+        let exercise = ExerciseMetadata {
+            id: None,
+            name: name.to_string(),
+            set_type_config: config.clone(),
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        };
+        db.save_exercise(&exercise).await.expect("save failed");
+    }
+
+    let exercises = db.get_exercises().await.expect("get_exercises failed");
+    for (name, config) in configs.iter() {
+        let loaded = exercises
+            .iter()
+            .find(|e| e.name == *name)
+            .expect("exercise missing");
+        assert_eq!(&loaded.set_type_config, config, "{} config", name);
+    }
+}
+
+/// get_historical_best_for_exercise ranks timed sets by Failure Measure.
+#[wasm_bindgen_test]
+async fn test_historical_best_timed_ranked_by_failure_measure() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init failed");
+    let mut exercise = ExerciseMetadata {
+        id: None,
+        name: "Plank".to_string(),
+        set_type_config: SetTypeConfig::Timed { increment_secs: 5 },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    let eid = db.save_exercise(&exercise).await.expect("save failed");
+    exercise.id = Some(eid.clone());
+
+    let day = |d: i64| d as f64 * MS_PER_DAY;
+
+    // Set A: 60 s @ RPE 10 → failure 60 s
+    let set_a = CompletedSet {
+        set_number: 1,
+        reps: 1,
+        rpe: 10.0,
+        set_type: SetType::Timed { duration_secs: 60 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    db.log_set_at(&eid, &set_a, day(3) + 1000.0)
+        .await
+        .expect("log A");
+
+    // Set B: 50 s @ RPE 7 → failure 65 s (best)
+    let set_b = CompletedSet {
+        set_number: 1,
+        reps: 1,
+        rpe: 7.0,
+        set_type: SetType::Timed { duration_secs: 50 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    db.log_set_at(&eid, &set_b, day(5) + 1000.0)
+        .await
+        .expect("log B");
+
+    let best = db
+        .get_historical_best_for_exercise(
+            &exercise,
+            E1rmFormula::default(),
+            day(0),
+            day(10),
+            day(11),
+        )
+        .await
+        .expect("query failed")
+        .expect("Should find a best set");
+    assert_eq!(best.set_type, SetType::Timed { duration_secs: 50 });
+}
+
+/// Carries have a weight but no reps, so they never feed the e1RM queries.
+#[wasm_bindgen_test]
+async fn test_carry_sets_excluded_from_e1rm() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init failed");
+    let exercise = ExerciseMetadata {
+        id: None,
+        name: "Farmer Carry".to_string(),
+        set_type_config: SetTypeConfig::Carry {
+            min_weight: 16.0,
+            increment: 4.0,
+            increment_m: 5.0,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    let eid = db.save_exercise(&exercise).await.expect("save failed");
+
+    let day = |d: i64| d as f64 * MS_PER_DAY;
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 1,
+        rpe: 8.0,
+        set_type: SetType::Carry {
+            weight: 32.0,
+            distance_m: 40.0,
+        },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    db.log_set_at(&eid, &set, day(3) + 1000.0)
+        .await
+        .expect("log carry");
+
+    let best = db
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), day(0), day(10), day(11))
+        .await
+        .expect("query failed");
+    assert!(best.is_none(), "Carry must not rank by e1RM");
+
+    let last = db
+        .get_last_set_for_exercise(&eid)
+        .await
+        .expect("query failed")
+        .expect("carry should be stored");
+    assert_eq!(last.set_type, set.set_type);
+}
+
 /// get_historical_best_for_exercise returns None for both types when no sets in window.
 #[wasm_bindgen_test]
 async fn test_historical_best_returns_none_empty_window_weighted() {
@@ -1970,7 +2795,13 @@ async fn test_historical_best_returns_none_empty_window_weighted() {
     let day = |d: i64| d as f64 * MS_PER_DAY;
 
     let best = db
-        .get_historical_best_for_exercise(&exercise, day(0), day(10), day(11))
+        .get_historical_best_for_exercise(
+            &exercise,
+            E1rmFormula::default(),
+            day(0),
+            day(10),
+            day(11),
+        )
         .await
         .expect("query failed");
 
@@ -1983,7 +2814,13 @@ async fn test_historical_best_returns_none_empty_window_bodyweight() {
     let day = |d: i64| d as f64 * MS_PER_DAY;
 
     let best = db
-        .get_historical_best_for_exercise(&exercise, day(0), day(10), day(11))
+        .get_historical_best_for_exercise(
+            &exercise,
+            E1rmFormula::default(),
+            day(0),
+            day(10),
+            day(11),
+        )
         .await
         .expect("query failed");
 
@@ -2005,6 +2842,8 @@ async fn test_latest_set_today_returns_most_recent() {
         reps: 5,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 80.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_early, today_start + 1000.0)
         .await
@@ -2016,6 +2855,8 @@ async fn test_latest_set_today_returns_most_recent() {
         reps: 3,
         rpe: 9.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set_late, today_start + 5000.0)
         .await
@@ -2062,6 +2903,8 @@ async fn test_latest_set_today_ignores_other_days() {
         reps: 5,
         rpe: 8.0,
         set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &yesterday_set, day(9) + 5000.0)
         .await
@@ -2095,9 +2938,15 @@ async fn test_discard_plan_soft_deletes_session_sets_and_unstarts_plan() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid_a = db
         .save_exercise(&exercise_a)
@@ -2110,9 +2959,15 @@ async fn test_discard_plan_soft_deletes_session_sets_and_unstarts_plan() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid_b = db
         .save_exercise(&exercise_b)
@@ -2140,6 +2995,8 @@ async fn test_discard_plan_soft_deletes_session_sets_and_unstarts_plan() {
         reps: 5,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 60.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid_a, &old_set, started_at - 100_000.0)
         .await
@@ -2151,12 +3008,16 @@ async fn test_discard_plan_soft_deletes_session_sets_and_unstarts_plan() {
         reps: 8,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 80.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     let new_set_2 = CompletedSet {
         set_number: 2,
         reps: 6,
         rpe: 8.0,
         set_type: SetType::Weighted { weight: 85.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid_a, &new_set_1, started_at + 1000.0)
         .await
@@ -2171,6 +3032,8 @@ async fn test_discard_plan_soft_deletes_session_sets_and_unstarts_plan() {
         reps: 10,
         rpe: 6.5,
         set_type: SetType::Weighted { weight: 50.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid_b, &other_set, started_at + 3000.0)
         .await
@@ -2258,6 +3121,11 @@ async fn test_discard_plan_no_sets_unstarts_plan() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db
         .save_exercise(&exercise)
@@ -2308,9 +3176,15 @@ async fn test_discard_plan_tombstones_sync_eligible() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db
         .save_exercise(&exercise)
@@ -2335,6 +3209,8 @@ async fn test_discard_plan_tombstones_sync_eligible() {
         reps: 5,
         rpe: 7.0,
         set_type: SetType::Weighted { weight: 70.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
     };
     db.log_set_at(&eid, &set, started_at + 500.0)
         .await
@@ -2398,9 +3274,15 @@ async fn test_archive_exercise_moves_to_archived() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&exercise).await.expect("save failed");
 
@@ -2464,6 +3346,11 @@ async fn test_unarchive_exercise_returns_to_active() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&exercise).await.expect("save failed");
 
@@ -2521,9 +3408,15 @@ async fn test_preview_archive_returns_zero_no_plans() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&exercise).await.expect("save failed");
 
@@ -2547,6 +3440,11 @@ async fn save_bw_exercise(db: &Database, name: &str) -> String {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     db.save_exercise(&ex).await.expect("save_exercise failed")
 }
@@ -2973,9 +3871,15 @@ async fn make_exercise(db: &mut Database, name: &str) -> String {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     db.save_exercise(&ex).await.expect("save_exercise failed")
 }
@@ -2999,6 +3903,8 @@ async fn test_permanent_delete_cascades_to_completed_sets() {
                 reps: 5,
                 rpe: 7.0,
                 set_type: SetType::Weighted { weight: 100.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await
@@ -3346,6 +4252,8 @@ async fn test_preview_permanent_delete_set_count() {
                 reps: 5,
                 rpe: 7.0,
                 set_type: SetType::Weighted { weight: 80.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
         )
         .await
@@ -3425,6 +4333,11 @@ async fn make_simple_template(db: &mut Database, tname: &str, ename: &str) -> (S
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save_exercise failed");
     let plan_ex = PlanExercise {
@@ -3435,9 +4348,16 @@ async fn make_simple_template(db: &mut Database, tname: &str, ename: &str) -> (S
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         },
         planned_sets: 3,
         position: 0,
+        group_id: None,
+        targets: Default::default(),
     };
     let tid = db
         .save_template(tname, &[plan_ex])
@@ -3642,9 +4562,15 @@ async fn make_weighted_exercise(db: &Database, name: &str) -> String {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     db.save_exercise(&ex).await.expect("save_exercise failed")
 }
@@ -3664,6 +4590,8 @@ async fn test_get_max_weight_per_rep_single_rep_count() {
             reps: 5,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         1_000_000.0,
     )
@@ -3697,6 +4625,8 @@ async fn test_get_max_weight_per_rep_multiple_rep_counts() {
                 reps: 5,
                 rpe: 7.0,
                 set_type: SetType::Weighted { weight: w },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
             1_000_000.0 * (i as f64),
         )
@@ -3711,6 +4641,8 @@ async fn test_get_max_weight_per_rep_multiple_rep_counts() {
             reps: 3,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 130.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         4_000_000.0,
     )
@@ -3769,6 +4701,8 @@ async fn test_get_max_weight_per_rep_since_ms_boundary() {
             reps: 5,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 80.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         since_ms,
     )
@@ -3783,6 +4717,8 @@ async fn test_get_max_weight_per_rep_since_ms_boundary() {
             reps: 5,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 999.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         since_ms - 1.0,
     )
@@ -3821,6 +4757,8 @@ async fn test_get_max_weight_per_rep_fold_propagation() {
             reps: 8,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 60.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         1_000_000.0,
     )
@@ -3835,6 +4773,8 @@ async fn test_get_max_weight_per_rep_fold_propagation() {
             reps: 3,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 50.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         2_000_000.0,
     )
@@ -3870,6 +4810,11 @@ async fn test_get_max_weight_per_rep_excludes_bodyweight_sets() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let bw_eid = db.save_exercise(&bw_ex).await.expect("save bw exercise");
 
@@ -3881,6 +4826,8 @@ async fn test_get_max_weight_per_rep_excludes_bodyweight_sets() {
             reps: 10,
             rpe: 7.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         1_000_000.0,
     )
@@ -3913,14 +4860,20 @@ async fn test_e1rm_session_history_empty_when_no_sets() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
     let history = db
-        .get_e1rm_session_history(&eid, 12)
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 12)
         .await
         .expect("get_e1rm_session_history");
 
@@ -3934,7 +4887,7 @@ async fn test_e1rm_session_history_empty_when_no_sets() {
 /// Sets on different days produce one entry per day, each with the max e1RM for that day.
 #[wasm_bindgen_test]
 async fn test_e1rm_session_history_one_entry_per_day() {
-    use crate::domain::e1rm::e1rm as compute_e1rm;
+    use crate::domain::e1rm::{E1rmModel, LinearRpe};
 
     let mut db = Database::new();
     db.init(None).await.expect("DB init");
@@ -3945,9 +4898,15 @@ async fn test_e1rm_session_history_one_entry_per_day() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -3960,6 +4919,8 @@ async fn test_e1rm_session_history_one_entry_per_day() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         day1_ms,
     )
@@ -3973,6 +4934,8 @@ async fn test_e1rm_session_history_one_entry_per_day() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 90.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         day1_ms + 300_000.0, // 5 min later, still day 1
     )
@@ -3988,6 +4951,8 @@ async fn test_e1rm_session_history_one_entry_per_day() {
             reps: 3,
             rpe: 9.0,
             set_type: SetType::Weighted { weight: 110.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         day2_ms,
     )
@@ -3996,7 +4961,7 @@ async fn test_e1rm_session_history_one_entry_per_day() {
 
     // Use a 52-week window so both days are included.
     let history = db
-        .get_e1rm_session_history(&eid, 52)
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 52)
         .await
         .expect("get_e1rm_session_history");
 
@@ -4006,7 +4971,7 @@ async fn test_e1rm_session_history_one_entry_per_day() {
     let (d1, e1) = history[0];
     let expected_d1 = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
     assert_eq!(d1, expected_d1, "First entry should be 2024-01-01");
-    let expected_e1 = compute_e1rm(100.0, 5, 8.0);
+    let expected_e1 = LinearRpe.e1rm(100.0, 5, 8.0);
     assert!(
         (e1 - expected_e1).abs() < 1e-6,
         "Day1 e1RM should be from the heavier set: expected {expected_e1}, got {e1}"
@@ -4016,7 +4981,7 @@ async fn test_e1rm_session_history_one_entry_per_day() {
     let (d2, e2) = history[1];
     let expected_d2 = chrono::NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
     assert_eq!(d2, expected_d2, "Second entry should be 2024-01-02");
-    let expected_e2 = compute_e1rm(110.0, 3, 9.0);
+    let expected_e2 = LinearRpe.e1rm(110.0, 3, 9.0);
     assert!(
         (e2 - expected_e2).abs() < 1e-6,
         "Day2 e1RM expected {expected_e2}, got {e2}"
@@ -4035,9 +5000,15 @@ async fn test_e1rm_session_history_excludes_sets_outside_window() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 5.0,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -4054,6 +5025,8 @@ async fn test_e1rm_session_history_excludes_sets_outside_window() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 120.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         recent_ms,
     )
@@ -4067,6 +5040,8 @@ async fn test_e1rm_session_history_excludes_sets_outside_window() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         old_ms,
     )
@@ -4074,7 +5049,7 @@ async fn test_e1rm_session_history_excludes_sets_outside_window() {
     .expect("log old set");
 
     let history = db
-        .get_e1rm_session_history(&eid, 12)
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 12)
         .await
         .expect("get_e1rm_session_history");
 
@@ -4097,6 +5072,11 @@ async fn test_e1rm_session_history_excludes_bodyweight_sets() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -4109,6 +5089,8 @@ async fn test_e1rm_session_history_excludes_bodyweight_sets() {
             reps: 10,
             rpe: 7.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         now_ms - 1_000.0,
     )
@@ -4116,7 +5098,7 @@ async fn test_e1rm_session_history_excludes_bodyweight_sets() {
     .expect("log bodyweight set");
 
     let history = db
-        .get_e1rm_session_history(&eid, 12)
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 12)
         .await
         .expect("get_e1rm_session_history");
 
@@ -4138,9 +5120,15 @@ async fn test_e1rm_session_history_sorted_oldest_first() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -4156,6 +5144,8 @@ async fn test_e1rm_session_history_sorted_oldest_first() {
                 set_type: SetType::Weighted {
                     weight: 60.0 + i as f32 * 5.0,
                 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
             base_ms + (i as f64) * 86_400_000.0,
         )
@@ -4164,7 +5154,7 @@ async fn test_e1rm_session_history_sorted_oldest_first() {
     }
 
     let history = db
-        .get_e1rm_session_history(&eid, 52)
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 52)
         .await
         .expect("get_e1rm_session_history");
 
@@ -4190,9 +5180,15 @@ async fn make_exercise_with_muscle(
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
     let groups = vec![ExerciseMuscleGroup {
@@ -4252,6 +5248,8 @@ async fn test_muscle_group_volume_single_primary_contribution() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         now_ms - 1_000.0,
     )
@@ -4283,6 +5281,57 @@ async fn test_muscle_group_volume_single_primary_contribution() {
     );
 }
 
+/// Sets tagged with a Sub-Muscle roll up into its parent's volume but not
+/// into its siblings'.
+#[wasm_bindgen_test]
+async fn test_muscle_group_volume_rolls_sub_muscles_up_to_parent() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = make_exercise_with_muscle(
+        &db,
+        "Lateral Raise",
+        MuscleGroup::SideDelts,
+        ContributionTier::Primary,
+    )
+    .await;
+    db.log_set_at(
+        &eid,
+        &CompletedSet {
+            set_number: 1,
+            reps: 12,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight: 10.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        },
+        js_sys::Date::now() - 1_000.0,
+    )
+    .await
+    .expect("log set");
+
+    let side = db
+        .get_muscle_group_volume(&MuscleGroup::SideDelts, 12)
+        .await
+        .expect("get_muscle_group_volume");
+    let shoulders = db
+        .get_muscle_group_volume(&MuscleGroup::Shoulders, 12)
+        .await
+        .expect("get_muscle_group_volume");
+    let rear = db
+        .get_muscle_group_volume(&MuscleGroup::RearDelts, 12)
+        .await
+        .expect("get_muscle_group_volume");
+
+    assert!((side.rolling_7d - 0.8).abs() < 1e-9);
+    assert!(
+        (shoulders.rolling_7d - 0.8).abs() < 1e-9,
+        "Shoulders should include Side Delts volume, got {}",
+        shoulders.rolling_7d
+    );
+    assert_eq!(rear.rolling_7d, 0.0);
+}
+
 /// Multi-muscle exercise: normalised_weight splits across muscles.
 /// Bench: Chest/Primary(1.0) + Triceps/Secondary(0.5). sum=1.5.
 /// Chest share = 1.0/1.5. Triceps share = 0.5/1.5.
@@ -4297,9 +5346,15 @@ async fn test_muscle_group_volume_normalised_across_multi_muscle_exercise() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
     db.set_muscle_groups(
@@ -4328,6 +5383,8 @@ async fn test_muscle_group_volume_normalised_across_multi_muscle_exercise() {
             reps: 5,
             rpe: 10.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         now_ms - 1_000.0,
     )
@@ -4346,8 +5403,9 @@ async fn test_muscle_group_volume_normalised_across_multi_muscle_exercise() {
         .await
         .expect("triceps volume");
 
-    let expected_chest = (10.0 / 10.0) * (1.0 / sum_w);
-    let expected_triceps = (10.0 / 10.0) * (0.5 / sum_w);
+    // One set at RPE 10 counts as a full set: 1.0 × tier weight / sum_weights.
+    let expected_chest = 1.0 / sum_w;
+    let expected_triceps = 0.5 / sum_w;
 
     assert!(
         (chest_vol.daily - expected_chest).abs() < 1e-9,
@@ -4386,6 +5444,8 @@ async fn test_muscle_group_volume_excludes_sets_outside_window() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         now_ms - 4.0 * 7.0 * 86_400_000.0,
     )
@@ -4399,6 +5459,8 @@ async fn test_muscle_group_volume_excludes_sets_outside_window() {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         now_ms - 20.0 * 7.0 * 86_400_000.0,
     )
@@ -4418,9 +5480,10 @@ async fn test_muscle_group_volume_excludes_sets_outside_window() {
     );
 }
 
-/// Bodyweight sets do not contribute to volume.
+/// Bodyweight and loaded bodyweight sets contribute to volume with the same
+/// RPE × normalised-tier formula as weighted sets.
 #[wasm_bindgen_test]
-async fn test_muscle_group_volume_excludes_bodyweight_sets() {
+async fn test_muscle_group_volume_includes_bodyweight_sets() {
     let mut db = crate::state::Database::new();
     db.init(None).await.expect("DB init");
 
@@ -4430,44 +5493,114 @@ async fn test_muscle_group_volume_excludes_bodyweight_sets() {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
     db.set_muscle_groups(
         &eid,
-        &[ExerciseMuscleGroup {
-            exercise_id: eid.clone(),
-            muscle_group: MuscleGroup::Back,
-            tier: ContributionTier::Primary,
-        }],
+        &[
+            ExerciseMuscleGroup {
+                exercise_id: eid.clone(),
+                muscle_group: MuscleGroup::Back,
+                tier: ContributionTier::Primary,
+            },
+            ExerciseMuscleGroup {
+                exercise_id: eid.clone(),
+                muscle_group: MuscleGroup::Biceps,
+                tier: ContributionTier::Secondary,
+            },
+        ],
     )
     .await
     .expect("set_muscle_groups");
 
     let now_ms = js_sys::Date::now();
-    db.log_set_at(
-        &eid,
-        &CompletedSet {
-            set_number: 1,
-            reps: 10,
-            rpe: 7.0,
-            set_type: SetType::Bodyweight,
-        },
-        now_ms - 1_000.0,
+    for (set_number, set_type) in [
+        (1, SetType::Bodyweight),
+        (
+            2,
+            SetType::LoadedBodyweight {
+                bodyweight: 80.0,
+                added: 10.0,
+            },
+        ),
+    ] {
+        db.log_set_at(
+            &eid,
+            &CompletedSet {
+                set_number,
+                reps: 8,
+                rpe: 7.5,
+                set_type,
+                kind: SetKind::Working,
+                comment: String::new(),
+            },
+            now_ms - 1_000.0,
+        )
+        .await
+        .expect("log bodyweight set");
+    }
+
+    let back = db
+        .get_muscle_group_volume(&MuscleGroup::Back, 12)
+        .await
+        .expect("get_muscle_group_volume");
+    let biceps = db
+        .get_muscle_group_volume(&MuscleGroup::Biceps, 12)
+        .await
+        .expect("get_muscle_group_volume");
+
+    // Per set: Back (0.75) × (1.0 / 1.5) = 0.5, Biceps (0.75) × (0.5 / 1.5) = 0.25.
+    assert!(
+        (back.daily - 1.0).abs() < 1e-9,
+        "two bodyweight sets should give Back 1.0, got {}",
+        back.daily
+    );
+    assert!(
+        (biceps.rolling_7d - 0.5).abs() < 1e-9,
+        "two bodyweight sets should give Biceps 0.5, got {}",
+        biceps.rolling_7d
+    );
+}
+
+/// Bodyweight sets already on disk, stored the way peers before v15 wrote
+/// them (`is_bodyweight = 1`, no weight), show up in existing users'
+/// volume without any data migration.
+#[wasm_bindgen_test]
+async fn test_muscle_group_volume_counts_legacy_bodyweight_rows() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid =
+        make_exercise_with_muscle(&db, "Dip", MuscleGroup::Triceps, ContributionTier::Primary)
+            .await;
+    let now_ms = js_sys::Date::now();
+    db.execute(
+        "INSERT INTO completed_sets (exercise_id, set_number, reps, rpe, weight, is_bodyweight, recorded_at, uuid, updated_at) \
+         VALUES (?, 1, 12, 9.0, NULL, 1, ?, 'legacy-dip-set', ?)",
+        &[
+            wasm_bindgen::JsValue::from_str(&eid),
+            wasm_bindgen::JsValue::from_f64(now_ms - 1_000.0),
+            wasm_bindgen::JsValue::from_f64(now_ms - 1_000.0),
+        ],
     )
     .await
-    .expect("log bodyweight set");
+    .expect("insert legacy bodyweight row");
 
     let vol = db
-        .get_muscle_group_volume(&MuscleGroup::Back, 12)
+        .get_muscle_group_volume(&MuscleGroup::Triceps, 12)
         .await
         .expect("get_muscle_group_volume");
 
-    assert_eq!(
-        vol.daily, 0.0,
-        "bodyweight sets must not contribute to volume"
+    assert!(
+        (vol.rolling_7d - 0.9).abs() < 1e-9,
+        "legacy bodyweight row should count as 0.9, got {}",
+        vol.rolling_7d
     );
-    assert_eq!(vol.rolling_7d, 0.0);
-    assert_eq!(vol.rolling_training_period, 0.0);
 }
 
 /// Sets older than 7d don't appear in rolling_7d, even if inside training window.
@@ -4489,6 +5622,8 @@ async fn test_muscle_group_volume_rolling_7d_excludes_older_sets() {
             reps: 10,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 20.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         now_ms - 3.0 * 86_400_000.0,
     )
@@ -4502,6 +5637,8 @@ async fn test_muscle_group_volume_rolling_7d_excludes_older_sets() {
             reps: 10,
             rpe: 7.0,
             set_type: SetType::Weighted { weight: 20.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         },
         now_ms - 10.0 * 86_400_000.0,
     )
@@ -4542,15 +5679,21 @@ async fn test_progress_state_no_sets_returns_insufficient() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
     let settings = Settings::default();
     let state = db
-        .get_progress_state(&eid, &settings)
+        .get_progress_state(&eid, E1rmFormula::default(), &settings)
         .await
         .expect("get_progress_state");
 
@@ -4571,9 +5714,15 @@ async fn test_progress_state_below_min_sessions_returns_insufficient() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -4587,6 +5736,8 @@ async fn test_progress_state_below_min_sessions_returns_insufficient() {
                 reps: 5,
                 rpe: 8.0,
                 set_type: SetType::Weighted { weight: 100.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
             now_ms - (i as f64 + 1.0) * 7.0 * 86_400_000.0,
         )
@@ -4596,7 +5747,7 @@ async fn test_progress_state_below_min_sessions_returns_insufficient() {
 
     let settings = Settings::default(); // min_sessions_for_regression = 3
     let state = db
-        .get_progress_state(&eid, &settings)
+        .get_progress_state(&eid, E1rmFormula::default(), &settings)
         .await
         .expect("get_progress_state");
 
@@ -4617,9 +5768,15 @@ async fn test_progress_state_positive_slope_returns_progressing() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -4635,6 +5792,8 @@ async fn test_progress_state_positive_slope_returns_progressing() {
                 reps: 5,
                 rpe: 8.0,
                 set_type: SetType::Weighted { weight },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
             now_ms - offset_days * 7.0 * 86_400_000.0,
         )
@@ -4644,7 +5803,7 @@ async fn test_progress_state_positive_slope_returns_progressing() {
 
     let settings = Settings::default();
     let state = db
-        .get_progress_state(&eid, &settings)
+        .get_progress_state(&eid, E1rmFormula::default(), &settings)
         .await
         .expect("get_progress_state");
 
@@ -4670,9 +5829,15 @@ async fn test_progress_state_negative_slope_returns_stalled() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -4688,6 +5853,8 @@ async fn test_progress_state_negative_slope_returns_stalled() {
                 reps: 5,
                 rpe: 8.0,
                 set_type: SetType::Weighted { weight },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
             now_ms - offset_days * 7.0 * 86_400_000.0,
         )
@@ -4697,7 +5864,7 @@ async fn test_progress_state_negative_slope_returns_stalled() {
 
     let settings = Settings::default();
     let state = db
-        .get_progress_state(&eid, &settings)
+        .get_progress_state(&eid, E1rmFormula::default(), &settings)
         .await
         .expect("get_progress_state");
 
@@ -4723,9 +5890,15 @@ async fn test_progress_state_flat_returns_stalled() {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 0.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     };
     let eid = db.save_exercise(&ex).await.expect("save exercise");
 
@@ -4740,6 +5913,8 @@ async fn test_progress_state_flat_returns_stalled() {
                 reps: 5,
                 rpe: 8.0,
                 set_type: SetType::Weighted { weight: 100.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             },
             now_ms - offset_days * 7.0 * 86_400_000.0,
         )
@@ -4749,7 +5924,7 @@ async fn test_progress_state_flat_returns_stalled() {
 
     let settings = Settings::default();
     let state = db
-        .get_progress_state(&eid, &settings)
+        .get_progress_state(&eid, E1rmFormula::default(), &settings)
         .await
         .expect("get_progress_state");
 
@@ -4760,3 +5935,1639 @@ async fn test_progress_state_flat_returns_stalled() {
         other => panic!("expected Stalled, got {other:?}"),
     }
 }
+
+// ── settings: weight_unit ─────────────────────────────────────────────────────
+
+/// Fresh databases default to kilograms.
+#[wasm_bindgen_test]
+async fn test_settings_weight_unit_defaults_to_kg() {
+    use crate::models::WeightUnit;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let settings = db.get_settings().await.expect("get_settings");
+    assert_eq!(settings.weight_unit, WeightUnit::Kg);
+}
+
+/// Switching to pounds persists and leaves stored set weights untouched.
+#[wasm_bindgen_test]
+async fn test_settings_weight_unit_round_trip_keeps_sets_canonical() {
+    use crate::models::WeightUnit;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let ex = ExerciseMetadata {
+        id: None,
+        name: "Bench Press".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    let eid = db.save_exercise(&ex).await.expect("save exercise");
+    db.log_set(
+        &eid,
+        &CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        },
+    )
+    .await
+    .expect("log set");
+
+    let mut settings = db.get_settings().await.expect("get_settings");
+    settings.weight_unit = WeightUnit::Lb;
+    db.update_settings(&settings)
+        .await
+        .expect("update_settings");
+
+    let reloaded = db.get_settings().await.expect("get_settings");
+    assert_eq!(reloaded.weight_unit, WeightUnit::Lb);
+
+    let sets = db
+        .get_sets_for_exercise(&eid, 10, 0)
+        .await
+        .expect("get sets");
+    assert_eq!(sets.len(), 1);
+    assert_eq!(sets[0].set_type, SetType::Weighted { weight: 100.0 });
+}
+
+/// The unit an exercise was entered in round-trips; existing exercises read
+/// as kilograms.
+#[wasm_bindgen_test]
+async fn test_exercise_weight_unit_round_trip() {
+    use crate::models::WeightUnit;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let ex = ExerciseMetadata {
+        id: None,
+        name: "Bench Press".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: WeightUnit::Lb.to_kg(45.0) as f32,
+            increment: WeightUnit::Lb.to_kg(5.0) as f32,
+            bar_weight: None,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: WeightUnit::Lb,
+    };
+    db.save_exercise(&ex).await.expect("save bench");
+    db.save_exercise(&ExerciseMetadata {
+        name: "Squat".to_string(),
+        weight_unit: WeightUnit::Kg,
+        ..ex.clone()
+    })
+    .await
+    .expect("save squat");
+
+    let exercises = db.get_exercises().await.expect("get_exercises");
+    let unit_of = |name: &str| {
+        exercises
+            .iter()
+            .find(|e| e.name == name)
+            .map(|e| e.weight_unit)
+            .unwrap()
+    };
+    assert_eq!(unit_of("Bench Press"), WeightUnit::Lb);
+    assert_eq!(unit_of("Squat"), WeightUnit::Kg);
+}
+
+// ── exercises: bar_weight / plates inventory ─────────────────────────────────
+
+/// A plate-loaded exercise keeps its bar weight through save and reload.
+#[wasm_bindgen_test]
+async fn test_save_exercise_round_trips_bar_weight() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let ex = ExerciseMetadata {
+        id: None,
+        name: "Squat".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: Some(20.0),
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    db.save_exercise(&ex).await.expect("save exercise");
+
+    let exercises = db.get_exercises().await.expect("get_exercises");
+    let squat = exercises.iter().find(|e| e.name == "Squat").unwrap();
+    assert_eq!(squat.set_type_config, ex.set_type_config);
+}
+
+/// Plates can be added, updated and soft-deleted.
+#[wasm_bindgen_test]
+async fn test_plates_save_update_delete() {
+    use crate::models::Plate;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    assert!(db.get_plates().await.expect("get_plates").is_empty());
+
+    let id = db
+        .save_plate(&Plate {
+            id: None,
+            weight: 20.0,
+            count: 2,
+        })
+        .await
+        .expect("save plate");
+    db.save_plate(&Plate {
+        id: None,
+        weight: 25.0,
+        count: 4,
+    })
+    .await
+    .expect("save plate");
+
+    let plates = db.get_plates().await.expect("get_plates");
+    assert_eq!(plates.len(), 2);
+    assert_eq!(plates[0].weight, 25.0, "heaviest plates first");
+
+    db.save_plate(&Plate {
+        id: Some(id.clone()),
+        weight: 20.0,
+        count: 6,
+    })
+    .await
+    .expect("update plate");
+    let plates = db.get_plates().await.expect("get_plates");
+    assert_eq!(plates.iter().find(|p| p.weight == 20.0).unwrap().count, 6);
+
+    db.delete_plate(&id).await.expect("delete plate");
+    let plates = db.get_plates().await.expect("get_plates");
+    assert_eq!(plates.len(), 1);
+    assert_eq!(plates[0].weight, 25.0);
+}
+
+// ── warm-up sets ─────────────────────────────────────────────────────────────
+
+/// Warm-up sets appear in history (tagged) but stay out of the Historical
+/// Signal: best set, e1RM session history and muscle-group volume.
+#[wasm_bindgen_test]
+async fn test_warmup_sets_excluded_from_historical_signal() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = make_exercise_with_muscle(
+        &db,
+        "Bench Press",
+        MuscleGroup::Chest,
+        ContributionTier::Primary,
+    )
+    .await;
+
+    // A heavy warm-up would otherwise dominate every e1RM-based query.
+    db.log_set(
+        &eid,
+        &CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 5.0,
+            set_type: SetType::Weighted { weight: 140.0 },
+            kind: SetKind::Warmup,
+            comment: String::new(),
+        },
+    )
+    .await
+    .expect("log warm-up");
+
+    let best = db
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), 0.0, 0.0, 1.0)
+        .await
+        .expect("best set");
+    assert!(best.is_none(), "warm-up must not be a best set");
+
+    let history = db
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 52)
+        .await
+        .expect("e1rm history");
+    assert!(history.is_empty(), "warm-up must not feed e1RM history");
+
+    let vol = db
+        .get_muscle_group_volume(&MuscleGroup::Chest, 12)
+        .await
+        .expect("volume");
+    assert_eq!(vol.rolling_training_period, 0.0);
+
+    db.log_set(
+        &eid,
+        &CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        },
+    )
+    .await
+    .expect("log working set");
+
+    let best = db
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), 0.0, 0.0, 1.0)
+        .await
+        .expect("best set")
+        .expect("working set is the best set");
+    assert_eq!(best.set_type, SetType::Weighted { weight: 100.0 });
+
+    let sets = db
+        .get_sets_for_exercise(&eid, 10, 0)
+        .await
+        .expect("get sets");
+    assert_eq!(sets.len(), 2);
+    assert_eq!(sets.iter().filter(|s| s.kind == SetKind::Warmup).count(), 1);
+}
+
+/// The warm-up ramp setting round-trips through the settings row.
+#[wasm_bindgen_test]
+async fn test_settings_warmup_ramp_round_trip() {
+    use crate::models::WarmupRamp;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let settings = db.get_settings().await.expect("get_settings");
+    assert_eq!(settings.warmup_ramp, WarmupRamp::default());
+
+    let mut updated = settings;
+    updated.warmup_ramp = "bar×8, 60%×3".parse().unwrap();
+    db.update_settings(&updated).await.expect("update_settings");
+
+    let reloaded = db.get_settings().await.expect("get_settings");
+    assert_eq!(reloaded.warmup_ramp, updated.warmup_ramp);
+}
+
+/// Volume targets default for existing rows and round-trip through the
+/// settings row.
+#[wasm_bindgen_test]
+async fn test_settings_volume_targets_round_trip() {
+    use crate::models::{MuscleGroup, VolumeTarget, VolumeTargets};
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let settings = db.get_settings().await.expect("get_settings");
+    assert_eq!(settings.volume_targets, VolumeTargets::default());
+
+    let mut updated = settings;
+    updated
+        .volume_targets
+        .set(&MuscleGroup::Quads, VolumeTarget::new(10, 16, 24));
+    db.update_settings(&updated).await.expect("update_settings");
+
+    let reloaded = db.get_settings().await.expect("get_settings");
+    assert_eq!(reloaded.volume_targets, updated.volume_targets);
+}
+
+/// The global e1RM Formula defaults to the linear RPE model and round-trips
+/// through the settings row.
+#[wasm_bindgen_test]
+async fn test_settings_e1rm_formula_round_trip() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let settings = db.get_settings().await.expect("get_settings");
+    assert_eq!(settings.e1rm_formula, E1rmFormula::LinearRpe);
+
+    let mut updated = settings;
+    updated.e1rm_formula = E1rmFormula::Epley;
+    db.update_settings(&updated).await.expect("update_settings");
+
+    let reloaded = db.get_settings().await.expect("get_settings");
+    assert_eq!(reloaded.e1rm_formula, E1rmFormula::Epley);
+}
+
+/// The trainee's RPE Calibration and the opt-in flag round-trip through
+/// the settings row; neither is set on a fresh database.
+#[wasm_bindgen_test]
+async fn test_settings_rpe_calibration_round_trip() {
+    use crate::models::RpeCalibration;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let settings = db.get_settings().await.expect("get_settings");
+    assert_eq!(settings.rpe_calibration, None);
+    assert!(!settings.use_rpe_calibration);
+
+    let mut updated = settings;
+    updated.rpe_calibration = Some(RpeCalibration {
+        rpe_step: 0.041,
+        rep_decay: 0.962,
+        sets: 30,
+    });
+    updated.use_rpe_calibration = true;
+    db.update_settings(&updated).await.expect("update_settings");
+
+    let reloaded = db.get_settings().await.expect("get_settings");
+    assert_eq!(reloaded.rpe_calibration, updated.rpe_calibration);
+    assert!(reloaded.use_rpe_calibration);
+}
+
+/// A per-exercise e1RM Formula override round-trips; no override reads back
+/// as `None`.
+#[wasm_bindgen_test]
+async fn test_exercise_e1rm_formula_round_trip() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let ex = ExerciseMetadata {
+        id: None,
+        name: "Squat".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: Some(E1rmFormula::RpeChart),
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    db.save_exercise(&ex).await.expect("save squat");
+    db.save_exercise(&ExerciseMetadata {
+        name: "Bench Press".to_string(),
+        e1rm_formula: None,
+        ..ex.clone()
+    })
+    .await
+    .expect("save bench");
+
+    let exercises = db.get_exercises().await.expect("get_exercises");
+    let squat = exercises.iter().find(|e| e.name == "Squat").unwrap();
+    assert_eq!(squat.e1rm_formula, Some(E1rmFormula::RpeChart));
+    let bench = exercises.iter().find(|e| e.name == "Bench Press").unwrap();
+    assert_eq!(bench.e1rm_formula, None);
+}
+
+/// A per-exercise Progression Strategy round-trips, percentage included.
+#[wasm_bindgen_test]
+async fn test_exercise_progression_round_trip() {
+    use crate::models::ProgressionStrategy;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let ex = ExerciseMetadata {
+        id: None,
+        name: "Squat".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        },
+        min_reps: 8,
+        max_reps: Some(12),
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: ProgressionStrategy::Double,
+        weight_unit: Default::default(),
+    };
+    db.save_exercise(&ex).await.expect("save squat");
+    db.save_exercise(&ExerciseMetadata {
+        name: "Bench Press".to_string(),
+        progression: ProgressionStrategy::PercentOfE1rm(75),
+        ..ex.clone()
+    })
+    .await
+    .expect("save bench");
+    db.save_exercise(&ExerciseMetadata {
+        name: "Deadlift".to_string(),
+        progression: ProgressionStrategy::Autoregulated,
+        ..ex.clone()
+    })
+    .await
+    .expect("save deadlift");
+
+    let exercises = db.get_exercises().await.expect("get_exercises");
+    let progression_of = |name: &str| {
+        exercises
+            .iter()
+            .find(|e| e.name == name)
+            .map(|e| e.progression)
+            .unwrap()
+    };
+    assert_eq!(progression_of("Squat"), ProgressionStrategy::Double);
+    assert_eq!(
+        progression_of("Bench Press"),
+        ProgressionStrategy::PercentOfE1rm(75)
+    );
+    assert_eq!(
+        progression_of("Deadlift"),
+        ProgressionStrategy::Autoregulated
+    );
+}
+
+/// The previous session is the working sets of the latest Training Day
+/// before the cut-off, oldest first; warm-ups and earlier days are left out.
+#[wasm_bindgen_test]
+async fn test_get_previous_session_working_sets() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid =
+        make_exercise_with_muscle(&db, "Squat", MuscleGroup::Quads, ContributionTier::Primary)
+            .await;
+    let day_ms = 86_400_000.0;
+    // 10:00 UTC on 2023-11-14.
+    let monday = 1_699_920_000_000.0 + 10.0 * 3_600_000.0;
+    let wednesday = monday + 2.0 * day_ms;
+    let set = |weight: f32, reps: u32, kind: SetKind| CompletedSet {
+        set_number: 1,
+        reps,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight },
+        kind,
+        comment: String::new(),
+    };
+    for (logged, recorded_at) in [
+        (set(100.0, 5, SetKind::Working), monday),
+        (set(60.0, 5, SetKind::Warmup), wednesday),
+        (set(105.0, 5, SetKind::Working), wednesday + 60_000.0),
+        (set(105.0, 4, SetKind::Working), wednesday + 120_000.0),
+        (set(110.0, 3, SetKind::Working), wednesday + 3.0 * day_ms),
+    ] {
+        db.log_set_at(&eid, &logged, recorded_at)
+            .await
+            .expect("log set");
+    }
+
+    let previous = db
+        .get_previous_session_working_sets(&eid, wednesday + day_ms)
+        .await
+        .expect("get_previous_session_working_sets");
+    let sets: Vec<(Option<f32>, u32)> = previous
+        .iter()
+        .map(|s| (s.set_type.weight(), s.reps))
+        .collect();
+    assert_eq!(sets, vec![(Some(105.0), 5), (Some(105.0), 4)]);
+
+    let none = db
+        .get_previous_session_working_sets(&eid, monday)
+        .await
+        .expect("get_previous_session_working_sets");
+    assert!(none.is_empty());
+}
+
+/// The e1RM history follows the formula it is asked for.
+#[wasm_bindgen_test]
+async fn test_e1rm_session_history_uses_formula() {
+    use crate::domain::e1rm::E1rmModel;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid =
+        make_exercise_with_muscle(&db, "Squat", MuscleGroup::Quads, ContributionTier::Primary)
+            .await;
+    db.log_set(
+        &eid,
+        &CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        },
+    )
+    .await
+    .expect("log set");
+
+    for formula in E1rmFormula::ALL {
+        let history = db
+            .get_e1rm_session_history(&eid, formula, 12)
+            .await
+            .expect("get_e1rm_session_history");
+        assert_eq!(history.len(), 1);
+        let expected = formula.e1rm(100.0, 5, 8.0);
+        assert!(
+            (history[0].1 - expected).abs() < 1e-9,
+            "{formula}: expected {expected}, got {}",
+            history[0].1
+        );
+    }
+}
+
+// ── set kinds ────────────────────────────────────────────────────────────────
+
+/// Drop sets count toward volume but not toward the e1RM; the kind
+/// round-trips through the set list.
+#[wasm_bindgen_test]
+async fn test_drop_set_counts_for_volume_not_e1rm() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = make_exercise_with_muscle(
+        &db,
+        "Bench Press",
+        MuscleGroup::Chest,
+        ContributionTier::Primary,
+    )
+    .await;
+
+    db.log_set(
+        &eid,
+        &CompletedSet {
+            set_number: 1,
+            reps: 12,
+            rpe: 10.0,
+            set_type: SetType::Weighted { weight: 60.0 },
+            kind: SetKind::Drop,
+            comment: String::new(),
+        },
+    )
+    .await
+    .expect("log drop set");
+
+    let best = db
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), 0.0, 0.0, 1.0)
+        .await
+        .expect("best set");
+    assert!(best.is_none(), "drop set must not be a best set");
+
+    let history = db
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 52)
+        .await
+        .expect("e1rm history");
+    assert!(history.is_empty(), "drop set must not feed e1RM history");
+
+    let vol = db
+        .get_muscle_group_volume(&MuscleGroup::Chest, 12)
+        .await
+        .expect("volume");
+    assert!(vol.rolling_training_period > 0.0);
+
+    let sets = db
+        .get_sets_for_exercise(&eid, 10, 0)
+        .await
+        .expect("get sets");
+    assert_eq!(sets.len(), 1);
+    assert_eq!(sets[0].kind, SetKind::Drop);
+}
+
+// ── loaded bodyweight / bodyweight log ───────────────────────────────────────
+
+/// Bodyweight entries can be logged, corrected and soft-deleted, newest first.
+#[wasm_bindgen_test]
+async fn test_bodyweight_log_save_update_delete() {
+    use crate::models::BodyweightEntry;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    assert!(db.get_bodyweight_log().await.expect("get log").is_empty());
+
+    let id = db
+        .save_bodyweight_entry(&BodyweightEntry {
+            id: None,
+            recorded_at: 1_000.0,
+            mass: 80.0,
+        })
+        .await
+        .expect("save entry");
+    db.save_bodyweight_entry(&BodyweightEntry {
+        id: None,
+        recorded_at: 2_000.0,
+        mass: 81.5,
+    })
+    .await
+    .expect("save entry");
+
+    let log = db.get_bodyweight_log().await.expect("get log");
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0].mass, 81.5, "newest entry first");
+
+    db.save_bodyweight_entry(&BodyweightEntry {
+        id: Some(id.clone()),
+        recorded_at: 1_000.0,
+        mass: 79.0,
+    })
+    .await
+    .expect("update entry");
+    let log = db.get_bodyweight_log().await.expect("get log");
+    assert_eq!(log[1].mass, 79.0);
+
+    db.delete_bodyweight_entry(&id).await.expect("delete entry");
+    let log = db.get_bodyweight_log().await.expect("get log");
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].mass, 81.5);
+}
+
+/// A loaded bodyweight set round-trips its bodyweight snapshot and feeds
+/// the best set, per-rep maxes and e1RM history with its System Load.
+#[wasm_bindgen_test]
+async fn test_loaded_bodyweight_set_uses_system_load() {
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let ex = ExerciseMetadata {
+        id: None,
+        name: "Weighted Pull-up".to_string(),
+        set_type_config: SetTypeConfig::LoadedBodyweight {
+            increment: 2.5,
+            assisted: false,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    let eid = db.save_exercise(&ex).await.expect("save exercise");
+
+    let exercises = db.get_exercises().await.expect("get_exercises");
+    let saved = exercises.iter().find(|e| e.name == ex.name).unwrap();
+    assert_eq!(saved.set_type_config, ex.set_type_config);
+
+    let set_type = SetType::LoadedBodyweight {
+        bodyweight: 80.0,
+        added: 20.0,
+    };
+    db.log_set(
+        &eid,
+        &CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 10.0,
+            set_type: set_type.clone(),
+            kind: SetKind::Working,
+            comment: String::new(),
+        },
+    )
+    .await
+    .expect("log set");
+
+    let sets = db
+        .get_sets_for_exercise(&eid, 10, 0)
+        .await
+        .expect("get sets");
+    assert_eq!(sets[0].set_type, set_type);
+
+    let best = db
+        .get_best_set_for_exercise(&eid, E1rmFormula::default(), 0.0, 0.0, 1.0)
+        .await
+        .expect("best set")
+        .expect("loaded set is a best set");
+    assert_eq!(best.set_type, set_type);
+
+    let maxes = db
+        .get_max_weight_per_rep(&eid, 0.0)
+        .await
+        .expect("per-rep maxes");
+    assert_eq!(maxes.get(&5), Some(&100.0));
+
+    let history = db
+        .get_e1rm_session_history(&eid, E1rmFormula::default(), 52)
+        .await
+        .expect("e1rm history");
+    assert_eq!(history.len(), 1);
+    assert!(
+        history[0].1 > 100.0,
+        "e1RM is computed from the System Load"
+    );
+}
+
+// ── history export ───────────────────────────────────────────────────────────
+
+/// The export honours the date and exercise filters, returns sets oldest
+/// first and tags sets logged while a plan ran with that plan's id.
+#[wasm_bindgen_test]
+async fn test_sets_for_export_filters_and_plan_id() {
+    use crate::export::ExportFilter;
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let squat = make_weighted_exercise(&db, "Squat").await;
+    let bench = make_weighted_exercise(&db, "Bench Press").await;
+    let set = |weight: f32| CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+
+    db.log_set_at(&squat, &set(90.0), 1_000.0)
+        .await
+        .expect("log old squat");
+    db.log_set_at(&bench, &set(60.0), 2_000.0)
+        .await
+        .expect("log old bench");
+
+    let plan_id = db.create_plan().await.expect("create plan");
+    db.add_exercise_to_plan(&plan_id, &squat, 3)
+        .await
+        .expect("add squat");
+    db.start_plan(&plan_id).await.expect("start plan");
+    db.log_set(&squat, &set(100.0)).await.expect("log squat");
+
+    let all = db
+        .get_sets_for_export(&ExportFilter::default())
+        .await
+        .expect("export all");
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].set.set_type, SetType::Weighted { weight: 90.0 });
+    assert_eq!(all[0].plan_id, None, "set predates the plan");
+    assert_eq!(all[2].plan_id.as_deref(), Some(plan_id.as_str()));
+
+    let squats = db
+        .get_sets_for_export(&ExportFilter {
+            exercise_id: Some(squat.clone()),
+            ..ExportFilter::default()
+        })
+        .await
+        .expect("export squat");
+    assert_eq!(squats.len(), 2);
+    assert!(squats.iter().all(|r| r.set.exercise_name == "Squat"));
+
+    let old = db
+        .get_sets_for_export(&ExportFilter {
+            from_ms: Some(1_500.0),
+            to_ms: Some(10_000.0),
+            exercise_id: None,
+        })
+        .await
+        .expect("export range");
+    assert_eq!(old.len(), 1);
+    assert_eq!(old[0].set.exercise_name, "Bench Press");
+}
+
+/// A history import skips sets that are already logged, each logged set
+/// accounting for one imported set, and writes nothing when it fails.
+#[wasm_bindgen_test]
+async fn test_import_history_skips_logged_sets_and_rolls_back() {
+    use crate::import::{
+        ExerciseMapping, ExerciseTarget, ImportPlan, ImportSource, ImportTime, PlannedSet,
+    };
+
+    let mut db = crate::state::Database::new();
+    db.init(None).await.expect("DB init");
+
+    let squat_id = make_weighted_exercise(&db, "Squat").await;
+    let squat = db
+        .get_exercises()
+        .await
+        .expect("get exercises")
+        .into_iter()
+        .find(|e| e.id.as_deref() == Some(squat_id.as_str()))
+        .expect("squat");
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    db.log_set_at(&squat_id, &set, 1_000.0)
+        .await
+        .expect("log squat");
+
+    let planned = PlannedSet {
+        recorded_at: ImportTime::Instant(1_000.0),
+        set: set.clone(),
+    };
+    let plan = ImportPlan {
+        source: ImportSource::Strong,
+        mappings: vec![ExerciseMapping {
+            source_name: "Squat".to_string(),
+            target: ExerciseTarget::Existing(squat.clone()),
+            sets: vec![planned.clone(), planned.clone()],
+        }],
+        skipped: Vec::new(),
+        defaulted_rpe: 0,
+    };
+    assert_eq!(db.count_already_imported(&plan).await.expect("count"), 1);
+    assert_eq!(db.import_history(&plan).await.expect("import"), 1);
+    assert_eq!(db.count_already_imported(&plan).await.expect("recount"), 2);
+    assert_eq!(db.import_history(&plan).await.expect("re-import"), 0);
+
+    let failing = ImportPlan {
+        mappings: vec![
+            ExerciseMapping {
+                source_name: "Squat".to_string(),
+                target: ExerciseTarget::Existing(squat.clone()),
+                sets: vec![PlannedSet {
+                    recorded_at: ImportTime::Instant(5_000.0),
+                    set: set.clone(),
+                }],
+            },
+            ExerciseMapping {
+                source_name: "Unsaved".to_string(),
+                target: ExerciseTarget::Existing(ExerciseMetadata {
+                    id: None,
+                    name: "Unsaved".to_string(),
+                    ..squat.clone()
+                }),
+                sets: vec![planned],
+            },
+        ],
+        ..plan
+    };
+    assert!(db.import_history(&failing).await.is_err());
+    let sets = db
+        .get_sets_for_exercise(&squat_id, 10, 0)
+        .await
+        .expect("get sets");
+    assert_eq!(sets.len(), 2, "the failed import rolled back");
+}
+
+#[wasm_bindgen_test]
+async fn test_merge_adds_missing_rows_and_keeps_local_ones() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init failed");
+
+    let exercise = ExerciseMetadata {
+        id: None,
+        name: "Merge Squat".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    let exercise_id = db.save_exercise(&exercise).await.expect("save failed");
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    let backed_up = db.log_set(&exercise_id, &set).await.expect("log failed");
+    let backup = db.export().await.expect("Export failed");
+
+    // After the backup: one set logged here, and the backed-up one lost.
+    db.log_set(&exercise_id, &set).await.expect("log failed");
+    db.execute(
+        "DELETE FROM completed_sets WHERE id = ?",
+        &[JsValue::from_f64(backed_up as f64)],
+    )
+    .await
+    .expect("delete failed");
+
+    let plan = db
+        .preview_merge(backup.clone())
+        .await
+        .expect("preview failed");
+    let sets = plan.summary.iter().find(|s| s.label == "Sets").unwrap();
+    assert_eq!((sets.added, sets.updated), (1, 0));
+    let exercises = plan
+        .summary
+        .iter()
+        .find(|s| s.label == "Exercises")
+        .unwrap();
+    assert_eq!(
+        (exercises.added, exercises.updated, exercises.skipped),
+        (0, 0, 1)
+    );
+
+    db.apply_merge(&plan).await.expect("merge failed");
+    let history = db
+        .get_sets_for_exercise(&exercise_id, 10, 0)
+        .await
+        .expect("history failed");
+    assert_eq!(history.len(), 2, "local and backed-up sets both kept");
+
+    let again = db.preview_merge(backup).await.expect("preview failed");
+    assert!(again.is_empty(), "merging the same backup twice is a no-op");
+}
+
+/// Exercise notes and set comments survive save and reload.
+#[wasm_bindgen_test]
+async fn test_exercise_notes_and_set_comments_round_trip() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let mut exercise = ExerciseMetadata {
+        id: None,
+        name: "Noted Bench".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: "Elbows tucked".to_string(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    let exercise_id = db.save_exercise(&exercise).await.expect("save exercise");
+    let saved = db.get_exercises().await.expect("get_exercises");
+    let bench = saved.iter().find(|e| e.name == "Noted Bench").unwrap();
+    assert_eq!(bench.notes, "Elbows tucked");
+
+    exercise.id = Some(exercise_id.clone());
+    exercise.notes = "Elbows tucked, pause on chest".to_string();
+    db.save_exercise(&exercise).await.expect("update exercise");
+    let saved = db.get_exercises().await.expect("get_exercises");
+    let bench = saved.iter().find(|e| e.name == "Noted Bench").unwrap();
+    assert_eq!(bench.notes, "Elbows tucked, pause on chest");
+
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 80.0 },
+        kind: SetKind::Working,
+        comment: "Left shoulder twinge".to_string(),
+    };
+    db.log_set(&exercise_id, &set).await.expect("log_set");
+    let history = db
+        .get_sets_for_exercise(&exercise_id, 10, 0)
+        .await
+        .expect("history");
+    assert_eq!(history[0].comment, "Left shoulder twinge");
+}
+
+/// Each set records the rest since the previous one; long gaps are not rest.
+#[wasm_bindgen_test]
+async fn test_log_set_records_rest_and_exercise_rest_duration() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let exercise = ExerciseMetadata {
+        id: None,
+        name: "Rested Squat".to_string(),
+        set_type_config: SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        },
+        min_reps: 1,
+        max_reps: None,
+        notes: String::new(),
+        rest_secs: Some(180),
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
+    };
+    let exercise_id = db.save_exercise(&exercise).await.expect("save exercise");
+    let saved = db.get_exercises().await.expect("get_exercises");
+    let squat = saved.iter().find(|e| e.name == "Rested Squat").unwrap();
+    assert_eq!(squat.rest_secs, Some(180));
+
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    let start = 1_700_000_000_000.0;
+    for at in [start, start + 150_000.0, start + 5.0 * 3_600_000.0] {
+        db.log_set_at(&exercise_id, &set, at)
+            .await
+            .expect("log_set_at");
+    }
+
+    let history = db
+        .get_sets_for_exercise(&exercise_id, 10, 0)
+        .await
+        .expect("history");
+    let rests: Vec<Option<u32>> = history.iter().map(|s| s.rest_secs).collect();
+    assert_eq!(rests, vec![None, Some(150), None], "newest first");
+}
+
+/// A set backfilled between two logged sets takes the rest since the one
+/// before it and shortens the rest of the one after it.
+#[wasm_bindgen_test]
+async fn test_log_set_at_backfill_rederives_the_next_rest() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let squat = make_weighted_exercise(&db, "Backfilled Squat").await;
+    let bench = make_weighted_exercise(&db, "Backfilled Bench").await;
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    let start = 1_700_000_000_000.0;
+    for at in [start, start + 300_000.0] {
+        db.log_set_at(&squat, &set, at).await.expect("log squat");
+    }
+    db.log_set_at(&bench, &set, start + 120_000.0)
+        .await
+        .expect("backfill bench");
+
+    let squats = db
+        .get_sets_for_exercise(&squat, 10, 0)
+        .await
+        .expect("squat history");
+    let rests: Vec<Option<u32>> = squats.iter().map(|s| s.rest_secs).collect();
+    assert_eq!(rests, vec![Some(180), None], "newest first");
+    let benches = db
+        .get_sets_for_exercise(&bench, 10, 0)
+        .await
+        .expect("bench history");
+    assert_eq!(benches[0].rest_secs, Some(120));
+}
+
+/// Moving a set's time re-derives its own rest and the rests of the sets
+/// after its old and new places.
+#[wasm_bindgen_test]
+async fn test_update_set_time_rederives_rests() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = make_weighted_exercise(&db, "Moved Squat").await;
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    let start = 1_700_000_000_000.0;
+    let mut ids = Vec::new();
+    for at in [start, start + 60_000.0, start + 150_000.0] {
+        ids.push(db.log_set_at(&eid, &set, at).await.expect("log set"));
+    }
+
+    db.update_set(ids[1], 5, 8.0, &set.set_type, "", start + 200_000.0)
+        .await
+        .expect("move set");
+
+    let history = db
+        .get_sets_for_exercise(&eid, 10, 0)
+        .await
+        .expect("history");
+    let rests: Vec<(i64, Option<u32>)> = history.iter().map(|s| (s.id, s.rest_secs)).collect();
+    assert_eq!(
+        rests,
+        vec![(ids[1], Some(50)), (ids[2], Some(150)), (ids[0], None)],
+        "newest first"
+    );
+}
+
+/// Deleting a set in the middle of a session measures the next set's rest
+/// from the set before the deleted one.
+#[wasm_bindgen_test]
+async fn test_delete_set_rederives_the_next_rest() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = make_weighted_exercise(&db, "Deleted Rest Squat").await;
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    let start = 1_700_000_000_000.0;
+    let mut ids = Vec::new();
+    for at in [start, start + 60_000.0, start + 150_000.0] {
+        ids.push(db.log_set_at(&eid, &set, at).await.expect("log set"));
+    }
+
+    db.delete_set(ids[1]).await.expect("delete set");
+
+    let history = db
+        .get_sets_for_exercise(&eid, 10, 0)
+        .await
+        .expect("history");
+    let rests: Vec<Option<u32>> = history.iter().map(|s| s.rest_secs).collect();
+    assert_eq!(rests, vec![Some(150), None], "newest first");
+}
+
+/// Slot groups survive a round trip through a template.
+#[wasm_bindgen_test]
+async fn test_template_preserves_slot_groups() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let plan_id = db.create_plan().await.expect("create_plan failed");
+    for name in ["Bench", "Row", "Curl"] {
+        let eid = db
+            .save_exercise(&ExerciseMetadata {
+                id: None,
+                name: name.to_string(),
+                set_type_config: SetTypeConfig::Bodyweight,
+                min_reps: 1,
+                max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            })
+            .await
+            .expect("save_exercise failed");
+        db.add_exercise_to_plan(&plan_id, &eid, 3)
+            .await
+            .expect("add_exercise_to_plan failed");
+    }
+    let plan = db.get_plan(&plan_id).await.expect("get_plan").unwrap();
+    let updates = crate::domain::slot_group::link_with_next(&plan.exercises, 0, "g1".to_string());
+    db.set_plan_slot_groups(&updates)
+        .await
+        .expect("set_plan_slot_groups failed");
+
+    let plan = db.get_plan(&plan_id).await.expect("get_plan").unwrap();
+    let groups: Vec<Option<&str>> = plan
+        .exercises
+        .iter()
+        .map(|pe| pe.group_id.as_deref())
+        .collect();
+    assert_eq!(groups, vec![Some("g1"), Some("g1"), None]);
+
+    let tid = db
+        .save_template("Upper", &plan.exercises)
+        .await
+        .expect("save_template failed");
+    let other_plan = db.create_plan().await.expect("create_plan failed");
+    db.load_template_into_plan(&other_plan, &tid)
+        .await
+        .expect("load_template_into_plan failed");
+    let loaded = db.get_plan(&other_plan).await.expect("get_plan").unwrap();
+    let groups: Vec<Option<&str>> = loaded
+        .exercises
+        .iter()
+        .map(|pe| pe.group_id.as_deref())
+        .collect();
+    assert_eq!(groups, vec![Some("g1"), Some("g1"), None]);
+}
+
+/// Slot Targets are stored per slot and survive a round trip through a template.
+#[wasm_bindgen_test]
+async fn test_slot_targets_round_trip_through_templates() {
+    use crate::models::SlotTargets;
+
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let plan_id = db.create_plan().await.expect("create_plan failed");
+    for name in ["Heavy Squat", "Light Squat"] {
+        let eid = db
+            .save_exercise(&ExerciseMetadata {
+                id: None,
+                name: name.to_string(),
+                set_type_config: SetTypeConfig::Bodyweight,
+                min_reps: 5,
+                max_reps: Some(8),
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            })
+            .await
+            .expect("save_exercise failed");
+        db.add_exercise_to_plan(&plan_id, &eid, 3)
+            .await
+            .expect("add_exercise_to_plan failed");
+    }
+    let heavy = SlotTargets {
+        rep_range: Some((3, Some(5))),
+        target_rpe: Some(9.0),
+    };
+    let open_ended = SlotTargets {
+        rep_range: Some((12, None)),
+        target_rpe: None,
+    };
+    let plan = db.get_plan(&plan_id).await.expect("get_plan").unwrap();
+    db.set_plan_slot_targets(&plan.exercises[0].id, &heavy)
+        .await
+        .expect("set_plan_slot_targets failed");
+    db.set_plan_slot_targets(&plan.exercises[1].id, &open_ended)
+        .await
+        .expect("set_plan_slot_targets failed");
+
+    let plan = db.get_plan(&plan_id).await.expect("get_plan").unwrap();
+    let targets: Vec<SlotTargets> = plan.exercises.iter().map(|pe| pe.targets).collect();
+    assert_eq!(targets, vec![heavy, open_ended]);
+    assert_eq!(
+        plan.exercises[0].exercise.min_reps, 5,
+        "the exercise's own Rep Range is untouched"
+    );
+
+    let tid = db
+        .save_template("Squat Day", &plan.exercises)
+        .await
+        .expect("save_template failed");
+    let other_plan = db.create_plan().await.expect("create_plan failed");
+    db.load_template_into_plan(&other_plan, &tid)
+        .await
+        .expect("load_template_into_plan failed");
+    let loaded = db.get_plan(&other_plan).await.expect("get_plan").unwrap();
+    let targets: Vec<SlotTargets> = loaded.exercises.iter().map(|pe| pe.targets).collect();
+    assert_eq!(targets, vec![heavy, open_ended]);
+
+    db.set_plan_slot_targets(&loaded.exercises[0].id, &SlotTargets::default())
+        .await
+        .expect("set_plan_slot_targets failed");
+    let loaded = db.get_plan(&other_plan).await.expect("get_plan").unwrap();
+    assert!(loaded.exercises[0].targets.is_empty());
+}
+
+/// Programs list their template days in order, and the next workout follows
+/// the highest index among started plans.
+#[wasm_bindgen_test]
+async fn test_program_days_and_started_index() {
+    use crate::models::{PlanExercise, Program, ProgramDay, ProgramPosition, ProgressionRule};
+
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = db
+        .save_exercise(&ExerciseMetadata {
+            id: None,
+            name: "Squat".to_string(),
+            set_type_config: SetTypeConfig::Bodyweight,
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        })
+        .await
+        .expect("save_exercise failed");
+    let slot = PlanExercise {
+        id: String::new(),
+        exercise: ExerciseMetadata {
+            id: Some(eid),
+            name: "Squat".to_string(),
+            set_type_config: SetTypeConfig::Bodyweight,
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        },
+        planned_sets: 3,
+        position: 0,
+        group_id: None,
+        targets: Default::default(),
+    };
+    let day_a = db
+        .save_template("Day A", std::slice::from_ref(&slot))
+        .await
+        .expect("save_template failed");
+    let day_b = db
+        .save_template("Day B", &[slot])
+        .await
+        .expect("save_template failed");
+
+    let day = |template_id: &str| ProgramDay {
+        id: String::new(),
+        template_id: template_id.to_string(),
+        template_name: String::new(),
+    };
+    let program_id = db
+        .create_program(&Program {
+            id: String::new(),
+            name: " Block 1 ".to_string(),
+            weeks: 4,
+            deload_week: Some(4),
+            progression: ProgressionRule::Volume { step: 1 },
+            days: vec![day(&day_a), day(&day_b)],
+        })
+        .await
+        .expect("create_program failed");
+
+    let programs = db.list_programs().await.expect("list_programs failed");
+    assert_eq!(programs.len(), 1);
+    let program = &programs[0];
+    assert_eq!(program.name, "Block 1");
+    assert_eq!(program.progression, ProgressionRule::Volume { step: 1 });
+    let names: Vec<&str> = program
+        .days
+        .iter()
+        .map(|d| d.template_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Day A", "Day B"]);
+
+    assert_eq!(
+        db.last_started_program_index(&program_id).await.unwrap(),
+        None
+    );
+
+    // A materialized but unstarted plan does not advance the program.
+    let plan_id = db.create_plan().await.expect("create_plan failed");
+    db.load_template_into_plan(&plan_id, &day_a)
+        .await
+        .expect("load_template_into_plan failed");
+    let position = ProgramPosition {
+        program_id: program_id.clone(),
+        index: 0,
+    };
+    db.set_plan_program(&plan_id, &position)
+        .await
+        .expect("set_plan_program failed");
+    assert_eq!(
+        db.get_plan(&plan_id).await.unwrap().unwrap().program,
+        Some(position)
+    );
+    assert_eq!(
+        db.last_started_program_index(&program_id).await.unwrap(),
+        None
+    );
+
+    db.start_plan(&plan_id).await.expect("start_plan failed");
+    assert_eq!(
+        db.last_started_program_index(&program_id).await.unwrap(),
+        Some(0)
+    );
+
+    // Loading a plain template unlinks the plan from the program.
+    db.load_template_into_plan(&plan_id, &day_b)
+        .await
+        .expect("load_template_into_plan failed");
+    assert_eq!(db.get_plan(&plan_id).await.unwrap().unwrap().program, None);
+
+    db.delete_program(&program_id)
+        .await
+        .expect("delete_program failed");
+    assert!(db.list_programs().await.unwrap().is_empty());
+}
+
+/// The deload advisor's load/RPE history keeps recent weighted working sets,
+/// oldest first, and skips warm-ups and sets outside the window.
+#[wasm_bindgen_test]
+async fn test_load_rpe_history_for_rpe_creep() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = db
+        .save_exercise(&ExerciseMetadata {
+            id: None,
+            name: "Bench".to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: None,
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        })
+        .await
+        .expect("save_exercise failed");
+
+    let day_ms = 86_400_000.0;
+    let now = js_sys::Date::now();
+    let set = |rpe: f32, kind: SetKind| CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe,
+        set_type: SetType::Weighted { weight: 80.0 },
+        kind,
+        comment: String::new(),
+    };
+    for (days_ago, rpe, kind) in [
+        (60.0, 6.0, SetKind::Working),
+        (9.0, 7.0, SetKind::Working),
+        (5.0, 5.0, SetKind::Warmup),
+        (5.0, 8.0, SetKind::Working),
+        (1.0, 8.5, SetKind::Working),
+    ] {
+        db.log_set_at(&eid, &set(rpe, kind), now - days_ago * day_ms)
+            .await
+            .expect("log_set_at failed");
+    }
+
+    let history = db
+        .get_load_rpe_history(&eid, 4)
+        .await
+        .expect("get_load_rpe_history failed");
+    let rpes: Vec<f64> = history.iter().map(|s| s.rpe).collect();
+    assert_eq!(rpes, vec![7.0, 8.0, 8.5]);
+    assert!(history.iter().all(|s| s.load == 80.0));
+    assert_eq!(
+        crate::domain::deload::rpe_creep(&history),
+        Some((80.0, 1.5))
+    );
+}
+
+/// Personal records are stored against their set and disappear from the
+/// exercise's list once that set is deleted.
+#[wasm_bindgen_test]
+async fn test_personal_records_follow_their_set() {
+    use crate::models::{PersonalRecord, RecordKind};
+
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = db
+        .save_exercise(&ExerciseMetadata {
+            id: None,
+            name: "Squat".to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: None,
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        })
+        .await
+        .expect("save_exercise failed");
+
+    let set = |weight: f32| CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    db.log_set_at(&eid, &set(100.0), 1_000.0)
+        .await
+        .expect("log_set_at failed");
+    let prior = db
+        .get_record_sets_before(&eid, 2_000.0)
+        .await
+        .expect("get_record_sets_before failed");
+    assert_eq!(prior.len(), 1);
+
+    let set_id = db
+        .log_set_at(&eid, &set(105.0), 2_000.0)
+        .await
+        .expect("log_set_at failed");
+    let record = PersonalRecord {
+        id: "pr-1".to_string(),
+        exercise_id: eid.clone(),
+        kind: RecordKind::WeightAtReps,
+        value: 105.0,
+        previous: 100.0,
+        weight: Some(105.0),
+        reps: 5,
+        recorded_at: 2_000.0,
+    };
+    db.save_personal_records(set_id, std::slice::from_ref(&record))
+        .await
+        .expect("save_personal_records failed");
+    assert_eq!(db.get_personal_records(&eid).await.unwrap(), vec![record]);
+
+    db.delete_set(set_id).await.expect("delete_set failed");
+    assert!(db.get_personal_records(&eid).await.unwrap().is_empty());
+}
+
+/// Editing a set soft-deletes the Personal Records it earned.
+#[wasm_bindgen_test]
+async fn test_update_set_withdraws_its_personal_records() {
+    use crate::models::{PersonalRecord, RecordKind};
+
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = make_weighted_exercise(&db, "Edited Bench").await;
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 80.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    let set_id = db
+        .log_set_at(&eid, &set, 1_000.0)
+        .await
+        .expect("log_set_at failed");
+    db.save_personal_records(
+        set_id,
+        &[PersonalRecord {
+            id: "pr-bench".to_string(),
+            exercise_id: eid.clone(),
+            kind: RecordKind::E1rm,
+            value: 93.3,
+            previous: 0.0,
+            weight: Some(80.0),
+            reps: 5,
+            recorded_at: 1_000.0,
+        }],
+    )
+    .await
+    .expect("save_personal_records failed");
+
+    db.update_set(set_id, 3, 8.0, &set.set_type, "", 1_000.0)
+        .await
+        .expect("update_set failed");
+    assert!(db.get_personal_records(&eid).await.unwrap().is_empty());
+}
+
+/// Training Days are distinct UTC dates with any set inside the window,
+/// oldest first; several sets on one day count once.
+#[wasm_bindgen_test]
+async fn test_training_days_are_distinct_dates() {
+    let mut db = Database::new();
+    db.init(None).await.expect("DB init");
+
+    let eid = db
+        .save_exercise(&ExerciseMetadata {
+            id: None,
+            name: "Squat".to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: None,
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        })
+        .await
+        .expect("save_exercise failed");
+
+    let day_ms = 86_400_000.0;
+    let now = js_sys::Date::now();
+    let set = CompletedSet {
+        set_number: 1,
+        reps: 5,
+        rpe: 8.0,
+        set_type: SetType::Weighted { weight: 100.0 },
+        kind: SetKind::Working,
+        comment: String::new(),
+    };
+    for days_ago in [60.0, 9.0, 9.0, 1.0] {
+        db.log_set_at(&eid, &set, now - days_ago * day_ms)
+            .await
+            .expect("log_set_at failed");
+    }
+
+    let days = db
+        .get_training_days(4)
+        .await
+        .expect("get_training_days failed");
+    let date = |days_ago: f64| {
+        chrono::DateTime::from_timestamp_millis((now - days_ago * day_ms) as i64)
+            .unwrap()
+            .date_naive()
+    };
+    assert_eq!(days, vec![date(9.0), date(1.0)]);
+}
@@ -25,9 +25,15 @@ pub enum WorkoutError {
     #[error("Invalid set data: {0}")]
     InvalidSetData(String),
 
+    #[error("Invalid bodyweight: {0}")]
+    InvalidBodyweight(String),
+
     #[error("Failed to save exercise: {0}")]
     SaveExerciseError(String),
 
     #[error("Failed to insert set: {0}")]
     InsertSetError(String),
+
+    #[error("Program finished: every workout has been started")]
+    ProgramFinished,
 }
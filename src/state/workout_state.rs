@@ -1,5 +1,15 @@
+use crate::domain::analysis::AnalysisSnapshot;
+use crate::domain::deload::DeloadAdvice;
+use crate::domain::e1rm::SuggestionModel;
+use crate::domain::program::ProgramWorkout;
+use crate::domain::rest::RestTimer;
+use crate::import::ImportPlan;
 use crate::log_buffer::{self, LogEntry};
-use crate::models::{CompletedSet, ExerciseMetadata, SetType, Settings, WorkoutPlan};
+use crate::models::{
+    BodyweightEntry, CompletedSet, E1rmFormula, ExerciseMetadata, HistorySet, MuscleGroup,
+    PersonalRecord, PlanExercise, Plate, Program, ProgramPosition, ProgressionStrategy,
+    RpeCalibration, SetKind, SetType, Settings, SlotTargets, VolumeZone, WarmupSet, WorkoutPlan,
+};
 use crate::state::{Database, Storage, error::WorkoutError};
 #[cfg(not(test))]
 use crate::sync::SyncCredentials;
@@ -16,6 +26,8 @@ fn js_log(msg: &str) {
 // Initial prediction constants
 const DEFAULT_WEIGHTED_REPS: u32 = 8;
 const DEFAULT_RPE: f32 = 7.0;
+/// Cold-start suggestion for timed, distance and carry sets, in measure increments.
+const DEFAULT_MEASURE_STEPS: u32 = 10;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct PredictedParameters {
@@ -25,6 +37,8 @@ pub struct PredictedParameters {
     /// True when the predicted rep count was clamped to the exercise's
     /// configured `[min_reps, max_reps]` range.
     pub reps_clamped: bool,
+    /// Suggested seconds or metres for timed, distance and carry exercises.
+    pub measure: Option<f64>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -33,6 +47,17 @@ pub struct WorkoutSession {
     pub exercise: ExerciseMetadata,
     pub completed_sets: Vec<CompletedSet>,
     pub predicted: PredictedParameters,
+    /// Warm-up ramp generated at session start; shown as checkable pre-sets
+    /// until the first working set is logged.
+    pub warmups: Vec<WarmupSet>,
+    /// Latest logged bodyweight in kg, snapshotted into loaded bodyweight
+    /// sets; `None` until the trainee logs one.
+    pub bodyweight: Option<f32>,
+    /// Overrides from the Plan Slot the session was started from.
+    pub targets: SlotTargets,
+    /// The exercise's own RPE Calibration, loaded at session start; `None`
+    /// falls back to the trainee's.
+    pub rpe_calibration: Option<RpeCalibration>,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
@@ -98,6 +123,16 @@ pub struct WorkoutState {
     current_plan: Signal<Option<WorkoutPlan>>,
     /// Cached snapshot of the debug log buffer for reactive UI rendering.
     log_entries: Signal<Vec<LogEntry>>,
+    /// User-owned plate inventory used by the plate calculator.
+    plates: Signal<Vec<Plate>>,
+    /// Dated bodyweight entries, newest first.
+    bodyweight_log: Signal<Vec<BodyweightEntry>>,
+    /// Rest countdown started by the last logged set; kept here rather than
+    /// in the Record Screen so it keeps running across tab switches.
+    rest_timer: Signal<Option<RestTimer>>,
+    /// Personal Records set by the last logged set, celebrated on the
+    /// Record Screen until dismissed or the next set is logged.
+    new_records: Signal<Vec<PersonalRecord>>,
 }
 
 impl Default for WorkoutState {
@@ -121,6 +156,10 @@ impl WorkoutState {
             sync_status: Signal::new(SyncStatus::Idle),
             current_plan: Signal::new(None),
             log_entries: Signal::new(Vec::new()),
+            plates: Signal::new(Vec::new()),
+            bodyweight_log: Signal::new(Vec::new()),
+            rest_timer: Signal::new(None),
+            new_records: Signal::new(Vec::new()),
         }
     }
 
@@ -196,6 +235,47 @@ impl WorkoutState {
         sig.set(exercises);
     }
 
+    pub fn plates(&self) -> Vec<Plate> {
+        (self.plates)()
+    }
+
+    pub fn set_plates(&self, plates: Vec<Plate>) {
+        let mut sig = self.plates;
+        sig.set(plates);
+    }
+
+    pub fn bodyweight_log(&self) -> Vec<BodyweightEntry> {
+        (self.bodyweight_log)()
+    }
+
+    pub fn set_bodyweight_log(&self, entries: Vec<BodyweightEntry>) {
+        let mut sig = self.bodyweight_log;
+        sig.set(entries);
+    }
+
+    pub fn rest_timer(&self) -> Option<RestTimer> {
+        (self.rest_timer)()
+    }
+
+    pub fn set_rest_timer(&self, timer: Option<RestTimer>) {
+        let mut sig = self.rest_timer;
+        sig.set(timer);
+    }
+
+    pub fn new_records(&self) -> Vec<PersonalRecord> {
+        (self.new_records)()
+    }
+
+    pub fn set_new_records(&self, records: Vec<PersonalRecord>) {
+        let mut sig = self.new_records;
+        sig.set(records);
+    }
+
+    /// Bodyweight in force right now, from the most recent log entry.
+    pub fn current_bodyweight(&self) -> Option<f32> {
+        crate::domain::bodyweight::bodyweight_at(&self.bodyweight_log(), js_sys::Date::now())
+    }
+
     pub fn settings(&self) -> Settings {
         (self.settings)()
     }
@@ -367,6 +447,14 @@ impl WorkoutStateManager {
             js_log(&format!("[DB Init] load_settings warning: {}", e));
         }
 
+        if let Err(e) = Self::load_plates(state).await {
+            js_log(&format!("[DB Init] load_plates warning: {}", e));
+        }
+
+        if let Err(e) = Self::load_bodyweight_log(state).await {
+            js_log(&format!("[DB Init] load_bodyweight_log warning: {}", e));
+        }
+
         if let Err(e) = Self::resume_active_plan(state).await {
             js_log(&format!("[DB Init] resume_active_plan warning: {}", e));
         }
@@ -425,9 +513,78 @@ impl WorkoutStateManager {
         Ok(id)
     }
 
+    /// Writes a previewed history import, skipping sets that are already
+    /// logged, and refreshes the library.  Returns the number of sets logged;
+    /// on an error nothing is written.
+    pub async fn import_history(
+        state: &WorkoutState,
+        plan: &ImportPlan,
+    ) -> Result<usize, WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let logged = db
+            .import_history(plan)
+            .await
+            .map_err(WorkoutError::Database)?;
+
+        Self::sync_exercises(state).await?;
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after history import failed: {}", e);
+        }
+        Ok(logged)
+    }
+
+    /// Number of a previewed history import's sets that are already logged.
+    pub async fn count_already_imported(
+        state: &WorkoutState,
+        plan: &ImportPlan,
+    ) -> Result<usize, WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.count_already_imported(plan)
+            .await
+            .map_err(WorkoutError::Database)
+    }
+
+    /// Writes a previewed merge-import and reloads the state it can touch.
+    pub async fn apply_merge(
+        state: &WorkoutState,
+        plan: &crate::merge::MergePlan,
+    ) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.apply_merge(plan).await.map_err(WorkoutError::Database)?;
+
+        Self::sync_exercises(state).await?;
+        if let Err(e) = Self::load_plates(state).await {
+            log::warn!("Failed to reload plates after merge: {}", e);
+        }
+        if let Err(e) = Self::load_bodyweight_log(state).await {
+            log::warn!("Failed to reload bodyweight log after merge: {}", e);
+        }
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after merge failed: {}", e);
+        }
+        Ok(())
+    }
+
     pub async fn start_session(
+        state: &WorkoutState,
+        exercise: ExerciseMetadata,
+    ) -> Result<(), WorkoutError> {
+        Self::start_session_with_targets(state, exercise, SlotTargets::default()).await
+    }
+
+    /// Start a session on a Plan Slot, so its Slot Targets shape the
+    /// Suggestions.
+    pub async fn start_slot_session(
+        state: &WorkoutState,
+        slot: &PlanExercise,
+    ) -> Result<(), WorkoutError> {
+        Self::start_session_with_targets(state, slot.exercise.clone(), slot.targets).await
+    }
+
+    async fn start_session_with_targets(
         state: &WorkoutState,
         mut exercise: ExerciseMetadata,
+        targets: SlotTargets,
     ) -> Result<(), WorkoutError> {
         // Implicitly complete any in-progress session before starting a new one.
         // This ensures sets from the previous exercise are persisted to disk
@@ -446,15 +603,13 @@ impl WorkoutStateManager {
             })?;
         exercise.id = Some(id.clone());
 
-        // Fetch last set for suggestions (only for weighted exercises)
+        // Fetch last set for suggestions (not needed for bodyweight exercises)
         let last_set = match exercise.set_type_config {
-            crate::models::SetTypeConfig::Weighted { .. } => {
-                db.get_last_set_for_exercise(&id).await.unwrap_or_else(|e| {
-                    log::warn!("Failed to fetch last set for suggestion: {}", e);
-                    None
-                })
-            }
-            _ => None,
+            crate::models::SetTypeConfig::Bodyweight => None,
+            _ => db.get_last_set_for_exercise(&id).await.unwrap_or_else(|e| {
+                log::warn!("Failed to fetch last set for suggestion: {}", e);
+                None
+            }),
         };
 
         // Sync exercises in state after saving new one
@@ -462,18 +617,97 @@ impl WorkoutStateManager {
             log::warn!("Failed to sync exercises after saving: {}", e);
         }
 
-        let predicted = Self::calculate_initial_predictions(
+        // Suggestions, the weight dial and set validation all work on the
+        // loading grid of the display unit.
+        let unit = state.settings().weight_unit;
+        exercise.set_type_config = exercise.loading_config(unit);
+        exercise.weight_unit = unit;
+
+        let rpe_calibration = db
+            .get_exercise_rpe_calibrations()
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to fetch RPE calibrations: {}", e);
+                Vec::new()
+            })
+            .into_iter()
+            .find_map(|(exercise_id, calibration)| (exercise_id == id).then_some(calibration));
+
+        let settings = state.settings();
+        let initial = Self::calculate_initial_predictions(
             &exercise,
             last_set.as_ref(),
-            state.settings().default_bodyweight_reps,
+            settings.default_bodyweight_reps,
+            &targets,
+        );
+        let weighted = matches!(
+            exercise.set_type_config,
+            crate::models::SetTypeConfig::Weighted { .. }
+        );
+
+        // Linear and Double progression build on the previous session's
+        // working sets.
+        let previous_session = match exercise.progression {
+            ProgressionStrategy::Linear | ProgressionStrategy::Double if weighted => {
+                let (_, today_start_ms, _) = Self::history_window(&settings);
+                db.get_previous_session_working_sets(&id, today_start_ms)
+                    .await
+                    .unwrap_or_else(|e| {
+                        log::warn!("Failed to fetch previous session: {}", e);
+                        Vec::new()
+                    })
+            }
+            _ => Vec::new(),
+        };
+
+        // The Blended e1RM caps warm-up reps and sets Percentage-of-e1RM
+        // Suggestions.
+        let model = Self::suggestion_model(&exercise, rpe_calibration, &settings);
+        let needs_e1rm = (initial.weight.is_some() && !settings.warmup_ramp.is_empty())
+            || (weighted && matches!(exercise.progression, ProgressionStrategy::PercentOfE1rm(_)));
+        let e1rm = if needs_e1rm {
+            let (historical_best, today_best) =
+                Self::fetch_best_sets(&db, &exercise, &settings).await;
+            Self::blended_weighted_e1rm(
+                historical_best.as_ref(),
+                today_best.as_ref(),
+                &settings,
+                model,
+            )
+        } else {
+            None
+        };
+
+        let predicted = Self::snap_to_plates(
+            Self::apply_progression(initial, &exercise, &targets, &previous_session, e1rm),
+            &exercise,
+            &state.plates(),
         );
 
+        // Warm-ups ramp up to the first predicted working weight.
+        let warmups = match predicted.weight {
+            Some(working) if !settings.warmup_ramp.is_empty() => {
+                crate::domain::warmup::generate_warmups(
+                    working as f64,
+                    e1rm,
+                    &model,
+                    &exercise.set_type_config,
+                    &settings.warmup_ramp,
+                )
+            }
+            _ => Vec::new(),
+        };
+
         // Use exercise_id as session_id so the UI can detect a new session started
         let session = WorkoutSession {
             session_id: exercise.id.clone(),
             exercise,
             completed_sets: Vec::new(),
             predicted,
+            warmups,
+            bodyweight: state.current_bodyweight(),
+            targets,
+            rpe_calibration,
         };
 
         state.set_current_session(Some(session));
@@ -481,34 +715,9 @@ impl WorkoutStateManager {
         Ok(())
     }
 
-    pub async fn log_set(state: &WorkoutState, set: CompletedSet) -> Result<(), WorkoutError> {
-        let mut session = state
-            .current_session()
-            .ok_or(WorkoutError::NoActiveSession)?;
-
-        let exercise_id = session
-            .exercise
-            .id
-            .clone()
-            .ok_or(WorkoutError::SessionNotPersisted)?;
-
-        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
-
-        crate::models::validate_completed_set(&set, &session.exercise)
-            .map_err(|e| WorkoutError::InvalidSetData(e.to_string()))?;
-
-        let _set_id =
-            db.log_set(&exercise_id, &set)
-                .await
-                .map_err(|e: crate::state::DatabaseError| {
-                    WorkoutError::InsertSetError(e.to_string())
-                })?;
-
-        session.completed_sets.push(set.clone());
-
-        // Pre-fetch all inputs for calculate_next_predictions (no async inside
-        // the pure function itself).
-        let settings = state.settings();
+    /// Returns `(since_ms, today_start_ms, today_end_ms)` for the suggestion
+    /// queries: the History Window start and the current Training Day bounds.
+    fn history_window(settings: &Settings) -> (f64, f64, f64) {
         let now = js_sys::Date::now();
         let history_window_ms = settings.history_window_days as f64 * 24.0 * 60.0 * 60.0 * 1000.0;
         let since_ms = now - history_window_ms;
@@ -526,10 +735,22 @@ impl WorkoutStateManager {
             d.value_of()
         };
         let today_end_ms = today_start_ms + 24.0 * 60.0 * 60.0 * 1000.0;
+        (since_ms, today_start_ms, today_end_ms)
+    }
+
+    /// Fetches `(historical_best, today_best)` for the exercise, logging and
+    /// swallowing query errors so suggestions degrade to the fallback path.
+    async fn fetch_best_sets(
+        db: &Database,
+        exercise: &ExerciseMetadata,
+        settings: &Settings,
+    ) -> (Option<CompletedSet>, Option<CompletedSet>) {
+        let (since_ms, today_start_ms, today_end_ms) = Self::history_window(settings);
 
         let historical_best = db
             .get_historical_best_for_exercise(
-                &session.exercise,
+                exercise,
+                exercise.e1rm_formula_or(settings.e1rm_formula),
                 since_ms,
                 today_start_ms,
                 today_end_ms,
@@ -540,31 +761,135 @@ impl WorkoutStateManager {
                 None
             });
 
-        let today_best = db
-            .get_latest_set_today(&exercise_id, today_start_ms, today_end_ms)
+        let today_best = match exercise.id.as_deref() {
+            Some(id) => db
+                .get_latest_set_today(id, today_start_ms, today_end_ms)
+                .await
+                .unwrap_or_else(|e| {
+                    log::warn!("Failed to fetch today_best: {}", e);
+                    None
+                }),
+            None => None,
+        };
+
+        (historical_best, today_best)
+    }
+
+    /// Check off the warm-up at `index`: log it as a [`SetKind::Warmup`] set
+    /// and mark it done.  Already-done warm-ups are ignored.
+    pub async fn log_warmup(state: &WorkoutState, index: usize) -> Result<(), WorkoutError> {
+        let mut session = state
+            .current_session()
+            .ok_or(WorkoutError::NoActiveSession)?;
+
+        let exercise_id = session
+            .exercise
+            .id
+            .clone()
+            .ok_or(WorkoutError::SessionNotPersisted)?;
+
+        let Some(warmup) = session.warmups.get(index).copied() else {
+            return Ok(());
+        };
+        if warmup.done {
+            return Ok(());
+        }
+
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let set = CompletedSet {
+            set_number: index as u32 + 1,
+            reps: warmup.reps,
+            rpe: crate::domain::warmup::WARMUP_RPE,
+            set_type: SetType::Weighted {
+                weight: warmup.weight,
+            },
+            kind: SetKind::Warmup,
+            comment: String::new(),
+        };
+        db.log_set(&exercise_id, &set)
             .await
-            .unwrap_or_else(|e| {
-                log::warn!("Failed to fetch today_best: {}", e);
-                None
-            });
+            .map_err(|e| WorkoutError::InsertSetError(e.to_string()))?;
+
+        session.warmups[index].done = true;
+        state.set_current_session(Some(session));
+        Ok(())
+    }
+
+    pub async fn log_set(state: &WorkoutState, set: CompletedSet) -> Result<(), WorkoutError> {
+        let mut session = state
+            .current_session()
+            .ok_or(WorkoutError::NoActiveSession)?;
+
+        let exercise_id = session
+            .exercise
+            .id
+            .clone()
+            .ok_or(WorkoutError::SessionNotPersisted)?;
+
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+
+        crate::models::validate_completed_set(&set, &session.exercise)
+            .map_err(|e| WorkoutError::InvalidSetData(e.describe(state.settings().weight_unit)))?;
+
+        let logged_at = js_sys::Date::now();
+        let set_id =
+            db.log_set(&exercise_id, &set)
+                .await
+                .map_err(|e: crate::state::DatabaseError| {
+                    WorkoutError::InsertSetError(e.to_string())
+                })?;
+        state.set_new_records(
+            Self::record_personal_records(
+                &db,
+                &exercise_id,
+                session
+                    .exercise
+                    .e1rm_formula_or(state.settings().e1rm_formula),
+                &set,
+                set_id,
+                logged_at,
+            )
+            .await,
+        );
+
+        session.completed_sets.push(set.clone());
+
+        let rest_secs = session.exercise.rest_duration_secs();
+        state.set_rest_timer((rest_secs > 0).then(|| RestTimer {
+            started_at: js_sys::Date::now(),
+            duration_secs: rest_secs,
+            exercise_name: session.exercise.name.clone(),
+        }));
+
+        // Pre-fetch all inputs for calculate_next_predictions (no async inside
+        // the pure function itself).
+        let settings = state.settings();
+        let (since_ms, _, _) = Self::history_window(&settings);
+        let (historical_best, today_best) =
+            Self::fetch_best_sets(&db, &session.exercise, &settings).await;
 
         let per_rep_maxes = match session.exercise.set_type_config {
-            crate::models::SetTypeConfig::Weighted { .. } => db
+            crate::models::SetTypeConfig::Weighted { .. }
+            | crate::models::SetTypeConfig::LoadedBodyweight { .. } => db
                 .get_max_weight_per_rep(&exercise_id, since_ms)
                 .await
                 .unwrap_or_else(|e| {
                     log::warn!("Failed to fetch per_rep_maxes: {}", e);
                     HashMap::new()
                 }),
-            crate::models::SetTypeConfig::Bodyweight => HashMap::new(),
+            _ => HashMap::new(),
         };
 
-        session.predicted = Self::calculate_next_predictions(
-            &session,
-            historical_best,
-            today_best,
-            per_rep_maxes,
-            &settings,
+        session.predicted = Self::snap_to_plates(
+            Self::calculate_next_predictions(
+                &session,
+                historical_best,
+                today_best,
+                per_rep_maxes,
+                &settings,
+            ),
+            &session.exercise,
+            &state.plates(),
         );
 
         state.set_current_session(Some(session));
@@ -593,6 +918,143 @@ impl WorkoutStateManager {
         Ok(())
     }
 
+    /// Detects and stores the Personal Records earned by a just-logged set,
+    /// judged against the exercise's sets from before `logged_at` using the
+    /// exercise's e1RM Formula. Failures are logged and yield no records: a
+    /// missed celebration must never fail the set itself.
+    async fn record_personal_records(
+        db: &Database,
+        exercise_id: &str,
+        formula: E1rmFormula,
+        set: &CompletedSet,
+        set_id: i64,
+        logged_at: f64,
+    ) -> Vec<PersonalRecord> {
+        use crate::domain::records::{RecordSet, detect};
+
+        let Some(candidate) = RecordSet::from_completed(set) else {
+            return Vec::new();
+        };
+        let prior: Vec<RecordSet> = match db.get_record_sets_before(exercise_id, logged_at).await {
+            Ok(sets) => sets.iter().filter_map(RecordSet::from_history).collect(),
+            Err(e) => {
+                log::warn!("Failed to read history for record detection: {}", e);
+                return Vec::new();
+            }
+        };
+        let records: Vec<PersonalRecord> = detect(&candidate, &prior, &formula)
+            .into_iter()
+            .map(|hit| PersonalRecord {
+                id: uuid::Uuid::new_v4().to_string(),
+                exercise_id: exercise_id.to_string(),
+                kind: hit.kind,
+                value: hit.value,
+                previous: hit.previous,
+                weight: candidate.load,
+                reps: set.reps,
+                recorded_at: logged_at,
+            })
+            .collect();
+        if let Err(e) = db.save_personal_records(set_id, &records).await {
+            log::warn!("Failed to save personal records: {}", e);
+        }
+        records
+    }
+
+    /// Saves an edit to a logged set and re-judges the Personal Records of
+    /// it and every later set of its exercise, from whichever of its old and
+    /// new times is earlier.
+    pub async fn update_set(
+        state: &WorkoutState,
+        original: &HistorySet,
+        edited: &CompletedSet,
+        recorded_at: f64,
+        formula: E1rmFormula,
+    ) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.update_set(
+            original.id,
+            edited.reps,
+            edited.rpe,
+            &edited.set_type,
+            &edited.comment,
+            recorded_at,
+        )
+        .await
+        .map_err(WorkoutError::Database)?;
+        Self::redetect_personal_records(
+            &db,
+            &original.exercise_id,
+            formula,
+            original.recorded_at.min(recorded_at),
+        )
+        .await;
+        Ok(())
+    }
+
+    /// Deletes a logged set and re-judges the Personal Records of the later
+    /// sets of its exercise, which may have been measured against it.
+    pub async fn delete_set(
+        state: &WorkoutState,
+        set: &HistorySet,
+        formula: E1rmFormula,
+    ) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.delete_set(set.id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        Self::redetect_personal_records(&db, &set.exercise_id, formula, set.recorded_at).await;
+        Ok(())
+    }
+
+    /// Withdraws and detects again the Personal Records of every live set of
+    /// the exercise logged at or after `from_ms`, each judged against the sets
+    /// before it as they are now. Failures are logged, as in
+    /// [`Self::record_personal_records`].
+    async fn redetect_personal_records(
+        db: &Database,
+        exercise_id: &str,
+        formula: E1rmFormula,
+        from_ms: f64,
+    ) {
+        if let Err(e) = db
+            .withdraw_personal_records_from(exercise_id, from_ms)
+            .await
+        {
+            log::warn!("Failed to withdraw personal records: {}", e);
+            return;
+        }
+        let sets = match db.get_record_sets_from(exercise_id, from_ms).await {
+            Ok(sets) => sets,
+            Err(e) => {
+                log::warn!("Failed to read sets for record detection: {}", e);
+                return;
+            }
+        };
+        for set in sets {
+            Self::record_personal_records(
+                db,
+                exercise_id,
+                formula,
+                &set.to_completed(),
+                set.id,
+                set.recorded_at,
+            )
+            .await;
+        }
+    }
+
+    /// The exercise's Personal Records, newest first.
+    pub async fn personal_records(
+        state: &WorkoutState,
+        exercise_id: &str,
+    ) -> Result<Vec<PersonalRecord>, WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.get_personal_records(exercise_id)
+            .await
+            .map_err(WorkoutError::Database)
+    }
+
     pub async fn complete_session(state: &WorkoutState) -> Result<(), WorkoutError> {
         state
             .current_session()
@@ -601,6 +1063,7 @@ impl WorkoutStateManager {
         Self::save_database(state).await?;
 
         state.set_current_session(None);
+        state.set_new_records(Vec::new());
 
         Ok(())
     }
@@ -755,36 +1218,193 @@ impl WorkoutStateManager {
         Ok(())
     }
 
-    /// Persist updated settings to the database and refresh app state.
-    pub async fn update_settings(
-        state: &WorkoutState,
-        settings: Settings,
-    ) -> Result<(), WorkoutError> {
+    /// Load the plate inventory from the database into app state.
+    pub async fn load_plates(state: &WorkoutState) -> Result<(), WorkoutError> {
         let db = state.database().ok_or(WorkoutError::NotInitialized)?;
-        db.update_settings(&settings)
+        let plates = db.get_plates().await.map_err(WorkoutError::Database)?;
+        state.set_plates(plates);
+        Ok(())
+    }
+
+    /// Insert or update a plate inventory row and refresh app state.
+    pub async fn save_plate(state: &WorkoutState, plate: Plate) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.save_plate(&plate)
             .await
             .map_err(WorkoutError::Database)?;
-        state.set_settings(settings);
+        Self::load_plates(state).await?;
 
-        // Auto-save the database file
         if let Err(e) = Self::save_database(state).await {
-            log::warn!("Auto-save after settings update failed: {}", e);
+            log::warn!("Auto-save after plate update failed: {}", e);
         }
 
         Ok(())
     }
 
-    // ── Workout Plan lifecycle ─────────────────────────────────────────────
+    /// Remove a plate inventory row and refresh app state.
+    pub async fn delete_plate(state: &WorkoutState, plate_id: &str) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.delete_plate(plate_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        Self::load_plates(state).await?;
 
-    pub async fn create_plan(state: &WorkoutState) -> Result<String, WorkoutError> {
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after plate removal failed: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Load the bodyweight log from the database into app state.
+    pub async fn load_bodyweight_log(state: &WorkoutState) -> Result<(), WorkoutError> {
         let db = state.database().ok_or(WorkoutError::NotInitialized)?;
-        let plan_id = db.create_plan().await.map_err(WorkoutError::Database)?;
-        let plan = db
-            .get_plan(&plan_id)
+        let entries = db
+            .get_bodyweight_log()
             .await
             .map_err(WorkoutError::Database)?;
-        state.set_current_plan(plan);
-        Ok(plan_id)
+        state.set_bodyweight_log(entries);
+        Ok(())
+    }
+
+    /// Insert or update a bodyweight entry and refresh app state.  The active
+    /// session picks up the new current bodyweight so the next loaded
+    /// bodyweight set uses it.
+    pub async fn save_bodyweight_entry(
+        state: &WorkoutState,
+        entry: BodyweightEntry,
+    ) -> Result<(), WorkoutError> {
+        crate::models::validate_bodyweight(entry.mass).map_err(|e| {
+            WorkoutError::InvalidBodyweight(e.describe(state.settings().weight_unit))
+        })?;
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.save_bodyweight_entry(&entry)
+            .await
+            .map_err(WorkoutError::Database)?;
+        Self::load_bodyweight_log(state).await?;
+        Self::refresh_session_bodyweight(state);
+
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after bodyweight update failed: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a bodyweight entry and refresh app state.
+    pub async fn delete_bodyweight_entry(
+        state: &WorkoutState,
+        entry_id: &str,
+    ) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.delete_bodyweight_entry(entry_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        Self::load_bodyweight_log(state).await?;
+        Self::refresh_session_bodyweight(state);
+
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after bodyweight removal failed: {}", e);
+        }
+
+        Ok(())
+    }
+
+    fn refresh_session_bodyweight(state: &WorkoutState) {
+        if let Some(mut session) = state.current_session() {
+            session.bodyweight = state.current_bodyweight();
+            state.set_current_session(Some(session));
+        }
+    }
+
+    /// Persist updated settings to the database and refresh app state.
+    pub async fn update_settings(
+        state: &WorkoutState,
+        settings: Settings,
+    ) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.update_settings(&settings)
+            .await
+            .map_err(WorkoutError::Database)?;
+        state.set_settings(settings);
+
+        // Auto-save the database file
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after settings update failed: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Refits the RPE Calibration to the Training Window's Sets: the
+    /// trainee's from every exercise pooled, and an exercise's own once it
+    /// has [`MIN_EXERCISE_SETS`](crate::domain::calibration::MIN_EXERCISE_SETS)
+    /// informative Sets.  Only changed exercise fits are written, so a
+    /// recalibration does not touch every exercise row for sync.
+    pub async fn calibrate_rpe(state: &WorkoutState) -> Result<(), WorkoutError> {
+        use crate::domain::calibration::{self, CalibrationSet, MIN_EXERCISE_SETS};
+
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let mut settings = state.settings();
+        let sessions = db
+            .get_calibration_sessions(settings.training_window_weeks.max(1) as u32)
+            .await
+            .map_err(WorkoutError::Database)?;
+
+        let mut by_exercise: HashMap<String, Vec<Vec<CalibrationSet>>> = HashMap::new();
+        for (exercise_id, sets) in &sessions {
+            by_exercise
+                .entry(exercise_id.clone())
+                .or_default()
+                .push(sets.clone());
+        }
+        let pooled: Vec<Vec<CalibrationSet>> = sessions.into_iter().map(|(_, sets)| sets).collect();
+        let fitted = calibration::fit(&pooled);
+        settings.rpe_calibration = (fitted.sets > 0).then_some(fitted);
+
+        let previous: HashMap<String, RpeCalibration> = db
+            .get_exercise_rpe_calibrations()
+            .await
+            .map_err(WorkoutError::Database)?
+            .into_iter()
+            .collect();
+        let exercise_ids: std::collections::BTreeSet<&String> =
+            previous.keys().chain(by_exercise.keys()).collect();
+        let mut current = HashMap::new();
+        for exercise_id in exercise_ids {
+            let own = by_exercise
+                .get(exercise_id)
+                .map(|sessions| calibration::fit(sessions))
+                .filter(|c| c.sets >= MIN_EXERCISE_SETS);
+            if own != previous.get(exercise_id).copied() {
+                db.set_exercise_rpe_calibration(exercise_id, own)
+                    .await
+                    .map_err(WorkoutError::Database)?;
+            }
+            current.insert(exercise_id.clone(), own);
+        }
+
+        if let Some(mut session) = state.current_session()
+            && let Some(id) = session.exercise.id.as_ref()
+        {
+            session.rpe_calibration = current.get(id).copied().flatten();
+            state.set_current_session(Some(session));
+        }
+
+        Self::update_settings(state, settings).await
+    }
+
+    // ── Workout Plan lifecycle ─────────────────────────────────────────────
+
+    pub async fn create_plan(state: &WorkoutState) -> Result<String, WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let plan_id = db.create_plan().await.map_err(WorkoutError::Database)?;
+        let plan = db
+            .get_plan(&plan_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        state.set_current_plan(plan);
+        Ok(plan_id)
     }
 
     pub async fn add_exercise_to_plan(
@@ -805,6 +1425,414 @@ impl WorkoutStateManager {
         Ok(())
     }
 
+    /// Joins the plan slot at `index` to the next one (superset / circuit),
+    /// or splits them apart when `link` is false.
+    pub async fn link_plan_slots(
+        state: &WorkoutState,
+        index: usize,
+        link: bool,
+    ) -> Result<(), WorkoutError> {
+        let plan = state.current_plan().ok_or(WorkoutError::NoActiveSession)?;
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let new_group_id = uuid::Uuid::new_v4().to_string();
+        let updates = if link {
+            crate::domain::slot_group::link_with_next(&plan.exercises, index, new_group_id)
+        } else {
+            crate::domain::slot_group::unlink_from_next(&plan.exercises, index, new_group_id)
+        };
+        db.set_plan_slot_groups(&updates)
+            .await
+            .map_err(WorkoutError::Database)?;
+        let refreshed = db
+            .get_plan(&plan.id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        state.set_current_plan(refreshed);
+        Ok(())
+    }
+
+    // ── Programs ───────────────────────────────────────────────────────────
+
+    /// Saves a new program. Auto-saves the database file on success.
+    pub async fn create_program(
+        state: &WorkoutState,
+        program: &Program,
+    ) -> Result<String, WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let id = db
+            .create_program(program)
+            .await
+            .map_err(WorkoutError::Database)?;
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after create_program failed: {}", e);
+        }
+        Ok(id)
+    }
+
+    /// Soft-deletes a program. Plans already materialized from it are kept.
+    pub async fn delete_program(
+        state: &WorkoutState,
+        program_id: &str,
+    ) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.delete_program(program_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after delete_program failed: {}", e);
+        }
+        Ok(())
+    }
+
+    /// The next workout of `program`, or `None` once the block is finished.
+    pub async fn next_program_workout(
+        state: &WorkoutState,
+        program: &Program,
+    ) -> Result<Option<ProgramWorkout>, WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let last_started = db
+            .last_started_program_index(&program.id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        Ok(crate::domain::program::next_workout(program, last_started))
+    }
+
+    /// Materializes the next workout of `program` as the Future Plan: loads
+    /// the day's template, reshapes its slots for the week's progression or
+    /// deload, and links the plan to the program.
+    pub async fn load_next_program_workout(
+        state: &WorkoutState,
+        program: &Program,
+    ) -> Result<(), WorkoutError> {
+        let workout = Self::next_program_workout(state, program)
+            .await?
+            .ok_or(WorkoutError::ProgramFinished)?;
+        let day = &program.days[workout.day];
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let plan_id = match state.current_plan() {
+            Some(plan) if plan.started_at.is_none() => plan.id,
+            _ => db.create_plan().await.map_err(WorkoutError::Database)?,
+        };
+
+        db.load_template_into_plan(&plan_id, &day.template_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        let loaded = db
+            .get_plan(&plan_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        let default_rpe = state.settings().target_rpe;
+        for slot in loaded.iter().flat_map(|p| p.exercises.iter()) {
+            let (planned_sets, targets) =
+                crate::domain::program::adjust_slot(program, &workout, slot, default_rpe);
+            if planned_sets != slot.planned_sets {
+                db.set_plan_slot_planned_sets(&slot.id, planned_sets)
+                    .await
+                    .map_err(WorkoutError::Database)?;
+            }
+            if targets != slot.targets {
+                db.set_plan_slot_targets(&slot.id, &targets)
+                    .await
+                    .map_err(WorkoutError::Database)?;
+            }
+        }
+        db.set_plan_program(
+            &plan_id,
+            &ProgramPosition {
+                program_id: program.id.clone(),
+                index: workout.index,
+            },
+        )
+        .await
+        .map_err(WorkoutError::Database)?;
+
+        let refreshed = db
+            .get_plan(&plan_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        state.set_current_plan(refreshed);
+        Ok(())
+    }
+
+    // ── Deload advisor ─────────────────────────────────────────────────────
+
+    /// Gathers the fatigue signals of every active exercise and muscle group
+    /// and asks [`crate::domain::deload::assess`] whether a deload is due.
+    pub async fn assess_deload(state: &WorkoutState) -> Result<DeloadAdvice, WorkoutError> {
+        use crate::domain::deload;
+
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let settings = state.settings();
+        let mut stalled = Vec::new();
+        let mut creep = Vec::new();
+        for exercise in state.exercises() {
+            let Some(id) = exercise.id.as_deref() else {
+                continue;
+            };
+            let progress = db
+                .get_progress_state(
+                    id,
+                    exercise.e1rm_formula_or(settings.e1rm_formula),
+                    &settings,
+                )
+                .await
+                .map_err(WorkoutError::Database)?;
+            if matches!(progress, crate::models::ProgressState::Stalled { .. }) {
+                stalled.push(exercise.name.clone());
+            }
+            let sets = db
+                .get_load_rpe_history(id, deload::RPE_CREEP_WINDOW_WEEKS)
+                .await
+                .map_err(WorkoutError::Database)?;
+            creep.push((exercise.name.clone(), deload::rpe_creep(&sets)));
+        }
+
+        let window = settings.training_window_weeks.max(0) as u32;
+        let first_set_at = db.first_set_at().await.map_err(WorkoutError::Database)?;
+        let now = js_sys::Date::now();
+        let mut volumes = Vec::new();
+        for group in MuscleGroup::ALL {
+            let volume = db
+                .get_muscle_group_volume(&group, window)
+                .await
+                .map_err(WorkoutError::Database)?;
+            let ratio = deload::volume_ratio(&volume, window, first_set_at, now);
+            volumes.push((group, ratio));
+        }
+
+        Ok(deload::assess(&stalled, &creep, &volumes))
+    }
+
+    /// Gathers the Analysis dashboard: Volume per Muscle Group, the Progress
+    /// State of every exercise and Training Days per week, all over the
+    /// Training Window.
+    pub async fn load_analysis(state: &WorkoutState) -> Result<AnalysisSnapshot, WorkoutError> {
+        use crate::domain::analysis::{self, ExerciseLink, ExerciseProgress, GroupVolume};
+
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let settings = state.settings();
+        let window = settings.training_window_weeks.max(1) as u32;
+
+        let mut progress = Vec::new();
+        for exercise in state.exercises() {
+            let Some(id) = exercise.id.clone() else {
+                continue;
+            };
+            let progress_state = db
+                .get_progress_state(
+                    &id,
+                    exercise.e1rm_formula_or(settings.e1rm_formula),
+                    &settings,
+                )
+                .await
+                .map_err(WorkoutError::Database)?;
+            progress.push(ExerciseProgress {
+                exercise: ExerciseLink {
+                    exercise_id: id,
+                    name: exercise.name.clone(),
+                },
+                state: progress_state,
+            });
+        }
+
+        let primary = db
+            .get_primary_muscle_groups_for_exercises()
+            .await
+            .map_err(WorkoutError::Database)?;
+        let mut volumes = Vec::new();
+        for group in MuscleGroup::ALL {
+            let volume = db
+                .get_muscle_group_volume(&group, window)
+                .await
+                .map_err(WorkoutError::Database)?;
+            if volume.rolling_training_period <= 0.0 {
+                continue;
+            }
+            let exercises = progress
+                .iter()
+                .filter(|p| {
+                    primary
+                        .get(&p.exercise.exercise_id)
+                        .is_some_and(|groups| groups.iter().any(|g| g.top_level() == group))
+                })
+                .map(|p| p.exercise.clone())
+                .collect();
+            let mut row = GroupVolume::new(group.clone(), &volume, window, exercises);
+            for sub in group.sub_muscles() {
+                let sub_volume = db
+                    .get_muscle_group_volume(&sub, 1)
+                    .await
+                    .map_err(WorkoutError::Database)?;
+                if sub_volume.rolling_7d > 0.0 {
+                    row.sub_muscles.push((sub, sub_volume.rolling_7d));
+                }
+            }
+            volumes.push(row);
+        }
+
+        let days = db
+            .get_training_days(window)
+            .await
+            .map_err(WorkoutError::Database)?;
+        let today = chrono::DateTime::from_timestamp_millis(js_sys::Date::now() as i64)
+            .map(|t| t.date_naive())
+            .unwrap_or_default();
+
+        Ok(AnalysisSnapshot {
+            volumes,
+            progress,
+            frequency: analysis::weekly_frequency(&days, today, window),
+        })
+    }
+
+    /// Each Muscle Group's Volume over the last 7 days with its Volume Zone
+    /// against the configured targets, in [`MuscleGroup::ALL`] order.
+    pub async fn weekly_volume(
+        state: &WorkoutState,
+    ) -> Result<Vec<(MuscleGroup, f64, VolumeZone)>, WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let targets = state.settings().volume_targets;
+        let mut weekly = Vec::new();
+        for group in MuscleGroup::ALL {
+            let volume = db
+                .get_muscle_group_volume(&group, 1)
+                .await
+                .map_err(WorkoutError::Database)?;
+            let zone = targets.zone(&group, volume.rolling_7d);
+            weekly.push((group, volume.rolling_7d, zone));
+        }
+        Ok(weekly)
+    }
+
+    /// Fills the unstarted plan (or a new one) with a Suggested Workout aimed
+    /// at this week's Volume deficits. Returns the number of slots added;
+    /// zero when every Muscle Group is at its MAV or recovering.
+    pub async fn suggest_workout(state: &WorkoutState) -> Result<usize, WorkoutError> {
+        use crate::domain::autoplan::{self, Candidate};
+
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let settings = state.settings();
+        let recent_since = js_sys::Date::now() - autoplan::RECOVERY_HOURS * 3600.0 * 1000.0;
+
+        let mut candidates = Vec::new();
+        let mut recent = Vec::new();
+        for exercise in state.exercises() {
+            let Some(id) = exercise.id.clone() else {
+                continue;
+            };
+            let muscle_groups = db
+                .get_muscle_groups(&id)
+                .await
+                .map_err(WorkoutError::Database)?;
+            let trained_recently = db
+                .get_latest_set_time(std::slice::from_ref(&id), recent_since)
+                .await
+                .map_err(WorkoutError::Database)?
+                .is_some();
+            recent.push(trained_recently);
+            candidates.push(Candidate {
+                exercise_id: id,
+                muscle_groups,
+            });
+        }
+        let recent: Vec<&Candidate> = candidates
+            .iter()
+            .zip(recent)
+            .filter_map(|(c, r)| r.then_some(c))
+            .collect();
+        let recovering = autoplan::recovering(&recent);
+
+        let weekly: Vec<(MuscleGroup, f64)> = Self::weekly_volume(state)
+            .await?
+            .into_iter()
+            .map(|(group, volume, _)| (group, volume))
+            .collect();
+        let deficits = autoplan::deficits(&weekly, &settings.volume_targets, &recovering);
+        let slots = autoplan::suggest(&deficits, &candidates, &recovering, settings.target_rpe);
+        if slots.is_empty() {
+            return Ok(0);
+        }
+
+        if state.current_plan().is_none_or(|p| p.started_at.is_some()) {
+            Self::create_plan(state).await?;
+        }
+        for slot in &slots {
+            Self::add_exercise_to_plan(state, &slot.exercise_id, slot.planned_sets).await?;
+        }
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after suggest_workout failed: {}", e);
+        }
+        Ok(slots.len())
+    }
+
+    /// Turns the Future Plan into a deload: every slot gets half its planned
+    /// sets at a lower target RPE. With `template_id` the template is loaded
+    /// first, into the unstarted plan or a new one.
+    pub async fn build_deload_plan(
+        state: &WorkoutState,
+        template_id: Option<&str>,
+    ) -> Result<(), WorkoutError> {
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        let unstarted = state.current_plan().filter(|p| p.started_at.is_none());
+        let plan_id = match (unstarted, template_id) {
+            (Some(plan), _) => plan.id,
+            (None, Some(_)) => db.create_plan().await.map_err(WorkoutError::Database)?,
+            (None, None) => return Err(WorkoutError::NoActiveSession),
+        };
+        if let Some(template_id) = template_id {
+            db.load_template_into_plan(&plan_id, template_id)
+                .await
+                .map_err(WorkoutError::Database)?;
+        }
+
+        let loaded = db
+            .get_plan(&plan_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        let default_rpe = state.settings().target_rpe;
+        for slot in loaded.iter().flat_map(|p| p.exercises.iter()) {
+            let base_rpe = slot.targets.target_rpe_or(default_rpe);
+            let (planned_sets, targets) = crate::domain::deload::deload_slot(slot, base_rpe);
+            if planned_sets != slot.planned_sets {
+                db.set_plan_slot_planned_sets(&slot.id, planned_sets)
+                    .await
+                    .map_err(WorkoutError::Database)?;
+            }
+            db.set_plan_slot_targets(&slot.id, &targets)
+                .await
+                .map_err(WorkoutError::Database)?;
+        }
+
+        let refreshed = db
+            .get_plan(&plan_id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        state.set_current_plan(refreshed);
+        if let Err(e) = Self::save_database(state).await {
+            log::warn!("Auto-save after build_deload_plan failed: {}", e);
+        }
+        Ok(())
+    }
+
+    /// Stores the Rep Range and target RPE overrides of a plan slot.
+    pub async fn set_plan_slot_targets(
+        state: &WorkoutState,
+        plan_exercise_id: &str,
+        targets: SlotTargets,
+    ) -> Result<(), WorkoutError> {
+        let plan = state.current_plan().ok_or(WorkoutError::NoActiveSession)?;
+        let db = state.database().ok_or(WorkoutError::NotInitialized)?;
+        db.set_plan_slot_targets(plan_exercise_id, &targets)
+            .await
+            .map_err(WorkoutError::Database)?;
+        let refreshed = db
+            .get_plan(&plan.id)
+            .await
+            .map_err(WorkoutError::Database)?;
+        state.set_current_plan(refreshed);
+        Ok(())
+    }
+
     pub async fn remove_exercise_from_plan(
         state: &WorkoutState,
         plan_exercise_id: &str,
@@ -834,17 +1862,14 @@ impl WorkoutStateManager {
             .map_err(WorkoutError::Database)?;
         // Auto-start a session on the first planned exercise so the user
         // lands directly on the recording UI instead of an empty state.
-        let first_exercise = refreshed
-            .as_ref()
-            .and_then(|p| p.exercises.first())
-            .map(|pe| pe.exercise.clone());
+        let first_slot = refreshed.as_ref().and_then(|p| p.exercises.first());
         // IMPORTANT: start the session BEFORE updating the plan signal.
         // Updating the plan signal triggers a re-render which unmounts
         // PlanBuilder (where this spawn lives). Dioxus drops spawned tasks
         // when the owning component unmounts, so any awaits after
         // set_current_plan would be cancelled.
-        if let Some(exercise) = first_exercise {
-            Self::start_session(state, exercise).await?;
+        if let Some(slot) = first_slot {
+            Self::start_slot_session(state, slot).await?;
         }
         state.set_current_plan(refreshed);
         Ok(())
@@ -912,6 +1937,7 @@ impl WorkoutStateManager {
             .map_err(WorkoutError::Database)?;
         state.set_current_plan(None);
         state.set_current_session(None);
+        state.set_rest_timer(None);
         Ok(())
     }
 
@@ -932,6 +1958,7 @@ impl WorkoutStateManager {
             .map_err(WorkoutError::Database)?;
         state.set_current_plan(refreshed);
         state.set_current_session(None);
+        state.set_rest_timer(None);
         Ok(())
     }
 
@@ -992,10 +2019,155 @@ impl WorkoutStateManager {
         Ok(())
     }
 
+    /// Snap a predicted weight to the nearest load buildable from the bar and
+    /// plate inventory.
+    ///
+    /// Only applies to plate-loaded exercises (`bar_weight` set) with a
+    /// non-empty inventory; candidate loads must also pass `validate_weight`
+    /// so the suggestion can always be logged as-is.  Returns `predicted`
+    /// unchanged when no buildable load qualifies.
+    fn snap_to_plates(
+        mut predicted: PredictedParameters,
+        exercise: &ExerciseMetadata,
+        plates: &[Plate],
+    ) -> PredictedParameters {
+        let crate::models::SetTypeConfig::Weighted {
+            min_weight,
+            increment,
+            bar_weight: Some(bar),
+        } = exercise.set_type_config
+        else {
+            return predicted;
+        };
+        let Some(weight) = predicted.weight else {
+            return predicted;
+        };
+        if plates.is_empty() {
+            return predicted;
+        }
+
+        let accept =
+            |w: f64| crate::models::validate_weight(w as f32, min_weight, increment).is_ok();
+        if let Some(load) =
+            crate::domain::plates::nearest_load(weight as f64, bar as f64, plates, accept)
+        {
+            predicted.weight = Some(load.total as f32);
+        }
+        predicted
+    }
+
+    /// The model Suggestions project with: the RPE Calibration — the
+    /// exercise's own, else the trainee's — once the trainee opted in,
+    /// otherwise the exercise's e1RM Formula.
+    fn suggestion_model(
+        exercise: &ExerciseMetadata,
+        rpe_calibration: Option<RpeCalibration>,
+        settings: &Settings,
+    ) -> SuggestionModel {
+        SuggestionModel::new(
+            exercise.e1rm_formula_or(settings.e1rm_formula),
+            rpe_calibration.or(settings.rpe_calibration),
+            settings.use_rpe_calibration,
+        )
+    }
+
+    /// Blended e1RM from the System Load of the `today_best` and
+    /// `historical_best` sets, or `None` when neither is available.
+    fn blended_weighted_e1rm(
+        historical_best: Option<&CompletedSet>,
+        today_best: Option<&CompletedSet>,
+        settings: &Settings,
+        model: SuggestionModel,
+    ) -> Option<f64> {
+        use crate::domain::e1rm::{E1rmModel, blended_e1rm};
+
+        let set_e1rm = |s: &CompletedSet| {
+            s.set_type
+                .system_load()
+                .map(|load| model.e1rm(load as f64, s.reps, s.rpe as f64))
+        };
+        match (
+            today_best.and_then(set_e1rm),
+            historical_best.and_then(set_e1rm),
+        ) {
+            (None, None) => None,
+            (Some(t), Some(h)) => Some(blended_e1rm(t, h, settings.today_blend_factor)),
+            (Some(t), None) => Some(t),
+            (None, Some(h)) => Some(h),
+        }
+    }
+
+    /// Cold-start Suggestion from the last set, if any.  Slot Targets clamp
+    /// the suggested reps into their Rep Range and replace the default RPE.
     fn calculate_initial_predictions(
         exercise: &ExerciseMetadata,
         last_set: Option<&CompletedSet>,
         default_bodyweight_reps: u32,
+        targets: &SlotTargets,
+    ) -> PredictedParameters {
+        let mut predicted = Self::default_predictions(exercise, last_set, default_bodyweight_reps);
+        if let Some((min, max)) = targets.rep_range
+            && predicted.measure.is_none()
+        {
+            (predicted.reps, _) = clamp_reps(predicted.reps, min as u32, max.map(|m| m as u32));
+        }
+        if let Some(rpe) = targets.target_rpe {
+            predicted.rpe = rpe as f32;
+        }
+        predicted
+    }
+
+    /// Replaces the weight and reps of `predicted` with the exercise's
+    /// Progression Strategy: Linear and Double from the working sets of
+    /// `previous_session`, Percentage of e1RM from `e1rm`.  Autoregulated
+    /// and non-weighted exercises, and rules missing their input, keep
+    /// `predicted` as it is.
+    fn apply_progression(
+        mut predicted: PredictedParameters,
+        exercise: &ExerciseMetadata,
+        targets: &SlotTargets,
+        previous_session: &[CompletedSet],
+        e1rm: Option<f64>,
+    ) -> PredictedParameters {
+        use crate::domain::progression::{self, Prescription};
+
+        let config = &exercise.set_type_config;
+        let crate::models::SetTypeConfig::Weighted { increment, .. } = *config else {
+            return predicted;
+        };
+        let (min_reps, max_reps) = targets.rep_range_for(exercise);
+        let min_reps = min_reps as u32;
+        let max_reps = max_reps.map(|v| v as u32);
+        let last_session: Vec<(f64, u32)> = previous_session
+            .iter()
+            .filter_map(|s| s.set_type.weight().map(|w| (w as f64, s.reps)))
+            .collect();
+
+        let prescription = match exercise.progression {
+            ProgressionStrategy::Autoregulated => None,
+            ProgressionStrategy::Linear => {
+                progression::linear(&last_session, min_reps, increment as f64)
+            }
+            ProgressionStrategy::Double => {
+                progression::double(&last_session, min_reps, max_reps, increment as f64)
+            }
+            ProgressionStrategy::PercentOfE1rm(pct) => e1rm.map(|e1rm| Prescription {
+                weight: progression::percent_of_e1rm(e1rm, pct),
+                reps: min_reps,
+            }),
+        };
+        if let Some(prescription) = prescription {
+            predicted.weight = Some(config.snap_weight(prescription.weight) as f32);
+            predicted.reps = prescription.reps;
+            predicted.reps_clamped = false;
+        }
+        predicted
+    }
+
+    fn default_predictions(
+        exercise: &ExerciseMetadata,
+        last_set: Option<&CompletedSet>,
+        default_bodyweight_reps: u32,
     ) -> PredictedParameters {
         match &exercise.set_type_config {
             crate::models::SetTypeConfig::Weighted { min_weight, .. } => {
@@ -1014,6 +2186,24 @@ impl WorkoutStateManager {
                     reps: DEFAULT_WEIGHTED_REPS,
                     rpe: DEFAULT_RPE,
                     reps_clamped: false,
+                    measure: None,
+                }
+            }
+            crate::models::SetTypeConfig::LoadedBodyweight { .. } => {
+                // The dial shows the magnitude; assistance is signed on logging.
+                let weight = last_set
+                    .and_then(|ls| match ls.set_type {
+                        SetType::LoadedBodyweight { added, .. } => Some(added.abs()),
+                        _ => None,
+                    })
+                    .unwrap_or(0.0);
+
+                PredictedParameters {
+                    weight: Some(weight),
+                    reps: DEFAULT_WEIGHTED_REPS,
+                    rpe: DEFAULT_RPE,
+                    reps_clamped: false,
+                    measure: None,
                 }
             }
             crate::models::SetTypeConfig::Bodyweight => PredictedParameters {
@@ -1021,7 +2211,33 @@ impl WorkoutStateManager {
                 reps: default_bodyweight_reps,
                 rpe: DEFAULT_RPE,
                 reps_clamped: false,
+                measure: None,
             },
+            config => {
+                // Repeat the last set's load and measure; with no history start
+                // a carry at its minimum weight and any measured set at
+                // DEFAULT_MEASURE_STEPS increments.
+                let increment = config.measure_increment().unwrap_or(1.0);
+                let weight = match config {
+                    crate::models::SetTypeConfig::Carry { min_weight, .. } => Some(
+                        last_set
+                            .and_then(|ls| ls.set_type.weight())
+                            .unwrap_or(*min_weight),
+                    ),
+                    _ => None,
+                };
+                let measure = last_set
+                    .and_then(|ls| ls.set_type.measure())
+                    .unwrap_or(DEFAULT_MEASURE_STEPS as f64 * increment);
+
+                PredictedParameters {
+                    weight,
+                    reps: 1,
+                    rpe: DEFAULT_RPE,
+                    reps_clamped: false,
+                    measure: Some(measure),
+                }
+            }
         }
     }
 
@@ -1038,12 +2254,30 @@ impl WorkoutStateManager {
     /// 4. Select the rep count with the highest positive margin, falling back to
     ///    the rep count with the least-negative margin when none are positive.
     /// 5. Clamp to `[min_reps, max_reps]`.
+    /// 6. Snap the weight to the exercise's `min_weight + n × increment` grid.
+    ///
+    /// ### Loaded bodyweight path
+    /// The weighted path run on System Load, after which the projected load is
+    /// turned back into added or assisting weight against the session's
+    /// bodyweight (`domain::bodyweight::weight_for_load`).
     ///
     /// ### Bodyweight path
     /// `failure_reps = set.reps + (10 - set.rpe)`.  Blend today and historical
     /// failure_reps, subtract the RIR implied by `target_rpe`, round to nearest
     /// integer, and clamp.
     ///
+    /// ### Timed / distance / carry path
+    /// The same shape in seconds or metres: blend today and historical Failure
+    /// Measure (`domain::reserve`), subtract the reserve implied by
+    /// `target_rpe` and snap to the exercise's measure increment.  A carry
+    /// keeps the weight of the latest set.
+    ///
+    /// ### Progression Strategies
+    /// Weighted exercises on Linear or Double progression hold the
+    /// prescription set at session start for every working set.  Percentage
+    /// of e1RM takes that percentage of the Blended e1RM for the bottom of
+    /// the rep range (`apply_progression`).
+    ///
     /// ### No-data fallback
     /// When neither `today_best` nor `historical_best` are present, delegate to
     /// `calculate_initial_predictions`.
@@ -1054,45 +2288,64 @@ impl WorkoutStateManager {
         per_rep_maxes: HashMap<u32, f64>,
         settings: &Settings,
     ) -> PredictedParameters {
-        use crate::domain::e1rm::{blended_e1rm, e1rm, predicted_weight};
+        use crate::domain::e1rm::{E1rmModel, blended_e1rm};
 
         let exercise = &session.exercise;
-        let min_reps = exercise.min_reps as u32;
-        let max_reps = exercise.max_reps.map(|v| v as u32);
-        let target_rpe = settings.target_rpe;
-
-        match &exercise.set_type_config {
-            crate::models::SetTypeConfig::Weighted { .. } => {
-                // Compute e1RM from today_best and historical_best (weighted sets only).
-                let today_e1rm: Option<f64> = today_best.as_ref().and_then(|s| {
-                    if let SetType::Weighted { weight } = s.set_type {
-                        Some(e1rm(weight as f64, s.reps, s.rpe as f64))
-                    } else {
-                        None
-                    }
-                });
-
-                let hist_e1rm: Option<f64> = historical_best.as_ref().and_then(|s| {
-                    if let SetType::Weighted { weight } = s.set_type {
-                        Some(e1rm(weight as f64, s.reps, s.rpe as f64))
-                    } else {
-                        None
-                    }
-                });
-
-                // No history at all → fall back to initial predictions.
-                let blended = match (today_e1rm, hist_e1rm) {
-                    (None, None) => {
-                        let last_session_set = session.completed_sets.last();
-                        return Self::calculate_initial_predictions(
+        let model = Self::suggestion_model(exercise, session.rpe_calibration, settings);
+        let (min_reps, max_reps) = session.targets.rep_range_for(exercise);
+        let min_reps = min_reps as u32;
+        let max_reps = max_reps.map(|v| v as u32);
+        let target_rpe = session.targets.target_rpe_or(settings.target_rpe);
+
+        if let crate::models::SetTypeConfig::Weighted { .. } = exercise.set_type_config {
+            match exercise.progression {
+                ProgressionStrategy::Autoregulated => {}
+                ProgressionStrategy::Linear | ProgressionStrategy::Double => {
+                    return session.predicted;
+                }
+                ProgressionStrategy::PercentOfE1rm(_) => {
+                    if let Some(blended) = Self::blended_weighted_e1rm(
+                        historical_best.as_ref(),
+                        today_best.as_ref(),
+                        settings,
+                        model,
+                    ) {
+                        let predicted = PredictedParameters {
+                            weight: None,
+                            reps: min_reps,
+                            rpe: target_rpe as f32,
+                            reps_clamped: false,
+                            measure: None,
+                        };
+                        return Self::apply_progression(
+                            predicted,
                             exercise,
-                            last_session_set,
-                            settings.default_bodyweight_reps,
+                            &session.targets,
+                            &[],
+                            Some(blended),
                         );
                     }
-                    (Some(t), Some(h)) => blended_e1rm(t, h, settings.today_blend_factor),
-                    (Some(t), None) => t,
-                    (None, Some(h)) => h,
+                }
+            }
+        }
+
+        match &exercise.set_type_config {
+            config @ (crate::models::SetTypeConfig::Weighted { .. }
+            | crate::models::SetTypeConfig::LoadedBodyweight { .. }) => {
+                // No history at all → fall back to initial predictions.
+                let Some(blended) = Self::blended_weighted_e1rm(
+                    historical_best.as_ref(),
+                    today_best.as_ref(),
+                    settings,
+                    model,
+                ) else {
+                    let last_session_set = session.completed_sets.last();
+                    return Self::calculate_initial_predictions(
+                        exercise,
+                        last_session_set,
+                        settings.default_bodyweight_reps,
+                        &session.targets,
+                    );
                 };
 
                 // Build the rep search range.
@@ -1112,7 +2365,7 @@ impl WorkoutStateManager {
                 let mut best_margin: Option<f64> = None;
 
                 for r in min_reps..=upper {
-                    let proj = predicted_weight(blended, r, target_rpe);
+                    let proj = model.predicted_weight(blended, r, target_rpe);
                     // historical_max[r] = max weight across all sets where reps_done >= r
                     // (already folded in get_max_weight_per_rep).
                     let hist_max = per_rep_maxes.get(&r).copied();
@@ -1165,14 +2418,38 @@ impl WorkoutStateManager {
                 let raw_reps = best_rep.unwrap_or(min_reps);
                 let (clamped_reps, reps_clamped) = clamp_reps(raw_reps, min_reps, max_reps);
 
-                // Compute the weight for the chosen rep count.
-                let weight = predicted_weight(blended, clamped_reps, target_rpe) as f32;
+                // Compute the weight for the chosen rep count, snapped to a load
+                // the lifter can actually build on this exercise.
+                let load = model.predicted_weight(blended, clamped_reps, target_rpe);
+                let weight = match *config {
+                    crate::models::SetTypeConfig::LoadedBodyweight {
+                        increment,
+                        assisted,
+                    } => {
+                        let Some(bodyweight) = session.bodyweight else {
+                            return Self::calculate_initial_predictions(
+                                exercise,
+                                session.completed_sets.last(),
+                                settings.default_bodyweight_reps,
+                                &session.targets,
+                            );
+                        };
+                        crate::domain::bodyweight::weight_for_load(
+                            load,
+                            bodyweight as f64,
+                            assisted,
+                            increment as f64,
+                        ) as f32
+                    }
+                    _ => config.snap_weight(load) as f32,
+                };
 
                 PredictedParameters {
                     weight: Some(weight),
                     reps: clamped_reps,
                     rpe: target_rpe as f32,
                     reps_clamped,
+                    measure: None,
                 }
             }
 
@@ -1202,6 +2479,7 @@ impl WorkoutStateManager {
                             exercise,
                             last_session_set,
                             settings.default_bodyweight_reps,
+                            &session.targets,
                         );
                     }
                     (Some(t), Some(h)) => blended_e1rm(t, h, settings.today_blend_factor),
@@ -1225,6 +2503,57 @@ impl WorkoutStateManager {
                     reps: clamped_reps,
                     rpe: target_rpe as f32,
                     reps_clamped,
+                    measure: None,
+                }
+            }
+
+            config => {
+                use crate::domain::reserve::{failure_measure, suggested_measure};
+
+                let increment = config.measure_increment().unwrap_or(1.0);
+                let set_failure = |s: &CompletedSet| {
+                    s.set_type
+                        .measure()
+                        .map(|m| failure_measure(m, s.rpe as f64, increment))
+                };
+
+                // No history → fall back.
+                let blended = match (
+                    today_best.as_ref().and_then(set_failure),
+                    historical_best.as_ref().and_then(set_failure),
+                ) {
+                    (None, None) => {
+                        let last_session_set = session.completed_sets.last();
+                        return Self::calculate_initial_predictions(
+                            exercise,
+                            last_session_set,
+                            settings.default_bodyweight_reps,
+                            &session.targets,
+                        );
+                    }
+                    (Some(t), Some(h)) => blended_e1rm(t, h, settings.today_blend_factor),
+                    (Some(t), None) => t,
+                    (None, Some(h)) => h,
+                };
+
+                let weight = match config {
+                    crate::models::SetTypeConfig::Carry { min_weight, .. } => Some(
+                        session
+                            .completed_sets
+                            .last()
+                            .or(today_best.as_ref())
+                            .and_then(|s| s.set_type.weight())
+                            .unwrap_or(*min_weight),
+                    ),
+                    _ => None,
+                };
+
+                PredictedParameters {
+                    weight,
+                    reps: 1,
+                    rpe: target_rpe as f32,
+                    reps_clamped: false,
+                    measure: Some(suggested_measure(blended, target_rpe, increment)),
                 }
             }
         }
@@ -1260,6 +2589,14 @@ impl WorkoutStateManager {
             js_log(&format!("[UI] sync_exercises warning: {}", e));
         }
 
+        if let Err(e) = Self::load_plates(state).await {
+            js_log(&format!("[UI] load_plates warning: {}", e));
+        }
+
+        if let Err(e) = Self::load_bodyweight_log(state).await {
+            js_log(&format!("[UI] load_bodyweight_log warning: {}", e));
+        }
+
         if let Err(e) = Self::resume_active_plan(state).await {
             js_log(&format!("[UI] resume_active_plan warning: {}", e));
         }
@@ -1358,6 +2695,11 @@ mod tests {
                 set_type_config: SetTypeConfig::Bodyweight,
                 min_reps: 1,
                 max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
             },
             completed_sets: Vec::new(),
             predicted: PredictedParameters {
@@ -1365,7 +2707,12 @@ mod tests {
                 reps: 10,
                 rpe: 7.0,
                 reps_clamped: false,
+                measure: None,
             },
+            warmups: Vec::new(),
+            bodyweight: None,
+            targets: Default::default(),
+            rpe_calibration: None,
         }
     }
 
@@ -1403,12 +2750,23 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
-        let predicted = WorkoutStateManager::calculate_initial_predictions(&exercise, None, 10);
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &exercise,
+            None,
+            10,
+            &SlotTargets::default(),
+        );
 
         assert_eq!(predicted.weight, Some(0.0));
         assert_eq!(predicted.reps, 8);
@@ -1423,9 +2781,15 @@ mod tests {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
         let last_set = CompletedSet {
@@ -1433,10 +2797,16 @@ mod tests {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         };
 
-        let predicted =
-            WorkoutStateManager::calculate_initial_predictions(&exercise, Some(&last_set), 10);
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &exercise,
+            Some(&last_set),
+            10,
+            &SlotTargets::default(),
+        );
 
         assert_eq!(predicted.weight, Some(100.0));
         assert_eq!(predicted.reps, 8);
@@ -1451,9 +2821,19 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
-        let predicted = WorkoutStateManager::calculate_initial_predictions(&exercise, None, 10);
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &exercise,
+            None,
+            10,
+            &SlotTargets::default(),
+        );
 
         assert_eq!(predicted.weight, None);
         assert_eq!(predicted.reps, 10);
@@ -1468,9 +2848,19 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
 
-        let predicted = WorkoutStateManager::calculate_initial_predictions(&exercise, None, 15);
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &exercise,
+            None,
+            15,
+            &SlotTargets::default(),
+        );
 
         assert_eq!(predicted.weight, None);
         assert_eq!(predicted.reps, 15);
@@ -1492,22 +2882,35 @@ mod tests {
                 set_type_config: SetTypeConfig::Weighted {
                     min_weight: 0.0,
                     increment: 2.5,
+                    bar_weight: None,
                 },
                 min_reps,
                 max_reps,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
             },
             completed_sets: vec![CompletedSet {
                 set_number: 1,
                 reps: 5,
                 rpe: 8.0,
                 set_type: SetType::Weighted { weight: 100.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             }],
             predicted: PredictedParameters {
                 weight: Some(100.0),
                 reps: 5,
                 rpe: 8.0,
                 reps_clamped: false,
+                measure: None,
             },
+            warmups: Vec::new(),
+            bodyweight: None,
+            targets: Default::default(),
+            rpe_calibration: None,
         }
     }
 
@@ -1520,19 +2923,31 @@ mod tests {
                 set_type_config: SetTypeConfig::Bodyweight,
                 min_reps,
                 max_reps,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
             },
             completed_sets: vec![CompletedSet {
                 set_number: 1,
                 reps: 10,
                 rpe: 8.0,
                 set_type: SetType::Bodyweight,
+                kind: SetKind::Working,
+                comment: String::new(),
             }],
             predicted: PredictedParameters {
                 weight: None,
                 reps: 10,
                 rpe: 8.0,
                 reps_clamped: false,
+                measure: None,
             },
+            warmups: Vec::new(),
+            bodyweight: None,
+            targets: Default::default(),
+            rpe_calibration: None,
         }
     }
 
@@ -1558,6 +2973,59 @@ mod tests {
         assert!(!predicted.reps_clamped);
     }
 
+    // ── RPE Calibration ──────────────────────────────────────────────────────
+
+    /// Suggestions follow the RPE Calibration only once the trainee opts
+    /// in, and an exercise's own calibration beats the trainee's.
+    #[test]
+    fn test_next_predictions_use_rpe_calibration_when_opted_in() {
+        let best = Some(CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        });
+        let flat = RpeCalibration {
+            rep_decay: 0.99,
+            ..RpeCalibration::POPULATION
+        };
+        let steep = RpeCalibration {
+            rep_decay: 0.95,
+            ..RpeCalibration::POPULATION
+        };
+        let predict = |session: &WorkoutSession, settings: &Settings| {
+            WorkoutStateManager::calculate_next_predictions(
+                session,
+                best.clone(),
+                best.clone(),
+                HashMap::new(),
+                settings,
+            )
+            .weight
+            .unwrap()
+        };
+        let session = weighted_session(10, Some(10));
+        let population = predict(&session, &default_settings());
+
+        let mut settings = Settings {
+            rpe_calibration: Some(flat),
+            ..default_settings()
+        };
+        assert_eq!(predict(&session, &settings), population);
+
+        settings.use_rpe_calibration = true;
+        let trainee = predict(&session, &settings);
+        assert!(trainee > population);
+
+        let own = WorkoutSession {
+            rpe_calibration: Some(steep),
+            ..session.clone()
+        };
+        assert!(predict(&own, &settings) < population);
+    }
+
     // ── QA: Weighted path — positive-margin selection ────────────────────────
 
     /// After a new personal-best weight set, the projected weight for at least one
@@ -1570,6 +3038,8 @@ mod tests {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 120.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         });
         // historical_best: 100kg for 5 reps @ RPE 8 (previous best)
         let historical_best = Some(CompletedSet {
@@ -1577,6 +3047,8 @@ mod tests {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         });
 
         let settings = Settings {
@@ -1617,6 +3089,8 @@ mod tests {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         });
 
         let settings = Settings {
@@ -1658,6 +3132,8 @@ mod tests {
             reps: 10,
             rpe: 8.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         });
         // No historical best.
         let settings = Settings {
@@ -1666,20 +3142,79 @@ mod tests {
             ..Settings::default()
         };
 
-        let session = bodyweight_session(1, None);
+        let session = bodyweight_session(1, None);
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            None,
+            today_best,
+            HashMap::new(),
+            &settings,
+        );
+
+        // failure_reps = 12, target_rpe=8 → rir_at_target=2
+        // suggested = round(12 - 2) = 10
+        assert_eq!(predicted.reps, 10);
+        assert_eq!(predicted.weight, None);
+        assert!(!predicted.reps_clamped);
+    }
+
+    /// Slot Targets replace the exercise's Rep Range and the global target RPE.
+    #[test]
+    fn test_next_predictions_bodyweight_honours_slot_targets() {
+        let today_best = Some(CompletedSet {
+            set_number: 1,
+            reps: 10,
+            rpe: 8.0,
+            set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
+        });
+        let settings = Settings {
+            target_rpe: 8.0,
+            today_blend_factor: 1.0,
+            ..Settings::default()
+        };
+        let session = WorkoutSession {
+            targets: SlotTargets {
+                rep_range: Some((3, Some(6))),
+                target_rpe: Some(9.0),
+            },
+            ..bodyweight_session(1, None)
+        };
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            None,
+            today_best,
+            HashMap::new(),
+            &settings,
+        );
+
+        // failure_reps = 12, slot RPE 9 → 11 reps, clamped to the slot's 6.
+        assert_eq!(predicted.reps, 6);
+        assert!(predicted.reps_clamped);
+        assert_eq!(predicted.rpe, 9.0);
+    }
+
+    /// With no history the fallback still lands inside the slot's Rep Range.
+    #[test]
+    fn test_next_predictions_no_data_fallback_honours_slot_targets() {
+        let session = WorkoutSession {
+            targets: SlotTargets {
+                rep_range: Some((3, Some(5))),
+                target_rpe: Some(9.0),
+            },
+            ..weighted_session(1, None)
+        };
         let predicted = WorkoutStateManager::calculate_next_predictions(
             &session,
             None,
-            today_best,
+            None,
             HashMap::new(),
-            &settings,
+            &default_settings(),
         );
-
-        // failure_reps = 12, target_rpe=8 → rir_at_target=2
-        // suggested = round(12 - 2) = 10
-        assert_eq!(predicted.reps, 10);
-        assert_eq!(predicted.weight, None);
-        assert!(!predicted.reps_clamped);
+        assert_eq!(predicted.weight, Some(100.0));
+        assert_eq!(predicted.reps, 5);
+        assert_eq!(predicted.rpe, 9.0);
     }
 
     /// A higher RPE on the logged set (harder) → higher failure_reps → more reps suggested.
@@ -1691,6 +3226,8 @@ mod tests {
                 reps: 10,
                 rpe,
                 set_type: SetType::Bodyweight,
+                kind: SetKind::Working,
+                comment: String::new(),
             });
             let settings = Settings {
                 target_rpe: 8.0,
@@ -1741,6 +3278,8 @@ mod tests {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         });
 
         let settings = Settings {
@@ -1757,22 +3296,35 @@ mod tests {
                 set_type_config: SetTypeConfig::Weighted {
                     min_weight: 0.0,
                     increment: 2.5,
+                    bar_weight: None,
                 },
                 min_reps: 5,
                 max_reps: Some(5),
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
             },
             completed_sets: vec![CompletedSet {
                 set_number: 1,
                 reps: 5,
                 rpe: 8.0,
                 set_type: SetType::Weighted { weight: 100.0 },
+                kind: SetKind::Working,
+                comment: String::new(),
             }],
             predicted: PredictedParameters {
                 weight: Some(100.0),
                 reps: 5,
                 rpe: 8.0,
                 reps_clamped: false,
+                measure: None,
             },
+            warmups: Vec::new(),
+            bodyweight: None,
+            targets: Default::default(),
+            rpe_calibration: None,
         };
 
         let predicted = WorkoutStateManager::calculate_next_predictions(
@@ -1803,6 +3355,8 @@ mod tests {
             reps: 20,
             rpe: 10.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         });
         let settings = Settings {
             target_rpe: 8.0,
@@ -1832,6 +3386,8 @@ mod tests {
             reps: 10,
             rpe: 8.0,
             set_type: SetType::Bodyweight,
+            kind: SetKind::Working,
+            comment: String::new(),
         });
         let settings = Settings {
             target_rpe: 8.0,
@@ -1864,6 +3420,8 @@ mod tests {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 120.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         });
         // historical_best: 100kg for 5 reps @ RPE 8 (lower)
         let historical_best = Some(CompletedSet {
@@ -1871,6 +3429,8 @@ mod tests {
             reps: 5,
             rpe: 8.0,
             set_type: SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         });
 
         // With factor=1.0 (today only) projected weight is higher than factor=0.0.
@@ -1911,6 +3471,321 @@ mod tests {
         );
     }
 
+    // ── QA: Suggestion snapping in the display unit ──────────────────────────
+
+    /// Kilogram users get suggestions on the exercise's 2.5 kg grid.
+    #[test]
+    fn test_next_predictions_weighted_snaps_to_kg_increment() {
+        let best = Some(CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight: 101.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        });
+        let session = weighted_session(5, Some(5));
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            best.clone(),
+            best,
+            HashMap::new(),
+            &default_settings(),
+        );
+        let w = predicted.weight.unwrap() as f64;
+        let steps = w / 2.5;
+        assert!(
+            (steps - steps.round()).abs() < 1e-4,
+            "{w} is not on the 2.5 kg grid"
+        );
+    }
+
+    /// An exercise captured in pounds (45 lb bar, 5 lb steps) gets suggestions
+    /// on whole 5 lb loads, while the returned weight stays canonical kg.
+    #[test]
+    fn test_next_predictions_weighted_snaps_to_lb_increment() {
+        use crate::models::WeightUnit;
+
+        let unit = WeightUnit::Lb;
+        let mut session = weighted_session(5, Some(5));
+        session.exercise.set_type_config = SetTypeConfig::Weighted {
+            min_weight: unit.to_kg(45.0) as f32,
+            increment: unit.to_kg(5.0) as f32,
+            bar_weight: None,
+        };
+        let best = Some(CompletedSet {
+            set_number: 1,
+            reps: 5,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight: 101.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        });
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            best.clone(),
+            best,
+            HashMap::new(),
+            &default_settings(),
+        );
+        let lb = unit.from_kg(predicted.weight.unwrap() as f64);
+        let steps = (lb - 45.0) / 5.0;
+        assert!(
+            (steps - steps.round()).abs() < 1e-3,
+            "{lb} lb is not on the 5 lb grid"
+        );
+        // Sanity: roughly the same load as the ~101 kg input, not a pound value.
+        assert!((predicted.weight.unwrap() - 101.0).abs() < 5.0);
+    }
+
+    // ── Progression Strategies ───────────────────────────────────────────────
+
+    fn working_set(weight: f32, reps: u32) -> CompletedSet {
+        CompletedSet {
+            set_number: 1,
+            reps,
+            rpe: 8.0,
+            set_type: SetType::Weighted { weight },
+            kind: SetKind::Working,
+            comment: String::new(),
+        }
+    }
+
+    /// Linear progression adds one increment after a session where every
+    /// set hit the bottom of the rep range, and repeats the weight otherwise.
+    #[test]
+    fn test_apply_progression_linear() {
+        let mut session = weighted_session(5, Some(5));
+        session.exercise.progression = ProgressionStrategy::Linear;
+        let initial = session.predicted;
+
+        let hit = [working_set(100.0, 5), working_set(100.0, 5)];
+        let predicted = WorkoutStateManager::apply_progression(
+            initial,
+            &session.exercise,
+            &session.targets,
+            &hit,
+            None,
+        );
+        assert_eq!(predicted.weight, Some(102.5));
+        assert_eq!(predicted.reps, 5);
+
+        let missed = [working_set(100.0, 5), working_set(100.0, 4)];
+        let predicted = WorkoutStateManager::apply_progression(
+            initial,
+            &session.exercise,
+            &session.targets,
+            &missed,
+            None,
+        );
+        assert_eq!(predicted.weight, Some(100.0));
+    }
+
+    /// Double progression adds reps up to `max_reps`, then weight with reps
+    /// back at `min_reps`; Slot Targets replace the exercise's range.
+    #[test]
+    fn test_apply_progression_double() {
+        let mut session = weighted_session(8, Some(12));
+        session.exercise.progression = ProgressionStrategy::Double;
+        let initial = session.predicted;
+
+        let climbing = [working_set(40.0, 10), working_set(40.0, 9)];
+        let predicted = WorkoutStateManager::apply_progression(
+            initial,
+            &session.exercise,
+            &session.targets,
+            &climbing,
+            None,
+        );
+        assert_eq!((predicted.weight, predicted.reps), (Some(40.0), 10));
+
+        let topped = [working_set(40.0, 12), working_set(40.0, 12)];
+        let predicted = WorkoutStateManager::apply_progression(
+            initial,
+            &session.exercise,
+            &session.targets,
+            &topped,
+            None,
+        );
+        assert_eq!((predicted.weight, predicted.reps), (Some(42.5), 8));
+
+        let targets = SlotTargets {
+            rep_range: Some((6, Some(9))),
+            ..Default::default()
+        };
+        let predicted = WorkoutStateManager::apply_progression(
+            initial,
+            &session.exercise,
+            &targets,
+            &climbing,
+            None,
+        );
+        assert_eq!((predicted.weight, predicted.reps), (Some(42.5), 6));
+    }
+
+    /// Without a previous session, or on an autoregulated exercise, the
+    /// initial Suggestion is kept.
+    #[test]
+    fn test_apply_progression_keeps_initial_without_input() {
+        let mut session = weighted_session(5, Some(5));
+        let initial = session.predicted;
+        let sets = [working_set(100.0, 5)];
+        assert_eq!(
+            WorkoutStateManager::apply_progression(
+                initial,
+                &session.exercise,
+                &session.targets,
+                &sets,
+                Some(150.0),
+            ),
+            initial
+        );
+
+        session.exercise.progression = ProgressionStrategy::Linear;
+        assert_eq!(
+            WorkoutStateManager::apply_progression(
+                initial,
+                &session.exercise,
+                &session.targets,
+                &[],
+                None,
+            ),
+            initial
+        );
+    }
+
+    /// Linear and Double progression hold the session's prescription for
+    /// every working set, whatever the e1RM says.
+    #[test]
+    fn test_next_predictions_rule_holds_the_session_prescription() {
+        let best = Some(working_set(140.0, 5));
+        for strategy in [ProgressionStrategy::Linear, ProgressionStrategy::Double] {
+            let mut session = weighted_session(1, Some(10));
+            session.exercise.progression = strategy;
+            let predicted = WorkoutStateManager::calculate_next_predictions(
+                &session,
+                best.clone(),
+                best.clone(),
+                HashMap::new(),
+                &default_settings(),
+            );
+            assert_eq!(predicted, session.predicted);
+        }
+    }
+
+    /// Percentage of e1RM prescribes that share of the Blended e1RM for the
+    /// bottom of the rep range, snapped to the exercise's increment.
+    #[test]
+    fn test_next_predictions_percent_of_e1rm() {
+        use crate::domain::e1rm::E1rmModel;
+
+        let mut session = weighted_session(3, Some(6));
+        session.exercise.progression = ProgressionStrategy::PercentOfE1rm(80);
+        let best = Some(working_set(100.0, 5));
+        let settings = default_settings();
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            best.clone(),
+            best,
+            HashMap::new(),
+            &settings,
+        );
+
+        let e1rm = E1rmFormula::LinearRpe.e1rm(100.0, 5, 8.0);
+        let expected = (e1rm * 0.8 / 2.5).round() * 2.5;
+        assert_eq!(predicted.weight, Some(expected as f32));
+        assert_eq!(predicted.reps, 3);
+        assert!((predicted.rpe as f64 - settings.target_rpe).abs() < 1e-6);
+    }
+
+    /// Percentage of e1RM without any history falls back to the initial
+    /// Suggestion like the autoregulated path.
+    #[test]
+    fn test_next_predictions_percent_of_e1rm_no_data_fallback() {
+        let mut session = weighted_session(3, Some(6));
+        session.exercise.progression = ProgressionStrategy::PercentOfE1rm(80);
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            None,
+            None,
+            HashMap::new(),
+            &default_settings(),
+        );
+        assert_eq!(predicted.weight, Some(100.0));
+    }
+
+    // ── snap_to_plates ───────────────────────────────────────────────────────
+
+    fn plate_loaded_exercise() -> ExerciseMetadata {
+        ExerciseMetadata {
+            id: Some("e1".to_string()),
+            name: "Squat".to_string(),
+            set_type_config: SetTypeConfig::Weighted {
+                min_weight: 20.0,
+                increment: 2.5,
+                bar_weight: Some(20.0),
+            },
+            min_reps: 1,
+            max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
+        }
+    }
+
+    fn prediction(weight: f32) -> PredictedParameters {
+        PredictedParameters {
+            weight: Some(weight),
+            reps: 5,
+            rpe: 8.0,
+            reps_clamped: false,
+            measure: None,
+        }
+    }
+
+    /// With only 20 kg plates, a 97.5 kg suggestion snaps to the nearest
+    /// buildable load (100 kg) and keeps the other parameters.
+    #[test]
+    fn test_snap_to_plates_picks_nearest_buildable_load() {
+        let plates = vec![Plate {
+            id: None,
+            weight: 20.0,
+            count: 4,
+        }];
+        let predicted = WorkoutStateManager::snap_to_plates(
+            prediction(97.5),
+            &plate_loaded_exercise(),
+            &plates,
+        );
+        assert_eq!(predicted.weight, Some(100.0));
+        assert_eq!(predicted.reps, 5);
+    }
+
+    /// Exercises without a bar weight, or an empty inventory, leave the
+    /// suggestion untouched.
+    #[test]
+    fn test_snap_to_plates_noop_without_bar_or_inventory() {
+        let plates = vec![Plate {
+            id: None,
+            weight: 20.0,
+            count: 4,
+        }];
+        let mut not_loaded = plate_loaded_exercise();
+        not_loaded.set_type_config = SetTypeConfig::Weighted {
+            min_weight: 20.0,
+            increment: 2.5,
+            bar_weight: None,
+        };
+        let predicted = WorkoutStateManager::snap_to_plates(prediction(97.5), &not_loaded, &plates);
+        assert_eq!(predicted.weight, Some(97.5));
+
+        let predicted =
+            WorkoutStateManager::snap_to_plates(prediction(97.5), &plate_loaded_exercise(), &[]);
+        assert_eq!(predicted.weight, Some(97.5));
+    }
+
     // ── QA: No async in calculate_next_predictions (structural test) ──────────
 
     /// This test exercises the pure function directly without any DB calls,
@@ -1977,8 +3852,261 @@ mod tests {
             set_type_config: SetTypeConfig::Bodyweight,
             min_reps: 3,
             max_reps: Some(10),
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         };
-        let predicted = WorkoutStateManager::calculate_initial_predictions(&exercise, None, 10);
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &exercise,
+            None,
+            10,
+            &SlotTargets::default(),
+        );
         assert!(!predicted.reps_clamped);
     }
+
+    // ── Timed / distance / carry path ─────────────────────────────────────────
+
+    fn measured_session(config: SetTypeConfig, set_type: SetType) -> WorkoutSession {
+        WorkoutSession {
+            session_id: Some("s3".to_string()),
+            exercise: ExerciseMetadata {
+                id: Some("e3".to_string()),
+                name: "Measured".to_string(),
+                set_type_config: config,
+                min_reps: 1,
+                max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            },
+            completed_sets: vec![CompletedSet {
+                set_number: 1,
+                reps: 1,
+                rpe: 8.0,
+                set_type,
+                kind: SetKind::Working,
+                comment: String::new(),
+            }],
+            predicted: PredictedParameters {
+                weight: None,
+                reps: 1,
+                rpe: 8.0,
+                reps_clamped: false,
+                measure: None,
+            },
+            warmups: Vec::new(),
+            bodyweight: None,
+            targets: Default::default(),
+            rpe_calibration: None,
+        }
+    }
+
+    /// With no history a timed exercise starts at DEFAULT_MEASURE_STEPS
+    /// increments and a carry at its minimum weight.
+    #[test]
+    fn test_initial_predictions_measured_cold_start() {
+        let plank = measured_session(
+            SetTypeConfig::Timed { increment_secs: 5 },
+            SetType::Timed { duration_secs: 40 },
+        );
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &plank.exercise,
+            None,
+            10,
+            &SlotTargets::default(),
+        );
+        assert_eq!(predicted.weight, None);
+        assert_eq!(predicted.measure, Some(50.0));
+
+        let carry = measured_session(
+            SetTypeConfig::Carry {
+                min_weight: 16.0,
+                increment: 4.0,
+                increment_m: 5.0,
+            },
+            SetType::Carry {
+                weight: 32.0,
+                distance_m: 40.0,
+            },
+        );
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &carry.exercise,
+            None,
+            10,
+            &SlotTargets::default(),
+        );
+        assert_eq!(predicted.weight, Some(16.0));
+        assert_eq!(predicted.measure, Some(50.0));
+    }
+
+    /// Today 40 s @ RPE 8 (failure 50 s) and historical 55 s @ RPE 9 (failure
+    /// 60 s) blend to 55 s; at target RPE 8 that leaves 10 s in reserve → 45 s.
+    #[test]
+    fn test_next_predictions_timed_blends_failure_measure() {
+        let session = measured_session(
+            SetTypeConfig::Timed { increment_secs: 5 },
+            SetType::Timed { duration_secs: 40 },
+        );
+        let today = session.completed_sets[0].clone();
+        let historical = CompletedSet {
+            set_number: 1,
+            reps: 1,
+            rpe: 9.0,
+            set_type: SetType::Timed { duration_secs: 55 },
+            kind: SetKind::Working,
+            comment: String::new(),
+        };
+
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            Some(historical),
+            Some(today),
+            HashMap::new(),
+            &default_settings(),
+        );
+
+        assert_eq!(predicted.weight, None);
+        assert_eq!(predicted.reps, 1);
+        assert_eq!(predicted.measure, Some(45.0));
+    }
+
+    /// A carry progresses the distance and keeps the weight of the latest set.
+    #[test]
+    fn test_next_predictions_carry_keeps_latest_weight() {
+        let session = measured_session(
+            SetTypeConfig::Carry {
+                min_weight: 16.0,
+                increment: 4.0,
+                increment_m: 5.0,
+            },
+            SetType::Carry {
+                weight: 32.0,
+                distance_m: 40.0,
+            },
+        );
+        let today = session.completed_sets[0].clone();
+
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            None,
+            Some(today),
+            HashMap::new(),
+            &default_settings(),
+        );
+
+        assert_eq!(predicted.weight, Some(32.0));
+        assert_eq!(predicted.measure, Some(40.0));
+    }
+
+    // ── Loaded bodyweight path ────────────────────────────────────────────────
+
+    fn loaded_session(assisted: bool, bodyweight: f32, added: f32) -> WorkoutSession {
+        WorkoutSession {
+            session_id: Some("s4".to_string()),
+            exercise: ExerciseMetadata {
+                id: Some("e4".to_string()),
+                name: "Pull-up".to_string(),
+                set_type_config: SetTypeConfig::LoadedBodyweight {
+                    increment: 2.5,
+                    assisted,
+                },
+                min_reps: 5,
+                max_reps: Some(5),
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
+            },
+            completed_sets: vec![CompletedSet {
+                set_number: 1,
+                reps: 5,
+                rpe: 8.0,
+                set_type: SetType::LoadedBodyweight { bodyweight, added },
+                kind: SetKind::Working,
+                comment: String::new(),
+            }],
+            predicted: PredictedParameters {
+                weight: None,
+                reps: 5,
+                rpe: 8.0,
+                reps_clamped: false,
+                measure: None,
+            },
+            warmups: Vec::new(),
+            bodyweight: Some(bodyweight),
+            targets: Default::default(),
+            rpe_calibration: None,
+        }
+    }
+
+    /// The dial starts at the magnitude of the last added or assisting weight.
+    #[test]
+    fn test_initial_predictions_loaded_bodyweight_repeats_last_magnitude() {
+        let session = loaded_session(true, 80.0, -20.0);
+        let predicted = WorkoutStateManager::calculate_initial_predictions(
+            &session.exercise,
+            session.completed_sets.last(),
+            10,
+            &SlotTargets::default(),
+        );
+        assert_eq!(predicted.weight, Some(20.0));
+        assert_eq!(predicted.reps, DEFAULT_WEIGHTED_REPS);
+
+        let cold = WorkoutStateManager::calculate_initial_predictions(
+            &session.exercise,
+            None,
+            10,
+            &SlotTargets::default(),
+        );
+        assert_eq!(cold.weight, Some(0.0));
+    }
+
+    /// 5 reps @ RPE 8 at 80 kg + 20 kg predicts the same 100 kg System Load
+    /// again; gaining 5 kg of bodyweight leaves 5 kg less to add.
+    #[test]
+    fn test_next_predictions_loaded_bodyweight_tracks_system_load() {
+        let session = loaded_session(false, 80.0, 20.0);
+        let today = session.completed_sets[0].clone();
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            None,
+            Some(today.clone()),
+            HashMap::new(),
+            &default_settings(),
+        );
+        assert_eq!(predicted.weight, Some(20.0));
+        assert_eq!(predicted.reps, 5);
+
+        let mut heavier = session.clone();
+        heavier.bodyweight = Some(85.0);
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &heavier,
+            None,
+            Some(today),
+            HashMap::new(),
+            &default_settings(),
+        );
+        assert_eq!(predicted.weight, Some(15.0));
+    }
+
+    /// Assistance is the gap between bodyweight and the projected System Load.
+    #[test]
+    fn test_next_predictions_assisted_bodyweight_suggests_assistance() {
+        let session = loaded_session(true, 80.0, -20.0);
+        let today = session.completed_sets[0].clone();
+        let predicted = WorkoutStateManager::calculate_next_predictions(
+            &session,
+            None,
+            Some(today),
+            HashMap::new(),
+            &default_settings(),
+        );
+        assert_eq!(predicted.weight, Some(20.0));
+    }
 }
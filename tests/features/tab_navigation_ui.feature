@@ -24,11 +24,11 @@ Feature: Tab Navigation UI
     And I should see the Workout interface
 
   @e2e
-  Scenario: User can click Analysis tab and see history
+  Scenario: User can click Analysis tab and see the dashboard
     Given the app is loaded
     When I click on the "Analysis" tab
     Then the "Analysis" tab should be active
-    And I should see the Analysis history view
+    And I should see the Analysis dashboard
 
   @unit
   Scenario: Tab active state indication
@@ -90,6 +90,7 @@ async fn following_exercises_exist(world: &mut ExerciseListWorld, step: &cucumbe
                     SetTypeConfig::Weighted {
                         min_weight: 0.0,
                         increment: 2.5,
+                        bar_weight: None,
                     }
                 } else {
                     SetTypeConfig::Bodyweight
@@ -101,6 +102,11 @@ async fn following_exercises_exist(world: &mut ExerciseListWorld, step: &cucumbe
                     set_type_config: config,
                     min_reps: 1,
                     max_reps: None,
+                    notes: String::new(),
+                    rest_secs: None,
+                    e1rm_formula: None,
+                    progression: Default::default(),
+                    weight_unit: Default::default(),
                 });
             }
         }
@@ -154,9 +160,15 @@ async fn database_contains_standard_exercises(world: &mut ExerciseListWorld) {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     });
     world.exercises.push(ExerciseMetadata {
         id: None,
@@ -164,6 +176,11 @@ async fn database_contains_standard_exercises(world: &mut ExerciseListWorld) {
         set_type_config: SetTypeConfig::Bodyweight,
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     });
 }
 
@@ -78,6 +78,7 @@ async fn following_exercises_exist(
                     SetTypeConfig::Weighted {
                         min_weight: 0.0,
                         increment: 2.5,
+                        bar_weight: None,
                     }
                 } else {
                     SetTypeConfig::Bodyweight
@@ -89,6 +90,11 @@ async fn following_exercises_exist(
                     set_type_config: config,
                     min_reps: 1,
                     max_reps: None,
+                    notes: String::new(),
+                    rest_secs: None,
+                    e1rm_formula: None,
+                    progression: Default::default(),
+                    weight_unit: Default::default(),
                 });
             }
         }
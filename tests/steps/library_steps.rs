@@ -100,9 +100,15 @@ async fn step_save_exercise(world: &mut LibraryWorld) {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     });
     world.render_component();
 }
@@ -133,9 +139,15 @@ async fn step_exercise_exists(world: &mut LibraryWorld, name: String) {
         set_type_config: SetTypeConfig::Weighted {
             min_weight: 20.0,
             increment: 2.5,
+            bar_weight: None,
         },
         min_reps: 1,
         max_reps: None,
+        notes: String::new(),
+        rest_secs: None,
+        e1rm_formula: None,
+        progression: Default::default(),
+        weight_unit: Default::default(),
     });
 }
 
@@ -156,6 +168,7 @@ async fn step_save_changes(world: &mut LibraryWorld) {
         ex.set_type_config = SetTypeConfig::Weighted {
             min_weight: 60.0,
             increment: 2.5,
+            bar_weight: None,
         };
     }
     world.render_component();
@@ -3,7 +3,7 @@ use dioxus::prelude::*;
 use dioxus_history::MemoryHistory;
 use simple_strength_assistant::app::{Route, TabNavigationState};
 use simple_strength_assistant::components::tab_bar::Tab;
-use simple_strength_assistant::models::{ExerciseMetadata, PlanExercise, SetTypeConfig};
+use simple_strength_assistant::models::{ExerciseMetadata, PlanExercise, SetKind, SetTypeConfig};
 use simple_strength_assistant::state::{PredictedParameters, WorkoutSession, WorkoutState};
 
 #[derive(Debug, Default, World)]
@@ -78,9 +78,15 @@ async fn step_click_start(world: &mut WorkoutWorld) {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         },
         completed_sets: Vec::new(),
         predicted: PredictedParameters {
@@ -88,7 +94,12 @@ async fn step_click_start(world: &mut WorkoutWorld) {
             reps: 8,
             rpe: 7.0,
             reps_clamped: false,
+            measure: None,
         },
+        warmups: Vec::new(),
+        bodyweight: None,
+        targets: Default::default(),
+        rpe_calibration: None,
     });
     world.active_tab = Tab::Workout;
     world.render_component();
@@ -175,22 +186,35 @@ async fn step_active_session_with_sets(world: &mut WorkoutWorld, exercise_name:
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         },
         completed_sets: vec![simple_strength_assistant::models::CompletedSet {
             set_number: 1,
             reps: 5,
             rpe: 7.0,
             set_type: simple_strength_assistant::models::SetType::Weighted { weight: 100.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         }],
         predicted: PredictedParameters {
             weight: Some(100.0),
             reps: 8,
             rpe: 7.0,
             reps_clamped: false,
+            measure: None,
         },
+        warmups: Vec::new(),
+        bodyweight: None,
+        targets: Default::default(),
+        rpe_calibration: None,
     });
     world.has_active_plan = true;
 }
@@ -206,9 +230,15 @@ async fn step_switch_exercise(world: &mut WorkoutWorld, exercise_name: String) {
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 2.5,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         },
         completed_sets: Vec::new(),
         predicted: PredictedParameters {
@@ -216,7 +246,12 @@ async fn step_switch_exercise(world: &mut WorkoutWorld, exercise_name: String) {
             reps: 8,
             rpe: 7.0,
             reps_clamped: false,
+            measure: None,
         },
+        warmups: Vec::new(),
+        bodyweight: None,
+        targets: Default::default(),
+        rpe_calibration: None,
     });
     world.active_tab = Tab::Workout;
     world.render_component();
@@ -324,9 +359,15 @@ async fn step_start_plan(world: &mut WorkoutWorld) {
                 set_type_config: SetTypeConfig::Weighted {
                     min_weight: 0.0,
                     increment: 2.5,
+                    bar_weight: None,
                 },
                 min_reps: 1,
                 max_reps: None,
+                notes: String::new(),
+                rest_secs: None,
+                e1rm_formula: None,
+                progression: Default::default(),
+                weight_unit: Default::default(),
             },
             completed_sets: Vec::new(),
             predicted: PredictedParameters {
@@ -334,7 +375,12 @@ async fn step_start_plan(world: &mut WorkoutWorld) {
                 reps: 8,
                 rpe: 7.0,
                 reps_clamped: false,
+                measure: None,
             },
+            warmups: Vec::new(),
+            bodyweight: None,
+            targets: Default::default(),
+            rpe_calibration: None,
         });
     }
 }
@@ -381,9 +427,15 @@ async fn step_start_exercise_from_library(world: &mut WorkoutWorld, exercise_nam
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         },
         completed_sets: Vec::new(),
         predicted: PredictedParameters {
@@ -391,7 +443,12 @@ async fn step_start_exercise_from_library(world: &mut WorkoutWorld, exercise_nam
             reps: 8,
             rpe: 7.0,
             reps_clamped: false,
+            measure: None,
         },
+        warmups: Vec::new(),
+        bodyweight: None,
+        targets: Default::default(),
+        rpe_calibration: None,
     });
     world.active_tab = Tab::Workout;
 }
@@ -449,12 +506,20 @@ async fn step_exercise_tab_with_counts(world: &mut WorkoutWorld, completed: u32,
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         },
         planned_sets: planned,
         position: 0,
+        group_id: None,
+        targets: Default::default(),
     }];
 
     let completed_counts = vec![completed];
@@ -581,6 +646,8 @@ async fn step_started_plan_with_sets(
             reps: 5,
             rpe: 7.0,
             set_type: simple_strength_assistant::models::SetType::Weighted { weight: 80.0 },
+            kind: SetKind::Working,
+            comment: String::new(),
         });
     }
     world.current_session = Some(WorkoutSession {
@@ -591,9 +658,15 @@ async fn step_started_plan_with_sets(
             set_type_config: SetTypeConfig::Weighted {
                 min_weight: 0.0,
                 increment: 5.0,
+                bar_weight: None,
             },
             min_reps: 1,
             max_reps: None,
+            notes: String::new(),
+            rest_secs: None,
+            e1rm_formula: None,
+            progression: Default::default(),
+            weight_unit: Default::default(),
         },
         completed_sets,
         predicted: PredictedParameters {
@@ -601,7 +674,12 @@ async fn step_started_plan_with_sets(
             reps: 5,
             rpe: 7.0,
             reps_clamped: false,
+            measure: None,
         },
+        warmups: Vec::new(),
+        bodyweight: None,
+        targets: Default::default(),
+        rpe_calibration: None,
     });
     world.has_active_plan = true;
     world.planned_exercises = vec![exercise_name];
@@ -159,11 +159,11 @@ async fn should_see_library_placeholder(world: &mut TabNavigationWorld) {
     );
 }
 
-#[then("I should see the Analysis history view")]
-async fn should_see_analysis_history(world: &mut TabNavigationWorld) {
+#[then("I should see the Analysis dashboard")]
+async fn should_see_analysis_dashboard(world: &mut TabNavigationWorld) {
     assert_eq!(
         world.active_tab, "Analysis",
-        "Should be viewing Analysis tab to see history view"
+        "Should be viewing Analysis tab to see the dashboard"
     );
 }
 